@@ -175,6 +175,22 @@ fn load_diabetes_data_rows(rows: usize) -> (Vec<f64>, Vec<f64>) {
     (x, y)
 }
 
+fn load_diabetes_numeric_columns(rows: usize) -> Vec<Vec<f64>> {
+    // Load the numeric columns of the first N rows of diabetes-prediction.csv:
+    // age, hypertension, heart_disease, bmi, HbA1c_level, blood_glucose_level, diabetes
+    let path = "../../data/diabetes-prediction.csv";
+    let contents = fs::read_to_string(path).expect("Failed to read diabetes-prediction.csv");
+
+    let mut columns: Vec<Vec<f64>> = (0..7).map(|_| Vec::with_capacity(rows)).collect();
+    for line in contents.lines().skip(1).take(rows) {
+        let fields: Vec<&str> = line.split(',').collect();
+        for (column, &field) in columns.iter_mut().zip(&[1, 2, 3, 5, 6, 7, 8]) {
+            column.push(fields[field].parse::<f64>().unwrap_or(f64::NAN));
+        }
+    }
+    columns
+}
+
 fn bench_sum(c: &mut Criterion) {
     let data = generate_data(1_000_000);
     c.bench_function("sum 1M", |b| b.iter(|| sum(black_box(&data))));
@@ -267,6 +283,36 @@ fn bench_corrcoeff(c: &mut Criterion) {
     });
 }
 
+fn bench_correlation_matrix(c: &mut Criterion) {
+    let columns = load_diabetes_numeric_columns(100_000);
+    let refs: Vec<&[f64]> = columns.iter().map(|c| c.as_slice()).collect();
+
+    c.bench_function("correlation_matrix pearson 100K x 7 (diabetes)", |b| {
+        b.iter(|| correlation_matrix(black_box(&refs), CorrelationMethod::Pearson))
+    });
+
+    c.bench_function("corrcoeff pairwise 100K x 7 (diabetes)", |b| {
+        b.iter(|| {
+            let p = refs.len();
+            let mut out = vec![0.0; p * p];
+            for i in 0..p {
+                for j in 0..p {
+                    out[i * p + j] = corrcoeff(black_box(refs[i]), black_box(refs[j]));
+                }
+            }
+            out
+        })
+    });
+
+    c.bench_function("correlation_matrix spearman 100K x 7 (diabetes)", |b| {
+        b.iter(|| correlation_matrix(black_box(&refs), CorrelationMethod::Spearman))
+    });
+
+    c.bench_function("correlation_matrix kendall 100K x 7 (diabetes)", |b| {
+        b.iter(|| correlation_matrix(black_box(&refs), CorrelationMethod::Kendall))
+    });
+}
+
 fn bench_cumsum(c: &mut Criterion) {
     let data = generate_data(1_000_000);
     c.bench_function("cumsum 1M", |b| b.iter(|| cumsum(black_box(&data))));
@@ -428,6 +474,7 @@ criterion_group!(
     bench_range,
     bench_covariance,
     bench_corrcoeff,
+    bench_correlation_matrix,
     bench_cumsum,
     bench_diff,
     bench_rank,
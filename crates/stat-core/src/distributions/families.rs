@@ -0,0 +1,2530 @@
+use super::traits::{ContinuousDistribution, DiscreteDistribution};
+use super::{ensure_probability, invalid_params, invert_cdf, std_normal_cdf, DistributionError};
+use crate::random::RandomSource;
+use statrs::distribution::{self as sd, Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+use statrs::function::beta::{beta_reg, ln_beta};
+use statrs::function::erf::{erfc, erfc_inv};
+use statrs::function::gamma::{gamma, gamma_lr, gamma_ur, ln_gamma};
+use statrs::statistics::{
+    DiscreteDistribution as StatrsDiscreteMoments, Distribution as StatrsMoments, Max, Min,
+};
+use std::f64::consts::{LN_2, PI, SQRT_2};
+
+/// Internal: define a continuous family as a validated wrapper around a statrs distribution.
+///
+/// Generates the struct, a `new` constructor taking the listed parameters, one accessor per
+/// parameter, and a `ContinuousDistribution` impl that delegates to statrs and samples with
+/// the family's inherent `draw` method.
+///
+/// Optional `cdf = .., sf = ..` closures replace the statrs tail functions, and a trailing
+/// `; { .. }` block adds trait items such as closed-form `ln_sf` or `inverse_sf` overrides.
+macro_rules! continuous_family {
+    (@tail $method:ident) => {
+        #[inline]
+        fn $method(&self, x: f64) -> f64 {
+            self.inner.$method(x)
+        }
+    };
+    (@tail $method:ident, $body:expr) => {
+        #[inline]
+        fn $method(&self, x: f64) -> f64 {
+            ($body)(self, x)
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $name:ident($($param:ident),+) => $ctor:expr
+        $(, cdf = $cdf:expr, sf = $sf:expr)?
+        $(; { $($extra:tt)* })?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            $($param: f64,)+
+            inner: sd::$name,
+        }
+
+        impl $name {
+            pub fn new($($param: f64),+) -> Result<Self, DistributionError> {
+                #[allow(clippy::redundant_closure_call)]
+                let inner = ($ctor)($($param),+).map_err(invalid_params)?;
+                Ok(Self { $($param,)+ inner })
+            }
+
+            $(
+                #[inline]
+                pub fn $param(&self) -> f64 {
+                    self.$param
+                }
+            )+
+        }
+
+        impl ContinuousDistribution for $name {
+            #[inline]
+            fn pdf(&self, x: f64) -> f64 {
+                self.inner.pdf(x)
+            }
+
+            #[inline]
+            fn ln_pdf(&self, x: f64) -> f64 {
+                self.inner.ln_pdf(x)
+            }
+
+            continuous_family!(@tail cdf $(, $cdf)?);
+
+            continuous_family!(@tail sf $(, $sf)?);
+
+            fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+                ensure_probability(p)?;
+                Ok(self.inner.inverse_cdf(p))
+            }
+
+            fn mean(&self) -> f64 {
+                StatrsMoments::mean(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn variance(&self) -> f64 {
+                StatrsMoments::variance(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn skewness(&self) -> f64 {
+                StatrsMoments::skewness(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn entropy(&self) -> f64 {
+                StatrsMoments::entropy(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn support(&self) -> (f64, f64) {
+                (Min::min(&self.inner), Max::max(&self.inner))
+            }
+
+            #[inline]
+            fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+                self.draw(rng)
+            }
+
+            $($($extra)*)?
+        }
+    };
+}
+
+// =============================================================================
+// Tail Helpers
+// =============================================================================
+
+/// Internal: `ln(1 - exp(a))` for `a <= 0`, accurate at both ends (Mächler 2012).
+#[inline]
+fn ln1m_exp(a: f64) -> f64 {
+    if a > -LN_2 {
+        (-a.exp_m1()).ln()
+    } else {
+        (-a.exp()).ln_1p()
+    }
+}
+
+/// Internal: log upper-tail probability of the standard normal.
+///
+/// Uses `erfc` until it underflows, then the asymptotic series of the Mills ratio.
+fn ln_std_normal_sf(z: f64) -> f64 {
+    if z < 0.0 {
+        (-0.5 * erfc(-z / SQRT_2)).ln_1p()
+    } else if z < 35.0 {
+        (0.5 * erfc(z / SQRT_2)).ln()
+    } else {
+        let r = 1.0 / (z * z);
+        let series = 1.0 - r * (1.0 - 3.0 * r * (1.0 - 5.0 * r * (1.0 - 7.0 * r)));
+        -0.5 * z * z - 0.5 * (2.0 * PI).ln() - z.ln() + series.ln()
+    }
+}
+
+/// Internal: standard normal `z` with upper-tail probability `p`.
+#[inline]
+fn std_normal_isf(p: f64) -> f64 {
+    SQRT_2 * erfc_inv(2.0 * p)
+}
+
+// =============================================================================
+// Continuous Families
+// =============================================================================
+
+continuous_family!(
+    /// Normal distribution with mean `mean` and standard deviation `sd > 0`.
+    Normal(mean, sd) => sd::Normal::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            ln_std_normal_sf((self.mean - x) / self.sd)
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            ln_std_normal_sf((x - self.mean) / self.sd)
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(self.mean + self.sd * std_normal_isf(p))
+        }
+    }
+);
+
+impl Normal {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.mean + self.sd * rng.standard_normal()
+    }
+}
+
+continuous_family!(
+    /// Gamma distribution with `shape > 0` and `rate > 0`.
+    Gamma(shape, rate) => sd::Gamma::new
+);
+
+impl Gamma {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        rng.standard_gamma(self.shape) / self.rate
+    }
+}
+
+continuous_family!(
+    /// Beta distribution on `[0, 1]` with shapes `alpha > 0` and `beta > 0`.
+    Beta(alpha, beta) => sd::Beta::new
+);
+
+impl Beta {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        rng.beta(self.alpha, self.beta)
+    }
+}
+
+continuous_family!(
+    /// Location-scale Student's t distribution with `dof > 0` degrees of freedom.
+    StudentsT(location, scale, dof) => sd::StudentsT::new;
+    {
+        /// Uses symmetry about `location`, so the upper tail is as accurate as the lower.
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            Ok(2.0 * self.location - self.inverse_cdf(p)?)
+        }
+    }
+);
+
+impl StudentsT {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let z = rng.standard_normal();
+        if self.dof.is_infinite() {
+            return self.location + self.scale * z;
+        }
+        let chi2 = 2.0 * rng.standard_gamma(0.5 * self.dof);
+        self.location + self.scale * z / (chi2 / self.dof).sqrt()
+    }
+}
+
+continuous_family!(
+    /// Chi-squared distribution with `dof > 0` degrees of freedom.
+    ChiSquared(dof) => sd::ChiSquared::new
+);
+
+impl ChiSquared {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        2.0 * rng.standard_gamma(0.5 * self.dof)
+    }
+}
+
+continuous_family!(
+    /// Fisher-Snedecor F distribution with `df1 > 0` and `df2 > 0` degrees of freedom.
+    FisherSnedecor(df1, df2) => sd::FisherSnedecor::new
+);
+
+impl FisherSnedecor {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let num = 2.0 * rng.standard_gamma(0.5 * self.df1) / self.df1;
+        let den = 2.0 * rng.standard_gamma(0.5 * self.df2) / self.df2;
+        num / den
+    }
+}
+
+continuous_family!(
+    /// Exponential distribution with `rate > 0`.
+    Exp(rate) => sd::Exp::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            ln1m_exp(self.ln_sf(x))
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            if x < 0.0 {
+                0.0
+            } else {
+                -self.rate * x
+            }
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(-p.ln() / self.rate)
+        }
+    }
+);
+
+impl Exp {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        rng.standard_exponential() / self.rate
+    }
+}
+
+continuous_family!(
+    /// Continuous uniform distribution on `[min, max]`.
+    Uniform(min, max) => sd::Uniform::new;
+    {
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(self.max - p * (self.max - self.min))
+        }
+    }
+);
+
+impl Uniform {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.min + (self.max - self.min) * rng.next_f64()
+    }
+}
+
+continuous_family!(
+    /// Cauchy distribution with `location` and `scale > 0`.
+    Cauchy(location, scale) => sd::Cauchy::new,
+    cdf = |d: &Cauchy, x: f64| d.scale.atan2(d.location - x) / PI,
+    sf = |d: &Cauchy, x: f64| d.scale.atan2(x - d.location) / PI;
+    {
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(match p {
+                0.0 => f64::INFINITY,
+                1.0 => f64::NEG_INFINITY,
+                _ => self.location + self.scale / (PI * p).tan(),
+            })
+        }
+    }
+);
+
+impl Cauchy {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let u = rng.next_open_f64() - 0.5;
+        self.location + self.scale * (PI * u).tan()
+    }
+}
+
+continuous_family!(
+    /// Laplace (double exponential) distribution with `location` and `scale > 0`.
+    Laplace(location, scale) => sd::Laplace::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            let t = (x - self.location) / self.scale;
+            if t <= 0.0 {
+                t - LN_2
+            } else {
+                (-0.5 * (-t).exp()).ln_1p()
+            }
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            let t = (x - self.location) / self.scale;
+            if t >= 0.0 {
+                -t - LN_2
+            } else {
+                (-0.5 * t.exp()).ln_1p()
+            }
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(if p <= 0.5 {
+                self.location - self.scale * (2.0 * p).ln()
+            } else {
+                self.location + self.scale * (2.0 * (1.0 - p)).ln()
+            })
+        }
+    }
+);
+
+impl Laplace {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let u = rng.next_open_f64() - 0.5;
+        self.location - self.scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+    }
+}
+
+continuous_family!(
+    /// Log-normal distribution: `ln X ~ Normal(location, scale)`.
+    LogNormal(location, scale) => sd::LogNormal::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            if x <= 0.0 {
+                return f64::NEG_INFINITY;
+            }
+            ln_std_normal_sf((self.location - x.ln()) / self.scale)
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            if x <= 0.0 {
+                return 0.0;
+            }
+            ln_std_normal_sf((x.ln() - self.location) / self.scale)
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok((self.location + self.scale * std_normal_isf(p)).exp())
+        }
+    }
+);
+
+impl LogNormal {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        (self.location + self.scale * rng.standard_normal()).exp()
+    }
+}
+
+continuous_family!(
+    /// Weibull distribution with `shape > 0` and `scale > 0`.
+    Weibull(shape, scale) => sd::Weibull::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            ln1m_exp(self.ln_sf(x))
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            if x < 0.0 {
+                0.0
+            } else {
+                -(x / self.scale).powf(self.shape)
+            }
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(self.scale * (-p.ln()).powf(1.0 / self.shape))
+        }
+    }
+);
+
+impl Weibull {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.scale * rng.standard_exponential().powf(1.0 / self.shape)
+    }
+}
+
+continuous_family!(
+    /// Pareto (type I) distribution on `[scale, inf)` with `shape > 0`.
+    Pareto(scale, shape) => sd::Pareto::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            ln1m_exp(self.ln_sf(x))
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            if x < self.scale {
+                0.0
+            } else {
+                self.shape * (self.scale / x).ln()
+            }
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(self.scale * p.powf(-1.0 / self.shape))
+        }
+    }
+);
+
+impl Pareto {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.scale * rng.next_open_f64().powf(-1.0 / self.shape)
+    }
+}
+
+continuous_family!(
+    /// Triangular distribution on `[min, max]` with peak at `mode`.
+    Triangular(min, max, mode) => sd::Triangular::new;
+    {
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            let (min, max, mode) = (self.min, self.max, self.mode);
+            if p <= (max - mode) / (max - min) {
+                Ok(max - (p * (max - min) * (max - mode)).sqrt())
+            } else {
+                self.inverse_cdf(1.0 - p)
+            }
+        }
+    }
+);
+
+impl Triangular {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let (min, max, mode) = (self.min, self.max, self.mode);
+        let u = rng.next_f64();
+        if u < (mode - min) / (max - min) {
+            min + (u * (max - min) * (mode - min)).sqrt()
+        } else {
+            max - ((1.0 - u) * (max - min) * (max - mode)).sqrt()
+        }
+    }
+}
+
+continuous_family!(
+    /// Inverse gamma distribution with `shape > 0` and `rate > 0`.
+    InverseGamma(shape, rate) => sd::InverseGamma::new
+);
+
+impl InverseGamma {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.rate / rng.standard_gamma(self.shape)
+    }
+}
+
+continuous_family!(
+    /// Gumbel (type I extreme value) distribution for maxima with `location` and `scale > 0`.
+    Gumbel(location, scale) => sd::Gumbel::new;
+    {
+        fn ln_cdf(&self, x: f64) -> f64 {
+            -(-(x - self.location) / self.scale).exp()
+        }
+
+        fn ln_sf(&self, x: f64) -> f64 {
+            ln1m_exp(self.ln_cdf(x))
+        }
+
+        fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+            ensure_probability(p)?;
+            Ok(self.location - self.scale * (-(-p).ln_1p()).ln())
+        }
+    }
+);
+
+impl Gumbel {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.location - self.scale * rng.standard_exponential().ln()
+    }
+}
+
+continuous_family!(
+    /// Erlang distribution: a gamma distribution with integer `shape >= 1` and `rate > 0`.
+    Erlang(shape, rate) => |shape: f64, rate: f64| {
+        if !(shape >= 1.0 && shape.fract() == 0.0) {
+            return Err(format!("Erlang shape must be a positive integer; received {shape}"));
+        }
+        sd::Erlang::new(shape as u64, rate).map_err(|e| e.to_string())
+    }
+);
+
+impl Erlang {
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        rng.standard_gamma(self.shape) / self.rate
+    }
+}
+
+// =============================================================================
+// Closed-Form Continuous Families
+// =============================================================================
+
+/// Internal: `InvalidParameters` unless `ok`, with the message built lazily.
+fn require(ok: bool, msg: impl FnOnce() -> String) -> Result<(), DistributionError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(DistributionError::InvalidParameters(msg()))
+    }
+}
+
+/// Internal: `ln(1 + exp(a))` without overflow.
+#[inline]
+fn softplus(a: f64) -> f64 {
+    a.max(0.0) + (-a.abs()).exp().ln_1p()
+}
+
+/// Internal: standard normal log-density.
+#[inline]
+fn ln_std_normal_pdf(z: f64) -> f64 {
+    -0.5 * z * z - 0.5 * (2.0 * PI).ln()
+}
+
+/// Gauss-Legendre nodes and weights on `[-1, 1]` (positive half; symmetric).
+const GL10_X: [f64; 5] = [
+    0.14887433898163122,
+    0.4333953941292472,
+    0.6794095682990244,
+    0.8650633666889845,
+    0.9739065285171717,
+];
+const GL10_W: [f64; 5] = [
+    0.29552422471475287,
+    0.26926671930999635,
+    0.21908636251598204,
+    0.1494513491505806,
+    0.06667134430868814,
+];
+
+/// Internal: integral of `f` over `[a, b]` by composite 10-point Gauss-Legendre on `panels`
+/// equal panels.
+fn gauss_legendre<F: Fn(f64) -> f64>(a: f64, b: f64, panels: usize, f: F) -> f64 {
+    let half = 0.5 * (b - a) / panels as f64;
+    let mut sum = 0.0;
+    for i in 0..panels {
+        let mid = a + (2 * i + 1) as f64 * half;
+        for (x, w) in GL10_X.iter().zip(GL10_W.iter()) {
+            sum += w * (f(mid - half * x) + f(mid + half * x));
+        }
+    }
+    sum * half
+}
+
+const EULER_GAMMA: f64 = 0.5772156649015329;
+
+/// Generalized extreme value distribution with `location`, `scale > 0` and `shape` (xi).
+///
+/// `shape > 0` is the Fréchet type (bounded below), `shape < 0` the reversed Weibull type
+/// (bounded above) and `shape == 0` the Gumbel distribution. Moments that are infinite for
+/// the given shape are returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gev {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl Gev {
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, DistributionError> {
+        require(
+            location.is_finite() && scale > 0.0 && scale.is_finite() && shape.is_finite(),
+            || {
+                format!(
+                    "GEV requires finite location and shape and a positive finite scale; \
+                     received location={location}, scale={scale}, shape={shape}"
+                )
+            },
+        )?;
+        Ok(Self {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    #[inline]
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Internal: `ln t(x)`, where `cdf = exp(-t)`; `None` outside the support.
+    fn ln_t(&self, x: f64) -> Option<f64> {
+        let z = (x - self.location) / self.scale;
+        if self.shape == 0.0 {
+            return Some(-z);
+        }
+        let arg = self.shape * z;
+        if arg <= -1.0 {
+            return None;
+        }
+        Some(-arg.ln_1p() / self.shape)
+    }
+
+    /// Internal: the `x` with `t(x) = t`.
+    fn x_at(&self, t: f64) -> f64 {
+        let ln_t = t.ln();
+        if self.shape == 0.0 {
+            self.location - self.scale * ln_t
+        } else {
+            self.location + self.scale * (-self.shape * ln_t).exp_m1() / self.shape
+        }
+    }
+
+    /// Internal: `Gamma(1 - k xi)` for the moment formulas.
+    #[inline]
+    fn g(&self, k: f64) -> f64 {
+        gamma(1.0 - k * self.shape)
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.x_at(rng.standard_exponential())
+    }
+}
+
+impl ContinuousDistribution for Gev {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        match self.ln_t(x) {
+            Some(ln_t) if ln_t.is_finite() => {
+                (self.shape + 1.0) * ln_t - ln_t.exp() - self.scale.ln()
+            }
+            _ => f64::NEG_INFINITY,
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.ln_cdf(x).exp()
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        match self.ln_t(x) {
+            Some(ln_t) => -ln_t.exp(),
+            // Below the lower end (xi > 0) or above the upper end (xi < 0).
+            None if self.shape > 0.0 => f64::NEG_INFINITY,
+            None => 0.0,
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        -self.ln_cdf(x).exp_m1()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        ln1m_exp(self.ln_cdf(x))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.x_at(-p.ln()))
+    }
+
+    fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.x_at(-(-p).ln_1p()))
+    }
+
+    fn mean(&self) -> f64 {
+        match self.shape {
+            0.0 => self.location + self.scale * EULER_GAMMA,
+            xi if xi < 1.0 => self.location + self.scale * (self.g(1.0) - 1.0) / xi,
+            _ => f64::NAN,
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        match self.shape {
+            0.0 => self.scale * self.scale * PI * PI / 6.0,
+            xi if xi < 0.5 => {
+                let (g1, g2) = (self.g(1.0), self.g(2.0));
+                self.scale * self.scale * (g2 - g1 * g1) / (xi * xi)
+            }
+            _ => f64::NAN,
+        }
+    }
+
+    fn skewness(&self) -> f64 {
+        match self.shape {
+            // 12 sqrt(6) zeta(3) / pi^3
+            0.0 => 1.1395470994046486,
+            xi if xi < 1.0 / 3.0 => {
+                let (g1, g2, g3) = (self.g(1.0), self.g(2.0), self.g(3.0));
+                xi.signum() * (g3 - 3.0 * g1 * g2 + 2.0 * g1.powi(3)) / (g2 - g1 * g1).powf(1.5)
+            }
+            _ => f64::NAN,
+        }
+    }
+
+    fn entropy(&self) -> f64 {
+        self.scale.ln() + EULER_GAMMA * self.shape + EULER_GAMMA + 1.0
+    }
+
+    fn support(&self) -> (f64, f64) {
+        let end = self.location - self.scale / self.shape;
+        match self.shape {
+            xi if xi > 0.0 => (end, f64::INFINITY),
+            xi if xi < 0.0 => (f64::NEG_INFINITY, end),
+            _ => (f64::NEG_INFINITY, f64::INFINITY),
+        }
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Logistic distribution with `location` and `scale > 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    pub fn new(location: f64, scale: f64) -> Result<Self, DistributionError> {
+        require(
+            location.is_finite() && scale > 0.0 && scale.is_finite(),
+            || {
+                format!(
+                    "logistic requires a finite location and a positive finite scale; \
+                     received location={location}, scale={scale}"
+                )
+            },
+        )?;
+        Ok(Self { location, scale })
+    }
+
+    #[inline]
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    fn z(&self, x: f64) -> f64 {
+        (x - self.location) / self.scale
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let u = rng.next_open_f64();
+        self.location + self.scale * (u / (1.0 - u)).ln()
+    }
+}
+
+impl ContinuousDistribution for Logistic {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let a = self.z(x).abs();
+        -a - 2.0 * (-a).exp().ln_1p() - self.scale.ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        1.0 / (1.0 + (-self.z(x)).exp())
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        -softplus(-self.z(x))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        1.0 / (1.0 + self.z(x).exp())
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        -softplus(self.z(x))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.location + self.scale * (p.ln() - (-p).ln_1p()))
+    }
+
+    fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.location + self.scale * ((-p).ln_1p() - p.ln()))
+    }
+
+    fn mean(&self) -> f64 {
+        self.location
+    }
+
+    fn variance(&self) -> f64 {
+        self.scale * self.scale * PI * PI / 3.0
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn entropy(&self) -> f64 {
+        self.scale.ln() + 2.0
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Log-logistic (Fisk) distribution on `(0, inf)` with `scale > 0` (the median) and
+/// `shape > 0`: `ln X` is logistic with location `ln scale` and scale `1 / shape`.
+///
+/// The `k`-th moment exists only for `shape > k`; otherwise it is returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogLogistic {
+    scale: f64,
+    shape: f64,
+}
+
+impl LogLogistic {
+    pub fn new(scale: f64, shape: f64) -> Result<Self, DistributionError> {
+        require(
+            scale > 0.0 && scale.is_finite() && shape > 0.0 && shape.is_finite(),
+            || {
+                format!(
+                    "log-logistic requires positive finite scale and shape; \
+                     received scale={scale}, shape={shape}"
+                )
+            },
+        )?;
+        Ok(Self { scale, shape })
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Internal: logistic variate `shape * ln(x / scale)`.
+    #[inline]
+    fn y(&self, x: f64) -> f64 {
+        self.shape * (x / self.scale).ln()
+    }
+
+    /// Internal: `E[X^k] / scale^k = (k pi / shape) / sin(k pi / shape)` for `k < shape`.
+    fn raw_moment(&self, k: f64) -> f64 {
+        if k >= self.shape {
+            return f64::NAN;
+        }
+        let b = k * PI / self.shape;
+        b / b.sin()
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let u = rng.next_open_f64();
+        self.scale * (u / (1.0 - u)).powf(1.0 / self.shape)
+    }
+}
+
+impl ContinuousDistribution for LogLogistic {
+    fn pdf(&self, x: f64) -> f64 {
+        if x == 0.0 {
+            // Infinite for shape < 1, 1 / scale for shape == 1, zero otherwise.
+            return (self.shape / self.scale) * 0f64.powf(self.shape - 1.0);
+        }
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return if x == 0.0 {
+                self.pdf(0.0).ln()
+            } else {
+                f64::NEG_INFINITY
+            };
+        }
+        let a = self.y(x).abs();
+        self.shape.ln() - x.ln() - a - 2.0 * (-a).exp().ln_1p()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        1.0 / (1.0 + (-self.y(x)).exp())
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        -softplus(-self.y(x))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        1.0 / (1.0 + self.y(x).exp())
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -softplus(self.y(x))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.scale * ((p.ln() - (-p).ln_1p()) / self.shape).exp())
+    }
+
+    fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.scale * (((-p).ln_1p() - p.ln()) / self.shape).exp())
+    }
+
+    fn mean(&self) -> f64 {
+        self.scale * self.raw_moment(1.0)
+    }
+
+    fn variance(&self) -> f64 {
+        let (m1, m2) = (self.raw_moment(1.0), self.raw_moment(2.0));
+        self.scale * self.scale * (m2 - m1 * m1)
+    }
+
+    fn skewness(&self) -> f64 {
+        let (m1, m2, m3) = (
+            self.raw_moment(1.0),
+            self.raw_moment(2.0),
+            self.raw_moment(3.0),
+        );
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / (m2 - m1 * m1).powf(1.5)
+    }
+
+    fn entropy(&self) -> f64 {
+        (self.scale / self.shape).ln() + 2.0
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (0.0, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Internal: `ln I0(kappa) - kappa`, the log of the exponentially scaled Bessel function.
+fn ln_bessel_i0_scaled(kappa: f64) -> f64 {
+    if kappa <= 30.0 {
+        // Power series sum_k (kappa^2 / 4)^k / (k!)^2.
+        let q = kappa * kappa / 4.0;
+        let (mut term, mut sum) = (1.0, 1.0);
+        for k in 1..500 {
+            term *= q / (k * k) as f64;
+            sum += term;
+            if term < 1e-17 * sum {
+                break;
+            }
+        }
+        return sum.ln() - kappa;
+    }
+    // Asymptotic series I0(k) e^-k sqrt(2 pi k) ~ sum_k ((2k - 1)!!)^2 / (k! (8 kappa)^k).
+    let (mut term, mut sum) = (1.0f64, 1.0f64);
+    for k in 1..60 {
+        let next = term * ((2 * k - 1) * (2 * k - 1)) as f64 / (8.0 * k as f64 * kappa);
+        if next >= term || next < 1e-17 * sum {
+            break;
+        }
+        term = next;
+        sum += term;
+    }
+    sum.ln() - 0.5 * (2.0 * PI * kappa).ln()
+}
+
+/// Internal: Bessel ratios `I_j(kappa) / I_0(kappa)` for `j = 1..=n` (Miller's backward
+/// recurrence on `I_j / I_{j-1}`).
+fn bessel_ratios(kappa: f64, n: usize) -> Vec<f64> {
+    let mut ratios = vec![0.0; n + 1];
+    let mut r = 0.0;
+    for j in (1..=n + 30).rev() {
+        r = 1.0 / (2.0 * j as f64 / kappa + r);
+        if j <= n {
+            ratios[j] = r;
+        }
+    }
+    for j in 1..=n {
+        ratios[j] *= if j == 1 { 1.0 } else { ratios[j - 1] };
+    }
+    ratios[0] = 1.0;
+    ratios
+}
+
+/// Von Mises (circular normal) distribution with mean direction `mu` and concentration
+/// `kappa >= 0`, as a distribution on `[mu - pi, mu + pi]`.
+///
+/// The CDF uses the Fourier series in the Bessel ratios `I_j(kappa) / I_0(kappa)`. `mean` and
+/// `variance` are the ordinary (linear) moments on that interval; the circular variance is
+/// `1 - I_1(kappa) / I_0(kappa)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VonMises {
+    mu: f64,
+    kappa: f64,
+}
+
+impl VonMises {
+    pub fn new(mu: f64, kappa: f64) -> Result<Self, DistributionError> {
+        require(mu.is_finite() && kappa >= 0.0 && kappa.is_finite(), || {
+            format!(
+                "von Mises requires a finite mu and a finite kappa >= 0; \
+                     received mu={mu}, kappa={kappa}"
+            )
+        })?;
+        Ok(Self { mu, kappa })
+    }
+
+    #[inline]
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    #[inline]
+    pub fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    /// Internal: number of Fourier terms needed for double precision.
+    #[inline]
+    fn terms(&self) -> usize {
+        30 + (12.0 * self.kappa.sqrt()) as usize
+    }
+
+    /// Internal: probability of `(-pi, theta]` around `mu` for `theta <= -pi / 2`, by direct
+    /// quadrature of the density; the Fourier series loses relative accuracy in the tail.
+    fn tail_mass(&self, theta: f64) -> f64 {
+        let ln_norm = (2.0 * PI).ln() + ln_bessel_i0_scaled(self.kappa);
+        let width = theta + PI;
+        let panels = ((width * (1.0 + self.kappa)).ceil() as usize).clamp(1, 256);
+        gauss_legendre(-PI, theta, panels, |t| {
+            (self.kappa * (t.cos() - 1.0) - ln_norm).exp()
+        })
+    }
+
+    /// Best & Fisher (1979) rejection sampler.
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let kappa = self.kappa;
+        if kappa < 1e-8 {
+            return self.mu + PI * (2.0 * rng.next_f64() - 1.0);
+        }
+        let tau = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+        let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * kappa);
+        let r = (1.0 + rho * rho) / (2.0 * rho);
+        loop {
+            let z = (PI * rng.next_f64()).cos();
+            let f = (1.0 + r * z) / (r + z);
+            let c = kappa * (r - f);
+            let u = rng.next_open_f64();
+            if c * (2.0 - c) > u || (c / u).ln() + 1.0 >= c {
+                let theta = f.clamp(-1.0, 1.0).acos();
+                return if rng.next_f64() < 0.5 {
+                    self.mu - theta
+                } else {
+                    self.mu + theta
+                };
+            }
+        }
+    }
+}
+
+impl ContinuousDistribution for VonMises {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let theta = x - self.mu;
+        if theta.is_nan() {
+            return f64::NAN;
+        }
+        if theta.abs() > PI {
+            return f64::NEG_INFINITY;
+        }
+        self.kappa * (theta.cos() - 1.0) - (2.0 * PI).ln() - ln_bessel_i0_scaled(self.kappa)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let theta = x - self.mu;
+        if theta.is_nan() {
+            return f64::NAN;
+        }
+        if theta <= -PI {
+            return 0.0;
+        }
+        if theta >= PI {
+            return 1.0;
+        }
+        if theta <= -PI / 2.0 {
+            return self.tail_mass(theta);
+        }
+        if theta >= PI / 2.0 {
+            return 1.0 - self.tail_mass(-theta);
+        }
+        let ratios = bessel_ratios(self.kappa, self.terms());
+        let series: f64 = (1..ratios.len())
+            .map(|j| ratios[j] * (j as f64 * theta).sin() / j as f64)
+            .sum();
+        ((theta + PI) / (2.0 * PI) + series / PI).clamp(0.0, 1.0)
+    }
+
+    /// Uses symmetry about `mu`, so the upper tail is as accurate as the lower.
+    fn sf(&self, x: f64) -> f64 {
+        self.cdf(2.0 * self.mu - x)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        let start = self.mu - PI;
+        Ok(start + invert_cdf(p, 0.0, |t| self.cdf(start + t)).min(2.0 * PI))
+    }
+
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+
+    /// `E[(X - mu)^2] = pi^2 / 3 + 4 sum_j (-1)^j (I_j / I_0) / j^2`.
+    fn variance(&self) -> f64 {
+        let ratios = bessel_ratios(self.kappa, self.terms());
+        let series: f64 = (1..ratios.len())
+            .map(|j| {
+                let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+                sign * ratios[j] / (j * j) as f64
+            })
+            .sum();
+        PI * PI / 3.0 + 4.0 * series
+    }
+
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    fn entropy(&self) -> f64 {
+        let a = bessel_ratios(self.kappa, 1)[1];
+        (2.0 * PI).ln() + ln_bessel_i0_scaled(self.kappa) + self.kappa * (1.0 - a)
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (self.mu - PI, self.mu + PI)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Rayleigh distribution on `[0, inf)` with `scale > 0` (sigma).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rayleigh {
+    scale: f64,
+}
+
+impl Rayleigh {
+    pub fn new(scale: f64) -> Result<Self, DistributionError> {
+        require(scale > 0.0 && scale.is_finite(), || {
+            format!("Rayleigh requires a positive finite scale; received scale={scale}")
+        })?;
+        Ok(Self { scale })
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.scale * (2.0 * rng.standard_exponential()).sqrt()
+    }
+}
+
+impl ContinuousDistribution for Rayleigh {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let z = x / self.scale;
+        z / self.scale * (-0.5 * z * z).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let z = x / self.scale;
+        z.ln() - self.scale.ln() - 0.5 * z * z
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        -self.ln_sf(x).exp_m1()
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln1m_exp(self.ln_sf(x))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.ln_sf(x).exp()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let z = x / self.scale;
+        -0.5 * z * z
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.scale * (-2.0 * (-p).ln_1p()).sqrt())
+    }
+
+    fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.scale * (-2.0 * p.ln()).sqrt())
+    }
+
+    fn mean(&self) -> f64 {
+        self.scale * (PI / 2.0).sqrt()
+    }
+
+    fn variance(&self) -> f64 {
+        (4.0 - PI) / 2.0 * self.scale * self.scale
+    }
+
+    fn skewness(&self) -> f64 {
+        2.0 * PI.sqrt() * (PI - 3.0) / (4.0 - PI).powf(1.5)
+    }
+
+    fn entropy(&self) -> f64 {
+        1.0 + (self.scale / SQRT_2).ln() + EULER_GAMMA / 2.0
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (0.0, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Internal: Owen's T function `T(h, a) = 1/(2 pi) int_0^a exp(-h^2 (1 + x^2) / 2) / (1 + x^2) dx`.
+///
+/// Integrates directly for `|a| <= 1` (composite 10-point Gauss-Legendre, with panels no wider
+/// than `1 / |h|`) and uses `T(h, a) + T(ah, 1/a) = (Phi(h) + Phi(ah)) / 2 - Phi(h) Phi(ah)`
+/// for `h, a >= 0` otherwise.
+fn owens_t(h: f64, a: f64) -> f64 {
+    if a < 0.0 {
+        return -owens_t(h, -a);
+    }
+    let h = h.abs();
+    if a == 0.0 {
+        return 0.0;
+    }
+    if a > 1.0 {
+        let ah = a * h;
+        let (ph, pah) = (std_normal_cdf(h), std_normal_cdf(ah));
+        return 0.5 * (ph + pah) - ph * pah - owens_t(ah, 1.0 / a);
+    }
+    let panels = ((a * h).ceil() as usize).clamp(1, 64);
+    let integral = gauss_legendre(0.0, a, panels, |x| {
+        let s = 1.0 + x * x;
+        (-0.5 * h * h * s).exp() / s
+    });
+    integral / (2.0 * PI)
+}
+
+/// Skew-normal distribution (Azzalini) with `location`, `scale > 0` and `shape` (alpha).
+///
+/// `shape == 0` is the normal distribution. The CDF uses Owen's T function; there is no closed
+/// form for the entropy, which is returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl SkewNormal {
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, DistributionError> {
+        require(
+            location.is_finite() && scale > 0.0 && scale.is_finite() && shape.is_finite(),
+            || {
+                format!(
+                    "skew-normal requires finite location and shape and a positive finite scale; \
+                     received location={location}, scale={scale}, shape={shape}"
+                )
+            },
+        )?;
+        Ok(Self {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    #[inline]
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    #[inline]
+    fn z(&self, x: f64) -> f64 {
+        (x - self.location) / self.scale
+    }
+
+    /// Internal: `delta = shape / sqrt(1 + shape^2)`.
+    #[inline]
+    fn delta(&self) -> f64 {
+        self.shape / (1.0 + self.shape * self.shape).sqrt()
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let delta = self.delta();
+        let (u, v) = (rng.standard_normal(), rng.standard_normal());
+        let z = delta * u.abs() + (1.0 - delta * delta).sqrt() * v;
+        self.location + self.scale * z
+    }
+}
+
+impl ContinuousDistribution for SkewNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = self.z(x);
+        if z.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        LN_2 - self.scale.ln() + ln_std_normal_pdf(z) + ln_std_normal_sf(-self.shape * z)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z = self.z(x);
+        if z.is_infinite() {
+            return if z > 0.0 { 1.0 } else { 0.0 };
+        }
+        (std_normal_cdf(z) - 2.0 * owens_t(z, self.shape)).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        let z = self.z(x);
+        if z.is_infinite() {
+            return if z > 0.0 { 0.0 } else { 1.0 };
+        }
+        (std_normal_cdf(-z) + 2.0 * owens_t(z, self.shape)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        let z = invert_cdf(p, f64::NEG_INFINITY, |z| {
+            self.cdf(self.location + self.scale * z)
+        });
+        Ok(self.location + self.scale * z)
+    }
+
+    fn mean(&self) -> f64 {
+        self.location + self.scale * self.delta() * (2.0 / PI).sqrt()
+    }
+
+    fn variance(&self) -> f64 {
+        let delta = self.delta();
+        self.scale * self.scale * (1.0 - 2.0 * delta * delta / PI)
+    }
+
+    fn skewness(&self) -> f64 {
+        let m = self.delta() * (2.0 / PI).sqrt();
+        (4.0 - PI) / 2.0 * m.powi(3) / (1.0 - m * m).powf(1.5)
+    }
+
+    fn entropy(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Internal: `ln(Phi(hi) - Phi(lo))` for standardized `lo <= hi`, accurate in either tail.
+fn ln_std_normal_mass(lo: f64, hi: f64) -> f64 {
+    if lo >= hi {
+        return f64::NEG_INFINITY;
+    }
+    if lo >= 0.0 {
+        // Both in the upper tail: difference of survival functions.
+        let (a, b) = (ln_std_normal_sf(lo), ln_std_normal_sf(hi));
+        a + ln1m_exp(b - a)
+    } else if hi <= 0.0 {
+        let (a, b) = (ln_std_normal_sf(-hi), ln_std_normal_sf(-lo));
+        a + ln1m_exp(b - a)
+    } else {
+        (-std_normal_cdf(lo) - std_normal_cdf(-hi)).ln_1p()
+    }
+}
+
+/// Normal distribution with `location` (the untruncated mean) and `scale > 0` (the untruncated
+/// standard deviation), truncated to `[lower, upper]`.
+///
+/// Either bound may be infinite. Probabilities are computed in log space, so truncation far
+/// into a tail (e.g. `lower = location + 30 scale`) stays accurate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TruncatedNormal {
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl TruncatedNormal {
+    pub fn new(
+        location: f64,
+        scale: f64,
+        lower: f64,
+        upper: f64,
+    ) -> Result<Self, DistributionError> {
+        require(
+            location.is_finite() && scale > 0.0 && scale.is_finite() && lower < upper,
+            || {
+                format!(
+                    "truncated normal requires a finite location, a positive finite scale and \
+                     lower < upper; received location={location}, scale={scale}, \
+                     lower={lower}, upper={upper}"
+                )
+            },
+        )?;
+        Ok(Self {
+            location,
+            scale,
+            lower,
+            upper,
+        })
+    }
+
+    #[inline]
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    #[inline]
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Internal: standardized bounds `(alpha, beta)`.
+    #[inline]
+    fn bounds(&self) -> (f64, f64) {
+        (
+            (self.lower - self.location) / self.scale,
+            (self.upper - self.location) / self.scale,
+        )
+    }
+
+    /// Internal: log of the untruncated mass `Phi(beta) - Phi(alpha)`.
+    #[inline]
+    fn ln_mass(&self) -> f64 {
+        let (a, b) = self.bounds();
+        ln_std_normal_mass(a, b)
+    }
+
+    /// Internal: `E[Y^k]` for `k = 1, 2, 3` of the standardized truncated variable.
+    fn standardized_moments(&self) -> [f64; 3] {
+        let (a, b) = self.bounds();
+        let ln_z = self.ln_mass();
+        // x^k phi(x) / Z, taken as zero at infinite bounds.
+        let term = |x: f64, k: i32| {
+            if x.is_infinite() {
+                0.0
+            } else {
+                x.powi(k) * (ln_std_normal_pdf(x) - ln_z).exp()
+            }
+        };
+        let m1 = term(a, 0) - term(b, 0);
+        let m2 = 1.0 + term(a, 1) - term(b, 1);
+        let m3 = 2.0 * m1 + term(a, 2) - term(b, 2);
+        [m1, m2, m3]
+    }
+
+    /// Internal: quantile by inverting the untruncated normal CDF, working with upper-tail
+    /// probabilities when the interval lies above the mean.
+    fn quantile(&self, p: f64) -> f64 {
+        let (a, b) = self.bounds();
+        let z = if a > 0.0 {
+            let (sa, sb) = (std_normal_cdf(-a), std_normal_cdf(-b));
+            std_normal_isf(sa - p * (sa - sb))
+        } else {
+            let (pa, pb) = (std_normal_cdf(a), std_normal_cdf(b));
+            -std_normal_isf(pa + p * (pb - pa))
+        };
+        (self.location + self.scale * z).clamp(self.lower, self.upper)
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.quantile(rng.next_open_f64())
+    }
+}
+
+impl ContinuousDistribution for TruncatedNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x < self.lower || x > self.upper {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        ln_std_normal_pdf(z) - self.scale.ln() - self.ln_mass()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.ln_cdf(x).exp()
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x >= self.upper {
+            return 0.0;
+        }
+        let (a, _) = self.bounds();
+        let z = (x - self.location) / self.scale;
+        (ln_std_normal_mass(a, z) - self.ln_mass()).min(0.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.ln_sf(x).exp()
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x <= self.lower {
+            return 0.0;
+        }
+        let (_, b) = self.bounds();
+        let z = (x - self.location) / self.scale;
+        (ln_std_normal_mass(z, b) - self.ln_mass()).min(0.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(self.quantile(p))
+    }
+
+    fn mean(&self) -> f64 {
+        self.location + self.scale * self.standardized_moments()[0]
+    }
+
+    fn variance(&self) -> f64 {
+        let [m1, m2, _] = self.standardized_moments();
+        self.scale * self.scale * (m2 - m1 * m1)
+    }
+
+    fn skewness(&self) -> f64 {
+        let [m1, m2, m3] = self.standardized_moments();
+        (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / (m2 - m1 * m1).powf(1.5)
+    }
+
+    /// `ln(sqrt(2 pi e) scale Z) + (alpha phi(alpha) - beta phi(beta)) / (2 Z)`.
+    fn entropy(&self) -> f64 {
+        let [_, m2, _] = self.standardized_moments();
+        0.5 * (2.0 * PI).ln() + self.scale.ln() + self.ln_mass() + 0.5 * m2
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (self.lower, self.upper)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+// =============================================================================
+// Noncentral Families
+// =============================================================================
+//
+// The chi-squared and F distributions are Poisson mixtures of their central counterparts, with
+// weights `Pois(j; ncp / 2)`. The t CDF uses the series of Lenth (1989, AS 243). All series are
+// summed outward from the largest weight, so large noncentralities do not underflow. Quantiles
+// invert the CDF by bisection.
+
+/// Internal: `sum_j term(j)` over `j >= 0`, starting at `mode` and moving outward.
+///
+/// `term` returns `(bound, value)`, where `bound` caps the magnitude of this and all further
+/// terms in the same direction; a direction stops once its bound is negligible.
+fn sum_outward<F: Fn(f64) -> (f64, f64)>(mode: f64, term: F) -> f64 {
+    const EPS: f64 = 1e-16;
+    const MAX_TERMS: usize = 100_000;
+
+    let mut sum = 0.0;
+    let mut j = mode;
+    for _ in 0..MAX_TERMS {
+        let (bound, value) = term(j);
+        sum += value;
+        if bound <= EPS * sum.abs() || bound == 0.0 {
+            break;
+        }
+        j += 1.0;
+    }
+    let mut j = mode - 1.0;
+    while j >= 0.0 {
+        let (bound, value) = term(j);
+        sum += value;
+        if bound <= EPS * sum.abs() || bound == 0.0 {
+            break;
+        }
+        j -= 1.0;
+    }
+    sum
+}
+
+/// Internal: Poisson-weighted mixture `sum_j Pois(j; mu) term(j)` for terms in `[0, 1]`.
+fn poisson_mixture<F: Fn(f64) -> f64>(mu: f64, term: F) -> f64 {
+    if mu == 0.0 {
+        return term(0.0);
+    }
+    let ln_mu = mu.ln();
+    sum_outward(mu.floor(), |j| {
+        let w = (j * ln_mu - mu - ln_gamma(j + 1.0)).exp();
+        (w, w * term(j))
+    })
+}
+
+fn require_noncentral(
+    name: &str,
+    dofs: &[f64],
+    ncp: f64,
+    signed: bool,
+) -> Result<(), DistributionError> {
+    let dof_ok = dofs.iter().all(|&d| d > 0.0 && d.is_finite());
+    let ncp_ok = ncp.is_finite() && (signed || ncp >= 0.0);
+    require(dof_ok && ncp_ok, || {
+        let bound = if signed { "finite" } else { "finite and >= 0" };
+        format!(
+            "{name} requires positive finite degrees of freedom and a {bound} noncentrality; \
+             received dof={dofs:?}, ncp={ncp}"
+        )
+    })
+}
+
+/// Internal: central chi-squared log-density with `dof` degrees of freedom.
+fn chi_squared_ln_pdf(x: f64, dof: f64) -> f64 {
+    let a = dof / 2.0;
+    if x == 0.0 {
+        return match a.partial_cmp(&1.0) {
+            Some(std::cmp::Ordering::Less) => f64::INFINITY,
+            Some(std::cmp::Ordering::Equal) => -LN_2,
+            _ => f64::NEG_INFINITY,
+        };
+    }
+    (a - 1.0) * (x / 2.0).ln() - x / 2.0 - ln_gamma(a) - LN_2
+}
+
+/// Internal: skewness from the first three raw moments.
+#[inline]
+fn skewness_from_raw(m1: f64, m2: f64, m3: f64) -> f64 {
+    (m3 - 3.0 * m1 * m2 + 2.0 * m1.powi(3)) / (m2 - m1 * m1).powf(1.5)
+}
+
+/// Noncentral chi-squared distribution with `dof > 0` degrees of freedom and noncentrality
+/// `ncp >= 0`.
+///
+/// `ncp == 0` is the central chi-squared distribution. There is no closed form for the entropy,
+/// which is returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoncentralChiSquared {
+    dof: f64,
+    ncp: f64,
+}
+
+impl NoncentralChiSquared {
+    pub fn new(dof: f64, ncp: f64) -> Result<Self, DistributionError> {
+        require_noncentral("noncentral chi-squared", &[dof], ncp, false)?;
+        Ok(Self { dof, ncp })
+    }
+
+    #[inline]
+    pub fn dof(&self) -> f64 {
+        self.dof
+    }
+
+    #[inline]
+    pub fn ncp(&self) -> f64 {
+        self.ncp
+    }
+
+    /// Internal: Poisson mixture of chi-squared variates with `dof + 2J` degrees of freedom.
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let j = rng.poisson(0.5 * self.ncp);
+        2.0 * rng.standard_gamma(0.5 * self.dof + j)
+    }
+}
+
+impl ContinuousDistribution for NoncentralChiSquared {
+    fn pdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x < 0.0 || x.is_infinite() {
+            return 0.0;
+        }
+        if x == 0.0 {
+            // Only the j = 0 component can be nonzero at the origin.
+            return (-self.ncp / 2.0).exp() * chi_squared_ln_pdf(0.0, self.dof).exp();
+        }
+        poisson_mixture(self.ncp / 2.0, |j| {
+            chi_squared_ln_pdf(x, self.dof + 2.0 * j).exp()
+        })
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x.is_infinite() {
+            return 1.0;
+        }
+        poisson_mixture(self.ncp / 2.0, |j| gamma_lr(self.dof / 2.0 + j, x / 2.0)).min(1.0)
+    }
+
+    /// Summed directly rather than as `1 - cdf`.
+    fn sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x <= 0.0 {
+            return 1.0;
+        }
+        if x.is_infinite() {
+            return 0.0;
+        }
+        poisson_mixture(self.ncp / 2.0, |j| gamma_ur(self.dof / 2.0 + j, x / 2.0)).min(1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(invert_cdf(p, 0.0, |x| self.cdf(x)))
+    }
+
+    fn mean(&self) -> f64 {
+        self.dof + self.ncp
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * (self.dof + 2.0 * self.ncp)
+    }
+
+    fn skewness(&self) -> f64 {
+        2f64.powf(1.5) * (self.dof + 3.0 * self.ncp) / (self.dof + 2.0 * self.ncp).powf(1.5)
+    }
+
+    fn entropy(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (0.0, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Internal: one tail of the noncentral t distribution at `t >= 0` (AS 243).
+///
+/// Returns `P(T <= t)`, or `P(T > t)` when `upper` is set. The upper tail sums the complementary
+/// incomplete beta functions directly, so it stays accurate far below machine epsilon.
+fn noncentral_t_tail(t: f64, dof: f64, ncp: f64, upper: bool) -> f64 {
+    let base = if upper { 0.0 } else { std_normal_cdf(-ncp) };
+    if t == 0.0 {
+        return if upper { std_normal_cdf(ncp) } else { base };
+    }
+    // x = t^2 / (t^2 + dof) and its complement, each without cancellation or overflow.
+    let (x, y) = (1.0 / (1.0 + dof / (t * t)), 1.0 / (1.0 + t * t / dof));
+    let b = dof / 2.0;
+    let ib = |a: f64| {
+        if upper {
+            beta_reg(b, a, y)
+        } else {
+            beta_reg(a, b, x)
+        }
+    };
+    let mu = ncp * ncp / 2.0;
+    let series = if mu == 0.0 {
+        ib(0.5)
+    } else {
+        let ln_mu = mu.ln();
+        let ln_q = (ncp.abs() / SQRT_2).ln();
+        sum_outward(mu.floor(), |j| {
+            let p = (j * ln_mu - mu - ln_gamma(j + 1.0)).exp();
+            let q = (ln_q + j * ln_mu - mu - ln_gamma(j + 1.5)).exp() * ncp.signum();
+            (p + q.abs(), p * ib(j + 0.5) + q * ib(j + 1.0))
+        })
+    };
+    (base + 0.5 * series).clamp(0.0, 1.0)
+}
+
+/// Noncentral Student's t distribution with `dof > 0` degrees of freedom and finite
+/// noncentrality `ncp`.
+///
+/// `ncp == 0` is the central t distribution. Moments of order `k` exist only for `dof > k`;
+/// there is no closed form for the entropy, which is returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoncentralT {
+    dof: f64,
+    ncp: f64,
+}
+
+impl NoncentralT {
+    pub fn new(dof: f64, ncp: f64) -> Result<Self, DistributionError> {
+        require_noncentral("noncentral t", &[dof], ncp, true)?;
+        Ok(Self { dof, ncp })
+    }
+
+    #[inline]
+    pub fn dof(&self) -> f64 {
+        self.dof
+    }
+
+    #[inline]
+    pub fn ncp(&self) -> f64 {
+        self.ncp
+    }
+
+    /// Internal: `E[T^k] = (dof/2)^(k/2) Gamma((dof - k)/2) / Gamma(dof/2) E[(Z + ncp)^k]` for
+    /// `k = 1, 2, 3` and `k < dof`.
+    fn raw_moment(&self, k: i32) -> f64 {
+        let kf = f64::from(k);
+        if self.dof <= kf {
+            return f64::NAN;
+        }
+        let d = self.ncp;
+        let normal = match k {
+            1 => d,
+            2 => d * d + 1.0,
+            _ => d * d * d + 3.0 * d,
+        };
+        let ln_scale = 0.5 * kf * (self.dof / 2.0).ln() + ln_gamma((self.dof - kf) / 2.0)
+            - ln_gamma(self.dof / 2.0);
+        ln_scale.exp() * normal
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let z = rng.standard_normal() + self.ncp;
+        let chi2 = 2.0 * rng.standard_gamma(0.5 * self.dof);
+        z / (chi2 / self.dof).sqrt()
+    }
+}
+
+impl ContinuousDistribution for NoncentralT {
+    /// Uses the CDF identity `f(t) = dof / t (F_{dof+2}(t sqrt(1 + 2/dof)) - F_dof(t))`, as R's
+    /// `dt`.
+    fn pdf(&self, t: f64) -> f64 {
+        let (dof, ncp) = (self.dof, self.ncp);
+        if t.is_nan() {
+            return f64::NAN;
+        }
+        if t.is_infinite() {
+            return 0.0;
+        }
+        if t.abs() > (dof * f64::EPSILON).sqrt() {
+            let shifted = NoncentralT {
+                dof: dof + 2.0,
+                ncp,
+            }
+            .cdf(t * (1.0 + 2.0 / dof).sqrt());
+            return (dof / t * (shifted - self.cdf(t))).max(0.0);
+        }
+        (ln_gamma((dof + 1.0) / 2.0) - ln_gamma(dof / 2.0) - 0.5 * (PI.ln() + dof.ln() + ncp * ncp))
+            .exp()
+    }
+
+    fn cdf(&self, t: f64) -> f64 {
+        if t.is_nan() {
+            return f64::NAN;
+        }
+        if t.is_infinite() {
+            return if t > 0.0 { 1.0 } else { 0.0 };
+        }
+        if t >= 0.0 {
+            noncentral_t_tail(t, self.dof, self.ncp, false)
+        } else {
+            noncentral_t_tail(-t, self.dof, -self.ncp, true)
+        }
+    }
+
+    /// For `t < 0` this is the lower tail of the reflected distribution.
+    fn sf(&self, t: f64) -> f64 {
+        if t.is_nan() {
+            return f64::NAN;
+        }
+        if t.is_infinite() {
+            return if t > 0.0 { 0.0 } else { 1.0 };
+        }
+        if t >= 0.0 {
+            noncentral_t_tail(t, self.dof, self.ncp, true)
+        } else {
+            noncentral_t_tail(-t, self.dof, -self.ncp, false)
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(invert_cdf(p, f64::NEG_INFINITY, |t| self.cdf(t)))
+    }
+
+    fn mean(&self) -> f64 {
+        self.raw_moment(1)
+    }
+
+    fn variance(&self) -> f64 {
+        let m1 = self.raw_moment(1);
+        self.raw_moment(2) - m1 * m1
+    }
+
+    fn skewness(&self) -> f64 {
+        skewness_from_raw(self.raw_moment(1), self.raw_moment(2), self.raw_moment(3))
+    }
+
+    fn entropy(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+/// Noncentral F distribution with `df1 > 0` and `df2 > 0` degrees of freedom and noncentrality
+/// `ncp >= 0` in the numerator.
+///
+/// `ncp == 0` is the central F distribution. Moments of order `k` exist only for `df2 > 2k`;
+/// there is no closed form for the entropy, which is returned as NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoncentralF {
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+}
+
+impl NoncentralF {
+    pub fn new(df1: f64, df2: f64, ncp: f64) -> Result<Self, DistributionError> {
+        require_noncentral("noncentral F", &[df1, df2], ncp, false)?;
+        Ok(Self { df1, df2, ncp })
+    }
+
+    #[inline]
+    pub fn df1(&self) -> f64 {
+        self.df1
+    }
+
+    #[inline]
+    pub fn df2(&self) -> f64 {
+        self.df2
+    }
+
+    #[inline]
+    pub fn ncp(&self) -> f64 {
+        self.ncp
+    }
+
+    /// Internal: `E[F^k] = (df2/df1)^k E[X1^k] E[X2^-k]` for `k = 1, 2, 3` and `2k < df2`, with
+    /// the noncentral chi-squared raw moments built from its cumulants.
+    fn raw_moment(&self, k: i32) -> f64 {
+        let kf = f64::from(k);
+        if self.df2 <= 2.0 * kf {
+            return f64::NAN;
+        }
+        let k1 = self.df1 + self.ncp;
+        let k2 = 2.0 * (self.df1 + 2.0 * self.ncp);
+        let numerator = match k {
+            1 => k1,
+            2 => k1 * k1 + k2,
+            _ => k1.powi(3) + 3.0 * k1 * k2 + 8.0 * (self.df1 + 3.0 * self.ncp),
+        };
+        let half = self.df2 / 2.0;
+        let inverse = (ln_gamma(half - kf) - ln_gamma(half) - kf * LN_2).exp();
+        (self.df2 / self.df1).powi(k) * numerator * inverse
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> f64 {
+        let j = rng.poisson(0.5 * self.ncp);
+        let num = 2.0 * rng.standard_gamma(0.5 * self.df1 + j) / self.df1;
+        let den = 2.0 * rng.standard_gamma(0.5 * self.df2) / self.df2;
+        num / den
+    }
+}
+
+impl ContinuousDistribution for NoncentralF {
+    fn pdf(&self, x: f64) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x < 0.0 || x.is_infinite() {
+            return 0.0;
+        }
+        if x == 0.0 {
+            let central = sd::FisherSnedecor::new(df1, df2).map_or(f64::NAN, |f| f.pdf(0.0));
+            return (-self.ncp / 2.0).exp() * central;
+        }
+        // Beta(df1/2 + j, df2/2) densities in y = df1 x / (df1 x + df2), times dy/dx.
+        let (a0, b) = (df1 / 2.0, df2 / 2.0);
+        let y = 1.0 / (1.0 + df2 / (df1 * x));
+        let ln_jacobian = (df1 * df2).ln() - 2.0 * (df1 * x + df2).ln();
+        poisson_mixture(self.ncp / 2.0, |j| {
+            let a = a0 + j;
+            ((a - 1.0) * y.ln() + (b - 1.0) * (-y).ln_1p() - ln_beta(a, b) + ln_jacobian).exp()
+        })
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x.is_infinite() {
+            return 1.0;
+        }
+        let y = 1.0 / (1.0 + df2 / (df1 * x));
+        poisson_mixture(self.ncp / 2.0, |j| beta_reg(df1 / 2.0 + j, df2 / 2.0, y)).min(1.0)
+    }
+
+    /// Summed directly rather than as `1 - cdf`.
+    fn sf(&self, x: f64) -> f64 {
+        let (df1, df2) = (self.df1, self.df2);
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        if x <= 0.0 {
+            return 1.0;
+        }
+        if x.is_infinite() {
+            return 0.0;
+        }
+        let z = 1.0 / (1.0 + df1 * x / df2);
+        poisson_mixture(self.ncp / 2.0, |j| beta_reg(df2 / 2.0, df1 / 2.0 + j, z)).min(1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        Ok(invert_cdf(p, 0.0, |x| self.cdf(x)))
+    }
+
+    fn mean(&self) -> f64 {
+        self.raw_moment(1)
+    }
+
+    fn variance(&self) -> f64 {
+        let m1 = self.raw_moment(1);
+        self.raw_moment(2) - m1 * m1
+    }
+
+    fn skewness(&self) -> f64 {
+        skewness_from_raw(self.raw_moment(1), self.raw_moment(2), self.raw_moment(3))
+    }
+
+    fn entropy(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn support(&self) -> (f64, f64) {
+        (0.0, f64::INFINITY)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64 {
+        self.draw(rng)
+    }
+}
+
+// =============================================================================
+// Discrete Families
+// =============================================================================
+
+/// Internal: `DiscreteDistribution` impl delegating to a statrs distribution in `self.inner`.
+macro_rules! impl_discrete_family {
+    ($name:ident, $moments:ident) => {
+        impl DiscreteDistribution for $name {
+            #[inline]
+            fn pmf(&self, k: u64) -> f64 {
+                self.inner.pmf(k)
+            }
+
+            #[inline]
+            fn ln_pmf(&self, k: u64) -> f64 {
+                self.inner.ln_pmf(k)
+            }
+
+            #[inline]
+            fn cdf(&self, k: u64) -> f64 {
+                self.inner.cdf(k)
+            }
+
+            #[inline]
+            fn sf(&self, k: u64) -> f64 {
+                self.inner.sf(k)
+            }
+
+            fn inverse_cdf(&self, p: f64) -> Result<u64, DistributionError> {
+                ensure_probability(p)?;
+                Ok(self.inner.inverse_cdf(p))
+            }
+
+            fn mean(&self) -> f64 {
+                $moments::mean(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn variance(&self) -> f64 {
+                $moments::variance(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn skewness(&self) -> f64 {
+                $moments::skewness(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn entropy(&self) -> f64 {
+                $moments::entropy(&self.inner).unwrap_or(f64::NAN)
+            }
+
+            fn support(&self) -> (u64, u64) {
+                (Min::min(&self.inner), Max::max(&self.inner))
+            }
+
+            #[inline]
+            fn sample_with(&self, rng: &mut dyn RandomSource) -> u64 {
+                self.draw(rng)
+            }
+        }
+    };
+}
+
+/// Poisson distribution with mean `lambda > 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+    inner: sd::Poisson,
+}
+
+impl Poisson {
+    pub fn new(lambda: f64) -> Result<Self, DistributionError> {
+        let inner = sd::Poisson::new(lambda).map_err(invalid_params)?;
+        Ok(Self { lambda, inner })
+    }
+
+    #[inline]
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> u64 {
+        rng.poisson(self.lambda) as u64
+    }
+}
+
+impl_discrete_family!(Poisson, StatrsMoments);
+
+/// Binomial distribution: successes in `n` trials with success probability `p`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+    inner: sd::Binomial,
+}
+
+impl Binomial {
+    pub fn new(n: u64, p: f64) -> Result<Self, DistributionError> {
+        let inner = sd::Binomial::new(p, n).map_err(invalid_params)?;
+        Ok(Self { n, p, inner })
+    }
+
+    #[inline]
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    #[inline]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> u64 {
+        rng.binomial(self.n, self.p) as u64
+    }
+}
+
+impl_discrete_family!(Binomial, StatrsMoments);
+
+/// Negative binomial distribution: failures before the `r`-th success, success probability `p`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+    inner: sd::NegativeBinomial,
+}
+
+impl NegativeBinomial {
+    pub fn new(r: f64, p: f64) -> Result<Self, DistributionError> {
+        let inner = sd::NegativeBinomial::new(r, p).map_err(invalid_params)?;
+        Ok(Self { r, p, inner })
+    }
+
+    #[inline]
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    #[inline]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Gamma-Poisson mixture.
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> u64 {
+        let lambda = rng.standard_gamma(self.r) * (1.0 - self.p) / self.p;
+        rng.poisson(lambda) as u64
+    }
+}
+
+impl_discrete_family!(NegativeBinomial, StatrsDiscreteMoments);
+
+/// Hypergeometric distribution: successes in `draws` draws without replacement from a
+/// population of `population` items containing `successes` successes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeometric {
+    population: u64,
+    successes: u64,
+    draws: u64,
+    inner: sd::Hypergeometric,
+}
+
+impl Hypergeometric {
+    pub fn new(population: u64, successes: u64, draws: u64) -> Result<Self, DistributionError> {
+        let inner =
+            sd::Hypergeometric::new(population, successes, draws).map_err(invalid_params)?;
+        Ok(Self {
+            population,
+            successes,
+            draws,
+            inner,
+        })
+    }
+
+    #[inline]
+    pub fn population(&self) -> u64 {
+        self.population
+    }
+
+    #[inline]
+    pub fn successes(&self) -> u64 {
+        self.successes
+    }
+
+    #[inline]
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    /// Sequential draws from the urn.
+    fn draw(&self, rng: &mut dyn RandomSource) -> u64 {
+        let (mut remaining, mut good) = (self.population, self.successes);
+        let mut drawn = 0;
+        for _ in 0..self.draws {
+            if (rng.next_f64() * remaining as f64) < good as f64 {
+                good -= 1;
+                drawn += 1;
+            }
+            remaining -= 1;
+        }
+        drawn
+    }
+}
+
+impl_discrete_family!(Hypergeometric, StatrsMoments);
+
+/// Geometric distribution: failures before the first success, success probability
+/// `0 < p <= 1`.
+///
+/// The support starts at zero, matching `NegativeBinomial` with `r = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+    p: f64,
+}
+
+impl Geometric {
+    pub fn new(p: f64) -> Result<Self, DistributionError> {
+        require(p > 0.0 && p <= 1.0, || {
+            format!("geometric requires 0 < p <= 1; received p={p}")
+        })?;
+        Ok(Self { p })
+    }
+
+    #[inline]
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    #[inline]
+    fn draw(&self, rng: &mut dyn RandomSource) -> u64 {
+        if self.p == 1.0 {
+            return 0;
+        }
+        (rng.standard_exponential() / -(-self.p).ln_1p()).floor() as u64
+    }
+}
+
+impl DiscreteDistribution for Geometric {
+    fn pmf(&self, k: u64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k == 0 {
+            return self.p.ln();
+        }
+        self.p.ln() + k as f64 * (-self.p).ln_1p()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        -self.ln_sf(k).exp_m1()
+    }
+
+    fn ln_cdf(&self, k: u64) -> f64 {
+        ln1m_exp(self.ln_sf(k))
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        self.ln_sf(k).exp()
+    }
+
+    fn ln_sf(&self, k: u64) -> f64 {
+        (k as f64 + 1.0) * (-self.p).ln_1p()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<u64, DistributionError> {
+        ensure_probability(p)?;
+        if p == 1.0 {
+            return Ok(if self.p == 1.0 { 0 } else { u64::MAX });
+        }
+        // Smallest k with (k + 1) ln(1 - p_success) <= ln(1 - p).
+        let k = ((-p).ln_1p() / (-self.p).ln_1p() - 1.0).ceil().max(0.0);
+        let mut k = if k.is_nan() { 0 } else { k as u64 };
+        // Guard against rounding in the ratio.
+        while k > 0 && self.cdf(k - 1) >= p {
+            k -= 1;
+        }
+        while self.cdf(k) < p {
+            k += 1;
+        }
+        Ok(k)
+    }
+
+    fn mean(&self) -> f64 {
+        (1.0 - self.p) / self.p
+    }
+
+    fn variance(&self) -> f64 {
+        (1.0 - self.p) / (self.p * self.p)
+    }
+
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / (1.0 - self.p).sqrt()
+    }
+
+    fn entropy(&self) -> f64 {
+        let q = 1.0 - self.p;
+        if q == 0.0 {
+            return 0.0;
+        }
+        -(q * q.ln() + self.p * self.p.ln()) / self.p
+    }
+
+    fn support(&self) -> (u64, u64) {
+        (0, u64::MAX)
+    }
+
+    #[inline]
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> u64 {
+        self.draw(rng)
+    }
+}
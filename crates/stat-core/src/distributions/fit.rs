@@ -0,0 +1,414 @@
+use super::families::{
+    Beta, Binomial, Exp, Gamma, Laplace, LogNormal, NegativeBinomial, Normal, Poisson, Weibull,
+};
+use super::traits::{ContinuousDistribution, DiscreteDistribution};
+use super::DistributionError;
+use crate::stats::{mean, median, variance};
+use statrs::function::gamma::digamma;
+
+// =============================================================================
+// Maximum-Likelihood Fitting
+// =============================================================================
+
+/// Result of a maximum-likelihood fit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitResult<D> {
+    /// Fitted distribution
+    pub distribution: D,
+    /// Estimated parameters, in constructor order (fixed parameters such as the binomial `n`
+    /// are omitted)
+    pub estimates: Vec<f64>,
+    /// Standard errors from the inverse observed Fisher information
+    pub std_errors: Vec<f64>,
+    /// Log-likelihood at the estimates
+    pub log_likelihood: f64,
+    /// Akaike information criterion, `2k - 2 ln L`
+    pub aic: f64,
+}
+
+const MAX_ITER: usize = 200;
+
+/// Internal: reject samples that are too small or fall outside the family's support.
+fn check_data<F>(
+    data: &[f64],
+    family: &str,
+    requirement: &str,
+    valid: F,
+) -> Result<(), DistributionError>
+where
+    F: Fn(f64) -> bool,
+{
+    if data.len() < 2 {
+        return Err(DistributionError::InvalidParameters(format!(
+            "{family} fit requires at least two observations"
+        )));
+    }
+    if !data.iter().all(|&x| x.is_finite() && valid(x)) {
+        return Err(DistributionError::InvalidParameters(format!(
+            "{family} fit requires {requirement}"
+        )));
+    }
+    Ok(())
+}
+
+fn not_converged(family: &str) -> DistributionError {
+    DistributionError::InvalidParameters(format!("{family} fit did not converge"))
+}
+
+fn continuous_fit<D: ContinuousDistribution>(
+    distribution: D,
+    data: &[f64],
+    estimates: Vec<f64>,
+    std_errors: Vec<f64>,
+) -> FitResult<D> {
+    let log_likelihood: f64 = data.iter().map(|&x| distribution.ln_pdf(x)).sum();
+    let aic = 2.0 * estimates.len() as f64 - 2.0 * log_likelihood;
+    FitResult {
+        distribution,
+        estimates,
+        std_errors,
+        log_likelihood,
+        aic,
+    }
+}
+
+fn discrete_fit<D: DiscreteDistribution>(
+    distribution: D,
+    data: &[f64],
+    estimates: Vec<f64>,
+    std_errors: Vec<f64>,
+) -> FitResult<D> {
+    let log_likelihood: f64 = data.iter().map(|&k| distribution.ln_pmf(k as u64)).sum();
+    let aic = 2.0 * estimates.len() as f64 - 2.0 * log_likelihood;
+    FitResult {
+        distribution,
+        estimates,
+        std_errors,
+        log_likelihood,
+        aic,
+    }
+}
+
+/// Internal: standard errors from a 2x2 observed information matrix `[[a, b], [b, d]]`.
+fn std_errors_2x2(a: f64, b: f64, d: f64) -> Vec<f64> {
+    let det = a * d - b * b;
+    vec![(d / det).sqrt(), (a / det).sqrt()]
+}
+
+/// Internal: trigamma function via the recurrence and the asymptotic series for `x >= 6`.
+fn trigamma(mut x: f64) -> f64 {
+    let mut acc = 0.0;
+    while x < 6.0 {
+        acc += 1.0 / (x * x);
+        x += 1.0;
+    }
+    let r = 1.0 / (x * x);
+    acc + 1.0 / x + r / 2.0 + r / x * (1.0 / 6.0 - r * (1.0 / 30.0 - r * (1.0 / 42.0 - r / 30.0)))
+}
+
+/// Internal: bracket a sign change of `f` on `(0, inf)` by halving and doubling from `x0`.
+fn bracket_positive<F: Fn(f64) -> f64>(f: F, x0: f64) -> Option<(f64, f64)> {
+    let s0 = f(x0).signum();
+    let (mut lo, mut hi) = (x0, x0);
+    for _ in 0..MAX_ITER {
+        lo *= 0.5;
+        hi *= 2.0;
+        if f(lo).signum() != s0 {
+            return Some((lo, lo * 2.0));
+        }
+        if f(hi).signum() != s0 {
+            return Some((hi * 0.5, hi));
+        }
+    }
+    None
+}
+
+/// Internal: root of a monotone `f` in `[lo, hi]` by Newton steps with bisection fallback.
+///
+/// `f` returns the value and derivative; `f(lo)` and `f(hi)` must differ in sign.
+fn newton_bracketed<F: Fn(f64) -> (f64, f64)>(f: F, mut lo: f64, mut hi: f64) -> Option<f64> {
+    let lo_sign = f(lo).0.signum();
+    let mut x = 0.5 * (lo + hi);
+    for _ in 0..MAX_ITER {
+        let (fx, dfx) = f(x);
+        if fx == 0.0 {
+            return Some(x);
+        }
+        if fx.signum() == lo_sign {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let mut next = x - fx / dfx;
+        if !(next > lo && next < hi) {
+            next = 0.5 * (lo + hi);
+        }
+        if (next - x).abs() <= 1e-14 * next.abs() {
+            return Some(next);
+        }
+        x = next;
+    }
+    None
+}
+
+/// Fit a normal distribution: `(mean, sd)` with the MLE (divide-by-`n`) standard deviation.
+pub fn fit_normal(data: &[f64]) -> Result<FitResult<Normal>, DistributionError> {
+    check_data(data, "normal", "finite data", |_| true)?;
+    let n = data.len() as f64;
+    let (m, sd) = (mean(data), variance(data).sqrt());
+    let dist = Normal::new(m, sd)?;
+    let se = vec![sd / n.sqrt(), sd / (2.0 * n).sqrt()];
+    Ok(continuous_fit(dist, data, vec![m, sd], se))
+}
+
+/// Fit a log-normal distribution: normal MLE of `ln x`, giving `(location, scale)`.
+pub fn fit_lognormal(data: &[f64]) -> Result<FitResult<LogNormal>, DistributionError> {
+    check_data(data, "log-normal", "positive data", |x| x > 0.0)?;
+    let logs: Vec<f64> = data.iter().map(|x| x.ln()).collect();
+    let n = data.len() as f64;
+    let (location, scale) = (mean(&logs), variance(&logs).sqrt());
+    let dist = LogNormal::new(location, scale)?;
+    let se = vec![scale / n.sqrt(), scale / (2.0 * n).sqrt()];
+    Ok(continuous_fit(dist, data, vec![location, scale], se))
+}
+
+/// Fit an exponential distribution: `rate = 1 / mean`.
+pub fn fit_exponential(data: &[f64]) -> Result<FitResult<Exp>, DistributionError> {
+    check_data(data, "exponential", "non-negative data", |x| x >= 0.0)?;
+    let rate = 1.0 / mean(data);
+    let dist = Exp::new(rate)?;
+    let se = vec![rate / (data.len() as f64).sqrt()];
+    Ok(continuous_fit(dist, data, vec![rate], se))
+}
+
+/// Fit a gamma distribution `(shape, rate)`.
+///
+/// Solves `ln k - digamma(k) = ln(mean) - mean(ln x)` for the shape by Newton iteration from
+/// Minka's closed-form approximation; the rate is then `shape / mean`.
+pub fn fit_gamma(data: &[f64]) -> Result<FitResult<Gamma>, DistributionError> {
+    check_data(data, "gamma", "positive data", |x| x > 0.0)?;
+    let n = data.len() as f64;
+    let m = mean(data);
+    let s = m.ln() - data.iter().map(|x| x.ln()).sum::<f64>() / n;
+    if s <= 0.0 {
+        return Err(DistributionError::InvalidParameters(
+            "gamma fit requires data that are not all equal".to_string(),
+        ));
+    }
+
+    let score = |k: f64| k.ln() - digamma(k) - s;
+    let k0 = (3.0 - s + ((s - 3.0).powi(2) + 24.0 * s).sqrt()) / (12.0 * s);
+    let (lo, hi) = bracket_positive(score, k0).ok_or_else(|| not_converged("gamma"))?;
+    let shape = newton_bracketed(|k| (score(k), 1.0 / k - trigamma(k)), lo, hi)
+        .ok_or_else(|| not_converged("gamma"))?;
+    let rate = shape / m;
+
+    let dist = Gamma::new(shape, rate)?;
+    let se = std_errors_2x2(n * trigamma(shape), -n / rate, n * shape / (rate * rate));
+    Ok(continuous_fit(dist, data, vec![shape, rate], se))
+}
+
+/// Fit a beta distribution `(alpha, beta)` to data in `(0, 1)`.
+///
+/// Newton iteration on the two score equations, started from the method-of-moments estimates.
+pub fn fit_beta(data: &[f64]) -> Result<FitResult<Beta>, DistributionError> {
+    check_data(data, "beta", "data in (0, 1)", |x| x > 0.0 && x < 1.0)?;
+    let n = data.len() as f64;
+    let g1 = data.iter().map(|x| x.ln()).sum::<f64>() / n;
+    let g2 = data.iter().map(|x| (-x).ln_1p()).sum::<f64>() / n;
+
+    let (m, v) = (mean(data), variance(data));
+    let common = m * (1.0 - m) / v - 1.0;
+    let (mut a, mut b) = if common > 0.0 {
+        (m * common, (1.0 - m) * common)
+    } else {
+        (1.0, 1.0)
+    };
+
+    let mut converged = false;
+    for _ in 0..MAX_ITER {
+        let psi_ab = digamma(a + b);
+        let f1 = digamma(a) - psi_ab - g1;
+        let f2 = digamma(b) - psi_ab - g2;
+        let t_ab = trigamma(a + b);
+        let (j11, j12, j22) = (trigamma(a) - t_ab, -t_ab, trigamma(b) - t_ab);
+        let det = j11 * j22 - j12 * j12;
+        let da = (j22 * f1 - j12 * f2) / det;
+        let db = (j11 * f2 - j12 * f1) / det;
+
+        // Halve the step until both shapes stay positive.
+        let mut step = 1.0;
+        while a - step * da <= 0.0 || b - step * db <= 0.0 {
+            step *= 0.5;
+        }
+        a -= step * da;
+        b -= step * db;
+        if (step * da).abs() <= 1e-12 * a && (step * db).abs() <= 1e-12 * b {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(not_converged("beta"));
+    }
+
+    let dist = Beta::new(a, b)?;
+    let t_ab = trigamma(a + b);
+    let se = std_errors_2x2(
+        n * (trigamma(a) - t_ab),
+        -n * t_ab,
+        n * (trigamma(b) - t_ab),
+    );
+    Ok(continuous_fit(dist, data, vec![a, b], se))
+}
+
+/// Fit a Weibull distribution `(shape, scale)` to positive data.
+///
+/// The shape solves the profile score equation by Newton iteration (data are rescaled by their
+/// maximum so powers cannot overflow); the scale then has a closed form.
+pub fn fit_weibull(data: &[f64]) -> Result<FitResult<Weibull>, DistributionError> {
+    check_data(data, "Weibull", "positive data", |x| x > 0.0)?;
+    let n = data.len() as f64;
+    let x_max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let logs: Vec<f64> = data.iter().map(|x| (x / x_max).ln()).collect();
+    let mean_log = mean(&logs);
+    let sd_log = variance(&logs).sqrt();
+    if sd_log == 0.0 {
+        return Err(DistributionError::InvalidParameters(
+            "Weibull fit requires data that are not all equal".to_string(),
+        ));
+    }
+
+    // Sums of z^k, z^k ln z and z^k ln² z with z = x / max.
+    let sums = |k: f64| {
+        logs.iter().fold((0.0, 0.0, 0.0), |(s0, s1, s2), &l| {
+            let w = (k * l).exp();
+            (s0 + w, s1 + w * l, s2 + w * l * l)
+        })
+    };
+    let score = |k: f64| {
+        let (s0, s1, s2) = sums(k);
+        let a = s1 / s0;
+        let value = a - 1.0 / k - mean_log;
+        let slope = s2 / s0 - a * a + 1.0 / (k * k);
+        (value, slope)
+    };
+    let k0 = std::f64::consts::PI / (6f64.sqrt() * sd_log);
+    let (lo, hi) = bracket_positive(|k| score(k).0, k0).ok_or_else(|| not_converged("Weibull"))?;
+    let shape = newton_bracketed(score, lo, hi).ok_or_else(|| not_converged("Weibull"))?;
+    let (s0, _, _) = sums(shape);
+    let scale = x_max * (s0 / n).powf(1.0 / shape);
+
+    // Observed information at (shape, scale), with z = x / scale and w = z^shape.
+    let (mut sw, mut swl, mut swll) = (0.0, 0.0, 0.0);
+    for &x in data {
+        let lz = (x / scale).ln();
+        let w = (shape * lz).exp();
+        sw += w;
+        swl += w * lz;
+        swll += w * lz * lz;
+    }
+    let i_kk = n / (shape * shape) + swll;
+    let i_kl = (n - sw - shape * swl) / scale;
+    let i_ll = shape * ((shape + 1.0) * sw - n) / (scale * scale);
+
+    let dist = Weibull::new(shape, scale)?;
+    Ok(continuous_fit(
+        dist,
+        data,
+        vec![shape, scale],
+        std_errors_2x2(i_kk, i_kl, i_ll),
+    ))
+}
+
+/// Fit a Laplace distribution: location is the sample median, scale the mean absolute
+/// deviation from it.
+///
+/// The likelihood is not differentiable in the location, so both standard errors use the
+/// asymptotic variance `scale² / n`.
+pub fn fit_laplace(data: &[f64]) -> Result<FitResult<Laplace>, DistributionError> {
+    check_data(data, "Laplace", "finite data", |_| true)?;
+    let n = data.len() as f64;
+    let location = median(data);
+    let scale = data.iter().map(|x| (x - location).abs()).sum::<f64>() / n;
+    let dist = Laplace::new(location, scale)?;
+    let se = scale / n.sqrt();
+    Ok(continuous_fit(
+        dist,
+        data,
+        vec![location, scale],
+        vec![se, se],
+    ))
+}
+
+/// Fit a Poisson distribution to non-negative integer counts: `lambda = mean`.
+pub fn fit_poisson(data: &[f64]) -> Result<FitResult<Poisson>, DistributionError> {
+    check_data(data, "Poisson", "non-negative integer counts", |x| {
+        x >= 0.0 && x.fract() == 0.0
+    })?;
+    let lambda = mean(data);
+    let dist = Poisson::new(lambda)?;
+    let se = vec![(lambda / data.len() as f64).sqrt()];
+    Ok(discrete_fit(dist, data, vec![lambda], se))
+}
+
+/// Fit the success probability of a binomial distribution with a known number of trials.
+pub fn fit_binomial(data: &[f64], trials: u64) -> Result<FitResult<Binomial>, DistributionError> {
+    check_data(data, "binomial", "integer counts in [0, trials]", |x| {
+        x >= 0.0 && x <= trials as f64 && x.fract() == 0.0
+    })?;
+    let p = mean(data) / trials as f64;
+    let dist = Binomial::new(trials, p)?;
+    let se = vec![(p * (1.0 - p) / (trials as f64 * data.len() as f64)).sqrt()];
+    Ok(discrete_fit(dist, data, vec![p], se))
+}
+
+/// Fit a negative binomial distribution `(r, p)` to non-negative integer counts.
+///
+/// For fixed `r` the MLE of `p` is `r / (r + mean)`; `r` solves the profile score equation by
+/// Newton iteration from the method-of-moments estimate. The MLE only exists for overdispersed
+/// data (sample variance above the mean).
+pub fn fit_negbin(data: &[f64]) -> Result<FitResult<NegativeBinomial>, DistributionError> {
+    check_data(
+        data,
+        "negative binomial",
+        "non-negative integer counts",
+        |x| x >= 0.0 && x.fract() == 0.0,
+    )?;
+    let n = data.len() as f64;
+    let (m, v) = (mean(data), variance(data));
+    if v <= m {
+        return Err(DistributionError::InvalidParameters(
+            "negative binomial fit requires overdispersed data (variance > mean)".to_string(),
+        ));
+    }
+
+    let score = |r: f64| {
+        let (mut psi, mut tri) = (0.0, 0.0);
+        for &x in data {
+            psi += digamma(x + r);
+            tri += trigamma(x + r);
+        }
+        let value = psi - n * digamma(r) + n * (r / (r + m)).ln();
+        let slope = tri - n * trigamma(r) + n * (1.0 / r - 1.0 / (r + m));
+        (value, slope)
+    };
+    let r0 = m * m / (v - m);
+    let (lo, hi) =
+        bracket_positive(|r| score(r).0, r0).ok_or_else(|| not_converged("negative binomial"))?;
+    let r = newton_bracketed(score, lo, hi).ok_or_else(|| not_converged("negative binomial"))?;
+    let p = r / (r + m);
+
+    let tri_sum: f64 = data.iter().map(|&x| trigamma(x + r)).sum();
+    let i_rr = n * trigamma(r) - tri_sum;
+    let i_rp = -n / p;
+    let i_pp = n * r / (p * p) + n * m / ((1.0 - p) * (1.0 - p));
+
+    let dist = NegativeBinomial::new(r, p)?;
+    Ok(discrete_fit(
+        dist,
+        data,
+        vec![r, p],
+        std_errors_2x2(i_rr, i_rp, i_pp),
+    ))
+}
@@ -0,0 +1,5235 @@
+mod families;
+mod fit;
+mod multivariate;
+mod traits;
+
+pub use families::*;
+pub use fit::*;
+pub use multivariate::*;
+pub use traits::*;
+
+use statrs::function::erf::erfc;
+use statrs::function::gamma::ln_gamma;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistributionError {
+    InvalidParameters(String),
+    LengthMismatch { input: usize, output: usize },
+    ProbabilityOutOfRange { value: f64 },
+}
+
+impl fmt::Display for DistributionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistributionError::InvalidParameters(msg) => write!(f, "invalid parameters: {msg}"),
+            DistributionError::LengthMismatch { input, output } => write!(
+                f,
+                "input ({input}) and output ({output}) slices must be the same length"
+            ),
+            DistributionError::ProbabilityOutOfRange { value } => {
+                write!(f, "probability must be within [0, 1]; received {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DistributionError {}
+
+fn invalid_params<E: fmt::Display>(err: E) -> DistributionError {
+    DistributionError::InvalidParameters(err.to_string())
+}
+
+fn ensure_probability(p: f64) -> Result<(), DistributionError> {
+    if !(0.0..=1.0).contains(&p) || p.is_nan() {
+        Err(DistributionError::ProbabilityOutOfRange { value: p })
+    } else {
+        Ok(())
+    }
+}
+
+fn apply_unary_op<F>(input: &[f64], output: &mut [f64], mut op: F) -> Result<(), DistributionError>
+where
+    F: FnMut(f64) -> f64,
+{
+    if input.len() != output.len() {
+        return Err(DistributionError::LengthMismatch {
+            input: input.len(),
+            output: output.len(),
+        });
+    }
+
+    for (dst, &value) in output.iter_mut().zip(input.iter()) {
+        *dst = op(value);
+    }
+
+    Ok(())
+}
+
+// Free functions
+//
+// Each family is also exposed as free functions taking the parameters on every call. They
+// construct the family struct (see `families`) and delegate to it, so validation is identical.
+//
+// For tail work prefer `*_sf`, `*_logsf`, `*_logcdf` and `*_isf` over `1 - *_cdf` and
+// `*_inv(1 - p)`, and sum `*_logpdf` / `*_logpmf` instead of multiplying densities.
+//
+// Every family has `*_sample(count, params.., seed)` and `*_sample_into(output, params.., seed)`.
+// Draws come from a `Xoshiro256PlusPlus` seeded with `seed`, so results are reproducible across
+// platforms.
+
+pub fn normal_pdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.pdf(x))
+}
+
+pub fn normal_cdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.cdf(x))
+}
+
+pub fn normal_inv(p: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Normal::new(mean, sd)?.inverse_cdf(p)
+}
+
+pub fn normal_pdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.pdf_array(input, output)
+}
+
+pub fn normal_cdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.cdf_array(input, output)
+}
+
+pub fn normal_logpdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.ln_pdf(x))
+}
+
+pub fn normal_logcdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.ln_cdf(x))
+}
+
+pub fn normal_sf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.sf(x))
+}
+
+pub fn normal_logsf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(Normal::new(mean, sd)?.ln_sf(x))
+}
+
+pub fn normal_isf(p: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Normal::new(mean, sd)?.inverse_sf(p)
+}
+
+pub fn normal_logpdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.ln_pdf_array(input, output)
+}
+
+pub fn normal_logcdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.ln_cdf_array(input, output)
+}
+
+pub fn normal_sf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.sf_array(input, output)
+}
+
+pub fn normal_logsf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.ln_sf_array(input, output)
+}
+
+pub fn normal_sample(
+    count: usize,
+    mean: f64,
+    sd: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    normal_sample_into(&mut output, mean, sd, seed)?;
+    Ok(output)
+}
+
+pub fn normal_sample_into(
+    output: &mut [f64],
+    mean: f64,
+    sd: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Normal::new(mean, sd)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn gamma_pdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.pdf(x))
+}
+
+pub fn gamma_cdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.cdf(x))
+}
+
+pub fn gamma_inv(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gamma::new(shape, rate)?.inverse_cdf(p)
+}
+
+pub fn gamma_pdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.pdf_array(input, output)
+}
+
+pub fn gamma_cdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.cdf_array(input, output)
+}
+
+pub fn gamma_logpdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.ln_pdf(x))
+}
+
+pub fn gamma_logcdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.ln_cdf(x))
+}
+
+pub fn gamma_sf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.sf(x))
+}
+
+pub fn gamma_logsf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Gamma::new(shape, rate)?.ln_sf(x))
+}
+
+pub fn gamma_isf(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gamma::new(shape, rate)?.inverse_sf(p)
+}
+
+pub fn gamma_logpdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.ln_pdf_array(input, output)
+}
+
+pub fn gamma_logcdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.ln_cdf_array(input, output)
+}
+
+pub fn gamma_sf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.sf_array(input, output)
+}
+
+pub fn gamma_logsf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.ln_sf_array(input, output)
+}
+
+pub fn gamma_sample(
+    count: usize,
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    gamma_sample_into(&mut output, shape, rate, seed)?;
+    Ok(output)
+}
+
+pub fn gamma_sample_into(
+    output: &mut [f64],
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Gamma::new(shape, rate)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn beta_pdf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.pdf(x))
+}
+
+pub fn beta_cdf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.cdf(x))
+}
+
+pub fn beta_inv(p: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Beta::new(alpha, beta)?.inverse_cdf(p)
+}
+
+pub fn beta_pdf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.pdf_array(input, output)
+}
+
+pub fn beta_cdf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.cdf_array(input, output)
+}
+
+pub fn beta_logpdf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.ln_pdf(x))
+}
+
+pub fn beta_logcdf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.ln_cdf(x))
+}
+
+pub fn beta_sf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.sf(x))
+}
+
+pub fn beta_logsf(x: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    Ok(Beta::new(alpha, beta)?.ln_sf(x))
+}
+
+pub fn beta_isf(p: f64, alpha: f64, beta: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Beta::new(alpha, beta)?.inverse_sf(p)
+}
+
+pub fn beta_logpdf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.ln_pdf_array(input, output)
+}
+
+pub fn beta_logcdf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.ln_cdf_array(input, output)
+}
+
+pub fn beta_sf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.sf_array(input, output)
+}
+
+pub fn beta_logsf_array(
+    input: &[f64],
+    alpha: f64,
+    beta: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.ln_sf_array(input, output)
+}
+
+pub fn beta_sample(
+    count: usize,
+    alpha: f64,
+    beta: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    beta_sample_into(&mut output, alpha, beta, seed)?;
+    Ok(output)
+}
+
+pub fn beta_sample_into(
+    output: &mut [f64],
+    alpha: f64,
+    beta: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Beta::new(alpha, beta)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn student_t_pdf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.pdf(x))
+}
+
+pub fn student_t_cdf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.cdf(x))
+}
+
+pub fn student_t_inv(p: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    StudentsT::new(mean, scale, dof)?.inverse_cdf(p)
+}
+
+pub fn student_t_pdf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.pdf_array(input, output)
+}
+
+pub fn student_t_cdf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.cdf_array(input, output)
+}
+
+pub fn student_t_logpdf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.ln_pdf(x))
+}
+
+pub fn student_t_logcdf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.ln_cdf(x))
+}
+
+pub fn student_t_sf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.sf(x))
+}
+
+pub fn student_t_logsf(x: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(StudentsT::new(mean, scale, dof)?.ln_sf(x))
+}
+
+pub fn student_t_isf(p: f64, mean: f64, scale: f64, dof: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    StudentsT::new(mean, scale, dof)?.inverse_sf(p)
+}
+
+pub fn student_t_logpdf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.ln_pdf_array(input, output)
+}
+
+pub fn student_t_logcdf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.ln_cdf_array(input, output)
+}
+
+pub fn student_t_sf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.sf_array(input, output)
+}
+
+pub fn student_t_logsf_array(
+    input: &[f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.ln_sf_array(input, output)
+}
+
+pub fn student_t_sample(
+    count: usize,
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    student_t_sample_into(&mut output, mean, scale, dof, seed)?;
+    Ok(output)
+}
+
+pub fn student_t_sample_into(
+    output: &mut [f64],
+    mean: f64,
+    scale: f64,
+    dof: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    StudentsT::new(mean, scale, dof)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn chi_squared_pdf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.pdf(x))
+}
+
+pub fn chi_squared_cdf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.cdf(x))
+}
+
+pub fn chi_squared_inv(p: f64, dof: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    ChiSquared::new(dof)?.inverse_cdf(p)
+}
+
+pub fn chi_squared_pdf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.pdf_array(input, output)
+}
+
+pub fn chi_squared_cdf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.cdf_array(input, output)
+}
+
+pub fn chi_squared_logpdf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.ln_pdf(x))
+}
+
+pub fn chi_squared_logcdf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.ln_cdf(x))
+}
+
+pub fn chi_squared_sf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.sf(x))
+}
+
+pub fn chi_squared_logsf(x: f64, dof: f64) -> Result<f64, DistributionError> {
+    Ok(ChiSquared::new(dof)?.ln_sf(x))
+}
+
+pub fn chi_squared_isf(p: f64, dof: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    ChiSquared::new(dof)?.inverse_sf(p)
+}
+
+pub fn chi_squared_logpdf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.ln_pdf_array(input, output)
+}
+
+pub fn chi_squared_logcdf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.ln_cdf_array(input, output)
+}
+
+pub fn chi_squared_sf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.sf_array(input, output)
+}
+
+pub fn chi_squared_logsf_array(
+    input: &[f64],
+    dof: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.ln_sf_array(input, output)
+}
+
+pub fn chi_squared_sample(
+    count: usize,
+    dof: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    chi_squared_sample_into(&mut output, dof, seed)?;
+    Ok(output)
+}
+
+pub fn chi_squared_sample_into(
+    output: &mut [f64],
+    dof: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    ChiSquared::new(dof)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn fisher_f_pdf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.pdf(x))
+}
+
+pub fn fisher_f_cdf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.cdf(x))
+}
+
+pub fn fisher_f_inv(p: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    FisherSnedecor::new(df1, df2)?.inverse_cdf(p)
+}
+
+pub fn fisher_f_pdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.pdf_array(input, output)
+}
+
+pub fn fisher_f_cdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.cdf_array(input, output)
+}
+
+pub fn fisher_f_logpdf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.ln_pdf(x))
+}
+
+pub fn fisher_f_logcdf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.ln_cdf(x))
+}
+
+pub fn fisher_f_sf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.sf(x))
+}
+
+pub fn fisher_f_logsf(x: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    Ok(FisherSnedecor::new(df1, df2)?.ln_sf(x))
+}
+
+pub fn fisher_f_isf(p: f64, df1: f64, df2: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    FisherSnedecor::new(df1, df2)?.inverse_sf(p)
+}
+
+pub fn fisher_f_logpdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.ln_pdf_array(input, output)
+}
+
+pub fn fisher_f_logcdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.ln_cdf_array(input, output)
+}
+
+pub fn fisher_f_sf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.sf_array(input, output)
+}
+
+pub fn fisher_f_logsf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.ln_sf_array(input, output)
+}
+
+pub fn fisher_f_sample(
+    count: usize,
+    df1: f64,
+    df2: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    fisher_f_sample_into(&mut output, df1, df2, seed)?;
+    Ok(output)
+}
+
+pub fn fisher_f_sample_into(
+    output: &mut [f64],
+    df1: f64,
+    df2: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    FisherSnedecor::new(df1, df2)?.sample_into(output, seed);
+    Ok(())
+}
+
+pub fn exponential_pdf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.pdf(x))
+}
+
+pub fn exponential_cdf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.cdf(x))
+}
+
+pub fn exponential_inv(p: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Exp::new(rate)?.inverse_cdf(p)
+}
+
+pub fn exponential_pdf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.pdf_array(input, output)
+}
+
+pub fn exponential_cdf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.cdf_array(input, output)
+}
+
+pub fn exponential_logpdf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.ln_pdf(x))
+}
+
+pub fn exponential_logcdf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.ln_cdf(x))
+}
+
+pub fn exponential_sf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.sf(x))
+}
+
+pub fn exponential_logsf(x: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Exp::new(rate)?.ln_sf(x))
+}
+
+pub fn exponential_isf(p: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Exp::new(rate)?.inverse_sf(p)
+}
+
+pub fn exponential_logpdf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.ln_pdf_array(input, output)
+}
+
+pub fn exponential_logcdf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.ln_cdf_array(input, output)
+}
+
+pub fn exponential_sf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.sf_array(input, output)
+}
+
+pub fn exponential_logsf_array(
+    input: &[f64],
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.ln_sf_array(input, output)
+}
+
+pub fn exponential_sample(
+    count: usize,
+    rate: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    exponential_sample_into(&mut output, rate, seed)?;
+    Ok(output)
+}
+
+pub fn exponential_sample_into(
+    output: &mut [f64],
+    rate: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Exp::new(rate)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Poisson distribution (discrete)
+pub fn poisson_pmf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.pmf(k_int))
+}
+
+pub fn poisson_cdf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.cdf(k_int))
+}
+
+pub fn poisson_inv(p: f64, lambda: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(Poisson::new(lambda)?.inverse_cdf(p)? as f64)
+}
+
+pub fn poisson_pmf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.pmf_array(input, output)
+}
+
+pub fn poisson_cdf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.cdf_array(input, output)
+}
+
+pub fn poisson_logpmf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.ln_pmf(k_int))
+}
+
+pub fn poisson_logcdf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.ln_cdf(k_int))
+}
+
+pub fn poisson_sf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.sf(k_int))
+}
+
+pub fn poisson_logsf(k: f64, lambda: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Poisson::new(lambda)?.ln_sf(k_int))
+}
+
+pub fn poisson_isf(p: f64, lambda: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(Poisson::new(lambda)?.inverse_sf(p)? as f64)
+}
+
+pub fn poisson_logpmf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.ln_pmf_array(input, output)
+}
+
+pub fn poisson_logcdf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.ln_cdf_array(input, output)
+}
+
+pub fn poisson_sf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.sf_array(input, output)
+}
+
+pub fn poisson_logsf_array(
+    input: &[f64],
+    lambda: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.ln_sf_array(input, output)
+}
+
+pub fn poisson_sample(count: usize, lambda: f64, seed: u64) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    poisson_sample_into(&mut output, lambda, seed)?;
+    Ok(output)
+}
+
+pub fn poisson_sample_into(
+    output: &mut [f64],
+    lambda: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Poisson::new(lambda)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Binomial distribution (discrete)
+pub fn binomial_pmf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.pmf(k_int))
+}
+
+pub fn binomial_cdf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.cdf(k_int))
+}
+
+pub fn binomial_inv(prob: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    ensure_probability(prob)?;
+    Ok(Binomial::new(n as u64, p)?.inverse_cdf(prob)? as f64)
+}
+
+pub fn binomial_pmf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.pmf_array(input, output)
+}
+
+pub fn binomial_cdf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.cdf_array(input, output)
+}
+
+pub fn binomial_logpmf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.ln_pmf(k_int))
+}
+
+pub fn binomial_logcdf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.ln_cdf(k_int))
+}
+
+pub fn binomial_sf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.sf(k_int))
+}
+
+pub fn binomial_logsf(k: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Binomial::new(n as u64, p)?.ln_sf(k_int))
+}
+
+pub fn binomial_isf(prob: f64, n: f64, p: f64) -> Result<f64, DistributionError> {
+    ensure_probability(prob)?;
+    Ok(Binomial::new(n as u64, p)?.inverse_sf(prob)? as f64)
+}
+
+pub fn binomial_logpmf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.ln_pmf_array(input, output)
+}
+
+pub fn binomial_logcdf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.ln_cdf_array(input, output)
+}
+
+pub fn binomial_sf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.sf_array(input, output)
+}
+
+pub fn binomial_logsf_array(
+    input: &[f64],
+    n: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.ln_sf_array(input, output)
+}
+
+pub fn binomial_sample(
+    count: usize,
+    n: f64,
+    p: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    binomial_sample_into(&mut output, n, p, seed)?;
+    Ok(output)
+}
+
+pub fn binomial_sample_into(
+    output: &mut [f64],
+    n: f64,
+    p: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Binomial::new(n as u64, p)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Uniform distribution
+pub fn uniform_pdf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.pdf(x))
+}
+
+pub fn uniform_cdf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.cdf(x))
+}
+
+pub fn uniform_inv(p: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Uniform::new(min, max)?.inverse_cdf(p)
+}
+
+pub fn uniform_pdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.pdf_array(input, output)
+}
+
+pub fn uniform_cdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.cdf_array(input, output)
+}
+
+pub fn uniform_logpdf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.ln_pdf(x))
+}
+
+pub fn uniform_logcdf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.ln_cdf(x))
+}
+
+pub fn uniform_sf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.sf(x))
+}
+
+pub fn uniform_logsf(x: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    Ok(Uniform::new(min, max)?.ln_sf(x))
+}
+
+pub fn uniform_isf(p: f64, min: f64, max: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Uniform::new(min, max)?.inverse_sf(p)
+}
+
+pub fn uniform_logpdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.ln_pdf_array(input, output)
+}
+
+pub fn uniform_logcdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.ln_cdf_array(input, output)
+}
+
+pub fn uniform_sf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.sf_array(input, output)
+}
+
+pub fn uniform_logsf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.ln_sf_array(input, output)
+}
+
+pub fn uniform_sample(
+    count: usize,
+    min: f64,
+    max: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    uniform_sample_into(&mut output, min, max, seed)?;
+    Ok(output)
+}
+
+pub fn uniform_sample_into(
+    output: &mut [f64],
+    min: f64,
+    max: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Uniform::new(min, max)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Cauchy distribution
+pub fn cauchy_pdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.pdf(x))
+}
+
+pub fn cauchy_cdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.cdf(x))
+}
+
+pub fn cauchy_inv(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Cauchy::new(location, scale)?.inverse_cdf(p)
+}
+
+pub fn cauchy_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.pdf_array(input, output)
+}
+
+pub fn cauchy_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.cdf_array(input, output)
+}
+
+pub fn cauchy_logpdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.ln_pdf(x))
+}
+
+pub fn cauchy_logcdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.ln_cdf(x))
+}
+
+pub fn cauchy_sf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.sf(x))
+}
+
+pub fn cauchy_logsf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Cauchy::new(location, scale)?.ln_sf(x))
+}
+
+pub fn cauchy_isf(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Cauchy::new(location, scale)?.inverse_sf(p)
+}
+
+pub fn cauchy_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.ln_pdf_array(input, output)
+}
+
+pub fn cauchy_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.ln_cdf_array(input, output)
+}
+
+pub fn cauchy_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.sf_array(input, output)
+}
+
+pub fn cauchy_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.ln_sf_array(input, output)
+}
+
+pub fn cauchy_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    cauchy_sample_into(&mut output, location, scale, seed)?;
+    Ok(output)
+}
+
+pub fn cauchy_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Cauchy::new(location, scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Laplace distribution
+pub fn laplace_pdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.pdf(x))
+}
+
+pub fn laplace_cdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.cdf(x))
+}
+
+pub fn laplace_inv(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Laplace::new(location, scale)?.inverse_cdf(p)
+}
+
+pub fn laplace_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.pdf_array(input, output)
+}
+
+pub fn laplace_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.cdf_array(input, output)
+}
+
+pub fn laplace_logpdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.ln_pdf(x))
+}
+
+pub fn laplace_logcdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.ln_cdf(x))
+}
+
+pub fn laplace_sf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.sf(x))
+}
+
+pub fn laplace_logsf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Laplace::new(location, scale)?.ln_sf(x))
+}
+
+pub fn laplace_isf(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Laplace::new(location, scale)?.inverse_sf(p)
+}
+
+pub fn laplace_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.ln_pdf_array(input, output)
+}
+
+pub fn laplace_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.ln_cdf_array(input, output)
+}
+
+pub fn laplace_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.sf_array(input, output)
+}
+
+pub fn laplace_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.ln_sf_array(input, output)
+}
+
+pub fn laplace_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    laplace_sample_into(&mut output, location, scale, seed)?;
+    Ok(output)
+}
+
+pub fn laplace_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Laplace::new(location, scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Log-normal distribution
+pub fn lognormal_pdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.pdf(x))
+}
+
+pub fn lognormal_cdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.cdf(x))
+}
+
+pub fn lognormal_inv(p: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    LogNormal::new(mean, sd)?.inverse_cdf(p)
+}
+
+pub fn lognormal_pdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.pdf_array(input, output)
+}
+
+pub fn lognormal_cdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.cdf_array(input, output)
+}
+
+pub fn lognormal_logpdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.ln_pdf(x))
+}
+
+pub fn lognormal_logcdf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.ln_cdf(x))
+}
+
+pub fn lognormal_sf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.sf(x))
+}
+
+pub fn lognormal_logsf(x: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    Ok(LogNormal::new(mean, sd)?.ln_sf(x))
+}
+
+pub fn lognormal_isf(p: f64, mean: f64, sd: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    LogNormal::new(mean, sd)?.inverse_sf(p)
+}
+
+pub fn lognormal_logpdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.ln_pdf_array(input, output)
+}
+
+pub fn lognormal_logcdf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.ln_cdf_array(input, output)
+}
+
+pub fn lognormal_sf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.sf_array(input, output)
+}
+
+pub fn lognormal_logsf_array(
+    input: &[f64],
+    mean: f64,
+    sd: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.ln_sf_array(input, output)
+}
+
+pub fn lognormal_sample(
+    count: usize,
+    mean: f64,
+    sd: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    lognormal_sample_into(&mut output, mean, sd, seed)?;
+    Ok(output)
+}
+
+pub fn lognormal_sample_into(
+    output: &mut [f64],
+    mean: f64,
+    sd: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    LogNormal::new(mean, sd)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Weibull distribution
+pub fn weibull_pdf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.pdf(x))
+}
+
+pub fn weibull_cdf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.cdf(x))
+}
+
+pub fn weibull_inv(p: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Weibull::new(shape, scale)?.inverse_cdf(p)
+}
+
+pub fn weibull_pdf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.pdf_array(input, output)
+}
+
+pub fn weibull_cdf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.cdf_array(input, output)
+}
+
+pub fn weibull_logpdf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.ln_pdf(x))
+}
+
+pub fn weibull_logcdf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.ln_cdf(x))
+}
+
+pub fn weibull_sf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.sf(x))
+}
+
+pub fn weibull_logsf(x: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Weibull::new(shape, scale)?.ln_sf(x))
+}
+
+pub fn weibull_isf(p: f64, shape: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Weibull::new(shape, scale)?.inverse_sf(p)
+}
+
+pub fn weibull_logpdf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.ln_pdf_array(input, output)
+}
+
+pub fn weibull_logcdf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.ln_cdf_array(input, output)
+}
+
+pub fn weibull_sf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.sf_array(input, output)
+}
+
+pub fn weibull_logsf_array(
+    input: &[f64],
+    shape: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.ln_sf_array(input, output)
+}
+
+pub fn weibull_sample(
+    count: usize,
+    shape: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    weibull_sample_into(&mut output, shape, scale, seed)?;
+    Ok(output)
+}
+
+pub fn weibull_sample_into(
+    output: &mut [f64],
+    shape: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Weibull::new(shape, scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Pareto distribution
+pub fn pareto_pdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.pdf(x))
+}
+
+pub fn pareto_cdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.cdf(x))
+}
+
+pub fn pareto_inv(p: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Pareto::new(scale, shape)?.inverse_cdf(p)
+}
+
+pub fn pareto_pdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.pdf_array(input, output)
+}
+
+pub fn pareto_cdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.cdf_array(input, output)
+}
+
+pub fn pareto_logpdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.ln_pdf(x))
+}
+
+pub fn pareto_logcdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.ln_cdf(x))
+}
+
+pub fn pareto_sf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.sf(x))
+}
+
+pub fn pareto_logsf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Pareto::new(scale, shape)?.ln_sf(x))
+}
+
+pub fn pareto_isf(p: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Pareto::new(scale, shape)?.inverse_sf(p)
+}
+
+pub fn pareto_logpdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.ln_pdf_array(input, output)
+}
+
+pub fn pareto_logcdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.ln_cdf_array(input, output)
+}
+
+pub fn pareto_sf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.sf_array(input, output)
+}
+
+pub fn pareto_logsf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.ln_sf_array(input, output)
+}
+
+pub fn pareto_sample(
+    count: usize,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    pareto_sample_into(&mut output, scale, shape, seed)?;
+    Ok(output)
+}
+
+pub fn pareto_sample_into(
+    output: &mut [f64],
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Pareto::new(scale, shape)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Triangular distribution
+pub fn triangular_pdf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.pdf(x))
+}
+
+pub fn triangular_cdf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.cdf(x))
+}
+
+pub fn triangular_inv(p: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Triangular::new(min, max, mode)?.inverse_cdf(p)
+}
+
+pub fn triangular_pdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.pdf_array(input, output)
+}
+
+pub fn triangular_cdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.cdf_array(input, output)
+}
+
+pub fn triangular_logpdf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.ln_pdf(x))
+}
+
+pub fn triangular_logcdf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.ln_cdf(x))
+}
+
+pub fn triangular_sf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.sf(x))
+}
+
+pub fn triangular_logsf(x: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    Ok(Triangular::new(min, max, mode)?.ln_sf(x))
+}
+
+pub fn triangular_isf(p: f64, min: f64, max: f64, mode: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Triangular::new(min, max, mode)?.inverse_sf(p)
+}
+
+pub fn triangular_logpdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.ln_pdf_array(input, output)
+}
+
+pub fn triangular_logcdf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.ln_cdf_array(input, output)
+}
+
+pub fn triangular_sf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.sf_array(input, output)
+}
+
+pub fn triangular_logsf_array(
+    input: &[f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.ln_sf_array(input, output)
+}
+
+pub fn triangular_sample(
+    count: usize,
+    min: f64,
+    max: f64,
+    mode: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    triangular_sample_into(&mut output, min, max, mode, seed)?;
+    Ok(output)
+}
+
+pub fn triangular_sample_into(
+    output: &mut [f64],
+    min: f64,
+    max: f64,
+    mode: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Triangular::new(min, max, mode)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Inverse gamma distribution
+pub fn invgamma_pdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.pdf(x))
+}
+
+pub fn invgamma_cdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.cdf(x))
+}
+
+pub fn invgamma_inv(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    InverseGamma::new(shape, rate)?.inverse_cdf(p)
+}
+
+pub fn invgamma_pdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.pdf_array(input, output)
+}
+
+pub fn invgamma_cdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.cdf_array(input, output)
+}
+
+pub fn invgamma_logpdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.ln_pdf(x))
+}
+
+pub fn invgamma_logcdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.ln_cdf(x))
+}
+
+pub fn invgamma_sf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.sf(x))
+}
+
+pub fn invgamma_logsf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(InverseGamma::new(shape, rate)?.ln_sf(x))
+}
+
+pub fn invgamma_isf(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    InverseGamma::new(shape, rate)?.inverse_sf(p)
+}
+
+pub fn invgamma_logpdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.ln_pdf_array(input, output)
+}
+
+pub fn invgamma_logcdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.ln_cdf_array(input, output)
+}
+
+pub fn invgamma_sf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.sf_array(input, output)
+}
+
+pub fn invgamma_logsf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.ln_sf_array(input, output)
+}
+
+pub fn invgamma_sample(
+    count: usize,
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    invgamma_sample_into(&mut output, shape, rate, seed)?;
+    Ok(output)
+}
+
+pub fn invgamma_sample_into(
+    output: &mut [f64],
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    InverseGamma::new(shape, rate)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Negative binomial distribution (discrete)
+pub fn negbin_pmf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.pmf(k_int))
+}
+
+pub fn negbin_cdf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.cdf(k_int))
+}
+
+pub fn negbin_inv(p: f64, r: f64, prob: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(NegativeBinomial::new(r, prob)?.inverse_cdf(p)? as f64)
+}
+
+pub fn negbin_pmf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.pmf_array(input, output)
+}
+
+pub fn negbin_cdf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.cdf_array(input, output)
+}
+
+pub fn negbin_logpmf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.ln_pmf(k_int))
+}
+
+pub fn negbin_logcdf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.ln_cdf(k_int))
+}
+
+pub fn negbin_sf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.sf(k_int))
+}
+
+pub fn negbin_logsf(k: f64, r: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(NegativeBinomial::new(r, p)?.ln_sf(k_int))
+}
+
+pub fn negbin_isf(p: f64, r: f64, prob: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(NegativeBinomial::new(r, prob)?.inverse_sf(p)? as f64)
+}
+
+pub fn negbin_logpmf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.ln_pmf_array(input, output)
+}
+
+pub fn negbin_logcdf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.ln_cdf_array(input, output)
+}
+
+pub fn negbin_sf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.sf_array(input, output)
+}
+
+pub fn negbin_logsf_array(
+    input: &[f64],
+    r: f64,
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.ln_sf_array(input, output)
+}
+
+pub fn negbin_sample(
+    count: usize,
+    r: f64,
+    p: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    negbin_sample_into(&mut output, r, p, seed)?;
+    Ok(output)
+}
+
+pub fn negbin_sample_into(
+    output: &mut [f64],
+    r: f64,
+    p: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    NegativeBinomial::new(r, p)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Gumbel (type I extreme value) distribution
+pub fn gumbel_pdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.pdf(x))
+}
+
+pub fn gumbel_cdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.cdf(x))
+}
+
+pub fn gumbel_inv(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gumbel::new(location, scale)?.inverse_cdf(p)
+}
+
+pub fn gumbel_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.pdf_array(input, output)
+}
+
+pub fn gumbel_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.cdf_array(input, output)
+}
+
+pub fn gumbel_logpdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.ln_pdf(x))
+}
+
+pub fn gumbel_logcdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.ln_cdf(x))
+}
+
+pub fn gumbel_sf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.sf(x))
+}
+
+pub fn gumbel_logsf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Gumbel::new(location, scale)?.ln_sf(x))
+}
+
+pub fn gumbel_isf(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gumbel::new(location, scale)?.inverse_sf(p)
+}
+
+pub fn gumbel_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.ln_pdf_array(input, output)
+}
+
+pub fn gumbel_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.ln_cdf_array(input, output)
+}
+
+pub fn gumbel_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.sf_array(input, output)
+}
+
+pub fn gumbel_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.ln_sf_array(input, output)
+}
+
+pub fn gumbel_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    gumbel_sample_into(&mut output, location, scale, seed)?;
+    Ok(output)
+}
+
+pub fn gumbel_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Gumbel::new(location, scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Generalized extreme value distribution
+pub fn gev_pdf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.pdf(x))
+}
+
+pub fn gev_cdf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.cdf(x))
+}
+
+pub fn gev_inv(p: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gev::new(location, scale, shape)?.inverse_cdf(p)
+}
+
+pub fn gev_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.pdf_array(input, output)
+}
+
+pub fn gev_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.cdf_array(input, output)
+}
+
+pub fn gev_logpdf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.ln_pdf(x))
+}
+
+pub fn gev_logcdf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.ln_cdf(x))
+}
+
+pub fn gev_sf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.sf(x))
+}
+
+pub fn gev_logsf(x: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(Gev::new(location, scale, shape)?.ln_sf(x))
+}
+
+pub fn gev_isf(p: f64, location: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Gev::new(location, scale, shape)?.inverse_sf(p)
+}
+
+pub fn gev_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.ln_pdf_array(input, output)
+}
+
+pub fn gev_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.ln_cdf_array(input, output)
+}
+
+pub fn gev_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.sf_array(input, output)
+}
+
+pub fn gev_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.ln_sf_array(input, output)
+}
+
+pub fn gev_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    gev_sample_into(&mut output, location, scale, shape, seed)?;
+    Ok(output)
+}
+
+pub fn gev_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Gev::new(location, scale, shape)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Logistic distribution
+pub fn logistic_pdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.pdf(x))
+}
+
+pub fn logistic_cdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.cdf(x))
+}
+
+pub fn logistic_inv(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Logistic::new(location, scale)?.inverse_cdf(p)
+}
+
+pub fn logistic_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.pdf_array(input, output)
+}
+
+pub fn logistic_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.cdf_array(input, output)
+}
+
+pub fn logistic_logpdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.ln_pdf(x))
+}
+
+pub fn logistic_logcdf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.ln_cdf(x))
+}
+
+pub fn logistic_sf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.sf(x))
+}
+
+pub fn logistic_logsf(x: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Logistic::new(location, scale)?.ln_sf(x))
+}
+
+pub fn logistic_isf(p: f64, location: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Logistic::new(location, scale)?.inverse_sf(p)
+}
+
+pub fn logistic_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.ln_pdf_array(input, output)
+}
+
+pub fn logistic_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.ln_cdf_array(input, output)
+}
+
+pub fn logistic_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.sf_array(input, output)
+}
+
+pub fn logistic_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.ln_sf_array(input, output)
+}
+
+pub fn logistic_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    logistic_sample_into(&mut output, location, scale, seed)?;
+    Ok(output)
+}
+
+pub fn logistic_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Logistic::new(location, scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Log-logistic distribution
+pub fn loglogistic_pdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.pdf(x))
+}
+
+pub fn loglogistic_cdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.cdf(x))
+}
+
+pub fn loglogistic_inv(p: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    LogLogistic::new(scale, shape)?.inverse_cdf(p)
+}
+
+pub fn loglogistic_pdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.pdf_array(input, output)
+}
+
+pub fn loglogistic_cdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.cdf_array(input, output)
+}
+
+pub fn loglogistic_logpdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.ln_pdf(x))
+}
+
+pub fn loglogistic_logcdf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.ln_cdf(x))
+}
+
+pub fn loglogistic_sf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.sf(x))
+}
+
+pub fn loglogistic_logsf(x: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    Ok(LogLogistic::new(scale, shape)?.ln_sf(x))
+}
+
+pub fn loglogistic_isf(p: f64, scale: f64, shape: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    LogLogistic::new(scale, shape)?.inverse_sf(p)
+}
+
+pub fn loglogistic_logpdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.ln_pdf_array(input, output)
+}
+
+pub fn loglogistic_logcdf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.ln_cdf_array(input, output)
+}
+
+pub fn loglogistic_sf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.sf_array(input, output)
+}
+
+pub fn loglogistic_logsf_array(
+    input: &[f64],
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.ln_sf_array(input, output)
+}
+
+pub fn loglogistic_sample(
+    count: usize,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    loglogistic_sample_into(&mut output, scale, shape, seed)?;
+    Ok(output)
+}
+
+pub fn loglogistic_sample_into(
+    output: &mut [f64],
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    LogLogistic::new(scale, shape)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Rayleigh distribution
+pub fn rayleigh_pdf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.pdf(x))
+}
+
+pub fn rayleigh_cdf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.cdf(x))
+}
+
+pub fn rayleigh_inv(p: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Rayleigh::new(scale)?.inverse_cdf(p)
+}
+
+pub fn rayleigh_pdf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.pdf_array(input, output)
+}
+
+pub fn rayleigh_cdf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.cdf_array(input, output)
+}
+
+pub fn rayleigh_logpdf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.ln_pdf(x))
+}
+
+pub fn rayleigh_logcdf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.ln_cdf(x))
+}
+
+pub fn rayleigh_sf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.sf(x))
+}
+
+pub fn rayleigh_logsf(x: f64, scale: f64) -> Result<f64, DistributionError> {
+    Ok(Rayleigh::new(scale)?.ln_sf(x))
+}
+
+pub fn rayleigh_isf(p: f64, scale: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Rayleigh::new(scale)?.inverse_sf(p)
+}
+
+pub fn rayleigh_logpdf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.ln_pdf_array(input, output)
+}
+
+pub fn rayleigh_logcdf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.ln_cdf_array(input, output)
+}
+
+pub fn rayleigh_sf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.sf_array(input, output)
+}
+
+pub fn rayleigh_logsf_array(
+    input: &[f64],
+    scale: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.ln_sf_array(input, output)
+}
+
+pub fn rayleigh_sample(count: usize, scale: f64, seed: u64) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    rayleigh_sample_into(&mut output, scale, seed)?;
+    Ok(output)
+}
+
+pub fn rayleigh_sample_into(
+    output: &mut [f64],
+    scale: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Rayleigh::new(scale)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Erlang distribution
+pub fn erlang_pdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.pdf(x))
+}
+
+pub fn erlang_cdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.cdf(x))
+}
+
+pub fn erlang_inv(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Erlang::new(shape, rate)?.inverse_cdf(p)
+}
+
+pub fn erlang_pdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.pdf_array(input, output)
+}
+
+pub fn erlang_cdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.cdf_array(input, output)
+}
+
+pub fn erlang_logpdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.ln_pdf(x))
+}
+
+pub fn erlang_logcdf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.ln_cdf(x))
+}
+
+pub fn erlang_sf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.sf(x))
+}
+
+pub fn erlang_logsf(x: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    Ok(Erlang::new(shape, rate)?.ln_sf(x))
+}
+
+pub fn erlang_isf(p: f64, shape: f64, rate: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Erlang::new(shape, rate)?.inverse_sf(p)
+}
+
+pub fn erlang_logpdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.ln_pdf_array(input, output)
+}
+
+pub fn erlang_logcdf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.ln_cdf_array(input, output)
+}
+
+pub fn erlang_sf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.sf_array(input, output)
+}
+
+pub fn erlang_logsf_array(
+    input: &[f64],
+    shape: f64,
+    rate: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.ln_sf_array(input, output)
+}
+
+pub fn erlang_sample(
+    count: usize,
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    erlang_sample_into(&mut output, shape, rate, seed)?;
+    Ok(output)
+}
+
+pub fn erlang_sample_into(
+    output: &mut [f64],
+    shape: f64,
+    rate: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Erlang::new(shape, rate)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Von Mises distribution
+pub fn vonmises_pdf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.pdf(x))
+}
+
+pub fn vonmises_cdf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.cdf(x))
+}
+
+pub fn vonmises_inv(p: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    VonMises::new(mu, kappa)?.inverse_cdf(p)
+}
+
+pub fn vonmises_pdf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.pdf_array(input, output)
+}
+
+pub fn vonmises_cdf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.cdf_array(input, output)
+}
+
+pub fn vonmises_logpdf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.ln_pdf(x))
+}
+
+pub fn vonmises_logcdf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.ln_cdf(x))
+}
+
+pub fn vonmises_sf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.sf(x))
+}
+
+pub fn vonmises_logsf(x: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    Ok(VonMises::new(mu, kappa)?.ln_sf(x))
+}
+
+pub fn vonmises_isf(p: f64, mu: f64, kappa: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    VonMises::new(mu, kappa)?.inverse_sf(p)
+}
+
+pub fn vonmises_logpdf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.ln_pdf_array(input, output)
+}
+
+pub fn vonmises_logcdf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.ln_cdf_array(input, output)
+}
+
+pub fn vonmises_sf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.sf_array(input, output)
+}
+
+pub fn vonmises_logsf_array(
+    input: &[f64],
+    mu: f64,
+    kappa: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.ln_sf_array(input, output)
+}
+
+pub fn vonmises_sample(
+    count: usize,
+    mu: f64,
+    kappa: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    vonmises_sample_into(&mut output, mu, kappa, seed)?;
+    Ok(output)
+}
+
+pub fn vonmises_sample_into(
+    output: &mut [f64],
+    mu: f64,
+    kappa: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    VonMises::new(mu, kappa)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Skew-normal distribution
+pub fn skewnormal_pdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.pdf(x))
+}
+
+pub fn skewnormal_cdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.cdf(x))
+}
+
+pub fn skewnormal_inv(
+    p: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    SkewNormal::new(location, scale, shape)?.inverse_cdf(p)
+}
+
+pub fn skewnormal_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.pdf_array(input, output)
+}
+
+pub fn skewnormal_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.cdf_array(input, output)
+}
+
+pub fn skewnormal_logpdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.ln_pdf(x))
+}
+
+pub fn skewnormal_logcdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.ln_cdf(x))
+}
+
+pub fn skewnormal_sf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.sf(x))
+}
+
+pub fn skewnormal_logsf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    Ok(SkewNormal::new(location, scale, shape)?.ln_sf(x))
+}
+
+pub fn skewnormal_isf(
+    p: f64,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    SkewNormal::new(location, scale, shape)?.inverse_sf(p)
+}
+
+pub fn skewnormal_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.ln_pdf_array(input, output)
+}
+
+pub fn skewnormal_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.ln_cdf_array(input, output)
+}
+
+pub fn skewnormal_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.sf_array(input, output)
+}
+
+pub fn skewnormal_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.ln_sf_array(input, output)
+}
+
+pub fn skewnormal_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    skewnormal_sample_into(&mut output, location, scale, shape, seed)?;
+    Ok(output)
+}
+
+pub fn skewnormal_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    shape: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    SkewNormal::new(location, scale, shape)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Truncated normal distribution
+pub fn truncnormal_pdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.pdf(x))
+}
+
+pub fn truncnormal_cdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.cdf(x))
+}
+
+pub fn truncnormal_inv(
+    p: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    TruncatedNormal::new(location, scale, lower, upper)?.inverse_cdf(p)
+}
+
+pub fn truncnormal_pdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.pdf_array(input, output)
+}
+
+pub fn truncnormal_cdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.cdf_array(input, output)
+}
+
+pub fn truncnormal_logpdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.ln_pdf(x))
+}
+
+pub fn truncnormal_logcdf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.ln_cdf(x))
+}
+
+pub fn truncnormal_sf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.sf(x))
+}
+
+pub fn truncnormal_logsf(
+    x: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    Ok(TruncatedNormal::new(location, scale, lower, upper)?.ln_sf(x))
+}
+
+pub fn truncnormal_isf(
+    p: f64,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    TruncatedNormal::new(location, scale, lower, upper)?.inverse_sf(p)
+}
+
+pub fn truncnormal_logpdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.ln_pdf_array(input, output)
+}
+
+pub fn truncnormal_logcdf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.ln_cdf_array(input, output)
+}
+
+pub fn truncnormal_sf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.sf_array(input, output)
+}
+
+pub fn truncnormal_logsf_array(
+    input: &[f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.ln_sf_array(input, output)
+}
+
+pub fn truncnormal_sample(
+    count: usize,
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    truncnormal_sample_into(&mut output, location, scale, lower, upper, seed)?;
+    Ok(output)
+}
+
+pub fn truncnormal_sample_into(
+    output: &mut [f64],
+    location: f64,
+    scale: f64,
+    lower: f64,
+    upper: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    TruncatedNormal::new(location, scale, lower, upper)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Hypergeometric distribution (discrete)
+pub fn hypergeom_pmf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.pmf(k_int))
+}
+
+pub fn hypergeom_cdf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.cdf(k_int))
+}
+
+pub fn hypergeom_inv(
+    p: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(
+        Hypergeometric::new(population as u64, successes as u64, draws as u64)?.inverse_cdf(p)?
+            as f64,
+    )
+}
+
+pub fn hypergeom_pmf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?.pmf_array(input, output)
+}
+
+pub fn hypergeom_cdf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?.cdf_array(input, output)
+}
+
+pub fn hypergeom_logpmf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.ln_pmf(k_int))
+}
+
+pub fn hypergeom_logcdf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.ln_cdf(k_int))
+}
+
+pub fn hypergeom_sf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.sf(k_int))
+}
+
+pub fn hypergeom_logsf(
+    k: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Hypergeometric::new(population as u64, successes as u64, draws as u64)?.ln_sf(k_int))
+}
+
+pub fn hypergeom_isf(
+    p: f64,
+    population: f64,
+    successes: f64,
+    draws: f64,
+) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    Ok(
+        Hypergeometric::new(population as u64, successes as u64, draws as u64)?.inverse_sf(p)?
+            as f64,
+    )
+}
+
+pub fn hypergeom_logpmf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?
+        .ln_pmf_array(input, output)
+}
+
+pub fn hypergeom_logcdf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?
+        .ln_cdf_array(input, output)
+}
+
+pub fn hypergeom_sf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?.sf_array(input, output)
+}
+
+pub fn hypergeom_logsf_array(
+    input: &[f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?
+        .ln_sf_array(input, output)
+}
+
+pub fn hypergeom_sample(
+    count: usize,
+    population: f64,
+    successes: f64,
+    draws: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    hypergeom_sample_into(&mut output, population, successes, draws, seed)?;
+    Ok(output)
+}
+
+pub fn hypergeom_sample_into(
+    output: &mut [f64],
+    population: f64,
+    successes: f64,
+    draws: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Hypergeometric::new(population as u64, successes as u64, draws as u64)?
+        .sample_into(output, seed);
+    Ok(())
+}
+
+// Geometric distribution (discrete)
+pub fn geometric_pmf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.pmf(k_int))
+}
+
+pub fn geometric_cdf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.cdf(k_int))
+}
+
+pub fn geometric_inv(prob: f64, p: f64) -> Result<f64, DistributionError> {
+    ensure_probability(prob)?;
+    Ok(Geometric::new(p)?.inverse_cdf(prob)? as f64)
+}
+
+pub fn geometric_pmf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.pmf_array(input, output)
+}
+
+pub fn geometric_cdf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.cdf_array(input, output)
+}
+
+pub fn geometric_logpmf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.ln_pmf(k_int))
+}
+
+pub fn geometric_logcdf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.ln_cdf(k_int))
+}
+
+pub fn geometric_sf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.sf(k_int))
+}
+
+pub fn geometric_logsf(k: f64, p: f64) -> Result<f64, DistributionError> {
+    let k_int = k as u64;
+    Ok(Geometric::new(p)?.ln_sf(k_int))
+}
+
+pub fn geometric_isf(prob: f64, p: f64) -> Result<f64, DistributionError> {
+    ensure_probability(prob)?;
+    Ok(Geometric::new(p)?.inverse_sf(prob)? as f64)
+}
+
+pub fn geometric_logpmf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.ln_pmf_array(input, output)
+}
+
+pub fn geometric_logcdf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.ln_cdf_array(input, output)
+}
+
+pub fn geometric_sf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.sf_array(input, output)
+}
+
+pub fn geometric_logsf_array(
+    input: &[f64],
+    p: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.ln_sf_array(input, output)
+}
+
+pub fn geometric_sample(count: usize, p: f64, seed: u64) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    geometric_sample_into(&mut output, p, seed)?;
+    Ok(output)
+}
+
+pub fn geometric_sample_into(
+    output: &mut [f64],
+    p: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    Geometric::new(p)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Studentized range distribution (Tukey's q), single range (`nranges = 1`)
+//
+// The CDF follows Copenhaver & Holland (1988) as implemented in R's `ptukey`: an outer
+// Gauss-Legendre integral over the chi distribution of the variance estimate, wrapped around
+// the probability of the range of `nmeans` normals (Hartley's form). Quantiles invert the CDF
+// with the secant method starting from the Odeh & Evans based initial value used by `qtukey`.
+
+const TUKEY_XLEG: [f64; 6] = [
+    0.9815606342467192,
+    0.9041172563704749,
+    0.7699026741943047,
+    0.5873179542866175,
+    0.3678314989981802,
+    0.1252334085114689,
+];
+const TUKEY_ALEG: [f64; 6] = [
+    0.04717533638651183,
+    0.10693932599531843,
+    0.16007832854334622,
+    0.20316742672306592,
+    0.2334925365383548,
+    0.24914704581340277,
+];
+const TUKEY_XLEGQ: [f64; 8] = [
+    0.9894009349916499,
+    0.9445750230732326,
+    0.8656312023878318,
+    0.755404408355003,
+    0.6178762444026438,
+    0.45801677765722737,
+    0.2816035507792589,
+    0.09501250983763744,
+];
+const TUKEY_ALEGQ: [f64; 8] = [
+    0.027152459411754096,
+    0.062253523938647894,
+    0.09515851168249279,
+    0.12462897125553388,
+    0.14959598881657674,
+    0.16915651939500254,
+    0.18260341504492358,
+    0.1894506104550685,
+];
+
+fn validate_studentized_range(nmeans: f64, df: f64) -> Result<(), DistributionError> {
+    if nmeans.is_nan() || nmeans < 2.0 || df.is_nan() || df < 2.0 {
+        return Err(DistributionError::InvalidParameters(format!(
+            "studentized range requires nmeans >= 2 and df >= 2; received nmeans={nmeans}, df={df}"
+        )));
+    }
+    Ok(())
+}
+
+#[inline]
+fn std_normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Probability that the range of `cc` standard normals is below `w` (infinite df).
+fn tukey_range_prob(w: f64, cc: f64) -> f64 {
+    const BB: f64 = 8.0;
+    const WLAR: f64 = 3.0;
+    const C1: f64 = -30.0;
+    const C2: f64 = -50.0;
+    const C3: f64 = 60.0;
+
+    let qsqz = w * 0.5;
+    // For w >= 16 the integral is 1 to within 1e-14.
+    if qsqz >= BB {
+        return 1.0;
+    }
+
+    // (2 * Phi(w / 2) - 1)^cc: first term of Hartley's form.
+    let mut pr_w = 2.0 * std_normal_cdf(qsqz) - 1.0;
+    pr_w = if pr_w >= (C2 / cc).exp() {
+        pr_w.powf(cc)
+    } else {
+        0.0
+    };
+
+    // Second term: Legendre quadrature over (w/2, 8) in two or three equal intervals.
+    let wincr = if w > WLAR { 2 } else { 3 };
+    let mut blb = qsqz;
+    let binc = (BB - qsqz) / wincr as f64;
+    let mut bub = blb + binc;
+    let mut einsum = 0.0;
+    let cc1 = cc - 1.0;
+
+    for _ in 0..wincr {
+        let mut elsum = 0.0;
+        let a = 0.5 * (bub + blb);
+        let b = 0.5 * (bub - blb);
+
+        for jj in 0..12 {
+            let (j, xx) = if jj >= 6 {
+                (11 - jj, TUKEY_XLEG[11 - jj])
+            } else {
+                (jj, -TUKEY_XLEG[jj])
+            };
+            let ac = a + b * xx;
+            let qexpo = ac * ac;
+            if qexpo > C3 {
+                break;
+            }
+
+            let rinsum = std_normal_cdf(ac) - std_normal_cdf(ac - w);
+            if rinsum >= (C1 / cc1).exp() {
+                elsum += TUKEY_ALEG[j] * (-(0.5 * qexpo)).exp() * rinsum.powf(cc1);
+            }
+        }
+        einsum += elsum * ((2.0 * b) * cc) / (2.0 * std::f64::consts::PI).sqrt();
+        blb = bub;
+        bub += binc;
+    }
+
+    pr_w += einsum;
+    if pr_w <= C1.exp() {
+        return 0.0;
+    }
+    pr_w.min(1.0)
+}
+
+fn studentized_range_cdf_unchecked(q: f64, nmeans: f64, df: f64) -> f64 {
+    const EPS1: f64 = -30.0;
+    const EPS2: f64 = 1.0e-14;
+
+    if q.is_nan() {
+        return f64::NAN;
+    }
+    if q <= 0.0 {
+        return 0.0;
+    }
+    if q.is_infinite() {
+        return 1.0;
+    }
+    if df > 25000.0 {
+        return tukey_range_prob(q, nmeans);
+    }
+
+    let f2 = df * 0.5;
+    let f21 = f2 - 1.0;
+    let ff4 = df * 0.25;
+    let ulen: f64 = if df <= 100.0 {
+        1.0
+    } else if df <= 800.0 {
+        0.5
+    } else if df <= 5000.0 {
+        0.25
+    } else {
+        0.125
+    };
+    let f2lf = f2 * df.ln() - df * std::f64::consts::LN_2 - ln_gamma(f2) + ulen.ln();
+
+    // Outer integral over the chi density in intervals of length `ulen`; at least 1 / ulen
+    // intervals are evaluated so the left tail is not missed.
+    let mut ans = 0.0;
+    for i in 1..=50 {
+        let mut otsum = 0.0;
+        let twa1 = (2 * i - 1) as f64 * ulen;
+
+        for jj in 0..16 {
+            let (j, u) = if jj >= 8 {
+                let j = jj - 8;
+                (j, twa1 + TUKEY_XLEGQ[j] * ulen)
+            } else {
+                (jj, twa1 - TUKEY_XLEGQ[jj] * ulen)
+            };
+            let t1 = f2lf + f21 * u.ln() - u * ff4;
+            if t1 >= EPS1 {
+                let qsqz = q * (u * 0.5).sqrt();
+                otsum += tukey_range_prob(qsqz, nmeans) * TUKEY_ALEGQ[j] * t1.exp();
+            }
+        }
+
+        if i as f64 * ulen >= 1.0 && otsum <= EPS2 {
+            break;
+        }
+        ans += otsum;
+    }
+
+    ans.min(1.0)
+}
+
+/// Initial quantile estimate for the secant iteration (AS 190.2).
+fn studentized_range_initial(p: f64, c: f64, v: f64) -> f64 {
+    const P0: f64 = 0.322232421088;
+    const Q0: f64 = 0.993484626060e-01;
+    const P1: f64 = -1.0;
+    const Q1: f64 = 0.588581570495;
+    const P2: f64 = -0.342242088547;
+    const Q2: f64 = 0.531103462366;
+    const P3: f64 = -0.204231210125;
+    const Q3: f64 = 0.103537752850;
+    const P4: f64 = -0.453642210148e-04;
+    const Q4: f64 = 0.38560700634e-02;
+    const C1: f64 = 0.8832;
+    const C2: f64 = 0.2368;
+    const C3: f64 = 1.214;
+    const C4: f64 = 1.208;
+    #[allow(clippy::approx_constant)] // published AS 190 constant, not sqrt(2)
+    const C5: f64 = 1.4142;
+    const VMAX: f64 = 120.0;
+
+    let ps = 0.5 - 0.5 * p;
+    let yi = (1.0 / (ps * ps)).ln().sqrt();
+    let mut t = yi
+        + ((((yi * P4 + P3) * yi + P2) * yi + P1) * yi + P0)
+            / ((((yi * Q4 + Q3) * yi + Q2) * yi + Q1) * yi + Q0);
+    if v < VMAX {
+        t += (t * t * t + t) / v / 4.0;
+    }
+    let mut q = C1 - C2 * t;
+    if v < VMAX {
+        q += -C3 / v + C4 * t / v;
+    }
+    t * (q * (c - 1.0).ln() + C5)
+}
+
+/// CDF of the studentized range distribution for `nmeans` groups and `df` degrees of freedom.
+pub fn studentized_range_cdf(q: f64, nmeans: f64, df: f64) -> Result<f64, DistributionError> {
+    validate_studentized_range(nmeans, df)?;
+    Ok(studentized_range_cdf_unchecked(q, nmeans, df))
+}
+
+/// Quantile of the studentized range distribution (e.g. the Tukey HSD critical value).
+pub fn studentized_range_inv(p: f64, nmeans: f64, df: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    validate_studentized_range(nmeans, df)?;
+    if p == 0.0 {
+        return Ok(0.0);
+    }
+    if p == 1.0 {
+        return Ok(f64::INFINITY);
+    }
+
+    const EPS: f64 = 1e-10;
+    const MAX_ITER: usize = 50;
+
+    let mut x0 = studentized_range_initial(p, nmeans, df);
+    let mut val0 = studentized_range_cdf_unchecked(x0, nmeans, df) - p;
+    let mut x1 = if val0 > 0.0 {
+        (x0 - 1.0).max(0.0)
+    } else {
+        x0 + 1.0
+    };
+    let mut val1 = studentized_range_cdf_unchecked(x1, nmeans, df) - p;
+
+    for _ in 1..MAX_ITER {
+        if val1 == val0 {
+            break;
+        }
+        let next = (x1 - val1 * (x1 - x0) / (val1 - val0)).max(0.0);
+        x0 = x1;
+        val0 = val1;
+        x1 = next;
+        val1 = studentized_range_cdf_unchecked(x1, nmeans, df) - p;
+        if (x1 - x0).abs() < EPS {
+            break;
+        }
+    }
+
+    Ok(x1)
+}
+
+pub fn studentized_range_cdf_array(
+    input: &[f64],
+    nmeans: f64,
+    df: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    validate_studentized_range(nmeans, df)?;
+    apply_unary_op(input, output, |q| {
+        studentized_range_cdf_unchecked(q, nmeans, df)
+    })
+}
+
+/// Internal: quantile of a continuous CDF by bracket expansion and bisection.
+///
+/// `lower` is the lower end of the support (`0` or `-inf`).
+fn invert_cdf<F: Fn(f64) -> f64>(p: f64, lower: f64, cdf: F) -> f64 {
+    if p == 0.0 {
+        return lower;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    let (mut lo, mut hi) = if lower == 0.0 {
+        (0.0, 1.0)
+    } else {
+        (-1.0, 1.0)
+    };
+    while cdf(hi) < p {
+        lo = hi;
+        hi *= 2.0;
+        if hi.is_infinite() {
+            return f64::INFINITY;
+        }
+    }
+    while lower != 0.0 && cdf(lo) > p {
+        hi = lo;
+        lo *= 2.0;
+        if lo.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if cdf(mid) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        if hi - lo <= 1e-13 * mid.abs().max(1e-300) {
+            break;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+// Noncentral chi-squared distribution
+/// Noncentral chi-squared density with `dof` degrees of freedom and noncentrality `ncp`.
+pub fn noncentral_chi_squared_pdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.pdf(x))
+}
+
+pub fn noncentral_chi_squared_cdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.cdf(x))
+}
+
+pub fn noncentral_chi_squared_inv(p: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralChiSquared::new(dof, ncp)?.inverse_cdf(p)
+}
+
+pub fn noncentral_chi_squared_pdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.pdf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_cdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.cdf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_logpdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.ln_pdf(x))
+}
+
+pub fn noncentral_chi_squared_logcdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.ln_cdf(x))
+}
+
+/// Upper tail `P(X > x)`, summed directly rather than as `1 - cdf`.
+pub fn noncentral_chi_squared_sf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.sf(x))
+}
+
+pub fn noncentral_chi_squared_logsf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralChiSquared::new(dof, ncp)?.ln_sf(x))
+}
+
+pub fn noncentral_chi_squared_isf(p: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralChiSquared::new(dof, ncp)?.inverse_sf(p)
+}
+
+pub fn noncentral_chi_squared_logpdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.ln_pdf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_logcdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.ln_cdf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_sf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.sf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_logsf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.ln_sf_array(input, output)
+}
+
+pub fn noncentral_chi_squared_sample(
+    count: usize,
+    dof: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    noncentral_chi_squared_sample_into(&mut output, dof, ncp, seed)?;
+    Ok(output)
+}
+
+pub fn noncentral_chi_squared_sample_into(
+    output: &mut [f64],
+    dof: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    NoncentralChiSquared::new(dof, ncp)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Noncentral t distribution
+/// Noncentral t density with `dof` degrees of freedom and noncentrality `ncp`.
+pub fn noncentral_t_pdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.pdf(x))
+}
+
+pub fn noncentral_t_cdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.cdf(x))
+}
+
+pub fn noncentral_t_inv(p: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralT::new(dof, ncp)?.inverse_cdf(p)
+}
+
+pub fn noncentral_t_pdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.pdf_array(input, output)
+}
+
+pub fn noncentral_t_cdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.cdf_array(input, output)
+}
+
+pub fn noncentral_t_logpdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.ln_pdf(x))
+}
+
+pub fn noncentral_t_logcdf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.ln_cdf(x))
+}
+
+/// Upper tail `P(T > t)`, summed directly rather than as `1 - cdf`.
+pub fn noncentral_t_sf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.sf(x))
+}
+
+pub fn noncentral_t_logsf(x: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralT::new(dof, ncp)?.ln_sf(x))
+}
+
+pub fn noncentral_t_isf(p: f64, dof: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralT::new(dof, ncp)?.inverse_sf(p)
+}
+
+pub fn noncentral_t_logpdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.ln_pdf_array(input, output)
+}
+
+pub fn noncentral_t_logcdf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.ln_cdf_array(input, output)
+}
+
+pub fn noncentral_t_sf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.sf_array(input, output)
+}
+
+pub fn noncentral_t_logsf_array(
+    input: &[f64],
+    dof: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.ln_sf_array(input, output)
+}
+
+pub fn noncentral_t_sample(
+    count: usize,
+    dof: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    noncentral_t_sample_into(&mut output, dof, ncp, seed)?;
+    Ok(output)
+}
+
+pub fn noncentral_t_sample_into(
+    output: &mut [f64],
+    dof: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    NoncentralT::new(dof, ncp)?.sample_into(output, seed);
+    Ok(())
+}
+
+// Noncentral F distribution
+/// Noncentral F density with `df1` and `df2` degrees of freedom and noncentrality `ncp`.
+pub fn noncentral_f_pdf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.pdf(x))
+}
+
+pub fn noncentral_f_cdf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.cdf(x))
+}
+
+pub fn noncentral_f_inv(p: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralF::new(df1, df2, ncp)?.inverse_cdf(p)
+}
+
+pub fn noncentral_f_pdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.pdf_array(input, output)
+}
+
+pub fn noncentral_f_cdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.cdf_array(input, output)
+}
+
+pub fn noncentral_f_logpdf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.ln_pdf(x))
+}
+
+pub fn noncentral_f_logcdf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.ln_cdf(x))
+}
+
+/// Upper tail `P(F > x)`, summed directly rather than as `1 - cdf`.
+pub fn noncentral_f_sf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.sf(x))
+}
+
+pub fn noncentral_f_logsf(x: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    Ok(NoncentralF::new(df1, df2, ncp)?.ln_sf(x))
+}
+
+pub fn noncentral_f_isf(p: f64, df1: f64, df2: f64, ncp: f64) -> Result<f64, DistributionError> {
+    ensure_probability(p)?;
+    NoncentralF::new(df1, df2, ncp)?.inverse_sf(p)
+}
+
+pub fn noncentral_f_logpdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.ln_pdf_array(input, output)
+}
+
+pub fn noncentral_f_logcdf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.ln_cdf_array(input, output)
+}
+
+pub fn noncentral_f_sf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.sf_array(input, output)
+}
+
+pub fn noncentral_f_logsf_array(
+    input: &[f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    output: &mut [f64],
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.ln_sf_array(input, output)
+}
+
+pub fn noncentral_f_sample(
+    count: usize,
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<Vec<f64>, DistributionError> {
+    let mut output = vec![0.0; count];
+    noncentral_f_sample_into(&mut output, df1, df2, ncp, seed)?;
+    Ok(output)
+}
+
+pub fn noncentral_f_sample_into(
+    output: &mut [f64],
+    df1: f64,
+    df2: f64,
+    ncp: f64,
+    seed: u64,
+) -> Result<(), DistributionError> {
+    NoncentralF::new(df1, df2, ncp)?.sample_into(output, seed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-10;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < EPS
+    }
+
+    #[test]
+    fn normal_basics() {
+        let pdf = normal_pdf(0.0, 0.0, 1.0).unwrap();
+        assert!(approx_eq(pdf, 0.3989422804014327));
+
+        let cdf = normal_cdf(1.96, 0.0, 1.0).unwrap();
+        assert!(approx_eq(cdf, 0.9750021048517795));
+
+        let inv = normal_inv(0.975, 0.0, 1.0).unwrap();
+        assert!(approx_eq(inv, 1.959963984540054));
+    }
+
+    #[test]
+    fn gamma_matches_known_values() {
+        let pdf = gamma_pdf(2.0, 3.0, 1.0).unwrap();
+        assert!(approx_eq(pdf, 0.2706705664732254));
+
+        let cdf = gamma_cdf(2.0, 3.0, 1.0).unwrap();
+        assert!(approx_eq(cdf, 0.32332358381693654));
+    }
+
+    #[test]
+    fn beta_quantiles() {
+        let inv = beta_inv(0.5, 2.0, 2.0).unwrap();
+        assert!(approx_eq(inv, 0.5));
+    }
+
+    #[test]
+    fn array_ops_write_results() {
+        let mut out = vec![0.0; 3];
+        normal_pdf_array(&[0.0, 1.0, 2.0], 0.0, 1.0, &mut out).unwrap();
+        assert!(out.iter().all(|v| *v > 0.0));
+    }
+
+    #[test]
+    fn poisson_basics() {
+        // Poisson with lambda=2
+        let pmf = poisson_pmf(2.0, 2.0).unwrap();
+        assert!(approx_eq(pmf, 0.2706705664732254)); // e^-2 * 2^2 / 2!
+
+        let cdf = poisson_cdf(3.0, 2.0).unwrap();
+        assert!(cdf > 0.8 && cdf < 0.9); // Should be around 0.857
+
+        let inv = poisson_inv(0.5, 2.0).unwrap();
+        assert!((1.0..=3.0).contains(&inv)); // Median should be around 2
+    }
+
+    #[test]
+    fn binomial_basics() {
+        // Binomial with n=10, p=0.5
+        let pmf = binomial_pmf(5.0, 10.0, 0.5).unwrap();
+        assert!(pmf > 0.24 && pmf < 0.25); // Should be around 0.246
+
+        let cdf = binomial_cdf(5.0, 10.0, 0.5).unwrap();
+        assert!(cdf > 0.62 && cdf < 0.63); // Should be around 0.623
+
+        let inv = binomial_inv(0.5, 10.0, 0.5).unwrap();
+        assert!((4.0..=6.0).contains(&inv)); // Median should be around 5
+    }
+
+    #[test]
+    fn studentized_range_two_means_matches_t() {
+        // With two means, Q = sqrt(2) * |T|.
+        for &(q, df) in &[(1.0, 5.0), (2.5, 12.0), (4.0, 30.0), (3.0, 200.0)] {
+            let t = q / std::f64::consts::SQRT_2;
+            let expected = 2.0 * student_t_cdf(t, 0.0, 1.0, df).unwrap() - 1.0;
+            let cdf = studentized_range_cdf(q, 2.0, df).unwrap();
+            assert!(
+                (cdf - expected).abs() < 1e-8,
+                "q={q} df={df}: {cdf} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn studentized_range_quantiles_match_tables() {
+        // Upper 5% points of the studentized range (Harter 1960 tables, as qtukey).
+        for &(k, df, expected) in &[
+            (3.0, 12.0, 3.772929),
+            (4.0, 20.0, 3.958293),
+            (10.0, 30.0, 4.824),
+            (2.0, 10.0, 3.151064),
+        ] {
+            let q = studentized_range_inv(0.95, k, df).unwrap();
+            assert!((q - expected).abs() < 5e-4, "k={k} df={df}: {q}");
+            let p = studentized_range_cdf(q, k, df).unwrap();
+            assert!((p - 0.95).abs() < 1e-9);
+        }
+        assert!(studentized_range_cdf(1.0, 1.0, 10.0).is_err());
+        assert!(studentized_range_inv(1.5, 3.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn noncentral_distributions_match_reference() {
+        // References from direct numerical integration of the defining densities (mpmath).
+        let close = |a: f64, b: f64, tol: f64| assert!((a - b).abs() < tol, "{a} vs {b}");
+        close(
+            noncentral_chi_squared_cdf(5.0, 3.0, 2.0).unwrap(),
+            0.5934051800831556,
+            1e-12,
+        );
+        close(
+            noncentral_chi_squared_sf(20.0, 3.0, 2.0).unwrap(),
+            3.743892497457985e-3,
+            1e-15,
+        );
+        close(
+            noncentral_chi_squared_pdf(4.0, 3.0, 2.0).unwrap(),
+            0.11839464506363486,
+            1e-12,
+        );
+        close(
+            noncentral_chi_squared_cdf(150.0, 10.0, 120.0).unwrap(),
+            0.817079416046584,
+            1e-12,
+        );
+        // statrs' erfc limits the t series to about 1e-11.
+        close(
+            noncentral_t_cdf(1.5, 10.0, 1.0).unwrap(),
+            0.6695168482153548,
+            1e-10,
+        );
+        close(
+            noncentral_t_cdf(-0.5, 5.0, 1.5).unwrap(),
+            0.025386352528454316,
+            1e-10,
+        );
+        close(
+            noncentral_t_cdf(3.0, 20.0, 4.0).unwrap(),
+            0.1739913998107359,
+            1e-10,
+        );
+        close(
+            noncentral_t_pdf(2.0, 10.0, 1.0).unwrap(),
+            0.22542404659048925,
+            1e-9,
+        );
+        close(
+            noncentral_f_cdf(2.0, 3.0, 20.0, 5.0).unwrap(),
+            0.4031300850345885,
+            1e-12,
+        );
+        close(
+            noncentral_f_pdf(1.5, 3.0, 20.0, 5.0).unwrap(),
+            0.2536683975286012,
+            1e-12,
+        );
+        close(
+            noncentral_f_sf(2.0, 3.0, 20.0, 5.0).unwrap(),
+            1.0 - noncentral_f_cdf(2.0, 3.0, 20.0, 5.0).unwrap(),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn noncentral_distributions_reduce_to_central() {
+        for &x in &[0.3, 1.0, 2.5, 7.0] {
+            let central = chi_squared_cdf(x, 4.0).unwrap();
+            assert!((noncentral_chi_squared_cdf(x, 4.0, 0.0).unwrap() - central).abs() < 1e-12);
+            let central = student_t_cdf(x - 2.0, 0.0, 1.0, 7.0).unwrap();
+            assert!((noncentral_t_cdf(x - 2.0, 7.0, 0.0).unwrap() - central).abs() < 1e-10);
+            let central = fisher_f_cdf(x, 3.0, 12.0).unwrap();
+            assert!((noncentral_f_cdf(x, 3.0, 12.0, 0.0).unwrap() - central).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn noncentral_quantiles_invert_cdf() {
+        let q = noncentral_t_inv(0.3, 10.0, 1.0).unwrap();
+        assert!((noncentral_t_cdf(q, 10.0, 1.0).unwrap() - 0.3).abs() < 1e-10);
+        let q = noncentral_f_inv(0.9, 3.0, 20.0, 5.0).unwrap();
+        assert!((noncentral_f_cdf(q, 3.0, 20.0, 5.0).unwrap() - 0.9).abs() < 1e-10);
+        let q = noncentral_chi_squared_inv(0.05, 6.0, 40.0).unwrap();
+        assert!((noncentral_chi_squared_cdf(q, 6.0, 40.0).unwrap() - 0.05).abs() < 1e-10);
+        assert_eq!(noncentral_chi_squared_inv(0.0, 6.0, 40.0).unwrap(), 0.0);
+
+        assert!(noncentral_chi_squared_cdf(1.0, 3.0, -1.0).is_err());
+        assert!(noncentral_t_cdf(1.0, 0.0, 1.0).is_err());
+        assert!(noncentral_f_cdf(1.0, 3.0, f64::INFINITY, 1.0).is_err());
+        assert!(noncentral_t_inv(1.5, 3.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn noncentral_structs_match_free_functions_and_moments() {
+        let chi2 = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+        assert_eq!(
+            chi2.cdf(5.0),
+            noncentral_chi_squared_cdf(5.0, 3.0, 2.0).unwrap()
+        );
+        assert_eq!(
+            chi2.ln_sf(20.0),
+            noncentral_chi_squared_logsf(20.0, 3.0, 2.0).unwrap()
+        );
+        assert_eq!(
+            chi2.sample(8, 5),
+            noncentral_chi_squared_sample(8, 3.0, 2.0, 5).unwrap()
+        );
+        assert!(approx_eq(chi2.mean(), 5.0));
+        assert!(approx_eq(chi2.variance(), 14.0));
+        assert!(approx_eq(
+            chi2.skewness(),
+            8f64.sqrt() * 9.0 / 7f64.powf(1.5)
+        ));
+
+        let f = NoncentralF::new(3.0, 20.0, 5.0).unwrap();
+        assert_eq!(f.pdf(1.5), noncentral_f_pdf(1.5, 3.0, 20.0, 5.0).unwrap());
+        assert!(approx_eq(f.mean(), 20.0 * 8.0 / (3.0 * 18.0)));
+        assert!(approx_eq(
+            f.variance(),
+            2.0 * (20.0f64 / 3.0).powi(2) * (64.0 + 13.0 * 18.0) / (18.0 * 18.0 * 16.0)
+        ));
+        assert!(NoncentralF::new(3.0, 6.0, 5.0).unwrap().skewness().is_nan());
+
+        // Central t: mean 0, variance dof / (dof - 2), skewness 0.
+        let t0 = NoncentralT::new(6.0, 0.0).unwrap();
+        assert!(approx_eq(t0.mean(), 0.0));
+        assert!(approx_eq(t0.variance(), 1.5));
+        assert!(approx_eq(t0.skewness(), 0.0));
+        let t = NoncentralT::new(10.0, 1.0).unwrap();
+        assert!(approx_eq(
+            t.mean(),
+            5f64.sqrt() * (ln_gamma(4.5) - ln_gamma(5.0)).exp()
+        ));
+        assert!(NoncentralT::new(2.0, 1.0).unwrap().variance().is_nan());
+        assert_eq!(
+            noncentral_t_isf(0.3, 10.0, 1.0).unwrap(),
+            t.inverse_sf(0.3).unwrap()
+        );
+
+        assert!(NoncentralChiSquared::new(3.0, -1.0).is_err());
+        assert!(NoncentralT::new(f64::NAN, 1.0).is_err());
+        assert!(noncentral_f_sample(4, 3.0, 0.0, 1.0, 1).is_err());
+    }
+
+    #[test]
+    fn noncentral_t_upper_tail_is_accurate() {
+        // With dof = 1 and ncp = 0 this is the standard Cauchy, sf(t) = atan(1 / t) / pi.
+        for &t in &[10.0f64, 1e8, 1e20] {
+            let exact = (1.0 / t).atan() / std::f64::consts::PI;
+            assert!(rel_close(
+                noncentral_t_sf(t, 1.0, 0.0).unwrap(),
+                exact,
+                1e-10
+            ));
+            assert!(rel_close(
+                noncentral_t_cdf(-t, 1.0, 0.0).unwrap(),
+                exact,
+                1e-10
+            ));
+        }
+        // References by numerical integration over the chi-squared mixing density.
+        for &(x, sf) in &[
+            (-3.0, 0.9998764176365638),
+            (0.7, 0.7909317864925347),
+            (4.0, 0.04212542510078601),
+        ] {
+            assert!(rel_close(noncentral_t_sf(x, 8.0, 1.5).unwrap(), sf, 1e-11));
+        }
+        assert!(rel_close(
+            noncentral_t_cdf(-3.0, 8.0, 1.5).unwrap(),
+            1.2358236343621574e-4,
+            1e-10
+        ));
+    }
+
+    /// Sample mean and variance of `draws`.
+    fn sample_moments(draws: &[f64]) -> (f64, f64) {
+        let n = draws.len() as f64;
+        let mean = draws.iter().sum::<f64>() / n;
+        let var = draws.iter().map(|d| (d - mean) * (d - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var)
+    }
+
+    #[test]
+    fn additional_families_match_reference() {
+        // References from mpmath (closed forms or numerical integration of the densities).
+        let close = |a: f64, b: f64, tol: f64| assert!(rel_close(a, b, tol), "{a} vs {b}");
+        close(
+            gev_pdf(1.3, 0.5, 2.0, 0.2).unwrap(),
+            0.1595342534868439,
+            1e-13,
+        );
+        close(
+            gev_cdf(1.3, 0.5, 2.0, 0.2).unwrap(),
+            0.5063216209766238,
+            1e-13,
+        );
+        close(
+            gev_cdf(1.3, 0.5, 2.0, -0.3).unwrap(),
+            0.520459244551392,
+            1e-13,
+        );
+        close(
+            gev_inv(0.3, 0.5, 2.0, 0.2).unwrap(),
+            0.13555344408234825,
+            1e-12,
+        );
+        let gev = Gev::new(0.5, 2.0, 0.2).unwrap();
+        close(gev.mean(), 2.142297137253034, 1e-12);
+        close(gev.variance(), 13.37614224919153, 1e-12);
+        close(gev.skewness(), 3.535071604621395, 1e-12);
+        close(
+            gumbel_cdf(1.0, 0.5, 2.0).unwrap(),
+            0.4589560693076638,
+            1e-13,
+        );
+        assert!(approx_eq(
+            gev_cdf(1.0, 0.5, 2.0, 0.0).unwrap(),
+            gumbel_cdf(1.0, 0.5, 2.0).unwrap()
+        ));
+
+        close(
+            logistic_pdf(1.7, 0.5, 2.0).unwrap(),
+            0.11439212022832865,
+            1e-13,
+        );
+        close(
+            logistic_cdf(1.7, 0.5, 2.0).unwrap(),
+            0.6456563062257955,
+            1e-13,
+        );
+        let loglogistic = LogLogistic::new(2.0, 5.0).unwrap();
+        close(loglogistic.pdf(1.5), 0.516692009974528, 1e-13);
+        close(loglogistic.cdf(1.5), 0.19179163378058406, 1e-13);
+        close(loglogistic.mean(), 2.13791866423119, 1e-13);
+        close(loglogistic.variance(), 0.7145293838425219, 1e-12);
+        assert!(LogLogistic::new(2.0, 1.5).unwrap().variance().is_nan());
+
+        let von_mises = VonMises::new(0.3, 2.5).unwrap();
+        close(von_mises.pdf(1.0), 0.3273876718708325, 1e-13);
+        close(von_mises.cdf(1.0), 0.8431252465937089, 1e-13);
+        close(von_mises.variance(), 0.5628834695927182, 1e-12);
+        close(von_mises.entropy(), 1.116223868633349, 1e-13);
+        close(
+            vonmises_inv(0.7, 0.3, 2.5).unwrap(),
+            0.657384138335936,
+            1e-12,
+        );
+        close(
+            vonmises_pdf(0.1, 0.0, 80.0).unwrap(),
+            2.38890621615939,
+            1e-13,
+        );
+        close(
+            vonmises_cdf(0.1, 0.0, 80.0).unwrap(),
+            0.8139771707867163,
+            1e-13,
+        );
+        assert!(approx_eq(
+            vonmises_pdf(2.0, 0.0, 0.0).unwrap(),
+            1.0 / (2.0 * std::f64::consts::PI)
+        ));
+
+        close(rayleigh_cdf(1.5, 2.0).unwrap(), 0.24516039801099266, 1e-13);
+        close(
+            erlang_cdf(2.5, 3.0, 1.5).unwrap(),
+            0.7229315566338927,
+            1e-13,
+        );
+        assert!(Erlang::new(2.5, 1.0).is_err());
+
+        // statrs' erfc limits the normal-based families to about 1e-11.
+        close(
+            skewnormal_pdf(1.0, 0.5, 2.0, 3.0).unwrap(),
+            0.2990385452432699,
+            1e-10,
+        );
+        close(
+            skewnormal_cdf(1.0, 0.5, 2.0, 3.0).unwrap(),
+            0.2292059560969519,
+            1e-10,
+        );
+        close(
+            skewnormal_cdf(-1.2, 0.0, 1.0, -0.7).unwrap(),
+            0.20066455542788525,
+            1e-10,
+        );
+        close(
+            skewnormal_cdf(3.5, 0.0, 1.0, -0.7).unwrap(),
+            0.9999978085131113,
+            1e-10,
+        );
+        close(
+            skewnormal_inv(0.25, 0.5, 2.0, 3.0).unwrap(),
+            1.0685149629103296,
+            1e-9,
+        );
+        assert!(approx_eq(
+            skewnormal_cdf(0.7, 1.0, 2.0, 0.0).unwrap(),
+            normal_cdf(0.7, 1.0, 2.0).unwrap()
+        ));
+
+        let truncated = TruncatedNormal::new(1.0, 2.0, -0.5, 4.0).unwrap();
+        close(truncated.pdf(1.5), 0.2736251247481559, 1e-10);
+        close(truncated.cdf(1.5), 0.5266022775751394, 1e-10);
+        close(truncated.mean(), 1.485786100564171, 1e-10);
+        close(truncated.variance(), 1.385579860597426, 1e-10);
+        close(truncated.skewness(), 0.2273193329519943, 1e-9);
+        close(truncated.entropy(), 1.467442266742356, 1e-10);
+        close(truncated.inverse_cdf(0.4).unwrap(), 1.046394485050851, 1e-9);
+        assert_eq!(truncated.pdf(4.5), 0.0);
+        // Truncation 30 sd into the tail.
+        let far = TruncatedNormal::new(0.0, 1.0, 30.0, f64::INFINITY).unwrap();
+        close(far.cdf(30.01), 0.2594651188321078, 1e-11);
+        close(far.mean(), 30.03325966743368, 1e-10);
+
+        close(
+            hypergeom_pmf(3.0, 20.0, 7.0, 12.0).unwrap(),
+            0.19865841073271414,
+            1e-13,
+        );
+        close(
+            hypergeom_cdf(3.0, 20.0, 7.0, 12.0).unwrap(),
+            0.25077399380804954,
+            1e-13,
+        );
+        assert!(hypergeom_pmf(1.0, 10.0, 12.0, 3.0).is_err());
+        close(
+            geometric_pmf(3.0, 0.3).unwrap(),
+            0.3 * 0.7f64.powi(3),
+            1e-14,
+        );
+        close(
+            geometric_cdf(3.0, 0.3).unwrap(),
+            1.0 - 0.7f64.powi(4),
+            1e-14,
+        );
+        assert_eq!(geometric_inv(0.76, 0.3).unwrap(), 4.0);
+        assert_eq!(geometric_inv(0.7599, 0.3).unwrap(), 3.0);
+        assert!(geometric_pmf(1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn multivariate_densities() {
+        assert!(approx_eq(
+            multinomial_pmf(&[2.0, 3.0, 1.0], &[0.2, 0.5, 0.3]).unwrap(),
+            0.09
+        ));
+        assert!(approx_eq(
+            multinomial_pmf(&[3.0, 7.0], &[0.3, 0.7]).unwrap(),
+            binomial_pmf(3.0, 10.0, 0.3).unwrap()
+        ));
+        assert_eq!(
+            multinomial_logpmf(&[1.0, 0.0], &[0.0, 1.0]).unwrap(),
+            f64::NEG_INFINITY
+        );
+        assert!(multinomial_pmf(&[1.0, 2.0], &[0.5, 0.6]).is_err());
+        assert!(multinomial_pmf(&[1.5, 2.0], &[0.5, 0.5]).is_err());
+        assert!(multinomial_pmf(&[1.0], &[0.5, 0.5]).is_err());
+
+        assert!(rel_close(
+            dirichlet_logpdf(&[0.2, 0.5, 0.3], &[1.5, 2.0, 3.0]).unwrap(),
+            1.184385371503574,
+            1e-13
+        ));
+        assert!(approx_eq(
+            dirichlet_pdf(&[0.3, 0.7], &[1.0, 1.0]).unwrap(),
+            1.0
+        ));
+        assert!(approx_eq(
+            dirichlet_pdf(&[0.3, 0.7], &[2.0, 3.0]).unwrap(),
+            beta_pdf(0.3, 2.0, 3.0).unwrap()
+        ));
+        assert_eq!(dirichlet_pdf(&[0.3, 0.6], &[2.0, 3.0]).unwrap(), 0.0);
+        assert!(dirichlet_pdf(&[0.3, 0.7], &[0.0, 3.0]).is_err());
+
+        let x = [2.0, 0.3, 0.3, 1.5];
+        let scale = [1.0, 0.2, 0.2, 0.8];
+        assert!(rel_close(
+            wishart_logpdf(&x, &scale, 2, 4.5).unwrap(),
+            -4.273926451389327,
+            1e-13
+        ));
+        // In one dimension the Wishart is a gamma with shape dof / 2 and rate 1 / (2 scale).
+        assert!(approx_eq(
+            wishart_pdf(&[1.7], &[0.6], 1, 5.0).unwrap(),
+            gamma_pdf(1.7, 2.5, 1.0 / 1.2).unwrap()
+        ));
+        assert_eq!(
+            wishart_logpdf(&[1.0, 2.0, 2.0, 1.0], &scale, 2, 4.5).unwrap(),
+            f64::NEG_INFINITY
+        );
+        assert!(wishart_logpdf(&x, &scale, 2, 0.5).is_err());
+        assert!(wishart_logpdf(&x, &[1.0, 2.0, 2.0, 1.0], 2, 4.5).is_err());
+        assert!(wishart_logpdf(&x, &scale, 3, 4.5).is_err());
+    }
+
+    #[test]
+    fn samples_match_theoretical_moments() {
+        use std::f64::consts::PI;
+
+        const N: usize = 200_000;
+        // (name, draws, mean, variance)
+        let cases: Vec<(&str, Vec<f64>, f64, f64)> = vec![
+            ("normal", normal_sample(N, 2.0, 3.0, 1).unwrap(), 2.0, 9.0),
+            ("gamma", gamma_sample(N, 2.5, 0.5, 2).unwrap(), 5.0, 10.0),
+            ("gamma<1", gamma_sample(N, 0.3, 1.0, 3).unwrap(), 0.3, 0.3),
+            (
+                "beta",
+                beta_sample(N, 2.0, 5.0, 4).unwrap(),
+                2.0 / 7.0,
+                10.0 / 392.0,
+            ),
+            (
+                "beta<1",
+                beta_sample(N, 0.2, 0.3, 5).unwrap(),
+                0.4,
+                0.24 / 1.5,
+            ),
+            (
+                "student_t",
+                student_t_sample(N, 1.0, 2.0, 6.0, 6).unwrap(),
+                1.0,
+                4.0 * 1.5,
+            ),
+            (
+                "chi_squared",
+                chi_squared_sample(N, 4.0, 7).unwrap(),
+                4.0,
+                8.0,
+            ),
+            (
+                "fisher_f",
+                fisher_f_sample(N, 5.0, 12.0, 8).unwrap(),
+                1.2,
+                2.0 * 144.0 * 15.0 / (5.0 * 100.0 * 8.0),
+            ),
+            (
+                "exponential",
+                exponential_sample(N, 4.0, 9).unwrap(),
+                0.25,
+                0.0625,
+            ),
+            ("poisson", poisson_sample(N, 3.5, 10).unwrap(), 3.5, 3.5),
+            (
+                "poisson_large",
+                poisson_sample(N, 150.0, 11).unwrap(),
+                150.0,
+                150.0,
+            ),
+            (
+                "binomial",
+                binomial_sample(N, 20.0, 0.3, 12).unwrap(),
+                6.0,
+                4.2,
+            ),
+            (
+                "binomial_large",
+                binomial_sample(N, 400.0, 0.7, 13).unwrap(),
+                280.0,
+                84.0,
+            ),
+            (
+                "uniform",
+                uniform_sample(N, -1.0, 3.0, 14).unwrap(),
+                1.0,
+                16.0 / 12.0,
+            ),
+            (
+                "laplace",
+                laplace_sample(N, 1.0, 2.0, 15).unwrap(),
+                1.0,
+                8.0,
+            ),
+            (
+                "lognormal",
+                lognormal_sample(N, 0.0, 0.5, 16).unwrap(),
+                0.125f64.exp(),
+                (0.25f64.exp() - 1.0) * 0.25f64.exp(),
+            ),
+            (
+                "weibull",
+                weibull_sample(N, 1.0, 2.0, 17).unwrap(),
+                2.0,
+                4.0,
+            ),
+            (
+                "pareto",
+                pareto_sample(N, 1.0, 5.0, 18).unwrap(),
+                1.25,
+                5.0 / (16.0 * 3.0),
+            ),
+            (
+                "triangular",
+                triangular_sample(N, 0.0, 3.0, 1.0, 19).unwrap(),
+                4.0 / 3.0,
+                7.0 / 18.0,
+            ),
+            (
+                "invgamma",
+                invgamma_sample(N, 5.0, 2.0, 20).unwrap(),
+                0.5,
+                4.0 / (16.0 * 3.0),
+            ),
+            (
+                "negbin",
+                negbin_sample(N, 3.0, 0.4, 21).unwrap(),
+                4.5,
+                11.25,
+            ),
+            (
+                "gumbel",
+                gumbel_sample(N, 1.0, 2.0, 22).unwrap(),
+                1.0 + 2.0 * 0.5772156649015329,
+                4.0 * PI * PI / 6.0,
+            ),
+            (
+                "logistic",
+                logistic_sample(N, -1.0, 0.5, 23).unwrap(),
+                -1.0,
+                0.25 * PI * PI / 3.0,
+            ),
+            (
+                "rayleigh",
+                rayleigh_sample(N, 2.0, 24).unwrap(),
+                2.0 * (PI / 2.0).sqrt(),
+                2.0 * (4.0 - PI),
+            ),
+            ("erlang", erlang_sample(N, 3.0, 2.0, 25).unwrap(), 1.5, 0.75),
+            (
+                "hypergeom",
+                hypergeom_sample(N, 20.0, 7.0, 12.0, 26).unwrap(),
+                4.2,
+                12.0 * 0.35 * 0.65 * 8.0 / 19.0,
+            ),
+            (
+                "geometric",
+                geometric_sample(N, 0.3, 27).unwrap(),
+                0.7 / 0.3,
+                0.7 / 0.09,
+            ),
+        ];
+        // Families whose moments need special functions: compare with the struct moments,
+        // which are checked against references in `additional_families_match_reference`.
+        let structs: Vec<(&str, Box<dyn ContinuousDistribution>)> = vec![
+            ("gev", Box::new(Gev::new(0.5, 2.0, -0.2).unwrap())),
+            ("loglogistic", Box::new(LogLogistic::new(2.0, 8.0).unwrap())),
+            ("vonmises", Box::new(VonMises::new(0.3, 2.5).unwrap())),
+            (
+                "skewnormal",
+                Box::new(SkewNormal::new(0.5, 2.0, 3.0).unwrap()),
+            ),
+            (
+                "truncnormal",
+                Box::new(TruncatedNormal::new(1.0, 2.0, -0.5, 4.0).unwrap()),
+            ),
+            (
+                "noncentral_chi_squared",
+                Box::new(NoncentralChiSquared::new(3.0, 4.0).unwrap()),
+            ),
+            (
+                "noncentral_t",
+                Box::new(NoncentralT::new(12.0, 1.5).unwrap()),
+            ),
+            (
+                "noncentral_f",
+                Box::new(NoncentralF::new(4.0, 20.0, 3.0).unwrap()),
+            ),
+        ];
+        let cases =
+            cases
+                .into_iter()
+                .chain(structs.into_iter().enumerate().map(|(i, (name, dist))| {
+                    (
+                        name,
+                        dist.sample(N, 28 + i as u64),
+                        dist.mean(),
+                        dist.variance(),
+                    )
+                }));
+        for (name, draws, mean, var) in cases {
+            let (m, v) = sample_moments(&draws);
+            assert!(
+                (m - mean).abs() < 5.0 * (var / N as f64).sqrt(),
+                "{name}: mean {m} vs {mean}"
+            );
+            assert!(
+                (v - var).abs() < 0.05 * var,
+                "{name}: variance {v} vs {var}"
+            );
+        }
+    }
+
+    #[test]
+    fn cauchy_samples_match_quartiles() {
+        let draws = cauchy_sample(100_000, 1.0, 2.0, 22).unwrap();
+        let below = |x: f64| draws.iter().filter(|&&d| d <= x).count() as f64 / 100_000.0;
+        assert!((below(-1.0) - 0.25).abs() < 0.01);
+        assert!((below(1.0) - 0.5).abs() < 0.01);
+        assert!((below(3.0) - 0.75).abs() < 0.01);
+    }
+
+    #[test]
+    fn samples_are_reproducible_and_validated() {
+        let a = normal_sample(16, 0.0, 1.0, 99).unwrap();
+        let mut b = vec![0.0; 16];
+        normal_sample_into(&mut b, 0.0, 1.0, 99).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, normal_sample(16, 0.0, 1.0, 100).unwrap());
+
+        assert!(normal_sample(4, 0.0, -1.0, 1).is_err());
+        assert!(gamma_sample(4, 0.0, 1.0, 1).is_err());
+        assert!(binomial_sample(4, 10.0, 1.5, 1).is_err());
+        assert!(uniform_sample(4, 2.0, 1.0, 1).is_err());
+        assert!(poisson_sample(0, 1.0, 1).unwrap().is_empty());
+
+        let draws = binomial_sample(1000, 10.0, 0.5, 3).unwrap();
+        assert!(draws
+            .iter()
+            .all(|&k| k.fract() == 0.0 && (0.0..=10.0).contains(&k)));
+    }
+
+    #[test]
+    fn structs_match_free_functions() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(normal.pdf(0.5), normal_pdf(0.5, 1.0, 2.0).unwrap());
+        assert_eq!(normal.cdf(0.5), normal_cdf(0.5, 1.0, 2.0).unwrap());
+        assert_eq!(
+            normal.inverse_cdf(0.3).unwrap(),
+            normal_inv(0.3, 1.0, 2.0).unwrap()
+        );
+        assert_eq!(normal.sample(8, 5), normal_sample(8, 1.0, 2.0, 5).unwrap());
+
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        assert_eq!(binomial.pmf(3), binomial_pmf(3.0, 10.0, 0.3).unwrap());
+        assert_eq!(binomial.cdf(3), binomial_cdf(3.0, 10.0, 0.3).unwrap());
+        assert_eq!(binomial.support(), (0, 10));
+        assert_eq!(
+            binomial.sample(8, 5),
+            binomial_sample(8, 10.0, 0.3, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn struct_moments_and_support() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert!(approx_eq(normal.mean(), 1.0));
+        assert!(approx_eq(normal.variance(), 4.0));
+        assert!(approx_eq(normal.std_dev(), 2.0));
+        assert_eq!(normal.support(), (f64::NEG_INFINITY, f64::INFINITY));
+
+        let exp = Exp::new(2.0).unwrap();
+        assert!(approx_eq(exp.entropy(), 1.0 - 2f64.ln()));
+        assert!(approx_eq(exp.skewness(), 2.0));
+        assert_eq!(exp.support(), (0.0, f64::INFINITY));
+
+        let cauchy = Cauchy::new(0.0, 1.0).unwrap();
+        assert!(cauchy.mean().is_nan());
+        assert!(cauchy.variance().is_nan());
+
+        let poisson = Poisson::new(3.5).unwrap();
+        assert!(approx_eq(poisson.mean(), 3.5));
+        assert!(approx_eq(poisson.variance(), 3.5));
+        assert_eq!(poisson.support(), (0, u64::MAX));
+
+        let negbin = NegativeBinomial::new(4.0, 0.25).unwrap();
+        assert!(approx_eq(negbin.mean(), 12.0));
+        assert!(approx_eq(negbin.variance(), 48.0));
+    }
+
+    #[test]
+    fn struct_sf_complements_cdf() {
+        let families: Vec<Box<dyn ContinuousDistribution>> = vec![
+            Box::new(Gamma::new(2.0, 1.5).unwrap()),
+            Box::new(StudentsT::new(0.0, 1.0, 5.0).unwrap()),
+            Box::new(Weibull::new(1.5, 2.0).unwrap()),
+        ];
+        for dist in &families {
+            for &x in &[0.1, 0.7, 2.5] {
+                assert!(approx_eq(dist.sf(x) + dist.cdf(x), 1.0));
+            }
+        }
+
+        let binomial = Binomial::new(12, 0.4).unwrap();
+        for k in 0..=12 {
+            assert!(approx_eq(binomial.sf(k) + binomial.cdf(k), 1.0));
+        }
+    }
+
+    #[test]
+    fn struct_generic_usage() {
+        fn median(dist: &impl ContinuousDistribution) -> f64 {
+            dist.inverse_cdf(0.5).unwrap()
+        }
+        assert!(approx_eq(median(&Laplace::new(3.0, 2.0).unwrap()), 3.0));
+        assert!(approx_eq(median(&Uniform::new(-1.0, 5.0).unwrap()), 2.0));
+
+        let dist: &dyn ContinuousDistribution = &LogNormal::new(0.0, 1.0).unwrap();
+        let mut out = vec![0.0; 2];
+        dist.cdf_array(&[1.0, std::f64::consts::E], &mut out)
+            .unwrap();
+        assert!(approx_eq(out[0], 0.5));
+        assert!(approx_eq(out[1], normal_cdf(1.0, 0.0, 1.0).unwrap()));
+        assert!(matches!(
+            dist.cdf_array(&[1.0], &mut out),
+            Err(DistributionError::LengthMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn struct_constructors_validate() {
+        assert!(matches!(
+            Normal::new(0.0, -1.0),
+            Err(DistributionError::InvalidParameters(_))
+        ));
+        assert!(Beta::new(0.0, 1.0).is_err());
+        assert!(Triangular::new(0.0, 1.0, 2.0).is_err());
+        assert!(Binomial::new(5, 1.5).is_err());
+        assert!(NegativeBinomial::new(2.0, 1.5).is_err());
+
+        let gamma = Gamma::new(2.0, 3.0).unwrap();
+        assert_eq!((gamma.shape(), gamma.rate()), (2.0, 3.0));
+        assert!(matches!(
+            gamma.inverse_cdf(1.5),
+            Err(DistributionError::ProbabilityOutOfRange { .. })
+        ));
+    }
+
+    fn rel_close(a: f64, b: f64, tol: f64) -> bool {
+        ((a - b) / b).abs() < tol
+    }
+
+    #[test]
+    fn normal_tails_are_accurate() {
+        assert!(rel_close(
+            normal_sf(10.0, 0.0, 1.0).unwrap(),
+            7.619853024160526e-24,
+            1e-9
+        ));
+        assert_eq!(1.0 - normal_cdf(10.0, 0.0, 1.0).unwrap(), 0.0);
+        assert!(rel_close(
+            normal_logsf(5.0, 0.0, 1.0).unwrap(),
+            -15.064998393988726,
+            1e-9
+        ));
+        assert!(rel_close(
+            normal_logsf(40.0, 0.0, 1.0).unwrap(),
+            -804.6084420137538,
+            1e-9
+        ));
+        assert!(rel_close(
+            normal_logcdf(-40.0, 0.0, 1.0).unwrap(),
+            -804.6084420137538,
+            1e-9
+        ));
+        assert!(normal_logcdf(10.0, 0.0, 1.0).unwrap() < 0.0);
+        assert!(rel_close(
+            normal_isf(1e-300, 0.0, 1.0).unwrap(),
+            37.0470962993612,
+            1e-10
+        ));
+        assert!(approx_eq(
+            normal_isf(0.025, 1.0, 2.0).unwrap(),
+            2.0 - normal_inv(0.025, 1.0, 2.0).unwrap()
+        ));
+    }
+
+    #[test]
+    fn log_density_matches_density() {
+        let x = [0.3, 1.2, 4.0];
+        let mut logs = [0.0; 3];
+        gamma_logpdf_array(&x, 2.5, 0.5, &mut logs).unwrap();
+        for (&xi, &l) in x.iter().zip(&logs) {
+            assert!(approx_eq(l, gamma_pdf(xi, 2.5, 0.5).unwrap().ln()));
+        }
+        assert!(approx_eq(
+            binomial_logpmf(3.0, 10.0, 0.4).unwrap(),
+            binomial_pmf(3.0, 10.0, 0.4).unwrap().ln()
+        ));
+        // Products of densities underflow long before sums of log densities do.
+        assert_eq!(normal_pdf(40.0, 0.0, 1.0).unwrap(), 0.0);
+        assert!(approx_eq(
+            normal_logpdf(40.0, 0.0, 1.0).unwrap(),
+            -800.0 - 0.5 * (2.0 * std::f64::consts::PI).ln()
+        ));
+    }
+
+    #[test]
+    fn upper_tails_of_other_families() {
+        assert!(rel_close(
+            chi_squared_sf(300.0, 3.0).unwrap(),
+            9.94875834632771e-65,
+            1e-9
+        ));
+        assert!(rel_close(
+            chi_squared_isf(1e-20, 4.0).unwrap(),
+            99.9663959741815,
+            1e-10
+        ));
+        assert!(rel_close(
+            gamma_isf(1e-30, 2.5, 0.5).unwrap(),
+            150.58968413567666,
+            1e-10
+        ));
+        assert!(rel_close(
+            student_t_isf(1e-12, 0.0, 1.0, 5.0).unwrap(),
+            393.9569595776038,
+            1e-8
+        ));
+        assert!(rel_close(
+            cauchy_sf(1e17, 0.0, 1.0).unwrap(),
+            3.183098861837907e-18,
+            1e-12
+        ));
+        assert!(rel_close(
+            cauchy_isf(3.183098861837907e-18, 0.0, 1.0).unwrap(),
+            1e17,
+            1e-10
+        ));
+        assert!(approx_eq(exponential_logsf(50.0, 2.0).unwrap(), -100.0));
+        assert!(approx_eq(
+            exponential_isf(1e-100, 1.0).unwrap(),
+            100.0 * 10f64.ln()
+        ));
+        assert!(approx_eq(
+            laplace_logsf(30.0, 0.0, 0.5).unwrap(),
+            -60.0 - 2f64.ln()
+        ));
+        assert!(rel_close(
+            weibull_isf(1e-50, 2.0, 3.0).unwrap(),
+            3.0 * (50.0 * 10f64.ln()).sqrt(),
+            1e-12
+        ));
+
+        let x = [0.5, 2.0, 7.5];
+        let mut sf = [0.0; 3];
+        let mut logsf = [0.0; 3];
+        let mut cdf = [0.0; 3];
+        let mut logcdf = [0.0; 3];
+        beta_sf_array(&[0.1, 0.5, 0.9], 2.0, 3.0, &mut sf).unwrap();
+        beta_cdf_array(&[0.1, 0.5, 0.9], 2.0, 3.0, &mut cdf).unwrap();
+        for (a, b) in sf.iter().zip(&cdf) {
+            assert!(approx_eq(a + b, 1.0));
+        }
+        fisher_f_logsf_array(&x, 3.0, 8.0, &mut logsf).unwrap();
+        fisher_f_logcdf_array(&x, 3.0, 8.0, &mut logcdf).unwrap();
+        for i in 0..3 {
+            assert!(approx_eq(logsf[i].exp() + logcdf[i].exp(), 1.0));
+        }
+    }
+
+    #[test]
+    fn isf_inverts_sf_for_every_continuous_family() {
+        let families: Vec<Box<dyn ContinuousDistribution>> = vec![
+            Box::new(Normal::new(1.0, 2.0).unwrap()),
+            Box::new(Gamma::new(0.7, 2.0).unwrap()),
+            Box::new(Beta::new(2.0, 5.0).unwrap()),
+            Box::new(StudentsT::new(0.0, 1.0, 3.0).unwrap()),
+            Box::new(ChiSquared::new(6.0).unwrap()),
+            Box::new(FisherSnedecor::new(4.0, 12.0).unwrap()),
+            Box::new(Exp::new(0.5).unwrap()),
+            Box::new(Uniform::new(-1.0, 3.0).unwrap()),
+            Box::new(Cauchy::new(0.0, 2.0).unwrap()),
+            Box::new(Laplace::new(1.0, 0.5).unwrap()),
+            Box::new(LogNormal::new(0.0, 0.5).unwrap()),
+            Box::new(Weibull::new(1.5, 2.0).unwrap()),
+            Box::new(Pareto::new(1.0, 3.0).unwrap()),
+            Box::new(Triangular::new(0.0, 4.0, 1.0).unwrap()),
+            Box::new(InverseGamma::new(3.0, 2.0).unwrap()),
+            Box::new(Gumbel::new(1.0, 2.0).unwrap()),
+            Box::new(Gev::new(0.0, 1.0, 0.3).unwrap()),
+            Box::new(Gev::new(0.0, 1.0, -0.3).unwrap()),
+            Box::new(Logistic::new(1.0, 0.5).unwrap()),
+            Box::new(LogLogistic::new(2.0, 3.0).unwrap()),
+            Box::new(VonMises::new(0.3, 30.0).unwrap()),
+            Box::new(Rayleigh::new(2.0).unwrap()),
+            Box::new(Erlang::new(3.0, 2.0).unwrap()),
+            Box::new(SkewNormal::new(0.5, 2.0, 3.0).unwrap()),
+            Box::new(TruncatedNormal::new(1.0, 2.0, -0.5, f64::INFINITY).unwrap()),
+            Box::new(NoncentralChiSquared::new(3.0, 4.0).unwrap()),
+            Box::new(NoncentralT::new(8.0, 1.5).unwrap()),
+            Box::new(NoncentralT::new(8.0, -1.5).unwrap()),
+            Box::new(NoncentralF::new(4.0, 20.0, 3.0).unwrap()),
+        ];
+        for (i, dist) in families.iter().enumerate() {
+            for &p in &[0.9, 0.5, 0.1, 1e-6, 1e-20] {
+                let x = dist.inverse_sf(p).unwrap();
+                if x == dist.support().1 {
+                    // Bounded support: the quantile rounds to the upper end for tiny `p`.
+                    assert!(p < f64::EPSILON);
+                    continue;
+                }
+                let back = dist.ln_sf(x);
+                assert!(
+                    (back - p.ln()).abs() < 1e-7 * p.ln().abs().max(1.0),
+                    "family {i}: isf({p}) = {x}, ln sf = {back}"
+                );
+            }
+            assert!(dist.inverse_sf(1.5).is_err());
+        }
+    }
+
+    #[test]
+    fn discrete_tails() {
+        assert!(rel_close(
+            poisson_sf(30.0, 3.0).unwrap(),
+            4.125405198113943e-21,
+            1e-9
+        ));
+        let k = poisson_isf(1e-20, 3.0).unwrap();
+        assert!(poisson_sf(k, 3.0).unwrap() <= 1e-20);
+        assert!(poisson_sf(k - 1.0, 3.0).unwrap() > 1e-20);
+
+        assert_eq!(binomial_isf(0.0, 10.0, 0.3).unwrap(), 10.0);
+        assert_eq!(binomial_isf(1.0, 10.0, 0.3).unwrap(), 0.0);
+        for &p in &[0.9, 0.5, 0.05] {
+            assert_eq!(
+                negbin_isf(p, 3.0, 0.4).unwrap(),
+                negbin_inv(1.0 - p, 3.0, 0.4).unwrap()
+            );
+        }
+        assert!(approx_eq(
+            negbin_logsf(4.0, 3.0, 0.4).unwrap(),
+            negbin_sf(4.0, 3.0, 0.4).unwrap().ln()
+        ));
+        assert!(poisson_isf(-0.1, 3.0).is_err());
+    }
+
+    const FIT_DATA: [f64; 10] = [0.8, 1.3, 2.1, 2.9, 3.4, 4.7, 5.2, 7.9, 1.1, 0.6];
+
+    fn assert_fit<D>(fit: &FitResult<D>, estimates: &[f64], std_errors: &[f64], log_lik: f64) {
+        for (a, b) in fit.estimates.iter().zip(estimates) {
+            assert!(rel_close(*a, *b, 1e-9), "estimate {a} vs {b}");
+        }
+        for (a, b) in fit.std_errors.iter().zip(std_errors) {
+            assert!(rel_close(*a, *b, 1e-8), "std error {a} vs {b}");
+        }
+        assert!(rel_close(fit.log_likelihood, log_lik, 1e-10));
+        let k = estimates.len() as f64;
+        assert!(approx_eq(fit.aic, 2.0 * k - 2.0 * log_lik));
+    }
+
+    #[test]
+    fn fit_closed_form_families() {
+        let fit = fit_normal(&FIT_DATA).unwrap();
+        let sd = crate::stats::variance(&FIT_DATA).sqrt();
+        assert!(approx_eq(fit.estimates[0], 3.0));
+        assert!(approx_eq(fit.estimates[1], sd));
+        assert!(approx_eq(fit.std_errors[0], sd / 10f64.sqrt()));
+        assert!(approx_eq(fit.std_errors[1], sd / 20f64.sqrt()));
+        let ll: f64 = FIT_DATA
+            .iter()
+            .map(|&x| normal_logpdf(x, 3.0, sd).unwrap())
+            .sum();
+        assert!(approx_eq(fit.log_likelihood, ll));
+
+        let fit = fit_exponential(&FIT_DATA).unwrap();
+        assert!(approx_eq(fit.estimates[0], 1.0 / 3.0));
+        assert!(approx_eq(fit.distribution.rate(), 1.0 / 3.0));
+
+        let fit = fit_lognormal(&FIT_DATA).unwrap();
+        let logs: Vec<f64> = FIT_DATA.iter().map(|x| x.ln()).collect();
+        assert!(approx_eq(fit.estimates[0], crate::stats::mean(&logs)));
+
+        let fit = fit_laplace(&FIT_DATA).unwrap();
+        assert!(approx_eq(fit.estimates[0], 2.5));
+        assert!(approx_eq(fit.estimates[1], 1.82));
+
+        let counts = [0.0, 2.0, 1.0, 3.0, 1.0, 0.0, 2.0, 4.0];
+        let fit = fit_poisson(&counts).unwrap();
+        assert!(approx_eq(fit.estimates[0], 1.625));
+        assert!(approx_eq(fit.std_errors[0], (1.625f64 / 8.0).sqrt()));
+
+        let fit = fit_binomial(&counts, 5).unwrap();
+        assert!(approx_eq(fit.estimates[0], 0.325));
+        assert_eq!(fit.distribution.n(), 5);
+    }
+
+    #[test]
+    fn fit_iterative_families() {
+        assert_fit(
+            &fit_gamma(&FIT_DATA).unwrap(),
+            &[1.7777218448158337, 0.5925739482719446],
+            &[0.732626864922274, 0.281763160530145],
+            -20.149794101754266,
+        );
+        assert_fit(
+            &fit_weibull(&FIT_DATA).unwrap(),
+            &[1.3871055015509526, 3.301810285797415],
+            &[0.342828540451089, 0.796259418237754],
+            -20.231582808905854,
+        );
+        assert_fit(
+            &fit_beta(&[0.12, 0.35, 0.41, 0.58, 0.22, 0.67, 0.29, 0.51, 0.44, 0.18]).unwrap(),
+            &[2.823781149403837, 4.681313566082946],
+            &[1.2042530617545, 2.06745308578645],
+            4.035299278422975,
+        );
+        assert_fit(
+            &fit_negbin(&[
+                0.0, 2.0, 1.0, 5.0, 0.0, 3.0, 8.0, 1.0, 0.0, 4.0, 2.0, 6.0, 0.0, 1.0, 12.0,
+            ])
+            .unwrap(),
+            &[0.9106209804670773, 0.23285840919267876],
+            &[0.486836224076928, 0.110299338288022],
+            -33.724903276438814,
+        );
+    }
+
+    #[test]
+    fn fit_recovers_sampled_parameters() {
+        let draws = gamma_sample(20_000, 3.0, 2.0, 8).unwrap();
+        let fit = fit_gamma(&draws).unwrap();
+        assert!((fit.estimates[0] - 3.0).abs() < 4.0 * fit.std_errors[0]);
+        assert!((fit.estimates[1] - 2.0).abs() < 4.0 * fit.std_errors[1]);
+
+        let draws = weibull_sample(20_000, 0.7, 5.0, 9).unwrap();
+        let fit = fit_weibull(&draws).unwrap();
+        assert!((fit.estimates[0] - 0.7).abs() < 4.0 * fit.std_errors[0]);
+        assert!((fit.estimates[1] - 5.0).abs() < 4.0 * fit.std_errors[1]);
+    }
+
+    #[test]
+    fn fit_rejects_invalid_data() {
+        assert!(fit_normal(&[1.0]).is_err());
+        assert!(fit_normal(&[1.0, f64::NAN]).is_err());
+        assert!(fit_gamma(&[1.0, -2.0, 3.0]).is_err());
+        assert!(fit_gamma(&[2.0, 2.0, 2.0]).is_err());
+        assert!(fit_beta(&[0.2, 1.0]).is_err());
+        assert!(fit_poisson(&[1.0, 2.5]).is_err());
+        assert!(fit_binomial(&[1.0, 6.0], 5).is_err());
+        assert!(matches!(
+            fit_negbin(&[1.0, 2.0, 1.0, 2.0]),
+            Err(DistributionError::InvalidParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use super::DistributionError;
+use statrs::function::gamma::ln_gamma;
+use std::f64::consts::{LN_2, PI};
+
+// Multivariate densities
+//
+// Multinomial, Dirichlet and Wishart (log-)densities over flat slices. Parameters are
+// validated as for the univariate families; an observation outside the support has density
+// zero (log-density `-inf`) rather than being an error. Matrices are dense, row-major
+// `dim x dim` slices.
+
+/// Tolerance on `sum(probs) == 1` and `sum(x) == 1` for simplex arguments.
+const SIMPLEX_TOLERANCE: f64 = 1e-9;
+
+fn invalid(msg: String) -> Result<f64, DistributionError> {
+    Err(DistributionError::InvalidParameters(msg))
+}
+
+/// Internal: lower Cholesky factor of a symmetric row-major `dim x dim` matrix, or `None` if
+/// it is not positive definite.
+fn cholesky(matrix: &[f64], dim: usize) -> Option<Vec<f64>> {
+    let mut lower = vec![0.0; dim * dim];
+    for i in 0..dim {
+        for j in 0..=i {
+            let dot: f64 = (0..j)
+                .map(|k| lower[i * dim + k] * lower[j * dim + k])
+                .sum();
+            let value = matrix[i * dim + j] - dot;
+            if i == j {
+                if value.is_nan() || value <= 0.0 {
+                    return None;
+                }
+                lower[i * dim + i] = value.sqrt();
+            } else {
+                lower[i * dim + j] = value / lower[j * dim + j];
+            }
+        }
+    }
+    Some(lower)
+}
+
+/// Internal: `ln det` of the matrix with lower Cholesky factor `lower`.
+fn cholesky_ln_det(lower: &[f64], dim: usize) -> f64 {
+    2.0 * (0..dim).map(|i| lower[i * dim + i].ln()).sum::<f64>()
+}
+
+/// Internal: `trace(A^-1 B)` where `A = L L^T`, by forward and back substitution on each
+/// column of `B`.
+fn cholesky_trace_solve(lower: &[f64], rhs: &[f64], dim: usize) -> f64 {
+    let mut trace = 0.0;
+    let mut column = vec![0.0; dim];
+    for c in 0..dim {
+        for i in 0..dim {
+            let dot: f64 = (0..i).map(|k| lower[i * dim + k] * column[k]).sum();
+            column[i] = (rhs[i * dim + c] - dot) / lower[i * dim + i];
+        }
+        for i in (0..dim).rev() {
+            let dot: f64 = (i + 1..dim).map(|k| lower[k * dim + i] * column[k]).sum();
+            column[i] = (column[i] - dot) / lower[i * dim + i];
+        }
+        trace += column[c];
+    }
+    trace
+}
+
+fn is_symmetric(matrix: &[f64], dim: usize) -> bool {
+    (0..dim).all(|i| {
+        (0..i).all(|j| {
+            let (a, b) = (matrix[i * dim + j], matrix[j * dim + i]);
+            (a - b).abs() <= 1e-12 * a.abs().max(b.abs()).max(1.0)
+        })
+    })
+}
+
+/// Multinomial log-probability of `counts` given category probabilities `probs`.
+///
+/// `counts` must be non-negative integers and `probs` non-negative with unit sum; the number
+/// of trials is `sum(counts)`.
+pub fn multinomial_logpmf(counts: &[f64], probs: &[f64]) -> Result<f64, DistributionError> {
+    if counts.len() != probs.len() || probs.is_empty() {
+        return invalid(format!(
+            "multinomial requires counts and probs of equal, non-zero length; received {} and {}",
+            counts.len(),
+            probs.len()
+        ));
+    }
+    if probs.iter().any(|&p| !(0.0..=1.0).contains(&p))
+        || (probs.iter().sum::<f64>() - 1.0).abs() > SIMPLEX_TOLERANCE
+    {
+        return invalid("multinomial probs must lie in [0, 1] and sum to 1".to_string());
+    }
+    if counts
+        .iter()
+        .any(|&k| !(k >= 0.0 && k.is_finite() && k.fract() == 0.0))
+    {
+        return invalid("multinomial counts must be non-negative integers".to_string());
+    }
+    let n: f64 = counts.iter().sum();
+    let mut total = ln_gamma(n + 1.0);
+    for (&k, &p) in counts.iter().zip(probs) {
+        if k > 0.0 {
+            total += k * p.ln() - ln_gamma(k + 1.0);
+        }
+    }
+    Ok(total)
+}
+
+/// Multinomial probability of `counts`; see [`multinomial_logpmf`].
+pub fn multinomial_pmf(counts: &[f64], probs: &[f64]) -> Result<f64, DistributionError> {
+    Ok(multinomial_logpmf(counts, probs)?.exp())
+}
+
+/// Dirichlet log-density at the point `x` of the simplex with concentrations `alpha > 0`.
+///
+/// Points off the simplex (negative entries or a sum other than 1) have log-density `-inf`.
+pub fn dirichlet_logpdf(x: &[f64], alpha: &[f64]) -> Result<f64, DistributionError> {
+    if x.len() != alpha.len() || alpha.len() < 2 {
+        return invalid(format!(
+            "Dirichlet requires x and alpha of equal length >= 2; received {} and {}",
+            x.len(),
+            alpha.len()
+        ));
+    }
+    if alpha.iter().any(|&a| !(a > 0.0 && a.is_finite())) {
+        return invalid("Dirichlet alpha entries must be positive and finite".to_string());
+    }
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(f64::NAN);
+    }
+    if x.iter().any(|&v| v < 0.0) || (x.iter().sum::<f64>() - 1.0).abs() > SIMPLEX_TOLERANCE {
+        return Ok(f64::NEG_INFINITY);
+    }
+    let mut total = ln_gamma(alpha.iter().sum());
+    for (&v, &a) in x.iter().zip(alpha) {
+        total -= ln_gamma(a);
+        // A zero coordinate with alpha == 1 contributes nothing rather than 0 * -inf.
+        if a != 1.0 {
+            total += (a - 1.0) * v.ln();
+        }
+    }
+    Ok(total)
+}
+
+/// Dirichlet density; see [`dirichlet_logpdf`].
+pub fn dirichlet_pdf(x: &[f64], alpha: &[f64]) -> Result<f64, DistributionError> {
+    Ok(dirichlet_logpdf(x, alpha)?.exp())
+}
+
+/// Wishart log-density of the `dim x dim` matrix `x` with scale matrix `scale` and `dof`
+/// degrees of freedom (`dof > dim - 1`).
+///
+/// `scale` must be symmetric positive definite. A symmetric `x` that is not positive definite
+/// lies outside the support and has log-density `-inf`.
+pub fn wishart_logpdf(
+    x: &[f64],
+    scale: &[f64],
+    dim: usize,
+    dof: f64,
+) -> Result<f64, DistributionError> {
+    let len = dim * dim;
+    if dim == 0 || x.len() != len || scale.len() != len {
+        return invalid(format!(
+            "Wishart requires x and scale of length dim * dim; received dim={dim}, \
+             x of length {}, scale of length {}",
+            x.len(),
+            scale.len()
+        ));
+    }
+    let p = dim as f64;
+    if !(dof > p - 1.0 && dof.is_finite()) {
+        return invalid(format!(
+            "Wishart requires finite dof > dim - 1; received dof={dof}, dim={dim}"
+        ));
+    }
+    let scale_factor = match cholesky(scale, dim) {
+        Some(lower) if is_symmetric(scale, dim) => lower,
+        _ => return invalid("Wishart scale must be symmetric positive definite".to_string()),
+    };
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(f64::NAN);
+    }
+    if !is_symmetric(x, dim) {
+        return invalid("Wishart x must be symmetric".to_string());
+    }
+    let x_factor = match cholesky(x, dim) {
+        Some(lower) => lower,
+        None => return Ok(f64::NEG_INFINITY),
+    };
+
+    // ln Gamma_p(dof / 2), the multivariate gamma function.
+    let ln_multi_gamma = p * (p - 1.0) / 4.0 * PI.ln()
+        + (0..dim)
+            .map(|j| ln_gamma((dof - j as f64) / 2.0))
+            .sum::<f64>();
+    Ok((dof - p - 1.0) / 2.0 * cholesky_ln_det(&x_factor, dim)
+        - cholesky_trace_solve(&scale_factor, x, dim) / 2.0
+        - dof * p / 2.0 * LN_2
+        - dof / 2.0 * cholesky_ln_det(&scale_factor, dim)
+        - ln_multi_gamma)
+}
+
+/// Wishart density; see [`wishart_logpdf`].
+pub fn wishart_pdf(
+    x: &[f64],
+    scale: &[f64],
+    dim: usize,
+    dof: f64,
+) -> Result<f64, DistributionError> {
+    Ok(wishart_logpdf(x, scale, dim, dof)?.exp())
+}
@@ -0,0 +1,310 @@
+use super::{apply_unary_op, ensure_probability, DistributionError};
+use crate::random::{RandomSource, Xoshiro256PlusPlus};
+
+/// A validated continuous distribution.
+///
+/// Moments that do not exist for the current parameters (e.g. the Cauchy mean, or the
+/// Student's t variance for `dof <= 2`) are returned as NaN. The trait is object safe, so
+/// generic code can take either `impl ContinuousDistribution` or `&dyn ContinuousDistribution`.
+pub trait ContinuousDistribution {
+    /// Probability density at `x`.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Natural logarithm of the density at `x`.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// Cumulative probability `P(X <= x)`.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Natural logarithm of the cumulative probability.
+    fn ln_cdf(&self, x: f64) -> f64 {
+        self.cdf(x).ln()
+    }
+
+    /// Survival function `P(X > x)`.
+    fn sf(&self, x: f64) -> f64 {
+        1.0 - self.cdf(x)
+    }
+
+    /// Natural logarithm of the survival function.
+    fn ln_sf(&self, x: f64) -> f64 {
+        self.sf(x).ln()
+    }
+
+    /// Quantile function; errors if `p` is outside `[0, 1]`.
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionError>;
+
+    /// Inverse survival function: the `x` with `sf(x) = p`.
+    ///
+    /// Unlike `inverse_cdf(1 - p)`, this stays accurate for upper-tail probabilities far below
+    /// machine epsilon. The default starts from `inverse_cdf` and polishes the root of
+    /// `ln_sf(x) = ln(p)` with safeguarded Newton steps; families with a closed form override it.
+    fn inverse_sf(&self, p: f64) -> Result<f64, DistributionError> {
+        ensure_probability(p)?;
+        let (lower, upper) = self.support();
+        if p == 0.0 {
+            return Ok(upper);
+        }
+        if p == 1.0 {
+            return Ok(lower);
+        }
+        let target = p.ln();
+        let excess = |x: f64| self.ln_sf(x) - target;
+
+        // Bracket the root, excess(lo) > 0 >= excess(hi), by stepping away from the initial
+        // guess in doubling steps. A CDF that rounds to just below `1 - eps` inverts to the
+        // upper end, so fall back to the median then.
+        let median = self.inverse_cdf(0.5)?;
+        let mut x = self.inverse_cdf((1.0 - p).min(1.0 - f64::EPSILON))?;
+        if !x.is_finite() {
+            x = median;
+        }
+        let mut step = (x - median)
+            .abs()
+            .max(f64::EPSILON * x.abs())
+            .max(f64::MIN_POSITIVE);
+        let (mut lo, mut hi) = (x, x);
+        if excess(x) > 0.0 {
+            loop {
+                hi = lo + step;
+                if hi >= upper {
+                    hi = upper;
+                    break;
+                }
+                if excess(hi) <= 0.0 {
+                    break;
+                }
+                lo = hi;
+                step *= 2.0;
+            }
+        } else {
+            loop {
+                lo = hi - step;
+                if lo <= lower {
+                    lo = lower;
+                    break;
+                }
+                if excess(lo) > 0.0 {
+                    break;
+                }
+                hi = lo;
+                step *= 2.0;
+            }
+        }
+        if lo.is_infinite() || hi.is_infinite() {
+            return Ok(if hi.is_infinite() { hi } else { lo });
+        }
+
+        x = 0.5 * (lo + hi);
+        for _ in 0..200 {
+            let fx = excess(x);
+            if fx == 0.0 {
+                return Ok(x);
+            }
+            if fx > 0.0 {
+                lo = x;
+            } else {
+                hi = x;
+            }
+            // d/dx ln sf(x) = -pdf(x) / sf(x)
+            let slope = -(self.ln_pdf(x) - self.ln_sf(x)).exp();
+            let mut next = x - fx / slope;
+            if !(next > lo && next < hi) {
+                next = 0.5 * (lo + hi);
+            }
+            if next == lo || next == hi || (next - x).abs() <= 4.0 * f64::EPSILON * next.abs() {
+                return Ok(next);
+            }
+            x = next;
+        }
+        Ok(x)
+    }
+
+    fn mean(&self) -> f64;
+
+    fn variance(&self) -> f64;
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn skewness(&self) -> f64;
+
+    /// Differential entropy in nats.
+    fn entropy(&self) -> f64;
+
+    /// Closed support `(min, max)`, possibly infinite.
+    fn support(&self) -> (f64, f64);
+
+    /// Draw one variate from `rng`.
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> f64;
+
+    fn pdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.pdf(x))
+    }
+
+    fn cdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.cdf(x))
+    }
+
+    fn ln_pdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.ln_pdf(x))
+    }
+
+    fn ln_cdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.ln_cdf(x))
+    }
+
+    fn sf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.sf(x))
+    }
+
+    fn ln_sf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |x| self.ln_sf(x))
+    }
+
+    /// Fill `output` with variates from a generator seeded with `seed`.
+    fn sample_into(&self, output: &mut [f64], seed: u64) {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+        for dst in output.iter_mut() {
+            *dst = self.sample_with(&mut rng);
+        }
+    }
+
+    /// Draw `count` variates from a generator seeded with `seed`.
+    fn sample(&self, count: usize, seed: u64) -> Vec<f64> {
+        let mut output = vec![0.0; count];
+        self.sample_into(&mut output, seed);
+        output
+    }
+}
+
+/// A validated distribution on the non-negative integers.
+///
+/// Array and sampling helpers use `f64` values, matching the free `*_pmf_array` functions;
+/// inputs are truncated to integers.
+pub trait DiscreteDistribution {
+    /// Probability mass at `k`.
+    fn pmf(&self, k: u64) -> f64;
+
+    /// Natural logarithm of the probability mass at `k`.
+    fn ln_pmf(&self, k: u64) -> f64 {
+        self.pmf(k).ln()
+    }
+
+    /// Cumulative probability `P(X <= k)`.
+    fn cdf(&self, k: u64) -> f64;
+
+    /// Natural logarithm of the cumulative probability.
+    fn ln_cdf(&self, k: u64) -> f64 {
+        self.cdf(k).ln()
+    }
+
+    /// Survival function `P(X > k)`.
+    fn sf(&self, k: u64) -> f64 {
+        1.0 - self.cdf(k)
+    }
+
+    /// Natural logarithm of the survival function.
+    fn ln_sf(&self, k: u64) -> f64 {
+        self.sf(k).ln()
+    }
+
+    /// Smallest `k` with `cdf(k) >= p`; errors if `p` is outside `[0, 1]`.
+    fn inverse_cdf(&self, p: f64) -> Result<u64, DistributionError>;
+
+    /// Smallest `k` with `sf(k) <= p`; errors if `p` is outside `[0, 1]`.
+    ///
+    /// Searches on `sf` directly (exponential then binary search), so tiny upper-tail
+    /// probabilities are not lost to `1 - p` rounding.
+    fn inverse_sf(&self, p: f64) -> Result<u64, DistributionError> {
+        ensure_probability(p)?;
+        let (lower, upper) = self.support();
+        if self.sf(lower) <= p {
+            return Ok(lower);
+        }
+        let (mut lo, mut hi) = (lower, lower);
+        let mut step = 1u64;
+        loop {
+            hi = hi.saturating_add(step).min(upper);
+            if self.sf(hi) <= p {
+                break;
+            }
+            if hi == upper {
+                return Ok(upper);
+            }
+            lo = hi;
+            step = step.saturating_mul(2);
+        }
+        // Invariant: sf(lo) > p >= sf(hi).
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if self.sf(mid) <= p {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Ok(hi)
+    }
+
+    fn mean(&self) -> f64;
+
+    fn variance(&self) -> f64;
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn skewness(&self) -> f64;
+
+    /// Shannon entropy in nats (NaN where no closed form is available).
+    fn entropy(&self) -> f64;
+
+    /// Support `(min, max)`; `u64::MAX` marks an unbounded upper end.
+    fn support(&self) -> (u64, u64);
+
+    /// Draw one variate from `rng`.
+    fn sample_with(&self, rng: &mut dyn RandomSource) -> u64;
+
+    fn pmf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.pmf(k as u64))
+    }
+
+    fn cdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.cdf(k as u64))
+    }
+
+    fn ln_pmf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.ln_pmf(k as u64))
+    }
+
+    fn ln_cdf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.ln_cdf(k as u64))
+    }
+
+    fn sf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.sf(k as u64))
+    }
+
+    fn ln_sf_array(&self, input: &[f64], output: &mut [f64]) -> Result<(), DistributionError> {
+        apply_unary_op(input, output, |k| self.ln_sf(k as u64))
+    }
+
+    /// Fill `output` with variates from a generator seeded with `seed`.
+    fn sample_into(&self, output: &mut [f64], seed: u64) {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+        for dst in output.iter_mut() {
+            *dst = self.sample_with(&mut rng) as f64;
+        }
+    }
+
+    /// Draw `count` variates from a generator seeded with `seed`.
+    fn sample(&self, count: usize, seed: u64) -> Vec<f64> {
+        let mut output = vec![0.0; count];
+        self.sample_into(&mut output, seed);
+        output
+    }
+}
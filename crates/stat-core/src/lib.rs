@@ -1,6 +1,8 @@
 #![allow(clippy::missing_safety_doc, clippy::needless_range_loop)]
 pub mod distributions;
+pub mod random;
 pub mod stats;
 
 pub use distributions::*;
+pub use random::*;
 pub use stats::*;
@@ -0,0 +1,384 @@
+//! Seedable pseudo-random number generation and core variate algorithms.
+//!
+//! Everything here is deterministic given a seed and needs no OS entropy, so the same seed
+//! produces the same stream natively and in WASM. The per-family `*_sample` functions in
+//! [`distributions`](crate::distributions) are built on these primitives.
+
+use statrs::function::gamma::ln_gamma;
+use std::sync::OnceLock;
+
+const TWO_POW_M53: f64 = 1.0 / (1u64 << 53) as f64;
+
+/// A source of uniformly distributed 64-bit words, plus the standard variates derived from it.
+///
+/// Implement `next_u64` to plug in another generator; the provided methods supply the
+/// transformations (ziggurat normals, Marsaglia-Tsang gammas, PTRS/BTRS discrete variates).
+pub trait RandomSource {
+    /// Next uniformly distributed 64-bit word.
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform variate on `[0, 1)` with 53 bits of precision.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * TWO_POW_M53
+    }
+
+    /// Uniform variate on the open interval `(0, 1)`, safe to pass to `ln`.
+    #[inline]
+    fn next_open_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) * TWO_POW_M53
+    }
+
+    /// Standard normal variate (Marsaglia-Tsang ziggurat, Doornik's 128-layer variant).
+    fn standard_normal(&mut self) -> f64 {
+        let zig = ziggurat();
+        loop {
+            let bits = self.next_u64();
+            let i = (bits & 0x7f) as usize;
+            let u = 2.0 * ((bits >> 11) as f64 * TWO_POW_M53) - 1.0;
+
+            if u.abs() < zig.ratio[i] {
+                return u * zig.x[i];
+            }
+
+            if i == 0 {
+                // Base layer overflow: sample the tail beyond R (Marsaglia 1964).
+                let (mut a, mut b) = (0.0, 0.0);
+                while b + b <= a * a {
+                    a = -self.next_open_f64().ln() / ZIGGURAT_R;
+                    b = -self.next_open_f64().ln();
+                }
+                return if u < 0.0 {
+                    -(ZIGGURAT_R + a)
+                } else {
+                    ZIGGURAT_R + a
+                };
+            }
+
+            let xx = u * zig.x[i];
+            let f0 = (-0.5 * (zig.x[i] * zig.x[i] - xx * xx)).exp();
+            let f1 = (-0.5 * (zig.x[i + 1] * zig.x[i + 1] - xx * xx)).exp();
+            if f1 + self.next_f64() * (f0 - f1) < 1.0 {
+                return xx;
+            }
+        }
+    }
+
+    /// Standard exponential variate (rate 1).
+    #[inline]
+    fn standard_exponential(&mut self) -> f64 {
+        -self.next_open_f64().ln()
+    }
+
+    /// Gamma variate with the given shape and rate 1 (Marsaglia & Tsang 2000).
+    ///
+    /// Shapes below 1 use the boost `G(a) = G(a + 1) * U^(1/a)`.
+    fn standard_gamma(&mut self, shape: f64) -> f64 {
+        if shape < 1.0 {
+            return self.standard_gamma(shape + 1.0) * self.next_open_f64().powf(1.0 / shape);
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let x = self.standard_normal();
+            let v = 1.0 + c * x;
+            if v <= 0.0 {
+                continue;
+            }
+            let v = v * v * v;
+            let u = self.next_open_f64();
+            let x_sq = x * x;
+            if u < 1.0 - 0.0331 * x_sq * x_sq || u.ln() < 0.5 * x_sq + d * (1.0 - v + v.ln()) {
+                return d * v;
+            }
+        }
+    }
+
+    /// Beta variate from two gamma variates, combined in log space so that small shapes
+    /// do not underflow to `0/0`.
+    fn beta(&mut self, alpha: f64, beta: f64) -> f64 {
+        let ln_x = ln_standard_gamma(self, alpha);
+        let ln_y = ln_standard_gamma(self, beta);
+        1.0 / (1.0 + (ln_y - ln_x).exp())
+    }
+
+    /// Poisson variate: multiplication method for `lambda < 10`, otherwise Hörmann's PTRS
+    /// transformed rejection (1993).
+    fn poisson(&mut self, lambda: f64) -> f64 {
+        if lambda <= 0.0 {
+            return 0.0;
+        }
+
+        if lambda < 10.0 {
+            let limit = (-lambda).exp();
+            let mut k = 0.0;
+            let mut prod = self.next_f64();
+            while prod > limit {
+                k += 1.0;
+                prod *= self.next_f64();
+            }
+            return k;
+        }
+
+        let slam = lambda.sqrt();
+        let loglam = lambda.ln();
+        let b = 0.931 + 2.53 * slam;
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let vr = 0.9277 - 3.6224 / (b - 2.0);
+
+        loop {
+            let u = self.next_f64() - 0.5;
+            let v = self.next_open_f64();
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+            if us >= 0.07 && v <= vr {
+                return k;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+            if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln()
+                <= -lambda + k * loglam - ln_gamma(k + 1.0)
+            {
+                return k;
+            }
+        }
+    }
+
+    /// Binomial variate: inversion for small means, otherwise Hörmann's BTRS transformed
+    /// rejection (1993).
+    fn binomial(&mut self, n: u64, p: f64) -> f64 {
+        if n == 0 || p <= 0.0 {
+            return 0.0;
+        }
+        if p >= 1.0 {
+            return n as f64;
+        }
+        if p > 0.5 {
+            return n as f64 - self.binomial(n, 1.0 - p);
+        }
+
+        let nf = n as f64;
+        let q = 1.0 - p;
+
+        if nf * p < 10.0 {
+            // Sequential search using pmf(k) / pmf(k-1) = ((n+1)s)/k - s with s = p/q.
+            let s = p / q;
+            let a = (nf + 1.0) * s;
+            let r0 = q.powf(nf);
+            'restart: loop {
+                let mut u = self.next_f64();
+                let mut r = r0;
+                let mut k = 0.0;
+                while u >= r {
+                    u -= r;
+                    k += 1.0;
+                    if k > nf {
+                        continue 'restart;
+                    }
+                    r *= a / k - s;
+                }
+                return k;
+            }
+        }
+
+        let spq = (nf * p * q).sqrt();
+        let b = 1.15 + 2.53 * spq;
+        let a = -0.0873 + 0.0248 * b + 0.01 * p;
+        let c = nf * p + 0.5;
+        let vr = 0.92 - 4.2 / b;
+        let alpha = (2.83 + 5.1 / b) * spq;
+        let lpq = (p / q).ln();
+        let m = ((nf + 1.0) * p).floor();
+        let h = ln_gamma(m + 1.0) + ln_gamma(nf - m + 1.0);
+
+        loop {
+            let u = self.next_f64() - 0.5;
+            let v = self.next_open_f64();
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + c).floor();
+            if k < 0.0 || k > nf {
+                continue;
+            }
+            if us >= 0.07 && v <= vr {
+                return k;
+            }
+            let v = (v * alpha / (a / (us * us) + b)).ln();
+            if v <= h - ln_gamma(k + 1.0) - ln_gamma(nf - k + 1.0) + (k - m) * lpq {
+                return k;
+            }
+        }
+    }
+}
+
+/// Internal: logarithm of a standard gamma variate without underflow for small shapes.
+fn ln_standard_gamma<R: RandomSource + ?Sized>(rng: &mut R, shape: f64) -> f64 {
+    if shape < 1.0 {
+        rng.standard_gamma(shape + 1.0).ln() + rng.next_open_f64().ln() / shape
+    } else {
+        rng.standard_gamma(shape).ln()
+    }
+}
+
+// =============================================================================
+// xoshiro256++
+// =============================================================================
+
+/// The xoshiro256++ generator (Blackman & Vigna): fast, 256 bits of state, period 2^256 - 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Create a generator whose state is expanded from `seed` with SplitMix64, as recommended
+    /// by the authors. Every seed (including 0) gives a valid, distinct stream.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut next = || {
+            sm = sm.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = sm;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            s: [next(), next(), next(), next()],
+        }
+    }
+}
+
+impl RandomSource for Xoshiro256PlusPlus {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = (s[0].wrapping_add(s[3])).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+// =============================================================================
+// Ziggurat Tables
+// =============================================================================
+
+const ZIGGURAT_LAYERS: usize = 128;
+/// Start of the tail for 128 layers.
+const ZIGGURAT_R: f64 = 3.442619855899;
+/// Area of each layer for 128 layers.
+const ZIGGURAT_V: f64 = 9.91256303526217e-3;
+
+struct Ziggurat {
+    x: [f64; ZIGGURAT_LAYERS + 1],
+    ratio: [f64; ZIGGURAT_LAYERS],
+}
+
+fn ziggurat() -> &'static Ziggurat {
+    static TABLES: OnceLock<Ziggurat> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let mut x = [0.0; ZIGGURAT_LAYERS + 1];
+        let mut f = (-0.5 * ZIGGURAT_R * ZIGGURAT_R).exp();
+        x[0] = ZIGGURAT_V / f;
+        x[1] = ZIGGURAT_R;
+        for i in 2..ZIGGURAT_LAYERS {
+            x[i] = (-2.0 * (ZIGGURAT_V / x[i - 1] + f).ln()).sqrt();
+            f = (-0.5 * x[i] * x[i]).exp();
+        }
+        x[ZIGGURAT_LAYERS] = 0.0;
+
+        let mut ratio = [0.0; ZIGGURAT_LAYERS];
+        for i in 0..ZIGGURAT_LAYERS {
+            ratio[i] = x[i + 1] / x[i];
+        }
+        Ziggurat { x, ratio }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro_reference_stream() {
+        // Reference outputs of xoshiro256++ from state [1, 2, 3, 4].
+        let mut rng = Xoshiro256PlusPlus { s: [1, 2, 3, 4] };
+        assert_eq!(rng.next_u64(), 41943041);
+        assert_eq!(rng.next_u64(), 58720359);
+        assert_eq!(rng.next_u64(), 3588806011781223);
+    }
+
+    #[test]
+    fn seeding_is_reproducible() {
+        let mut a = Xoshiro256PlusPlus::seed_from_u64(42);
+        let mut b = Xoshiro256PlusPlus::seed_from_u64(42);
+        let mut c = Xoshiro256PlusPlus::seed_from_u64(43);
+        let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let ys: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        let zs: Vec<u64> = (0..8).map(|_| c.next_u64()).collect();
+        assert_eq!(xs, ys);
+        assert_ne!(xs, zs);
+    }
+
+    #[test]
+    fn uniform_ranges() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        for _ in 0..10_000 {
+            let u = rng.next_f64();
+            assert!((0.0..1.0).contains(&u));
+            let v = rng.next_open_f64();
+            assert!(v > 0.0 && v < 1.0);
+        }
+    }
+
+    #[test]
+    fn ziggurat_tables_close_at_zero() {
+        let zig = ziggurat();
+        // The recurrence should land near zero at the top layer.
+        assert!(zig.x[ZIGGURAT_LAYERS - 1] < 0.3);
+        assert!(zig.x.windows(2).skip(1).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn standard_normal_matches_cdf() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+        let n = 200_000;
+        let draws: Vec<f64> = (0..n).map(|_| rng.standard_normal()).collect();
+        for (z, p) in [
+            (-2.5, 0.00620966532577613),
+            (-1.0, 0.15865525393145707),
+            (0.0, 0.5),
+            (1.5, 0.9331927987311419),
+            (3.5, 0.9997673709209645),
+        ] {
+            let frac = draws.iter().filter(|&&d| d <= z).count() as f64 / n as f64;
+            assert!((frac - p).abs() < 4e-3, "z={z}: {frac} vs {p}");
+        }
+    }
+
+    #[test]
+    fn discrete_variates_have_correct_means() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(11);
+        let n = 100_000;
+        for lambda in [0.5, 4.0, 25.0, 1000.0] {
+            let mean = (0..n).map(|_| rng.poisson(lambda)).sum::<f64>() / n as f64;
+            assert!(
+                (mean - lambda).abs() < 5.0 * (lambda / n as f64).sqrt(),
+                "lambda={lambda}"
+            );
+        }
+        for (trials, p) in [(10u64, 0.3), (1000, 0.2), (500, 0.9), (40, 0.01)] {
+            let expected = trials as f64 * p;
+            let sd = (expected * (1.0 - p) / n as f64).sqrt();
+            let mean = (0..n).map(|_| rng.binomial(trials, p)).sum::<f64>() / n as f64;
+            assert!((mean - expected).abs() < 5.0 * sd, "n={trials}, p={p}");
+        }
+    }
+}
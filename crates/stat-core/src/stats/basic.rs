@@ -1,5 +1,5 @@
-use crate::stats::simd::SimdAccum4;
-use wide::f64x4;
+use crate::stats::simd::{SimdAccum4, SimdAccum4F32, F32_FLUSH_BLOCK};
+use wide::{f32x8, f64x4};
 
 // =============================================================================
 // Basic Statistics
@@ -193,3 +193,117 @@ pub fn stan_moment(data: &[f64], k: usize) -> f64 {
     // Standardize by dividing by σ^k
     central_moment / sd.powi(k as i32)
 }
+
+// =============================================================================
+// f32 Variants
+// =============================================================================
+//
+// These accept `&[f32]` directly (e.g. a `Float32Array` view) and use `f32x8` lanes.
+// Lane accumulators are flushed into f64 totals every `F32_FLUSH_BLOCK` iterations, so results
+// are returned as f64 and rounding error stays bounded by a single block for long inputs.
+
+/// Calculate the sum of a slice of f32 values, accumulated in f64.
+/// Returns 0.0 for empty slices.
+pub fn sum_f32(data: &[f32]) -> f64 {
+    let mut acc = SimdAccum4F32::zero();
+    let mut total = 0.0;
+
+    simd_for_each_unrolled4_f32x8!(
+        data,
+        |base| {
+            acc.v1 += base.read_unaligned();
+            acc.v2 += base.add(1).read_unaligned();
+            acc.v3 += base.add(2).read_unaligned();
+            acc.v4 += base.add(3).read_unaligned();
+        },
+        |chunk| acc.v1 += chunk,
+        || total += acc.flush_add(),
+        |value| total += value as f64
+    );
+
+    total
+}
+
+/// Calculate the mean of a slice of f32 values.
+/// Returns NaN for empty slices.
+pub fn mean_f32(data: &[f32]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    sum_f32(data) / (data.len() as f64)
+}
+
+/// Internal: compute sum of squared deviations from mean for f32 input.
+///
+/// Deviations are taken from the f32-rounded mean `c` so the hot loop stays in f32, and the
+/// exact identity `Σ(x-m)² = Σ(x-c)² - n(m-c)²` removes the bias this introduces.
+#[inline(always)]
+pub(crate) fn sum_squared_deviations_f32(data: &[f32], m: f64) -> f64 {
+    let center = m as f32;
+    let center_vec = f32x8::splat(center);
+    let center = center as f64;
+    let mut acc = SimdAccum4F32::zero();
+    let mut total = 0.0;
+
+    simd_for_each_unrolled4_f32x8!(
+        data,
+        |base| {
+            let d1 = base.read_unaligned() - center_vec;
+            let d2 = base.add(1).read_unaligned() - center_vec;
+            let d3 = base.add(2).read_unaligned() - center_vec;
+            let d4 = base.add(3).read_unaligned() - center_vec;
+            acc.v1 += d1 * d1;
+            acc.v2 += d2 * d2;
+            acc.v3 += d3 * d3;
+            acc.v4 += d4 * d4;
+        },
+        |chunk| {
+            let diff = chunk - center_vec;
+            acc.v1 += diff * diff;
+        },
+        || total += acc.flush_add(),
+        |value| {
+            let diff = value as f64 - center;
+            total += diff * diff;
+        }
+    );
+
+    // Rounding can leave a tiny negative remainder for near-constant data; NaN passes through.
+    let shift = m - center;
+    let ss = total - data.len() as f64 * shift * shift;
+    if ss < 0.0 {
+        0.0
+    } else {
+        ss
+    }
+}
+
+/// Calculate the population variance of a slice of f32 values.
+/// Returns NaN for empty slices.
+pub fn variance_f32(data: &[f32]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    let m = mean_f32(data);
+    sum_squared_deviations_f32(data, m) / (data.len() as f64)
+}
+
+/// Calculate the sample variance of a slice of f32 values (divides by n-1).
+/// Returns NaN for slices with fewer than 2 elements.
+pub fn sample_variance_f32(data: &[f32]) -> f64 {
+    if data.len() < 2 {
+        return f64::NAN;
+    }
+    let m = mean_f32(data);
+    sum_squared_deviations_f32(data, m) / (data.len() as f64 - 1.0)
+}
+
+/// Calculate the population standard deviation of a slice of f32 values.
+pub fn stdev_f32(data: &[f32]) -> f64 {
+    variance_f32(data).sqrt()
+}
+
+/// Calculate the sample standard deviation of a slice of f32 values.
+pub fn sample_stdev_f32(data: &[f32]) -> f64 {
+    sample_variance_f32(data).sqrt()
+}
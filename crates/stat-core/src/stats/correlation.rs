@@ -1,7 +1,12 @@
-use crate::stats::basic::mean;
+use crate::stats::basic::{mean, mean_f32};
 use crate::stats::order::rank;
-use crate::stats::simd::SimdAccum4;
-use wide::f64x4;
+use crate::stats::regression::ols;
+use crate::stats::resampling::permutation_test_paired;
+use crate::stats::simd::{SimdAccum4, SimdAccum4F32, F32_FLUSH_BLOCK};
+use crate::stats::statistical_tests::{
+    normalci_with_alternative, t_p_value, z_p_value, Alternative,
+};
+use wide::{f32x8, f64x4};
 
 // =============================================================================
 // Correlation & Covariance
@@ -134,3 +139,873 @@ pub fn spearmancoeff(x: &[f64], y: &[f64]) -> f64 {
 
     corrcoeff(&x_ranks, &y_ranks)
 }
+
+/// Internal: sums over the tie groups (of sizes `t`) of one variable.
+#[derive(Debug, Default)]
+struct TieSums {
+    /// `sum t (t - 1) / 2`, the number of tied pairs.
+    pairs: f64,
+    /// `sum t (t - 1) (2t + 5)`.
+    v: f64,
+    /// `sum t (t - 1)`.
+    t1: f64,
+    /// `sum t (t - 1) (t - 2)`.
+    t2: f64,
+}
+
+impl TieSums {
+    fn add(&mut self, t: u64) {
+        let t = t as f64;
+        self.pairs += t * (t - 1.0) / 2.0;
+        self.v += t * (t - 1.0) * (2.0 * t + 5.0);
+        self.t1 += t * (t - 1.0);
+        self.t2 += t * (t - 1.0) * (t - 2.0);
+    }
+
+    /// Tie sums of an ascending sequence given as `eq(i)` = "element `i` equals `i + 1`".
+    fn from_runs(len: usize, eq: impl Fn(usize) -> bool) -> Self {
+        let mut sums = Self::default();
+        let mut run = 1u64;
+        for i in 0..len.saturating_sub(1) {
+            if eq(i) {
+                run += 1;
+            } else {
+                sums.add(run);
+                run = 1;
+            }
+        }
+        sums.add(run);
+        sums
+    }
+}
+
+/// Internal: pair counts behind Kendall's tau-b.
+struct KendallCounts {
+    n: f64,
+    /// Concordant minus discordant pairs, `S`.
+    score: f64,
+    x_ties: TieSums,
+    y_ties: TieSums,
+}
+
+impl KendallCounts {
+    /// Knight's `O(n log n)` algorithm: sort the pairs by `x` (then `y`) and count the
+    /// discordant pairs as the number of swaps a merge sort of the `y` sequence needs.
+    fn new(x: &[f64], y: &[f64]) -> Option<Self> {
+        if x.len() != y.len() || x.len() < 2 {
+            return None;
+        }
+        if x.iter().any(|v| v.is_nan()) || y.iter().any(|v| v.is_nan()) {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..x.len()).collect();
+        order.sort_unstable_by(|&a, &b| x[a].total_cmp(&x[b]).then(y[a].total_cmp(&y[b])));
+        let x_ties = TieSums::from_runs(order.len(), |i| x[order[i]] == x[order[i + 1]]);
+        let joint_ties = TieSums::from_runs(order.len(), |i| {
+            let (a, b) = (order[i], order[i + 1]);
+            x[a] == x[b] && y[a] == y[b]
+        });
+
+        let mut ys: Vec<f64> = order.iter().map(|&i| y[i]).collect();
+        let mut buffer = vec![0.0; ys.len()];
+        let discordant = merge_count_inversions(&mut ys, &mut buffer);
+        // `ys` is now sorted, so ties in y are adjacent.
+        let y_ties = TieSums::from_runs(ys.len(), |i| ys[i] == ys[i + 1]);
+
+        let n = x.len() as f64;
+        let total = n * (n - 1.0) / 2.0;
+        Some(Self {
+            n,
+            score: total - x_ties.pairs - y_ties.pairs + joint_ties.pairs - 2.0 * discordant as f64,
+            x_ties,
+            y_ties,
+        })
+    }
+
+    fn tau_b(&self) -> f64 {
+        let total = self.n * (self.n - 1.0) / 2.0;
+        if self.x_ties.pairs == total || self.y_ties.pairs == total {
+            return f64::NAN;
+        }
+        self.score / ((total - self.x_ties.pairs) * (total - self.y_ties.pairs)).sqrt()
+    }
+
+    /// Variance of `S` under independence, corrected for ties in both variables.
+    fn score_variance(&self) -> f64 {
+        let n = self.n;
+        let (x, y) = (&self.x_ties, &self.y_ties);
+        (n * (n - 1.0) * (2.0 * n + 5.0) - x.v - y.v) / 18.0
+            + x.t1 * y.t1 / (2.0 * n * (n - 1.0))
+            + x.t2 * y.t2 / (9.0 * n * (n - 1.0) * (n - 2.0))
+    }
+}
+
+/// Calculate Kendall's tau-b rank correlation coefficient between two slices.
+///
+/// Uses Knight's `O(n log n)` algorithm: sort the pairs by `x` (then `y`) and count the
+/// discordant pairs as the number of swaps a merge sort of the `y` sequence needs. Tau-b
+/// corrects for ties in either variable.
+pub fn kendallcoeff(x: &[f64], y: &[f64]) -> f64 {
+    KendallCounts::new(x, y).map_or(f64::NAN, |c| c.tau_b())
+}
+
+/// Internal: sort `values` ascending by bottom-up merge sort, returning the number of
+/// strict inversions (pairs `i < j` with `values[i] > values[j]`).
+fn merge_count_inversions(values: &mut [f64], buffer: &mut [f64]) -> u64 {
+    let len = values.len();
+    let mut inversions = 0u64;
+    let mut width = 1;
+    while width < len {
+        let mut start = 0;
+        while start < len {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut i, mut j, mut k) = (start, mid, start);
+            while i < mid && j < end {
+                if values[j] < values[i] {
+                    inversions += (mid - i) as u64;
+                    buffer[k] = values[j];
+                    j += 1;
+                } else {
+                    buffer[k] = values[i];
+                    i += 1;
+                }
+                k += 1;
+            }
+            buffer[k..k + mid - i].copy_from_slice(&values[i..mid]);
+            k += mid - i;
+            buffer[k..k + end - j].copy_from_slice(&values[j..end]);
+            start = end;
+        }
+        values.copy_from_slice(buffer);
+        width *= 2;
+    }
+    inversions
+}
+
+// =============================================================================
+// Other Correlation Measures
+// =============================================================================
+
+/// Calculate the point-biserial correlation between a dichotomous variable and a continuous
+/// one.
+///
+/// This is the Pearson correlation with `binary` coded by its two distinct values (any two
+/// values work, e.g. 0/1 or 1/2). Returns NaN unless `binary` takes exactly two values.
+pub fn pointbiserial(binary: &[f64], y: &[f64]) -> f64 {
+    let first = match binary.first() {
+        Some(&v) => v,
+        None => return f64::NAN,
+    };
+    let second = binary.iter().copied().find(|&v| v != first);
+    match second {
+        Some(second) if binary.iter().all(|&v| v == first || v == second) => corrcoeff(binary, y),
+        _ => f64::NAN,
+    }
+}
+
+/// Internal: residuals of `x` and `y` after regressing each on `controls` (with intercept).
+fn control_residuals(x: &[f64], y: &[f64], controls: &[&[f64]]) -> Option<(Vec<f64>, Vec<f64>)> {
+    let n = x.len();
+    if y.len() != n || controls.iter().any(|c| c.len() != n) {
+        return None;
+    }
+    let k = controls.len();
+    let mut design = vec![0.0; n * k];
+    for (j, c) in controls.iter().enumerate() {
+        for (i, &v) in c.iter().enumerate() {
+            design[i * k + j] = v;
+        }
+    }
+    let rx = ols(&design, k, x, true).residuals;
+    let ry = ols(&design, k, y, true).residuals;
+    Some((rx, ry))
+}
+
+/// Calculate the partial Pearson correlation of `x` and `y` controlling for `controls`.
+///
+/// The correlation of the residuals left after regressing `x` and `y` on the control columns
+/// (with an intercept). With no controls this is [`corrcoeff`].
+///
+/// Returns NaN if the lengths differ, there are too few observations for the regressions, or
+/// the controls are collinear.
+pub fn partialcorr(x: &[f64], y: &[f64], controls: &[&[f64]]) -> f64 {
+    if controls.is_empty() {
+        return corrcoeff(x, y);
+    }
+    match control_residuals(x, y, controls) {
+        Some((rx, ry)) => corrcoeff(&rx, &ry),
+        None => f64::NAN,
+    }
+}
+
+/// Internal: mean absolute difference of each value from all values, `mean_j |v_i - v_j|`.
+fn distance_row_means(values: &[f64]) -> Vec<f64> {
+    let n = values.len() as f64;
+    values
+        .iter()
+        .map(|&a| values.iter().map(|&b| (a - b).abs()).sum::<f64>() / n)
+        .collect()
+}
+
+/// Calculate the distance correlation (Székely, Rizzo & Bakirov 2007) between two slices.
+///
+/// Lies in `[0, 1]` and is zero only under independence, so unlike Pearson it detects
+/// non-monotone dependence. Double-centred distances are formed on the fly, in `O(n^2)` time
+/// and `O(n)` memory.
+///
+/// Returns NaN if the lengths differ, there are fewer than 2 values, either input contains
+/// NaN, or either input is constant.
+pub fn distancecorr(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() != y.len() || x.len() < 2 {
+        return f64::NAN;
+    }
+    if x.iter().any(|v| v.is_nan()) || y.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+
+    let (row_x, row_y) = (distance_row_means(x), distance_row_means(y));
+    let (grand_x, grand_y) = (mean(&row_x), mean(&row_y));
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for i in 0..x.len() {
+        for j in 0..x.len() {
+            let a = (x[i] - x[j]).abs() - row_x[i] - row_x[j] + grand_x;
+            let b = (y[i] - y[j]).abs() - row_y[i] - row_y[j] + grand_y;
+            sxy += a * b;
+            sxx += a * a;
+            syy += b * b;
+        }
+    }
+    if sxx <= 0.0 || syy <= 0.0 {
+        return f64::NAN;
+    }
+    (sxy.max(0.0) / (sxx * syy).sqrt()).sqrt()
+}
+
+/// Internal: Chatterjee's xi together with its asymptotic null variance (of `sqrt(n) xi`).
+fn chatterjee_xi_with_variance(x: &[f64], y: &[f64]) -> (f64, f64) {
+    let len = x.len();
+    if y.len() != len || len < 2 {
+        return (f64::NAN, f64::NAN);
+    }
+    if x.iter().any(|v| v.is_nan()) || y.iter().any(|v| v.is_nan()) {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let mut sorted_y = y.to_vec();
+    sorted_y.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    // r_i = #{j : y_j <= y_i}, l_i = #{j : y_j >= y_i}
+    let r = |v: f64| sorted_y.partition_point(|&w| w <= v) as f64;
+    let l = |v: f64| (len - sorted_y.partition_point(|&w| w < v)) as f64;
+
+    // Stable sort: ties in x keep their input order.
+    let mut order: Vec<usize> = (0..len).collect();
+    order.sort_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap());
+
+    let n = len as f64;
+    let jumps: f64 = order
+        .windows(2)
+        .map(|w| (r(y[w[1]]) - r(y[w[0]])).abs())
+        .sum();
+    let spread: f64 = y.iter().map(|&v| l(v) * (n - l(v))).sum();
+    if spread <= 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+    let xi = 1.0 - n * jumps / (2.0 * spread);
+
+    let has_ties = sorted_y.windows(2).any(|w| w[0] == w[1]);
+    if !has_ties {
+        return (xi, 0.4);
+    }
+    // General variance (Chatterjee 2021, Theorem 2.2), as computed by the XICOR package.
+    let mut u: Vec<f64> = y.iter().map(|&v| r(v) / n).collect();
+    u.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let (mut a, mut c, mut b, mut cumulative) = (0.0, 0.0, 0.0, 0.0);
+    for (i, &ui) in u.iter().enumerate() {
+        let weight = 2.0 * n - 2.0 * (i + 1) as f64 + 1.0;
+        a += weight * ui * ui;
+        c += weight * ui;
+        cumulative += ui;
+        let m = (cumulative + (n - (i + 1) as f64) * ui) / n;
+        b += m * m;
+    }
+    let (a, b, c) = (a / (n * n), b / n, c / (n * n));
+    let d = spread / (n * n * n);
+    (xi, (a - 2.0 * b + c * c) / (d * d))
+}
+
+/// Calculate Chatterjee's xi correlation coefficient of `y` on `x` (Chatterjee 2021).
+///
+/// Measures how well `y` is a function of `x`: it tends to 0 under independence and to 1
+/// when `y` is a measurable function of `x`, monotone or not. It is not symmetric in `x` and
+/// `y`. Ties in `x` are broken by input order, so the result is deterministic. Computed in
+/// `O(n log n)`.
+///
+/// Returns NaN if the lengths differ, there are fewer than 2 values, either input contains
+/// NaN, or `y` is constant.
+pub fn chatterjee_xi(x: &[f64], y: &[f64]) -> f64 {
+    chatterjee_xi_with_variance(x, y).0
+}
+
+// =============================================================================
+// Correlation Matrices
+// =============================================================================
+
+/// Correlation coefficient used by [`correlation_matrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorrelationMethod {
+    #[default]
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+/// Internal: dot products of `x` with four columns at once, loading each `x` chunk once.
+fn dot_block4(x: &[f64], ys: [&[f64]; 4]) -> [f64; 4] {
+    let len = x.len();
+    let chunks = len / 4;
+    let mut acc = SimdAccum4::zero();
+
+    unsafe {
+        let x_ptr = x.as_ptr() as *const f64x4;
+        let y_ptrs = ys.map(|y| y.as_ptr() as *const f64x4);
+        for i in 0..chunks {
+            let xv = x_ptr.add(i).read_unaligned();
+            acc.v1 += xv * y_ptrs[0].add(i).read_unaligned();
+            acc.v2 += xv * y_ptrs[1].add(i).read_unaligned();
+            acc.v3 += xv * y_ptrs[2].add(i).read_unaligned();
+            acc.v4 += xv * y_ptrs[3].add(i).read_unaligned();
+        }
+    }
+
+    let mut dots = [
+        acc.v1.reduce_add(),
+        acc.v2.reduce_add(),
+        acc.v3.reduce_add(),
+        acc.v4.reduce_add(),
+    ];
+    for i in (chunks * 4)..len {
+        for (dot, y) in dots.iter_mut().zip(ys) {
+            *dot = x[i].mul_add(y[i], *dot);
+        }
+    }
+    dots
+}
+
+/// Internal: dot product of two equally long slices.
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    let mut acc = SimdAccum4::zero();
+    let mut tail = 0.0;
+    simd_for_each_unrolled4_f64x4_zip!(
+        x,
+        y,
+        |bx, by| {
+            acc.v1 += bx.read_unaligned() * by.read_unaligned();
+            acc.v2 += bx.add(1).read_unaligned() * by.add(1).read_unaligned();
+            acc.v3 += bx.add(2).read_unaligned() * by.add(2).read_unaligned();
+            acc.v4 += bx.add(3).read_unaligned() * by.add(3).read_unaligned();
+        },
+        |xv, yv| {
+            acc.v1 += xv * yv;
+        },
+        |xs, ys| {
+            tail = xs.mul_add(ys, tail);
+        }
+    );
+    acc.reduce_add() + tail
+}
+
+/// Internal: fill the symmetric `p x p` matrix `out` with `scale * <a_i, a_j>` for every pair
+/// of prepared columns, four columns per pass over the rows.
+fn fill_gram(prepared: &[Option<Vec<f64>>], scale: f64, out: &mut [f64]) {
+    let p = prepared.len();
+    let ready: Vec<usize> = (0..p).filter(|&i| prepared[i].is_some()).collect();
+    let column = |i: usize| prepared[i].as_deref().unwrap();
+
+    for (k, &i) in ready.iter().enumerate() {
+        let rest = &ready[k..];
+        let mut blocks = rest.chunks_exact(4);
+        for block in &mut blocks {
+            let dots = dot_block4(
+                column(i),
+                [
+                    column(block[0]),
+                    column(block[1]),
+                    column(block[2]),
+                    column(block[3]),
+                ],
+            );
+            for (&j, d) in block.iter().zip(dots) {
+                out[i * p + j] = scale * d;
+                out[j * p + i] = scale * d;
+            }
+        }
+        for &j in blocks.remainder() {
+            let d = scale * dot(column(i), column(j));
+            out[i * p + j] = d;
+            out[j * p + i] = d;
+        }
+    }
+}
+
+/// Internal: copy the rows where both columns are non-NaN into the reusable buffers.
+fn gather_complete(x: &[f64], y: &[f64], xs: &mut Vec<f64>, ys: &mut Vec<f64>) {
+    xs.clear();
+    ys.clear();
+    for (&a, &b) in x.iter().zip(y) {
+        if !a.is_nan() && !b.is_nan() {
+            xs.push(a);
+            ys.push(b);
+        }
+    }
+}
+
+/// Internal: apply `pair` to the pairwise-complete rows of every column pair not already
+/// covered by the fast path (`has_nan` marks columns that need it).
+fn fill_pairwise_complete<F: Fn(&[f64], &[f64]) -> f64>(
+    columns: &[&[f64]],
+    has_nan: &[bool],
+    pair: F,
+    out: &mut [f64],
+) {
+    let p = columns.len();
+    let (mut xs, mut ys) = (Vec::new(), Vec::new());
+    for i in 0..p {
+        for j in i..p {
+            if !has_nan[i] && !has_nan[j] {
+                continue;
+            }
+            gather_complete(columns[i], columns[j], &mut xs, &mut ys);
+            let value = pair(&xs, &ys);
+            out[i * p + j] = value;
+            out[j * p + i] = value;
+        }
+    }
+}
+
+/// Internal: `true` if the columns are non-empty and of equal length.
+fn columns_are_valid(columns: &[&[f64]]) -> bool {
+    columns
+        .first()
+        .is_some_and(|c| !c.is_empty() && columns.iter().all(|d| d.len() == c.len()))
+}
+
+/// Calculate the `p x p` population covariance matrix of `p` equally long columns.
+///
+/// Returns a flat row-major matrix. Entry `(i, j)` is [`covariance`] of columns `i` and `j`
+/// over the rows where both are non-NaN (pairwise-complete observations), so the diagonal
+/// holds each column's population variance. NaN-free columns are centered once and all their
+/// pairs come from a blocked SIMD Gram product.
+///
+/// Returns an all-NaN matrix if the columns are empty or differ in length.
+pub fn covariance_matrix(columns: &[&[f64]]) -> Vec<f64> {
+    let p = columns.len();
+    let mut out = vec![f64::NAN; p * p];
+    if !columns_are_valid(columns) {
+        return out;
+    }
+    let n = columns[0].len();
+
+    let has_nan: Vec<bool> = columns
+        .iter()
+        .map(|c| c.iter().any(|v| v.is_nan()))
+        .collect();
+    let prepared: Vec<Option<Vec<f64>>> = columns
+        .iter()
+        .zip(&has_nan)
+        .map(|(c, &nan)| {
+            (!nan).then(|| {
+                let m = mean(c);
+                c.iter().map(|&v| v - m).collect()
+            })
+        })
+        .collect();
+    fill_gram(&prepared, 1.0 / n as f64, &mut out);
+    fill_pairwise_complete(columns, &has_nan, covariance, &mut out);
+    out
+}
+
+/// Calculate the `p x p` correlation matrix of `p` equally long columns.
+///
+/// Returns a flat row-major matrix. Entry `(i, j)` is the Pearson, Spearman or Kendall
+/// (tau-b) correlation of columns `i` and `j` over the rows where both are non-NaN
+/// (pairwise-complete observations, as R's `cor(use = "pairwise.complete.obs")`). Columns
+/// with zero variance give NaN rows, including on the diagonal.
+///
+/// For Pearson and Spearman, each NaN-free column is ranked (Spearman), centered and scaled
+/// to unit length once, and all pairs of such columns come from a blocked SIMD Gram product
+/// rather than `p^2` separate passes. Kendall's tau is computed pair by pair in
+/// `O(n log n)`.
+///
+/// Returns an all-NaN matrix if the columns are empty or differ in length.
+pub fn correlation_matrix(columns: &[&[f64]], method: CorrelationMethod) -> Vec<f64> {
+    let p = columns.len();
+    let mut out = vec![f64::NAN; p * p];
+    if !columns_are_valid(columns) {
+        return out;
+    }
+
+    let pair = match method {
+        CorrelationMethod::Pearson => corrcoeff,
+        CorrelationMethod::Spearman => spearmancoeff,
+        CorrelationMethod::Kendall => {
+            fill_pairwise_complete(columns, &vec![true; p], kendallcoeff, &mut out);
+            return out;
+        }
+    };
+
+    let has_nan: Vec<bool> = columns
+        .iter()
+        .map(|c| c.iter().any(|v| v.is_nan()))
+        .collect();
+    let prepared: Vec<Option<Vec<f64>>> = columns
+        .iter()
+        .zip(&has_nan)
+        .map(|(c, &nan)| {
+            if nan {
+                return None;
+            }
+            let mut values = if method == CorrelationMethod::Spearman {
+                rank(c)
+            } else {
+                c.to_vec()
+            };
+            let m = mean(&values);
+            values.iter_mut().for_each(|v| *v -= m);
+            let norm = dot(&values, &values).sqrt();
+            // A constant column has no correlation with anything; leave its row NaN.
+            (norm > 0.0).then(|| {
+                values.iter_mut().for_each(|v| *v /= norm);
+                values
+            })
+        })
+        .collect();
+    fill_gram(&prepared, 1.0, &mut out);
+    for i in (0..p).filter(|&i| prepared[i].is_some()) {
+        out[i * p + i] = 1.0;
+    }
+    fill_pairwise_complete(columns, &has_nan, pair, &mut out);
+    out
+}
+
+// =============================================================================
+// Correlation Tests
+// =============================================================================
+
+/// Result of a correlation significance test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorrelationTestResult {
+    /// Sample correlation coefficient
+    pub estimate: f64,
+    /// Test statistic: t for Pearson-type tests, z for normal approximations, or the
+    /// coefficient itself for permutation tests
+    pub statistic: f64,
+    pub p_value: f64,
+    /// Degrees of freedom of a t statistic
+    pub df: Option<f64>,
+    /// `1 - alpha` Fisher-z confidence interval for the coefficient, when one exists.
+    /// One-sided alternatives give a half-open interval ending at -1 or 1.
+    pub ci: Option<[f64; 2]>,
+}
+
+#[inline(always)]
+fn correlation_test_nan() -> CorrelationTestResult {
+    CorrelationTestResult {
+        estimate: f64::NAN,
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Internal: Fisher-z interval `tanh(atanh(r) ± z se)`.
+fn fisher_z_interval(r: f64, se: f64, alpha: f64, alternative: Alternative) -> [f64; 2] {
+    let [lo, hi] = normalci_with_alternative(alpha, r.atanh(), se, alternative);
+    [lo.tanh(), hi.tanh()]
+}
+
+/// Internal: t test of a Pearson-type coefficient with `df` degrees of freedom and a Fisher-z
+/// interval of standard error `se` (omitted when `se` is not finite).
+fn t_correlation_test(
+    r: f64,
+    df: f64,
+    se: f64,
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    if r.is_nan() || df < 1.0 {
+        return correlation_test_nan();
+    }
+    let t = r * (df / (1.0 - r * r)).sqrt();
+    CorrelationTestResult {
+        estimate: r,
+        statistic: t,
+        p_value: t_p_value(t, df, alternative),
+        df: Some(df),
+        ci: se
+            .is_finite()
+            .then(|| fisher_z_interval(r, se, alpha, alternative)),
+    }
+}
+
+/// Pearson correlation test, as R's `cor.test`.
+///
+/// The statistic is `t = r sqrt((n - 2) / (1 - r^2))` on `n - 2` degrees of freedom; the
+/// interval is the Fisher-z interval with standard error `1 / sqrt(n - 3)`, available for
+/// `n >= 4`.
+///
+/// Returns NaN for fewer than 3 observations or when [`corrcoeff`] is NaN.
+pub fn corrcoeff_test(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    let n = x.len() as f64;
+    t_correlation_test(
+        corrcoeff(x, y),
+        n - 2.0,
+        1.0 / (n - 3.0).sqrt(),
+        alternative,
+        alpha,
+    )
+}
+
+/// Spearman correlation test using the t approximation on `n - 2` degrees of freedom.
+///
+/// The Fisher-z interval uses the standard error `sqrt(1.06 / (n - 3))` of Fieller, Hartley &
+/// Pearson (1957), available for `n >= 4`.
+///
+/// Returns NaN for fewer than 3 observations or when [`spearmancoeff`] is NaN.
+pub fn spearmancoeff_test(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    let n = x.len() as f64;
+    t_correlation_test(
+        spearmancoeff(x, y),
+        n - 2.0,
+        (1.06 / (n - 3.0)).sqrt(),
+        alternative,
+        alpha,
+    )
+}
+
+/// Kendall tau-b test using the normal approximation with a tie-corrected variance of `S`
+/// (R's `cor.test(method = "kendall", exact = FALSE)`).
+///
+/// The Fisher-z interval uses the standard error `sqrt(0.437 / (n - 4))` of Fieller,
+/// Hartley & Pearson (1957), available for `n >= 5`.
+///
+/// Returns NaN for fewer than 3 observations or when [`kendallcoeff`] is NaN.
+pub fn kendallcoeff_test(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    let counts = match KendallCounts::new(x, y) {
+        Some(c) if c.n >= 3.0 => c,
+        _ => return correlation_test_nan(),
+    };
+    let tau = counts.tau_b();
+    if tau.is_nan() {
+        return correlation_test_nan();
+    }
+    let z = counts.score / counts.score_variance().sqrt();
+    let se = (0.437 / (counts.n - 4.0)).sqrt();
+    CorrelationTestResult {
+        estimate: tau,
+        statistic: z,
+        p_value: z_p_value(z, alternative),
+        df: None,
+        ci: se
+            .is_finite()
+            .then(|| fisher_z_interval(tau, se, alpha, alternative)),
+    }
+}
+
+/// Point-biserial correlation test: the Pearson test of [`pointbiserial`], equivalent to a
+/// pooled two-sample t-test between the two groups.
+pub fn pointbiserial_test(
+    binary: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    let n = binary.len() as f64;
+    t_correlation_test(
+        pointbiserial(binary, y),
+        n - 2.0,
+        1.0 / (n - 3.0).sqrt(),
+        alternative,
+        alpha,
+    )
+}
+
+/// Partial correlation test with `k` controls: `t` on `n - 2 - k` degrees of freedom and a
+/// Fisher-z interval with standard error `1 / sqrt(n - 3 - k)`.
+pub fn partialcorr_test(
+    x: &[f64],
+    y: &[f64],
+    controls: &[&[f64]],
+    alternative: Alternative,
+    alpha: f64,
+) -> CorrelationTestResult {
+    let (n, k) = (x.len() as f64, controls.len() as f64);
+    t_correlation_test(
+        partialcorr(x, y, controls),
+        n - 2.0 - k,
+        1.0 / (n - 3.0 - k).sqrt(),
+        alternative,
+        alpha,
+    )
+}
+
+/// Permutation test of independence based on [`distancecorr`].
+///
+/// The p-value is `(1 + #{dCor(perm) >= dCor}) / (permutations + 1)` over random
+/// re-pairings of `y` (see [`permutation_test_paired`]); the statistic is the observed
+/// coefficient. There is no Fisher-z interval for distance correlation, so `ci` is `None`.
+pub fn distancecorr_test(
+    x: &[f64],
+    y: &[f64],
+    permutations: usize,
+    seed: u64,
+) -> CorrelationTestResult {
+    let res = permutation_test_paired(x, y, distancecorr, Alternative::Greater, permutations, seed);
+    if res.p_value.is_nan() {
+        return correlation_test_nan();
+    }
+    CorrelationTestResult {
+        estimate: res.statistic,
+        statistic: res.statistic,
+        p_value: res.p_value,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Test of independence based on [`chatterjee_xi`], using its asymptotic normality.
+///
+/// The statistic is `z = sqrt(n) xi / tau`, with `tau^2 = 2/5` without ties in `y` and the
+/// general tie-corrected variance otherwise; large values reject independence, so the
+/// p-value is one-sided. There is no Fisher-z interval for xi, so `ci` is `None`.
+pub fn chatterjee_xi_test(x: &[f64], y: &[f64]) -> CorrelationTestResult {
+    let (xi, variance) = chatterjee_xi_with_variance(x, y);
+    if xi.is_nan() || variance.is_nan() || variance <= 0.0 {
+        return correlation_test_nan();
+    }
+    let z = (x.len() as f64).sqrt() * xi / variance.sqrt();
+    CorrelationTestResult {
+        estimate: xi,
+        statistic: z,
+        p_value: z_p_value(z, Alternative::Greater),
+        df: None,
+        ci: None,
+    }
+}
+
+// =============================================================================
+// f32 Variants
+// =============================================================================
+
+/// Internal: centered cross-product sums `(sxx, syy, sxy)` of f32 input in one `f32x8` pass.
+///
+/// Products are taken about the f32-rounded means and shifted back to the true means, so the
+/// result matches the two-pass f64 algorithm up to f32 input precision.
+fn centered_sums_f32(x: &[f32], y: &[f32]) -> (f64, f64, f64) {
+    let mean_x = mean_f32(x);
+    let mean_y = mean_f32(y);
+    let (center_x, center_y) = (mean_x as f32, mean_y as f32);
+    let mean_x_vec = f32x8::splat(center_x);
+    let mean_y_vec = f32x8::splat(center_y);
+    let (center_x, center_y) = (center_x as f64, center_y as f64);
+
+    let mut sxx_acc = SimdAccum4F32::zero();
+    let mut syy_acc = SimdAccum4F32::zero();
+    let mut sxy_acc = SimdAccum4F32::zero();
+    let (mut sum_xx, mut sum_yy, mut sum_xy) = (0.0, 0.0, 0.0);
+
+    simd_for_each_unrolled4_f32x8_zip!(
+        x,
+        y,
+        |bx, by| {
+            let x1 = bx.read_unaligned() - mean_x_vec;
+            let y1 = by.read_unaligned() - mean_y_vec;
+            let x2 = bx.add(1).read_unaligned() - mean_x_vec;
+            let y2 = by.add(1).read_unaligned() - mean_y_vec;
+            let x3 = bx.add(2).read_unaligned() - mean_x_vec;
+            let y3 = by.add(2).read_unaligned() - mean_y_vec;
+            let x4 = bx.add(3).read_unaligned() - mean_x_vec;
+            let y4 = by.add(3).read_unaligned() - mean_y_vec;
+
+            sxx_acc.v1 += x1 * x1;
+            syy_acc.v1 += y1 * y1;
+            sxy_acc.v1 += x1 * y1;
+            sxx_acc.v2 += x2 * x2;
+            syy_acc.v2 += y2 * y2;
+            sxy_acc.v2 += x2 * y2;
+            sxx_acc.v3 += x3 * x3;
+            syy_acc.v3 += y3 * y3;
+            sxy_acc.v3 += x3 * y3;
+            sxx_acc.v4 += x4 * x4;
+            syy_acc.v4 += y4 * y4;
+            sxy_acc.v4 += x4 * y4;
+        },
+        |xv, yv| {
+            let dx = xv - mean_x_vec;
+            let dy = yv - mean_y_vec;
+            sxx_acc.v1 += dx * dx;
+            syy_acc.v1 += dy * dy;
+            sxy_acc.v1 += dx * dy;
+        },
+        || {
+            sum_xx += sxx_acc.flush_add();
+            sum_yy += syy_acc.flush_add();
+            sum_xy += sxy_acc.flush_add();
+        },
+        |xs, ys| {
+            let dx = xs as f64 - center_x;
+            let dy = ys as f64 - center_y;
+            sum_xx = dx.mul_add(dx, sum_xx);
+            sum_yy = dy.mul_add(dy, sum_yy);
+            sum_xy = dx.mul_add(dy, sum_xy);
+        }
+    );
+
+    let n = x.len() as f64;
+    let (delta_x, delta_y) = (mean_x - center_x, mean_y - center_y);
+    (
+        sum_xx - n * delta_x * delta_x,
+        sum_yy - n * delta_y * delta_y,
+        sum_xy - n * delta_x * delta_y,
+    )
+}
+
+/// Calculate the population covariance between two slices of f32 values.
+pub fn covariance_f32(x: &[f32], y: &[f32]) -> f64 {
+    if x.len() != y.len() || x.is_empty() {
+        return f64::NAN;
+    }
+    let (_, _, sum_xy) = centered_sums_f32(x, y);
+    sum_xy / (x.len() as f64)
+}
+
+/// Calculate the Pearson correlation coefficient between two slices of f32 values.
+pub fn corrcoeff_f32(x: &[f32], y: &[f32]) -> f64 {
+    if x.len() != y.len() || x.is_empty() {
+        return f64::NAN;
+    }
+
+    let (sum_xx, sum_yy, sum_xy) = centered_sums_f32(x, y);
+    if sum_xx <= 0.0 || sum_yy <= 0.0 {
+        return f64::NAN;
+    }
+
+    sum_xy / (sum_xx * sum_yy).sqrt()
+}
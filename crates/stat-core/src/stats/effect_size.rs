@@ -0,0 +1,264 @@
+use crate::stats::basic::{mean, pooledvariance, sample_stdev};
+use crate::stats::order::rank_with_ties;
+use crate::stats::statistical_tests::{
+    chi_square_test_table, normalci, AnovaResult, ChiSquareMethod,
+};
+use statrs::function::gamma::ln_gamma;
+
+/// A standardized effect size with a `1 - alpha` confidence interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectSize {
+    pub estimate: f64,
+    pub ci: [f64; 2],
+}
+
+/// Proportion-of-variance effect sizes for a one-way ANOVA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnovaEffectSize {
+    /// `SS_between / SS_total`.
+    pub eta_squared: f64,
+    /// `SS_effect / (SS_effect + SS_error)`; equal to `eta_squared` for a one-way design.
+    pub partial_eta_squared: f64,
+    /// Less biased population estimate `(SS_between - df_b MS_within) / (SS_total + MS_within)`.
+    /// Can be slightly negative when the F statistic is below 1.
+    pub omega_squared: f64,
+}
+
+#[inline(always)]
+fn effect_size_nan() -> EffectSize {
+    EffectSize {
+        estimate: f64::NAN,
+        ci: [f64::NAN, f64::NAN],
+    }
+}
+
+/// Internal: `d` with a normal-approximation interval from its standard error.
+fn with_normal_ci(d: f64, se: f64, alpha: f64) -> EffectSize {
+    if d.is_nan() || se.is_nan() {
+        return effect_size_nan();
+    }
+    EffectSize {
+        estimate: d,
+        ci: normalci(alpha, d, se),
+    }
+}
+
+/// Internal: exact small-sample bias correction `J(df) = Γ(df/2) / (sqrt(df/2) Γ((df-1)/2))`.
+fn hedges_correction(df: f64) -> f64 {
+    (ln_gamma(df / 2.0) - (df / 2.0).sqrt().ln() - ln_gamma((df - 1.0) / 2.0)).exp()
+}
+
+// =============================================================================
+// Standardized Mean Differences
+// =============================================================================
+
+/// Cohen's d for two independent samples: `(mean(x) - mean(y)) / s_pooled`.
+///
+/// The interval uses the large-sample standard error
+/// `sqrt((n1 + n2) / (n1 n2) + d^2 / (2 (n1 + n2)))` (Hedges & Olkin, 1985).
+///
+/// Returns NaN if either sample has fewer than 2 observations or the pooled variance is 0.
+pub fn cohens_d(x: &[f64], y: &[f64], alpha: f64) -> EffectSize {
+    let pooled = pooledvariance(x, y);
+    if pooled.is_nan() || pooled <= 0.0 {
+        return effect_size_nan();
+    }
+    let (n1, n2) = (x.len() as f64, y.len() as f64);
+    let d = (mean(x) - mean(y)) / pooled.sqrt();
+    let se = ((n1 + n2) / (n1 * n2) + d * d / (2.0 * (n1 + n2))).sqrt();
+    with_normal_ci(d, se, alpha)
+}
+
+/// Hedges' g: Cohen's d scaled by the exact small-sample correction `J(n1 + n2 - 2)`.
+///
+/// The interval is the [`cohens_d`] interval scaled by the same factor.
+pub fn hedges_g(x: &[f64], y: &[f64], alpha: f64) -> EffectSize {
+    let d = cohens_d(x, y, alpha);
+    let j = hedges_correction((x.len() + y.len()) as f64 - 2.0);
+    EffectSize {
+        estimate: d.estimate * j,
+        ci: [d.ci[0] * j, d.ci[1] * j],
+    }
+}
+
+/// One-sample Cohen's d: `(mean - mu0) / s`, the effect size for [`ttest`](crate::ttest).
+///
+/// The interval uses the standard error `sqrt(1/n + d^2 / (2n))`.
+/// Returns NaN for fewer than 2 observations or zero variance.
+pub fn cohens_d_one_sample(data: &[f64], mu0: f64, alpha: f64) -> EffectSize {
+    let sd = sample_stdev(data);
+    if sd.is_nan() || sd <= 0.0 {
+        return effect_size_nan();
+    }
+    let n = data.len() as f64;
+    let d = (mean(data) - mu0) / sd;
+    let se = (1.0 / n + d * d / (2.0 * n)).sqrt();
+    with_normal_ci(d, se, alpha)
+}
+
+/// Paired Cohen's d (`d_z`): the one-sample d of the differences `x[i] - y[i]`.
+///
+/// Returns NaN if the samples have different lengths; see [`cohens_d_one_sample`].
+pub fn cohens_d_paired(x: &[f64], y: &[f64], alpha: f64) -> EffectSize {
+    if x.len() != y.len() {
+        return effect_size_nan();
+    }
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    cohens_d_one_sample(&diffs, 0.0, alpha)
+}
+
+// =============================================================================
+// Variance Explained
+// =============================================================================
+
+/// Partial eta squared from an F statistic: `F df_effect / (F df_effect + df_error)`.
+///
+/// Applies to any F test, e.g. a term of an [`ols`](crate::ols) model.
+pub fn partial_eta_squared(f: f64, df_effect: f64, df_error: f64) -> f64 {
+    let num = f * df_effect;
+    num / (num + df_error)
+}
+
+/// Eta squared, partial eta squared and omega squared for a one-way [`anova`](crate::anova).
+///
+/// All three follow from the F statistic and degrees of freedom, so they can be computed from
+/// an existing result without revisiting the data.
+pub fn anova_effect_size(result: &AnovaResult) -> AnovaEffectSize {
+    let f = result.f_score;
+    let df_b = result.df_between as f64;
+    let df_w = result.df_within as f64;
+    if f.is_nan() || result.df_between == 0 || result.df_within == 0 {
+        return AnovaEffectSize {
+            eta_squared: f64::NAN,
+            partial_eta_squared: f64::NAN,
+            omega_squared: f64::NAN,
+        };
+    }
+    let eta = partial_eta_squared(f, df_b, df_w);
+    AnovaEffectSize {
+        eta_squared: eta,
+        partial_eta_squared: eta,
+        omega_squared: df_b * (f - 1.0) / (df_b * f + df_w + 1.0),
+    }
+}
+
+// =============================================================================
+// Association for Contingency Tables
+// =============================================================================
+
+/// Cramér's V from a chi-square statistic: `sqrt(chi2 / (n (min(rows, cols) - 1)))`.
+///
+/// Returns NaN for fewer than two rows or columns or a non-positive `n`.
+pub fn cramers_v(chi_square: f64, n: f64, rows: usize, cols: usize) -> f64 {
+    let k = rows.min(cols);
+    if k < 2 || n <= 0.0 {
+        return f64::NAN;
+    }
+    (chi_square / (n * (k - 1) as f64)).sqrt()
+}
+
+/// Cramér's V for a row-major `rows x n_cols` contingency table.
+///
+/// Empty rows and columns are dropped, as in
+/// [`chi_square_test_table`](crate::chi_square_test_table).
+pub fn cramers_v_table(table: &[f64], n_cols: usize) -> f64 {
+    let result = chi_square_test_table(table, n_cols, ChiSquareMethod::Pearson);
+    if result.statistic.is_nan() {
+        return f64::NAN;
+    }
+    let rows = table
+        .chunks_exact(n_cols)
+        .filter(|row| row.iter().sum::<f64>() > 0.0)
+        .count();
+    let cols = (0..n_cols)
+        .filter(|&j| table.iter().skip(j).step_by(n_cols).sum::<f64>() > 0.0)
+        .count();
+    cramers_v(result.statistic, table.iter().sum(), rows, cols)
+}
+
+/// Signed phi coefficient of the 2x2 table `[a, b, c, d]`: `(ad - bc) / sqrt(r1 r2 c1 c2)`.
+///
+/// Its magnitude equals Cramér's V for a 2x2 table. Returns NaN if any margin is zero.
+pub fn phi_coefficient(table: [f64; 4]) -> f64 {
+    let [a, b, c, d] = table;
+    let denom = (a + b) * (c + d) * (a + c) * (b + d);
+    if denom <= 0.0 || denom.is_nan() {
+        return f64::NAN;
+    }
+    (a * d - b * c) / denom.sqrt()
+}
+
+// =============================================================================
+// Proportions
+// =============================================================================
+
+/// Cohen's h for two proportions: `2 asin(sqrt(p1)) - 2 asin(sqrt(p2))`.
+///
+/// The arcsine transformation stabilizes the variance, so equal values of `h` are equally
+/// detectable anywhere in `[0, 1]`. Returns NaN for proportions outside `[0, 1]`.
+pub fn cohens_h(p1: f64, p2: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p1) || !(0.0..=1.0).contains(&p2) {
+        return f64::NAN;
+    }
+    2.0 * p1.sqrt().asin() - 2.0 * p2.sqrt().asin()
+}
+
+// =============================================================================
+// Rank-Biserial Correlation
+// =============================================================================
+
+/// Rank-biserial correlation for two independent samples: `2U / (n1 n2) - 1`.
+///
+/// `U` is the [`mann_whitney_u`](crate::mann_whitney_u) statistic for `x`, so the result is
+/// `P(x > y) - P(x < y)`: positive when `x` tends to be larger, in `[-1, 1]`.
+///
+/// Returns NaN for empty samples or if any value is NaN.
+pub fn rank_biserial(x: &[f64], y: &[f64]) -> f64 {
+    let (n1, n2) = (x.len(), y.len());
+    if n1 == 0 || n2 == 0 || x.iter().chain(y).any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let combined: Vec<f64> = x.iter().chain(y).copied().collect();
+    let (ranks, _) = rank_with_ties(&combined);
+    let m = n1 as f64;
+    let u = ranks[..n1].iter().sum::<f64>() - m * (m + 1.0) / 2.0;
+    2.0 * u / (m * n2 as f64) - 1.0
+}
+
+/// Matched-pairs rank-biserial correlation about `mu0`: `(T+ - T-) / (T+ + T-)`.
+///
+/// Uses the signed ranks of [`wilcoxon_signed_rank`](crate::wilcoxon_signed_rank), with zero
+/// differences discarded. Returns NaN for empty input, NaN values, or all-zero differences.
+pub fn rank_biserial_one_sample(data: &[f64], mu0: f64) -> f64 {
+    if mu0.is_nan() || data.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let nonzero: Vec<f64> = data
+        .iter()
+        .map(|&v| v - mu0)
+        .filter(|&d| d != 0.0)
+        .collect();
+    if nonzero.is_empty() {
+        return f64::NAN;
+    }
+    let abs: Vec<f64> = nonzero.iter().map(|d| d.abs()).collect();
+    let (ranks, _) = rank_with_ties(&abs);
+    let signed: f64 = ranks
+        .iter()
+        .zip(&nonzero)
+        .map(|(&r, &d)| if d > 0.0 { r } else { -r })
+        .sum();
+    let n = nonzero.len() as f64;
+    signed / (n * (n + 1.0) / 2.0)
+}
+
+/// Matched-pairs rank-biserial correlation of the differences `x[i] - y[i]`.
+///
+/// Returns NaN if the samples have different lengths; see [`rank_biserial_one_sample`].
+pub fn rank_biserial_paired(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() != y.len() {
+        return f64::NAN;
+    }
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    rank_biserial_one_sample(&diffs, 0.0)
+}
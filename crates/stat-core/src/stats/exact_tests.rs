@@ -0,0 +1,577 @@
+use crate::distributions::{beta_inv, binomial_cdf, binomial_pmf, binomial_sf};
+use crate::random::{RandomSource, Xoshiro256PlusPlus};
+use crate::stats::statistical_tests::{Alternative, TestResult};
+use statrs::function::factorial::{ln_binomial, ln_factorial};
+use std::collections::HashMap;
+
+/// Relative tolerance used when comparing table probabilities, as in R, so that tables that
+/// are exactly as likely as the observed one are not lost to rounding.
+const REL_ERR: f64 = 1.0 + 1e-7;
+
+/// Exact r x c tests give up (returning NaN) after this many node expansions.
+const WORK_BUDGET: usize = 50_000_000;
+
+/// Result of Fisher's exact test on a 2x2 table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherExactResult {
+    /// Conditional maximum-likelihood estimate of the odds ratio.
+    pub odds_ratio: f64,
+    pub p_value: f64,
+    /// Confidence interval for the odds ratio (one-sided intervals use 0 or infinity).
+    pub ci: [f64; 2],
+}
+
+#[inline(always)]
+fn fisher_nan() -> FisherExactResult {
+    FisherExactResult {
+        odds_ratio: f64::NAN,
+        p_value: f64::NAN,
+        ci: [f64::NAN, f64::NAN],
+    }
+}
+
+#[inline(always)]
+fn exact_nan() -> TestResult {
+    TestResult {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Internal: root of a monotone `f` on the whole real line by bracket expansion and bisection.
+fn solve_monotone<F: Fn(f64) -> f64>(f: F) -> f64 {
+    let (mut lo, mut hi) = (-1.0, 1.0);
+    let increasing = f(hi) > f(lo);
+    let below = |v: f64| if increasing { v < 0.0 } else { v > 0.0 };
+    while below(f(hi)) && hi < 1e3 {
+        lo = hi;
+        hi *= 2.0;
+    }
+    while !below(f(lo)) && lo > -1e3 {
+        hi = lo;
+        lo *= 2.0;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if below(f(mid)) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        if hi - lo < 1e-12 {
+            break;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+// =============================================================================
+// Fisher's Exact Test (2x2)
+// =============================================================================
+
+/// Internal: the conditional (noncentral hypergeometric) distribution of the top-left cell.
+struct NoncentralHypergeometric {
+    lo: u64,
+    hi: u64,
+    /// Central log densities `ln dhyper(lo..=hi)`.
+    log_density: Vec<f64>,
+}
+
+impl NoncentralHypergeometric {
+    /// Margins: `m` in the first column, `n` in the second, `k` in the first row.
+    fn new(m: u64, n: u64, k: u64) -> Self {
+        let lo = k.saturating_sub(n);
+        let hi = k.min(m);
+        let log_total = ln_binomial(m + n, k);
+        let log_density = (lo..=hi)
+            .map(|x| ln_binomial(m, x) + ln_binomial(n, k - x) - log_total)
+            .collect();
+        Self {
+            lo,
+            hi,
+            log_density,
+        }
+    }
+
+    /// Probabilities of `lo..=hi` for the odds ratio `exp(log_psi)`.
+    fn density(&self, log_psi: f64) -> Vec<f64> {
+        let logs: Vec<f64> = self
+            .log_density
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| d + log_psi * (self.lo + i as u64) as f64)
+            .collect();
+        let max = logs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let weights: Vec<f64> = logs.iter().map(|&l| (l - max).exp()).collect();
+        let total: f64 = weights.iter().sum();
+        weights.into_iter().map(|w| w / total).collect()
+    }
+
+    fn mean(&self, log_psi: f64) -> f64 {
+        self.density(log_psi)
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| d * (self.lo + i as u64) as f64)
+            .sum()
+    }
+
+    /// `P(X <= x)` (or `P(X >= x)` when `upper`) for the odds ratio `exp(log_psi)`.
+    fn tail(&self, x: u64, log_psi: f64, upper: bool) -> f64 {
+        let density = self.density(log_psi);
+        let split = (x - self.lo) as usize;
+        if upper {
+            density[split..].iter().sum()
+        } else {
+            density[..=split].iter().sum()
+        }
+    }
+
+    /// Odds ratio whose lower-tail probability at `x` equals `alpha` (upper confidence bound).
+    fn upper_bound(&self, x: u64, alpha: f64) -> f64 {
+        if x == self.hi {
+            return f64::INFINITY;
+        }
+        solve_monotone(|t| self.tail(x, t, false) - alpha).exp()
+    }
+
+    /// Odds ratio whose upper-tail probability at `x` equals `alpha` (lower confidence bound).
+    fn lower_bound(&self, x: u64, alpha: f64) -> f64 {
+        if x == self.lo {
+            return 0.0;
+        }
+        solve_monotone(|t| self.tail(x, t, true) - alpha).exp()
+    }
+}
+
+/// Fisher's exact test on the 2x2 table `[a, b, c, d]` (rows `[a, b]` and `[c, d]`).
+///
+/// Conditions on all margins, so the top-left cell follows a (noncentral) hypergeometric
+/// distribution. The two-sided p-value sums the probabilities of all tables no more likely
+/// than the observed one; `Alternative::Greater` tests an odds ratio above 1. The odds ratio
+/// is the conditional maximum-likelihood estimate and the `1 - alpha` interval inverts the
+/// one-sided tests, matching R's `fisher.test`.
+///
+/// Returns NaN if any margin is zero or `alpha` is not in `(0, 1)`.
+pub fn fisher_exact(table: [u64; 4], alternative: Alternative, alpha: f64) -> FisherExactResult {
+    let [a, b, c, d] = table;
+    let (m, n, k) = (a + c, b + d, a + b);
+    if m == 0 || n == 0 || k == 0 || c + d == 0 || !(alpha > 0.0 && alpha < 1.0) {
+        return fisher_nan();
+    }
+    let dist = NoncentralHypergeometric::new(m, n, k);
+    let x = a;
+
+    let p_value = match alternative {
+        Alternative::Less => dist.tail(x, 0.0, false),
+        Alternative::Greater => dist.tail(x, 0.0, true),
+        Alternative::TwoSided => {
+            let density = dist.density(0.0);
+            let observed = density[(x - dist.lo) as usize] * REL_ERR;
+            density.iter().filter(|&&p| p <= observed).sum()
+        }
+    };
+
+    let odds_ratio = if x == dist.lo {
+        0.0
+    } else if x == dist.hi {
+        f64::INFINITY
+    } else {
+        solve_monotone(|t| dist.mean(t) - x as f64).exp()
+    };
+
+    let ci = match alternative {
+        Alternative::Less => [0.0, dist.upper_bound(x, alpha)],
+        Alternative::Greater => [dist.lower_bound(x, alpha), f64::INFINITY],
+        Alternative::TwoSided => [
+            dist.lower_bound(x, alpha / 2.0),
+            dist.upper_bound(x, alpha / 2.0),
+        ],
+    };
+
+    FisherExactResult {
+        odds_ratio,
+        p_value: p_value.min(1.0),
+        ci,
+    }
+}
+
+// =============================================================================
+// Fisher's Exact Test (r x c)
+// =============================================================================
+
+/// Internal: validated margins of a row-major count table, with empty rows/columns dropped.
+struct Margins {
+    rows: Vec<u64>,
+    cols: Vec<u64>,
+    total: u64,
+}
+
+impl Margins {
+    fn new(table: &[u64], n_cols: usize) -> Option<Self> {
+        if n_cols == 0 || table.is_empty() || !table.len().is_multiple_of(n_cols) {
+            return None;
+        }
+        let mut rows = vec![0u64; table.len() / n_cols];
+        let mut cols = vec![0u64; n_cols];
+        for (i, row) in table.chunks_exact(n_cols).enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                rows[i] += v;
+                cols[j] += v;
+            }
+        }
+        rows.retain(|&r| r > 0);
+        cols.retain(|&c| c > 0);
+        if rows.len() < 2 || cols.len() < 2 {
+            return None;
+        }
+        let total = rows.iter().sum();
+        Some(Self { rows, cols, total })
+    }
+
+    /// `ln` of the constant `Π r_i! Π c_j! / N!` in the table probability.
+    fn log_constant(&self) -> f64 {
+        self.rows.iter().map(|&r| ln_factorial(r)).sum::<f64>()
+            + self.cols.iter().map(|&c| ln_factorial(c)).sum::<f64>()
+            - ln_factorial(self.total)
+    }
+}
+
+/// Internal: `-Σ ln x_ij!` over the cells of a table (the table's log probability up to a
+/// constant).
+fn table_score(table: &[u64]) -> f64 {
+    -table.iter().map(|&x| ln_factorial(x)).sum::<f64>()
+}
+
+/// Internal: largest possible `-Σ ln x!` for one line of cells summing to `count` with
+/// capacities `caps`. The most even split maximizes the (concave) score.
+fn best_column(caps: &[u64], count: u64) -> f64 {
+    let mut sorted = caps.to_vec();
+    sorted.sort_unstable();
+    let mut remaining = count;
+    let mut score = 0.0;
+    for (i, &cap) in sorted.iter().enumerate() {
+        let share = (remaining / (sorted.len() - i) as u64).min(cap);
+        score -= ln_factorial(share);
+        remaining -= share;
+    }
+    score
+}
+
+/// Internal: smallest possible `-Σ ln x!` for one line of cells: fill the largest first.
+fn worst_column(caps: &[u64], count: u64) -> f64 {
+    let mut sorted = caps.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let mut remaining = count;
+    let mut score = 0.0;
+    for &cap in &sorted {
+        let take = cap.min(remaining);
+        score -= ln_factorial(take);
+        remaining -= take;
+    }
+    score
+}
+
+/// Internal: every way to split `count` over rows with capacities `caps`, as the sorted
+/// remaining capacities and the column's `-Σ ln x!`.
+fn column_splits(caps: &[u64], count: u64) -> Vec<(Vec<u64>, f64)> {
+    fn recurse(
+        caps: &[u64],
+        row: usize,
+        remaining: u64,
+        column: &mut Vec<u64>,
+        out: &mut Vec<(Vec<u64>, f64)>,
+    ) {
+        if row == caps.len() - 1 {
+            if remaining <= caps[row] {
+                column.push(remaining);
+                let mut rest: Vec<u64> =
+                    caps.iter().zip(column.iter()).map(|(c, x)| c - x).collect();
+                rest.sort_unstable();
+                let score = -column.iter().map(|&x| ln_factorial(x)).sum::<f64>();
+                out.push((rest, score));
+                column.pop();
+            }
+            return;
+        }
+        let capacity_after: u64 = caps[row + 1..].iter().sum();
+        for x in remaining.saturating_sub(capacity_after)..=remaining.min(caps[row]) {
+            column.push(x);
+            recurse(caps, row + 1, remaining - x, column, out);
+            column.pop();
+        }
+    }
+    let mut out = Vec::new();
+    recurse(
+        caps,
+        0,
+        count,
+        &mut Vec::with_capacity(caps.len()),
+        &mut out,
+    );
+    out
+}
+
+/// Internal: Fisher p-value by a network algorithm (Mehta & Patel, 1983).
+///
+/// Tables are built one column at a time. A node is the sorted multiset of remaining row
+/// totals, since its completions do not depend on which row is which, and the partial tables
+/// reaching it are merged by score. Bounds on the best and worst completion scores (from
+/// relaxing the row or column totals) let each group be counted in closed form or
+/// discarded instead of expanded. Returns `None` once the work budget is exhausted.
+fn network_p_value(rows: &[u64], cols: &[u64], log_constant: f64, threshold: f64) -> Option<f64> {
+    // Past scores are merged when they agree to this absolute tolerance.
+    const SCORE_GRID: f64 = 1e-9;
+    let last = cols.len() - 1;
+    let mut start = rows.to_vec();
+    start.sort_unstable();
+    let mut layer: HashMap<Vec<u64>, HashMap<i64, (f64, f64)>> = HashMap::new();
+    layer.entry(start).or_default().insert(0, (0.0, 1.0));
+
+    let mut p_value = 0.0;
+    let mut work = 0usize;
+    for col in 0..=last {
+        let mut next: HashMap<Vec<u64>, HashMap<i64, (f64, f64)>> = HashMap::new();
+        for (caps, pasts) in layer {
+            if col == last {
+                let leaf: f64 = -caps.iter().map(|&r| ln_factorial(r)).sum::<f64>();
+                for (score, weight) in pasts.into_values() {
+                    if score + leaf <= threshold {
+                        p_value += weight * (log_constant + score + leaf).exp();
+                    }
+                }
+                continue;
+            }
+
+            let remaining_cols = &cols[col..];
+            // Relaxing either the row or the column totals gives valid bounds; keep the tighter.
+            let best = f64::min(
+                remaining_cols.iter().map(|&c| best_column(&caps, c)).sum(),
+                caps.iter().map(|&r| best_column(remaining_cols, r)).sum(),
+            );
+            let worst = f64::max(
+                remaining_cols.iter().map(|&c| worst_column(&caps, c)).sum(),
+                caps.iter().map(|&r| worst_column(remaining_cols, r)).sum(),
+            );
+            // Σ Π 1/x! over all completions is N'! / (Π r'_i! Π c'_j!).
+            let mass = ln_factorial(caps.iter().sum())
+                - caps.iter().map(|&r| ln_factorial(r)).sum::<f64>()
+                - remaining_cols.iter().map(|&c| ln_factorial(c)).sum::<f64>();
+
+            let mut open = Vec::new();
+            for (score, weight) in pasts.into_values() {
+                if score + best <= threshold {
+                    p_value += weight * (log_constant + score + mass).exp();
+                } else if score + worst <= threshold {
+                    open.push((score, weight));
+                }
+            }
+            if open.is_empty() {
+                continue;
+            }
+
+            let splits = column_splits(&caps, cols[col]);
+            work += splits.len() * open.len();
+            if work > WORK_BUDGET {
+                return None;
+            }
+            for (child, cell_score) in splits {
+                let entry = next.entry(child).or_default();
+                for &(score, weight) in &open {
+                    let total = score + cell_score;
+                    let slot = entry
+                        .entry((total / SCORE_GRID).round() as i64)
+                        .or_insert((total, 0.0));
+                    slot.1 += weight;
+                }
+            }
+        }
+        layer = next;
+    }
+    Some(p_value.min(1.0))
+}
+
+/// Fisher's exact test (Fisher-Freeman-Halton) on a row-major `rows x n_cols` count table.
+///
+/// The p-value is the total probability, under independence with all margins fixed, of the
+/// tables no more likely than the observed one, computed exactly with a network algorithm.
+/// Tables too large for the work budget get a NaN p-value; use
+/// [`fisher_exact_table_simulated`] for those. The statistic is the probability of the
+/// observed table.
+///
+/// Empty rows and columns are dropped. Returns NaN for invalid shapes or fewer than two
+/// non-empty rows or columns.
+pub fn fisher_exact_table(table: &[u64], n_cols: usize) -> TestResult {
+    let margins = match Margins::new(table, n_cols) {
+        Some(m) => m,
+        None => return exact_nan(),
+    };
+    let log_constant = margins.log_constant();
+    let observed = table_score(table);
+
+    // Build the table along its longer dimension so each column has few cells, placing the
+    // largest columns first so the bounds prune early.
+    let (rows, cols) = if margins.rows.len() > margins.cols.len() {
+        (&margins.cols, &margins.rows)
+    } else {
+        (&margins.rows, &margins.cols)
+    };
+    let mut cols = cols.clone();
+    cols.sort_unstable_by(|a, b| b.cmp(a));
+    let p_value =
+        network_p_value(rows, &cols, log_constant, observed + REL_ERR.ln()).unwrap_or(f64::NAN);
+
+    TestResult {
+        statistic: (log_constant + observed).exp(),
+        p_value,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Monte Carlo version of [`fisher_exact_table`] for tables too large to enumerate.
+///
+/// Draws `replicates` random tables with the observed margins (by shuffling the pooled row
+/// labels) and reports `(1 + #{P(sim) <= P(obs)}) / (replicates + 1)`, as R's
+/// `simulate.p.value = TRUE` does. The same `seed` always gives the same p-value.
+///
+/// Returns NaN for invalid tables or zero replicates.
+pub fn fisher_exact_table_simulated(
+    table: &[u64],
+    n_cols: usize,
+    replicates: usize,
+    seed: u64,
+) -> TestResult {
+    let margins = match Margins::new(table, n_cols) {
+        Some(m) if replicates > 0 => m,
+        _ => return exact_nan(),
+    };
+    let observed = table_score(table);
+    let threshold = observed + REL_ERR.ln();
+
+    let mut labels: Vec<usize> = margins
+        .rows
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &r)| std::iter::repeat_n(i, r as usize))
+        .collect();
+    let (n_rows, n_cols) = (margins.rows.len(), margins.cols.len());
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut simulated = vec![0u64; n_rows * n_cols];
+    let mut hits = 0usize;
+
+    for _ in 0..replicates {
+        for i in (1..labels.len()).rev() {
+            let j = ((rng.next_u64() as u128 * (i as u128 + 1)) >> 64) as usize;
+            labels.swap(i, j);
+        }
+        simulated.fill(0);
+        let mut offset = 0;
+        for (j, &c) in margins.cols.iter().enumerate() {
+            for &row in &labels[offset..offset + c as usize] {
+                simulated[row * n_cols + j] += 1;
+            }
+            offset += c as usize;
+        }
+        if table_score(&simulated) <= threshold {
+            hits += 1;
+        }
+    }
+
+    TestResult {
+        statistic: (margins.log_constant() + observed).exp(),
+        p_value: (1 + hits) as f64 / (replicates + 1) as f64,
+        df: None,
+        ci: None,
+    }
+}
+
+// =============================================================================
+// Exact Binomial Test
+// =============================================================================
+
+/// Exact binomial test of `successes` out of `trials` against success probability `p`.
+///
+/// The statistic is the number of successes. The two-sided p-value sums the probabilities of
+/// all outcomes no more likely than the observed one (as R's `binom.test`), and `ci` is the
+/// Clopper-Pearson `1 - alpha` interval for the success probability.
+///
+/// Returns NaN if `trials` is 0, `successes > trials`, `p` is outside `[0, 1]`, or `alpha` is
+/// not in `(0, 1)`.
+pub fn binomial_test(
+    successes: u64,
+    trials: u64,
+    p: f64,
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if trials == 0
+        || successes > trials
+        || !(0.0..=1.0).contains(&p)
+        || !(alpha > 0.0 && alpha < 1.0)
+    {
+        return exact_nan();
+    }
+    let (x, n) = (successes as f64, trials as f64);
+    let pmf = |k: f64| binomial_pmf(k, n, p).unwrap_or(f64::NAN);
+    let cdf = |k: f64| binomial_cdf(k, n, p).unwrap_or(f64::NAN);
+    // P(X >= k), with P(X >= 0) = 1.
+    let upper = |k: f64| {
+        if k <= 0.0 {
+            1.0
+        } else {
+            binomial_sf(k - 1.0, n, p).unwrap_or(f64::NAN)
+        }
+    };
+
+    let p_value = match alternative {
+        Alternative::Less => cdf(x),
+        Alternative::Greater => upper(x),
+        Alternative::TwoSided => {
+            let d = pmf(x) * REL_ERR;
+            let expected = n * p;
+            if x == expected {
+                1.0
+            } else if x < expected {
+                let count = (expected.ceil() as u64..=trials)
+                    .filter(|&k| pmf(k as f64) <= d)
+                    .count() as f64;
+                cdf(x) + upper(n - count + 1.0)
+            } else {
+                let count = (0..=expected.floor() as u64)
+                    .filter(|&k| pmf(k as f64) <= d)
+                    .count() as f64;
+                let lower = if count > 0.0 { cdf(count - 1.0) } else { 0.0 };
+                lower + upper(x)
+            }
+        }
+    };
+
+    let lower_limit = |a: f64| {
+        if successes == 0 {
+            0.0
+        } else {
+            beta_inv(a, x, n - x + 1.0).unwrap_or(f64::NAN)
+        }
+    };
+    let upper_limit = |a: f64| {
+        if successes == trials {
+            1.0
+        } else {
+            beta_inv(1.0 - a, x + 1.0, n - x).unwrap_or(f64::NAN)
+        }
+    };
+    let ci = match alternative {
+        Alternative::Less => [0.0, upper_limit(alpha)],
+        Alternative::Greater => [lower_limit(alpha), 1.0],
+        Alternative::TwoSided => [lower_limit(alpha / 2.0), upper_limit(alpha / 2.0)],
+    };
+
+    TestResult {
+        statistic: x,
+        p_value: p_value.min(1.0),
+        df: None,
+        ci: Some(ci),
+    }
+}
@@ -0,0 +1,613 @@
+use crate::distributions::{chi_squared_sf, normal_inv, normal_sf, ContinuousDistribution, Normal};
+use crate::stats::basic::{mean, sample_stdev};
+use crate::stats::moments::{kurtosis, skewness};
+use crate::stats::statistical_tests::{Alternative, TestResult};
+use statrs::function::factorial::ln_binomial;
+
+/// Samples below this size (and without ties) use exact Kolmogorov-Smirnov distributions, as R
+/// does for the one-sample test.
+const KS_EXACT_LIMIT: usize = 100;
+
+/// Two-sample Kolmogorov-Smirnov tests use the exact distribution while `m * n` stays below this.
+const KS2_EXACT_LIMIT: usize = 10_000;
+
+#[inline(always)]
+fn gof_nan(df: Option<f64>) -> TestResult {
+    TestResult {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df,
+        ci: None,
+    }
+}
+
+/// Internal: sorted copy of `data`, or `None` if it is empty or contains NaN.
+fn sorted_finite(data: &[f64]) -> Option<Vec<f64>> {
+    if data.is_empty() || data.iter().any(|v| v.is_nan()) {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Some(sorted)
+}
+
+#[inline]
+fn has_ties(sorted: &[f64]) -> bool {
+    sorted.windows(2).any(|w| w[0] == w[1])
+}
+
+// =============================================================================
+// Kolmogorov Distributions
+// =============================================================================
+
+/// Internal: upper tail of the limiting Kolmogorov distribution, `P(K > x)`.
+///
+/// Uses the theta-function series for `x < 1` and the alternating series otherwise, so both
+/// tails converge in a handful of terms.
+fn kolmogorov_sf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    if x < 1.0 {
+        let c = -std::f64::consts::PI * std::f64::consts::PI / (8.0 * x * x);
+        let mut sum = 0.0;
+        for k in (1..200).step_by(2) {
+            let term = (c * (k * k) as f64).exp();
+            sum += term;
+            if term < 1e-17 * sum {
+                break;
+            }
+        }
+        1.0 - (2.0 * std::f64::consts::PI).sqrt() / x * sum
+    } else {
+        let mut sum = 0.0;
+        let mut sign = 1.0;
+        for k in 1..200 {
+            let term = (-2.0 * (k * k) as f64 * x * x).exp();
+            sum += sign * term;
+            if term < 1e-17 {
+                break;
+            }
+            sign = -sign;
+        }
+        (2.0 * sum).clamp(0.0, 1.0)
+    }
+}
+
+/// Internal: square matrix product `a * b` for row-major `m x m` matrices.
+fn matrix_multiply(a: &[f64], b: &[f64], m: usize) -> Vec<f64> {
+    let mut out = vec![0.0; m * m];
+    for i in 0..m {
+        for k in 0..m {
+            let aik = a[i * m + k];
+            if aik == 0.0 {
+                continue;
+            }
+            for j in 0..m {
+                out[i * m + j] += aik * b[k * m + j];
+            }
+        }
+    }
+    out
+}
+
+/// Internal: `a^n` with a decimal exponent carried separately to avoid overflow.
+fn matrix_power(a: &[f64], m: usize, n: usize) -> (Vec<f64>, i32) {
+    if n == 1 {
+        return (a.to_vec(), 0);
+    }
+    let (half, half_exp) = matrix_power(a, m, n / 2);
+    let squared = matrix_multiply(&half, &half, m);
+    let (mut v, mut exp) = if n.is_multiple_of(2) {
+        (squared, 2 * half_exp)
+    } else {
+        (matrix_multiply(a, &squared, m), 2 * half_exp)
+    };
+    if v[(m / 2) * m + m / 2] > 1e140 {
+        v.iter_mut().for_each(|x| *x *= 1e-140);
+        exp += 140;
+    }
+    (v, exp)
+}
+
+/// Internal: exact `P(D_n < d)` for the two-sided one-sample statistic.
+///
+/// Marsaglia, Tsang & Wang (2003), "Evaluating Kolmogorov's distribution".
+fn kolmogorov_exact_cdf(n: usize, d: f64) -> f64 {
+    let nf = n as f64;
+    if d <= 0.5 / nf {
+        return 0.0;
+    }
+    if d >= 1.0 {
+        return 1.0;
+    }
+    let k = (nf * d) as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nf * d;
+
+    let mut hm = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 >= j {
+                hm[i * m + j] = 1.0;
+            }
+        }
+    }
+    for i in 0..m {
+        hm[i * m] -= h.powi(i as i32 + 1);
+        hm[(m - 1) * m + i] -= h.powi((m - i) as i32);
+    }
+    if 2.0 * h - 1.0 > 0.0 {
+        hm[(m - 1) * m] += (2.0 * h - 1.0).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 > j {
+                for g in 1..=(i + 1 - j) {
+                    hm[i * m + j] /= g as f64;
+                }
+            }
+        }
+    }
+
+    let (q, mut exp) = matrix_power(&hm, m, n);
+    let mut s = q[(k - 1) * m + k - 1];
+    for i in 1..=n {
+        s = s * i as f64 / nf;
+        if s < 1e-140 {
+            s *= 1e140;
+            exp -= 140;
+        }
+    }
+    (s * 10f64.powi(exp)).clamp(0.0, 1.0)
+}
+
+/// Internal: exact `P(D+_n >= d)` for the one-sided one-sample statistic (Birnbaum & Tingey).
+fn kolmogorov_one_sided_exact_sf(n: usize, d: f64) -> f64 {
+    if d <= 0.0 {
+        return 1.0;
+    }
+    if d >= 1.0 {
+        return 0.0;
+    }
+    let nf = n as f64;
+    let upper = (nf * (1.0 - d)).floor() as u64;
+    let sum: f64 = (0..=upper)
+        .map(|j| {
+            let jf = j as f64;
+            let tail = 1.0 - d - jf / nf;
+            let log_tail = if nf - jf == 0.0 {
+                0.0
+            } else {
+                (nf - jf) * tail.ln()
+            };
+            (ln_binomial(n as u64, j) + log_tail + (jf - 1.0) * (d + jf / nf).ln()).exp()
+        })
+        .sum();
+    (d * sum).clamp(0.0, 1.0)
+}
+
+/// Internal: exact `P(D >= d)` for the two-sample statistic on `m` and `n` untied observations.
+///
+/// Counts lattice paths that stay inside the band (R's `psmirnov2x`), normalizing at each step
+/// so the recursion works directly with probabilities.
+fn smirnov_exact_sf(m: usize, n: usize, d: f64, alternative: Alternative) -> f64 {
+    let (mf, nf) = (m as f64, n as f64);
+    let q = (0.5 + (d * mf * nf - 1e-7).floor()) / (mf * nf);
+    let outside = |i: usize, j: usize| {
+        let diff = i as f64 / mf - j as f64 / nf;
+        match alternative {
+            Alternative::TwoSided => diff.abs() > q,
+            Alternative::Greater => diff > q,
+            Alternative::Less => -diff > q,
+        }
+    };
+
+    let mut u: Vec<f64> = (0..=n)
+        .map(|j| if outside(0, j) { 0.0 } else { 1.0 })
+        .collect();
+    for i in 1..=m {
+        let w = i as f64 / (i + n) as f64;
+        u[0] = if outside(i, 0) { 0.0 } else { w * u[0] };
+        for j in 1..=n {
+            u[j] = if outside(i, j) {
+                0.0
+            } else {
+                w * u[j] + u[j - 1]
+            };
+        }
+    }
+    (1.0 - u[n]).clamp(0.0, 1.0)
+}
+
+// =============================================================================
+// Kolmogorov-Smirnov Tests
+// =============================================================================
+
+/// One-sample Kolmogorov-Smirnov test of `data` against a fully specified distribution.
+///
+/// Any family from [`crate::distributions`] can be passed as `dist`. The statistic follows R:
+/// `D = sup |F_n - F|` for `TwoSided`, `D+ = sup (F_n - F)` for `Greater` (the empirical CDF
+/// lies above the null) and `D- = sup (F - F_n)` for `Less`.
+///
+/// Samples with fewer than 100 observations and no ties use exact p-values (Marsaglia-Tsang-Wang
+/// for two-sided, Birnbaum-Tingey for one-sided); otherwise the limiting Kolmogorov
+/// distribution is used.
+///
+/// Returns NaN for empty input or if any value is NaN.
+pub fn ks_test<D: ContinuousDistribution + ?Sized>(
+    data: &[f64],
+    dist: &D,
+    alternative: Alternative,
+) -> TestResult {
+    let sorted = match sorted_finite(data) {
+        Some(s) => s,
+        None => return gof_nan(None),
+    };
+    let n = sorted.len();
+    let nf = n as f64;
+
+    let (mut d_plus, mut d_minus) = (0.0f64, 0.0f64);
+    for (i, &x) in sorted.iter().enumerate() {
+        let f = dist.cdf(x);
+        d_plus = d_plus.max((i + 1) as f64 / nf - f);
+        d_minus = d_minus.max(f - i as f64 / nf);
+    }
+    let statistic = match alternative {
+        Alternative::TwoSided => d_plus.max(d_minus),
+        Alternative::Greater => d_plus,
+        Alternative::Less => d_minus,
+    };
+
+    let exact = n < KS_EXACT_LIMIT && !has_ties(&sorted);
+    let p_value = match (alternative, exact) {
+        (Alternative::TwoSided, true) => 1.0 - kolmogorov_exact_cdf(n, statistic),
+        (Alternative::TwoSided, false) => kolmogorov_sf(nf.sqrt() * statistic),
+        (_, true) => kolmogorov_one_sided_exact_sf(n, statistic),
+        (_, false) => (-2.0 * nf * statistic * statistic).exp(),
+    };
+
+    TestResult {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+        df: None,
+        ci: None,
+    }
+}
+
+/// Two-sample Kolmogorov-Smirnov test of whether `x` and `y` come from the same distribution.
+///
+/// `Greater` uses `D+ = sup (F_x - F_y)` and `Less` uses `D- = sup (F_y - F_x)`, matching R.
+/// The p-value is exact when `m * n < 10000` and the pooled sample has no ties; otherwise the
+/// asymptotic Kolmogorov (two-sided) or `exp(-2 mn/(m+n) D^2)` (one-sided) approximation is
+/// used.
+///
+/// Returns NaN for empty samples or if any value is NaN.
+pub fn ks_test_2samp(x: &[f64], y: &[f64], alternative: Alternative) -> TestResult {
+    let (xs, ys) = match (sorted_finite(x), sorted_finite(y)) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return gof_nan(None),
+    };
+    let (m, n) = (xs.len(), ys.len());
+    let (mf, nf) = (m as f64, n as f64);
+
+    // Walk the merged order statistics, evaluating both ECDFs after each distinct value.
+    let (mut i, mut j) = (0, 0);
+    let (mut d_plus, mut d_minus) = (0.0f64, 0.0f64);
+    let mut tied = false;
+    while i < m || j < n {
+        let v = match (xs.get(i), ys.get(j)) {
+            (Some(&a), Some(&b)) => a.min(b),
+            (Some(&a), None) => a,
+            (None, Some(&b)) => b,
+            (None, None) => break,
+        };
+        let (i0, j0) = (i, j);
+        while i < m && xs[i] == v {
+            i += 1;
+        }
+        while j < n && ys[j] == v {
+            j += 1;
+        }
+        tied |= i - i0 + j - j0 > 1;
+        let diff = i as f64 / mf - j as f64 / nf;
+        d_plus = d_plus.max(diff);
+        d_minus = d_minus.max(-diff);
+    }
+    let statistic = match alternative {
+        Alternative::TwoSided => d_plus.max(d_minus),
+        Alternative::Greater => d_plus,
+        Alternative::Less => d_minus,
+    };
+
+    let p_value = if m * n < KS2_EXACT_LIMIT && !tied {
+        smirnov_exact_sf(m, n, statistic, alternative)
+    } else {
+        let effective = mf * nf / (mf + nf);
+        match alternative {
+            Alternative::TwoSided => kolmogorov_sf(effective.sqrt() * statistic),
+            _ => (-2.0 * effective * statistic * statistic).exp(),
+        }
+    };
+
+    TestResult {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+        df: None,
+        ci: None,
+    }
+}
+
+// =============================================================================
+// Anderson-Darling Tests
+// =============================================================================
+
+/// Internal: Anderson-Darling `A^2` for sorted data given `ln F` and `ln (1 - F)`.
+///
+/// Working with log tails keeps the statistic finite for observations far in either tail.
+fn anderson_darling_statistic(ln_cdf: &[f64], ln_sf: &[f64]) -> f64 {
+    let n = ln_cdf.len();
+    let sum: f64 = (0..n)
+        .map(|i| (2 * i + 1) as f64 * (ln_cdf[i] + ln_sf[n - 1 - i]))
+        .sum();
+    -(n as f64) - sum / n as f64
+}
+
+/// Internal: limiting distribution of `A^2` (Marsaglia & Marsaglia, 2004).
+fn ad_inf_cdf(z: f64) -> f64 {
+    if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    }
+}
+
+/// Internal: finite-sample correction to [`ad_inf_cdf`] (Marsaglia & Marsaglia, 2004).
+fn ad_error_fix(n: f64, x: f64) -> f64 {
+    let c = 0.01265 + 0.1757 / n;
+    if x < c {
+        let t = x / c;
+        let t = t.sqrt() * (1.0 - t) * (49.0 * t - 102.0);
+        t * (0.0037 / (n * n) + 0.00078 / n + 0.00006) / n
+    } else if x < 0.8 {
+        let t = (x - c) / (0.8 - c);
+        let t =
+            -0.00022633 + (6.54034 - (14.6538 - (14.458 - (8.259 - 1.91864 * t) * t) * t) * t) * t;
+        t * (0.04213 / n + 0.01365 / (n * n))
+    } else {
+        (-130.2137
+            + (745.2337 - (1705.091 - (1950.646 - (1116.360 - 255.7844 * x) * x) * x) * x) * x)
+            / n
+    }
+}
+
+/// Anderson-Darling test of `data` against a fully specified distribution.
+///
+/// The statistic is `A^2`; the p-value uses Marsaglia & Marsaglia's (2004) approximation to
+/// the finite-sample distribution, which assumes the parameters of `dist` were not estimated
+/// from `data`. Use [`anderson_darling_normal`] to test normality with estimated parameters.
+///
+/// Returns NaN for empty input or if any value is NaN.
+pub fn anderson_darling<D: ContinuousDistribution + ?Sized>(data: &[f64], dist: &D) -> TestResult {
+    let sorted = match sorted_finite(data) {
+        Some(s) => s,
+        None => return gof_nan(None),
+    };
+    let ln_cdf: Vec<f64> = sorted.iter().map(|&x| dist.ln_cdf(x)).collect();
+    let ln_sf: Vec<f64> = sorted.iter().map(|&x| dist.ln_sf(x)).collect();
+    let statistic = anderson_darling_statistic(&ln_cdf, &ln_sf);
+
+    let p_value = if statistic.is_infinite() {
+        0.0
+    } else if statistic <= 0.0 {
+        1.0
+    } else {
+        let x = ad_inf_cdf(statistic);
+        1.0 - (x + ad_error_fix(sorted.len() as f64, x))
+    };
+
+    TestResult {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+        df: None,
+        ci: None,
+    }
+}
+
+/// Anderson-Darling test of normality with mean and standard deviation estimated from `data`.
+///
+/// The statistic is `A^2` against `N(mean, sample_stdev)`. The p-value uses D'Agostino &
+/// Stephens' (1986) modified statistic `A^2 (1 + 0.75/n + 2.25/n^2)` and their piecewise
+/// approximation, as in R's `nortest::ad.test`.
+///
+/// Returns NaN for fewer than 8 observations, any NaN value, or constant data.
+pub fn anderson_darling_normal(data: &[f64]) -> TestResult {
+    let sorted = match sorted_finite(data) {
+        Some(s) if s.len() >= 8 => s,
+        _ => return gof_nan(None),
+    };
+    let dist = match Normal::new(mean(&sorted), sample_stdev(&sorted)) {
+        Ok(d) => d,
+        Err(_) => return gof_nan(None),
+    };
+    let ln_cdf: Vec<f64> = sorted.iter().map(|&x| dist.ln_cdf(x)).collect();
+    let ln_sf: Vec<f64> = sorted.iter().map(|&x| dist.ln_sf(x)).collect();
+    let statistic = anderson_darling_statistic(&ln_cdf, &ln_sf);
+
+    let n = sorted.len() as f64;
+    let aa = statistic * (1.0 + 0.75 / n + 2.25 / (n * n));
+    let p_value = if aa < 0.2 {
+        1.0 - (-13.436 + 101.14 * aa - 223.73 * aa * aa).exp()
+    } else if aa < 0.34 {
+        1.0 - (-8.318 + 42.796 * aa - 59.938 * aa * aa).exp()
+    } else if aa < 0.6 {
+        (0.9177 - 4.279 * aa - 1.38 * aa * aa).exp()
+    } else if aa < 10.0 {
+        (1.2937 - 5.709 * aa + 0.0186 * aa * aa).exp()
+    } else {
+        // The quadratic turns back up past AA ~ 153; nortest floors the p-value here instead.
+        3.7e-24
+    };
+
+    TestResult {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+        df: None,
+        ci: None,
+    }
+}
+
+// =============================================================================
+// Normality Tests
+// =============================================================================
+
+/// Internal: `c[0] + c[1] x + c[2] x^2 + ...`.
+#[inline]
+fn poly(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0.0, |acc, &ci| acc * x + ci)
+}
+
+/// Shapiro-Wilk test of normality using Royston's (1995) algorithm AS R94.
+///
+/// The statistic is `W`, the squared correlation between the sorted data and Royston's
+/// approximation to the normal-order-statistic coefficients. The p-value is exact for `n = 3`
+/// and otherwise comes from Royston's normalizing transformations of `W`.
+///
+/// Returns NaN for fewer than 3 or more than 5000 observations, any NaN value, or constant
+/// data.
+pub fn shapiro_wilk(data: &[f64]) -> TestResult {
+    let x = match sorted_finite(data) {
+        Some(s) if (3..=5000).contains(&s.len()) => s,
+        _ => return gof_nan(None),
+    };
+    let n = x.len();
+    let nf = n as f64;
+    let range = x[n - 1] - x[0];
+    if range < 1e-19 * x[0].abs().max(1.0) {
+        return gof_nan(None);
+    }
+
+    // Coefficients for the lower half; the upper half is the mirror image with opposite sign.
+    let half = n / 2;
+    let mut a = vec![0.0; half];
+    if n == 3 {
+        a[0] = std::f64::consts::FRAC_1_SQRT_2;
+    } else {
+        const C1: [f64; 6] = [0.0, 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+        const C2: [f64; 6] = [0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+        let m: Vec<f64> = (1..=half)
+            .map(|i| normal_inv((i as f64 - 0.375) / (nf + 0.25), 0.0, 1.0).unwrap_or(f64::NAN))
+            .collect();
+        let summ2 = 2.0 * m.iter().map(|v| v * v).sum::<f64>();
+        let ssumm2 = summ2.sqrt();
+        let rsn = 1.0 / nf.sqrt();
+        let a1 = poly(&C1, rsn) - m[0] / ssumm2;
+
+        let (first, fac) = if n > 5 {
+            let a2 = -m[1] / ssumm2 + poly(&C2, rsn);
+            a[1] = a2;
+            let fac = ((summ2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1])
+                / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2))
+                .sqrt();
+            (2, fac)
+        } else {
+            (
+                1,
+                ((summ2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt(),
+            )
+        };
+        a[0] = a1;
+        for i in first..half {
+            a[i] = -m[i] / fac;
+        }
+    }
+
+    // W as the squared correlation between the data and the antisymmetric coefficients.
+    let coef = |i: usize| {
+        if i < half {
+            -a[i]
+        } else if n - 1 - i < half {
+            a[n - 1 - i]
+        } else {
+            0.0
+        }
+    };
+    let scaled: Vec<f64> = x.iter().map(|v| v / range).collect();
+    let x_mean = mean(&scaled);
+    let (mut saa, mut sxx, mut sax) = (0.0, 0.0, 0.0);
+    for (i, &v) in scaled.iter().enumerate() {
+        let (ai, xi) = (coef(i), v - x_mean);
+        saa += ai * ai;
+        sxx += xi * xi;
+        sax += ai * xi;
+    }
+    let w = (sax * sax / (saa * sxx)).min(1.0);
+
+    let p_value = if n == 3 {
+        let pw = 6.0 / std::f64::consts::PI * (w.sqrt().asin() - std::f64::consts::PI / 3.0);
+        pw.max(0.0)
+    } else {
+        let w1 = (1.0 - w).ln();
+        let (y, m, s) = if n <= 11 {
+            let gamma = -2.273 + 0.459 * nf;
+            if w1 >= gamma {
+                return TestResult {
+                    statistic: w,
+                    p_value: 1e-99,
+                    df: None,
+                    ci: None,
+                };
+            }
+            const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+            const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+            (-(gamma - w1).ln(), poly(&C3, nf), poly(&C4, nf).exp())
+        } else {
+            const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+            const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+            let ln_n = nf.ln();
+            (w1, poly(&C5, ln_n), poly(&C6, ln_n).exp())
+        };
+        normal_sf(y, m, s).unwrap_or(f64::NAN)
+    };
+
+    TestResult {
+        statistic: w,
+        p_value: p_value.clamp(0.0, 1.0),
+        df: None,
+        ci: None,
+    }
+}
+
+/// Jarque-Bera test of normality from the sample skewness and excess kurtosis.
+///
+/// `JB = n/6 (S^2 + K^2/4)` with the population [`skewness`] and [`kurtosis`], compared with a
+/// chi-square distribution with 2 degrees of freedom. The approximation is asymptotic and
+/// conservative for small samples.
+///
+/// Returns NaN for fewer than 4 observations, any NaN value, or constant data.
+pub fn jarque_bera(data: &[f64]) -> TestResult {
+    let df = Some(2.0);
+    if data.len() < 4 || data.iter().any(|v| v.is_nan()) {
+        return gof_nan(df);
+    }
+    let s = skewness(data);
+    let k = kurtosis(data);
+    if s.is_nan() || k.is_nan() {
+        return gof_nan(df);
+    }
+    let statistic = data.len() as f64 / 6.0 * (s * s + k * k / 4.0);
+    let p_value = chi_squared_sf(statistic, 2.0).unwrap_or(f64::NAN);
+
+    TestResult {
+        statistic,
+        p_value,
+        df,
+        ci: None,
+    }
+}
@@ -16,11 +16,24 @@ use crate::stats::order::{iqr, quantiles, quartiles};
 /// # Returns
 /// Vector of counts per bin.
 pub fn histogram(data: &[f64], bin_count: usize) -> Vec<usize> {
-    let bin_count = if bin_count == 0 { 4 } else { bin_count };
+    histogram_values(data.iter().copied(), bin_count)
+}
 
-    if data.is_empty() {
-        return vec![0; bin_count];
-    }
+/// Calculate a histogram with automatic bin width for f32 input.
+///
+/// Values are widened to f64 as they are read, so the counts match [`histogram`] on the
+/// widened data without allocating a copy.
+pub fn histogram_f32(data: &[f32], bin_count: usize) -> Vec<usize> {
+    histogram_values(data.iter().map(|&v| v as f64), bin_count)
+}
+
+/// Internal: two-pass automatic-width histogram over any re-iterable sequence of values.
+#[inline(always)]
+fn histogram_values<I>(data: I, bin_count: usize) -> Vec<usize>
+where
+    I: Iterator<Item = f64> + Clone,
+{
+    let bin_count = if bin_count == 0 { 4 } else { bin_count };
 
     // First pass: find min/max over finite values only.
     // NaN/±Inf are treated as out-of-domain for binning and skipped.
@@ -28,7 +41,7 @@ pub fn histogram(data: &[f64], bin_count: usize) -> Vec<usize> {
     let mut min_val = f64::INFINITY;
     let mut max_val = f64::NEG_INFINITY;
     let mut finite_count = 0usize;
-    for &v in data {
+    for v in data.clone() {
         if !v.is_finite() {
             continue;
         }
@@ -61,7 +74,7 @@ pub fn histogram(data: &[f64], bin_count: usize) -> Vec<usize> {
     // This loop can't be easily SIMD'd due to random writes to bins array
     unsafe {
         let bins_ptr = bins.as_mut_ptr();
-        for &value in data {
+        for value in data {
             if !value.is_finite() {
                 continue;
             }
@@ -96,6 +109,19 @@ fn edges_valid(edges: &[f64]) -> bool {
 /// # Returns
 /// Vector of counts per bin. Values outside the range are not counted.
 pub fn histogram_edges(data: &[f64], edges: &[f64]) -> Vec<usize> {
+    histogram_edges_values(data.iter().copied(), edges)
+}
+
+/// Calculate a histogram of f32 input with custom (f64) bin edges.
+///
+/// See [`histogram_edges`]; values are widened to f64 as they are read.
+pub fn histogram_edges_f32(data: &[f32], edges: &[f64]) -> Vec<usize> {
+    histogram_edges_values(data.iter().map(|&v| v as f64), edges)
+}
+
+/// Internal: custom-edge histogram over any sequence of values.
+#[inline(always)]
+fn histogram_edges_values(data: impl Iterator<Item = f64>, edges: &[f64]) -> Vec<usize> {
     if edges.len() < 2 {
         return vec![];
     }
@@ -106,7 +132,7 @@ pub fn histogram_edges(data: &[f64], edges: &[f64]) -> Vec<usize> {
     let num_bins = edges.len() - 1;
     let mut bins = vec![0usize; num_bins];
 
-    for &value in data {
+    for value in data {
         if value.is_nan() {
             continue;
         }
@@ -1,5 +1,5 @@
-use crate::stats::simd::SimdAccum4;
-use wide::f64x4;
+use crate::stats::simd::{SimdAccum4, SimdAccum4F32, F32_FLUSH_BLOCK};
+use wide::{f32x8, f64x4};
 
 // =============================================================================
 // Min / Max / Range
@@ -127,3 +127,75 @@ pub fn range(data: &[f64]) -> f64 {
         max_val - min_val
     }
 }
+
+// =============================================================================
+// f32 Variants
+// =============================================================================
+
+/// Calculate min and max of f32 input in a single `f32x8` pass.
+pub(crate) fn minmax_f32(data: &[f32]) -> (f64, f64) {
+    if data.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let mut min_acc = SimdAccum4F32::new(f32x8::splat(f32::INFINITY));
+    let mut max_acc = SimdAccum4F32::new(f32x8::splat(f32::NEG_INFINITY));
+    let mut min_tail = f32::INFINITY;
+    let mut max_tail = f32::NEG_INFINITY;
+
+    simd_for_each_unrolled4_f32x8!(
+        data,
+        |base| {
+            let c1 = base.read_unaligned();
+            let c2 = base.add(1).read_unaligned();
+            let c3 = base.add(2).read_unaligned();
+            let c4 = base.add(3).read_unaligned();
+
+            min_acc.v1 = min_acc.v1.min(c1);
+            min_acc.v2 = min_acc.v2.min(c2);
+            min_acc.v3 = min_acc.v3.min(c3);
+            min_acc.v4 = min_acc.v4.min(c4);
+
+            max_acc.v1 = max_acc.v1.max(c1);
+            max_acc.v2 = max_acc.v2.max(c2);
+            max_acc.v3 = max_acc.v3.max(c3);
+            max_acc.v4 = max_acc.v4.max(c4);
+        },
+        |chunk| {
+            min_acc.v1 = min_acc.v1.min(chunk);
+            max_acc.v1 = max_acc.v1.max(chunk);
+        },
+        || {},
+        |value| {
+            if value < min_tail {
+                min_tail = value;
+            }
+            if value > max_tail {
+                max_tail = value;
+            }
+        }
+    );
+
+    let min_val = min_acc.reduce_min().min(min_tail);
+    let max_val = max_acc.reduce_max().max(max_tail);
+    (min_val as f64, max_val as f64)
+}
+
+/// Calculate the minimum value in a slice of f32 values.
+/// Returns NaN for empty slices.
+pub fn min_f32(data: &[f32]) -> f64 {
+    minmax_f32(data).0
+}
+
+/// Calculate the maximum value in a slice of f32 values.
+/// Returns NaN for empty slices.
+pub fn max_f32(data: &[f32]) -> f64 {
+    minmax_f32(data).1
+}
+
+/// Calculate the range (max - min) of a slice of f32 values.
+/// Returns NaN for empty slices.
+pub fn range_f32(data: &[f32]) -> f64 {
+    let (min_val, max_val) = minmax_f32(data);
+    max_val - min_val
+}
@@ -9,6 +9,11 @@
 //! - If you want **explicit NaN rejection** (fail-fast) for statistical tests, use the provided
 //!   `*_strict` variants in `statistical_tests`.
 //!
+//! ## f32 input
+//! The descriptive statistics have `*_f32` variants (e.g. `mean_f32`, `corrcoeff_f32`) that take
+//! `&[f32]` directly and use `f32x8` SIMD. Lane accumulators are flushed into f64 in blocks and
+//! the results are returned as `f64`.
+//!
 //! This module is performance-oriented: hot paths avoid extra passes unless needed for numerical
 //! stability (e.g. centered correlation).
 
@@ -18,24 +23,46 @@ mod simd;
 
 mod basic;
 mod correlation;
+mod effect_size;
+mod exact_tests;
+mod goodness_of_fit;
 mod histogram;
+mod kde;
 mod minmax;
 mod moments;
+mod multiple_testing;
+mod nonparametric;
 mod order;
+mod power;
 mod regression;
+mod resampling;
+mod robust;
+mod running;
 mod statistical_tests;
 mod transform;
+mod weighted;
 
 // Re-export all public items to maintain the same public API
 pub use basic::*;
 pub use correlation::*;
+pub use effect_size::*;
+pub use exact_tests::*;
+pub use goodness_of_fit::*;
 pub use histogram::*;
+pub use kde::*;
 pub use minmax::*;
 pub use moments::*;
+pub use multiple_testing::*;
+pub use nonparametric::*;
 pub use order::*;
+pub use power::*;
 pub use regression::*;
+pub use resampling::*;
+pub use robust::*;
+pub use running::*;
 pub use statistical_tests::*;
 pub use transform::*;
+pub use weighted::*;
 
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,6 @@
-use crate::stats::basic::mean;
-use crate::stats::simd::SimdAccum4;
-use wide::f64x4;
+use crate::stats::basic::{mean, mean_f32};
+use crate::stats::simd::{SimdAccum4, SimdAccum4F32, F32_FLUSH_BLOCK};
+use wide::{f32x8, f64x4};
 
 // =============================================================================
 // Higher Moments: Skewness & Kurtosis
@@ -149,3 +149,110 @@ pub fn kurtosis(data: &[f64]) -> f64 {
     // Return excess kurtosis (subtract 3 from raw kurtosis)
     m4 / (m2 * m2) - 3.0
 }
+
+// =============================================================================
+// f32 Variants
+// =============================================================================
+
+/// Internal: population central moments `(m2, m3, m4)` of f32 input in one `f32x8` pass.
+///
+/// Powers are taken about the f32-rounded mean `c` and shifted back to the true mean with the
+/// binomial identities for `δ = mean - c`.
+fn central_moments_f32(data: &[f32]) -> (f64, f64, f64) {
+    let mean_val = mean_f32(data);
+    let center = mean_val as f32;
+    let center_vec = f32x8::splat(center);
+    let center = center as f64;
+
+    let mut s2_acc = SimdAccum4F32::zero();
+    let mut s3_acc = SimdAccum4F32::zero();
+    let mut s4_acc = SimdAccum4F32::zero();
+    let (mut s2, mut s3, mut s4) = (0.0, 0.0, 0.0);
+
+    simd_for_each_unrolled4_f32x8!(
+        data,
+        |base| {
+            let d1 = base.read_unaligned() - center_vec;
+            let d2 = base.add(1).read_unaligned() - center_vec;
+            let d3 = base.add(2).read_unaligned() - center_vec;
+            let d4 = base.add(3).read_unaligned() - center_vec;
+
+            let d1_sq = d1 * d1;
+            let d2_sq = d2 * d2;
+            let d3_sq = d3 * d3;
+            let d4_sq = d4 * d4;
+
+            s2_acc.v1 += d1_sq;
+            s3_acc.v1 += d1_sq * d1;
+            s4_acc.v1 += d1_sq * d1_sq;
+            s2_acc.v2 += d2_sq;
+            s3_acc.v2 += d2_sq * d2;
+            s4_acc.v2 += d2_sq * d2_sq;
+            s2_acc.v3 += d3_sq;
+            s3_acc.v3 += d3_sq * d3;
+            s4_acc.v3 += d3_sq * d3_sq;
+            s2_acc.v4 += d4_sq;
+            s3_acc.v4 += d4_sq * d4;
+            s4_acc.v4 += d4_sq * d4_sq;
+        },
+        |chunk| {
+            let diff = chunk - center_vec;
+            let diff_sq = diff * diff;
+            s2_acc.v1 += diff_sq;
+            s3_acc.v1 += diff_sq * diff;
+            s4_acc.v1 += diff_sq * diff_sq;
+        },
+        || {
+            s2 += s2_acc.flush_add();
+            s3 += s3_acc.flush_add();
+            s4 += s4_acc.flush_add();
+        },
+        |value| {
+            let diff = value as f64 - center;
+            let diff_sq = diff * diff;
+            s2 += diff_sq;
+            s3 += diff_sq * diff;
+            s4 += diff_sq * diff_sq;
+        }
+    );
+
+    let n = data.len() as f64;
+    let (s2, s3, s4) = (s2 / n, s3 / n, s4 / n);
+    let delta = mean_val - center;
+    let delta_sq = delta * delta;
+
+    let m2 = s2 - delta_sq;
+    let m3 = s3 - 3.0 * delta * s2 + 2.0 * delta_sq * delta;
+    let m4 = s4 - 4.0 * delta * s3 + 6.0 * delta_sq * s2 - 3.0 * delta_sq * delta_sq;
+    (m2, m3, m4)
+}
+
+/// Calculate the skewness of a slice of f32 values (requires at least 3 elements).
+/// Uses population formula: m3 / m2^(3/2).
+pub fn skewness_f32(data: &[f32]) -> f64 {
+    if data.len() < 3 || data.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+
+    let (m2, m3, _) = central_moments_f32(data);
+    if m2 <= 0.0 {
+        return f64::NAN;
+    }
+
+    m3 / m2.sqrt().powi(3)
+}
+
+/// Calculate the excess kurtosis of a slice of f32 values (requires at least 4 elements).
+/// Uses population formula: m4 / m2^2 - 3.
+pub fn kurtosis_f32(data: &[f32]) -> f64 {
+    if data.len() < 4 || data.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+
+    let (m2, _, m4) = central_moments_f32(data);
+    if m2 <= 0.0 {
+        return f64::NAN;
+    }
+
+    m4 / (m2 * m2) - 3.0
+}
@@ -0,0 +1,195 @@
+// =============================================================================
+// Multiple Testing
+// =============================================================================
+
+/// Adjustment method for a family of p-values (the methods of R's `p.adjust`).
+///
+/// - `Bonferroni`, `Holm`, `Hochberg` and `Hommel` control the family-wise error rate.
+///   Holm is uniformly more powerful than Bonferroni and valid under any dependence;
+///   Hochberg and Hommel are more powerful still but assume independence or positive
+///   dependence.
+/// - `BenjaminiHochberg` controls the false discovery rate under independence or positive
+///   dependence; `BenjaminiYekutieli` controls it under arbitrary dependence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PAdjustMethod {
+    Bonferroni,
+    #[default]
+    Holm,
+    Hochberg,
+    Hommel,
+    BenjaminiHochberg,
+    BenjaminiYekutieli,
+}
+
+/// Storey q-values and the estimated proportion of true null hypotheses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QValueResult {
+    /// q-value of each input p-value, in input order
+    pub q_values: Vec<f64>,
+    /// Estimated proportion of true nulls, `pi0`
+    pub pi0: f64,
+}
+
+/// Internal: indices of the valid p-values (within `[0, 1]`), sorted by ascending p-value.
+fn valid_order(p_values: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..p_values.len())
+        .filter(|&i| (0.0..=1.0).contains(&p_values[i]))
+        .collect();
+    order.sort_unstable_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+    order
+}
+
+/// Internal: step-up adjustment `min_{j >= i} min(1, factor(j) * p_(j))` over the sorted
+/// p-values, written back in input order. `factor` receives the 1-based rank.
+fn step_up<F: Fn(usize) -> f64>(p_values: &[f64], order: &[usize], factor: F) -> Vec<f64> {
+    let mut adjusted = vec![f64::NAN; p_values.len()];
+    let mut running = 1.0f64;
+    for (rank, &i) in order.iter().enumerate().rev() {
+        running = running.min(factor(rank + 1) * p_values[i]);
+        adjusted[i] = running;
+    }
+    adjusted
+}
+
+/// Internal: step-down adjustment `max_{j <= i} min(1, factor(j) * p_(j))`.
+fn step_down<F: Fn(usize) -> f64>(p_values: &[f64], order: &[usize], factor: F) -> Vec<f64> {
+    let mut adjusted = vec![f64::NAN; p_values.len()];
+    let mut running = 0.0f64;
+    for (rank, &i) in order.iter().enumerate() {
+        running = running.max((factor(rank + 1) * p_values[i]).min(1.0));
+        adjusted[i] = running;
+    }
+    adjusted
+}
+
+/// Internal: Hommel adjustment of ascending p-values (the algorithm of R's `p.adjust`).
+///
+/// For each subset size `m` from `n - 1` down to 2, the Simes critical value of the largest
+/// `m - 1` p-values caps the adjustments. This is `O(n^2)`, unlike the other methods.
+fn hommel_sorted(sorted: &[f64]) -> Vec<f64> {
+    let n = sorted.len();
+    let simes = |p: &[f64], m: usize| {
+        p.iter()
+            .enumerate()
+            .map(|(j, &v)| m as f64 * v / (j + 1) as f64)
+            .fold(f64::INFINITY, f64::min)
+    };
+    let start = simes(sorted, n);
+    let mut adjusted = vec![start; n];
+    let mut q = vec![start; n];
+    for m in (2..n).rev() {
+        // Minimum of m p_(k) / (k - n + m) over the largest m - 1 p-values.
+        let q1 = sorted[n - m + 1..]
+            .iter()
+            .enumerate()
+            .map(|(j, &v)| m as f64 * v / (j + 2) as f64)
+            .fold(f64::INFINITY, f64::min);
+        for (qi, &p) in q[..=n - m].iter_mut().zip(sorted) {
+            *qi = (m as f64 * p).min(q1);
+        }
+        let tail = q[n - m];
+        q[n - m + 1..].fill(tail);
+        for (a, &b) in adjusted.iter_mut().zip(&q) {
+            *a = a.max(b);
+        }
+    }
+    adjusted
+        .iter()
+        .zip(sorted)
+        .map(|(&a, &p)| a.max(p).min(1.0))
+        .collect()
+}
+
+/// Adjust p-values for multiple comparisons.
+///
+/// Entries that are NaN or outside `[0, 1]` are returned as NaN and do not count towards the
+/// number of tests, like `NA` in R's `p.adjust`. All methods except `Hommel` run in
+/// `O(n log n)` (one sort and one pass); Hommel is `O(n^2)`.
+///
+/// # Arguments
+/// * `p_values` - Unadjusted p-values, in any order
+/// * `method` - Adjustment method
+///
+/// # Returns
+/// Adjusted p-values in input order, capped at 1.
+pub fn p_adjust(p_values: &[f64], method: PAdjustMethod) -> Vec<f64> {
+    let order = valid_order(p_values);
+    let m = order.len() as f64;
+    match method {
+        PAdjustMethod::Bonferroni => p_values
+            .iter()
+            .map(|&p| {
+                if (0.0..=1.0).contains(&p) {
+                    (m * p).min(1.0)
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect(),
+        PAdjustMethod::Holm => step_down(p_values, &order, |rank| m - rank as f64 + 1.0),
+        PAdjustMethod::Hochberg => step_up(p_values, &order, |rank| m - rank as f64 + 1.0),
+        PAdjustMethod::BenjaminiHochberg => step_up(p_values, &order, |rank| m / rank as f64),
+        PAdjustMethod::BenjaminiYekutieli => {
+            let harmonic: f64 = (1..=order.len()).map(|k| 1.0 / k as f64).sum();
+            step_up(p_values, &order, |rank| harmonic * m / rank as f64)
+        }
+        PAdjustMethod::Hommel => {
+            let sorted: Vec<f64> = order.iter().map(|&i| p_values[i]).collect();
+            let mut adjusted = vec![f64::NAN; p_values.len()];
+            for (&i, a) in order.iter().zip(hommel_sorted(&sorted)) {
+                adjusted[i] = a;
+            }
+            adjusted
+        }
+    }
+}
+
+/// Storey's estimate of the proportion of true null hypotheses.
+///
+/// `pi0 = #{p > lambda} / (m (1 - lambda))`, capped at 1, where `m` counts the valid
+/// p-values. `lambda = 0.5` is the usual choice; `lambda = 0` gives `pi0 = 1` (the
+/// Benjamini-Hochberg case).
+///
+/// Returns NaN if no p-value lies in `[0, 1]` or `lambda` is outside `[0, 1)`.
+pub fn storey_pi0(p_values: &[f64], lambda: f64) -> f64 {
+    if !(0.0..1.0).contains(&lambda) {
+        return f64::NAN;
+    }
+    let (mut m, mut above) = (0usize, 0usize);
+    for &p in p_values {
+        if (0.0..=1.0).contains(&p) {
+            m += 1;
+            if p > lambda {
+                above += 1;
+            }
+        }
+    }
+    if m == 0 {
+        return f64::NAN;
+    }
+    (above as f64 / (m as f64 * (1.0 - lambda))).min(1.0)
+}
+
+/// Storey q-values: Benjamini-Hochberg adjusted p-values scaled by the estimated proportion of
+/// true nulls, `q_(i) = min_{j >= i} pi0 m p_(j) / j`.
+///
+/// The q-value of a test is the smallest positive false discovery rate at which it is called
+/// significant. `pi0` is estimated with [`storey_pi0`] at the given `lambda`; if it is zero
+/// (no p-value above `lambda`) it is raised to `1 / m`. Invalid entries are handled as in
+/// [`p_adjust`]; an invalid `lambda` gives all-NaN q-values.
+pub fn q_values(p_values: &[f64], lambda: f64) -> QValueResult {
+    let order = valid_order(p_values);
+    let m = order.len() as f64;
+    let pi0 = storey_pi0(p_values, lambda);
+    if pi0.is_nan() {
+        return QValueResult {
+            q_values: vec![f64::NAN; p_values.len()],
+            pi0,
+        };
+    }
+    let pi0 = pi0.max(1.0 / m);
+    QValueResult {
+        q_values: step_up(p_values, &order, |rank| pi0 * m / rank as f64),
+        pi0,
+    }
+}
@@ -0,0 +1,257 @@
+use crate::stats::order::rank_with_ties;
+use crate::stats::statistical_tests::{z_p_value, Alternative, TestResult};
+
+/// Samples below this size (and without ties) use exact null distributions, as R does.
+const EXACT_LIMIT: usize = 50;
+
+#[inline(always)]
+fn rank_test_nan() -> TestResult {
+    TestResult {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df: None,
+        ci: None,
+    }
+}
+
+// =============================================================================
+// Exact Null Distributions
+// =============================================================================
+
+/// Internal: counts of the Mann-Whitney statistic `U = 0..=m*n` under H0.
+///
+/// These are the coefficients of the Gaussian binomial `[m+n choose m]_q`, built with the
+/// q-Pascal rule `[t, k] = q^k [t-1, k] + [t-1, k-1]` so only additions are involved.
+fn mann_whitney_counts(m: usize, n: usize) -> Vec<f64> {
+    let mut counts: Vec<Vec<f64>> = (0..=m)
+        .map(|k| if k == 0 { vec![1.0] } else { Vec::new() })
+        .collect();
+
+    for t in 1..=(m + n) {
+        for k in (1..=m.min(t)).rev() {
+            let mut next = vec![0.0; k * (t - k) + 1];
+            for (u, &c) in counts[k].iter().enumerate() {
+                next[u + k] += c;
+            }
+            for (u, &c) in counts[k - 1].iter().enumerate() {
+                next[u] += c;
+            }
+            counts[k] = next;
+        }
+    }
+
+    counts.swap_remove(m)
+}
+
+/// Internal: counts of the signed-rank statistic `V = 0..=n(n+1)/2` under H0.
+fn signed_rank_counts(n: usize) -> Vec<f64> {
+    let mut counts = vec![0.0; n * (n + 1) / 2 + 1];
+    counts[0] = 1.0;
+    let mut max_sum = 0;
+    for i in 1..=n {
+        max_sum += i;
+        for s in (i..=max_sum).rev() {
+            counts[s] += counts[s - i];
+        }
+    }
+    counts
+}
+
+/// Internal: exact p-value of an integer statistic from its null counts.
+///
+/// `Less` uses `P(S <= s)`, `Greater` uses `P(S >= s)`, and the two-sided p-value doubles
+/// the smaller tail.
+fn exact_p_value(counts: &[f64], statistic: f64, alternative: Alternative) -> f64 {
+    let s = statistic.round() as usize;
+    let total: f64 = counts.iter().sum();
+    let lower: f64 = counts[..=s].iter().sum::<f64>() / total;
+    let upper: f64 = counts[s..].iter().sum::<f64>() / total;
+    match alternative {
+        Alternative::TwoSided => (2.0 * lower.min(upper)).min(1.0),
+        Alternative::Less => lower,
+        Alternative::Greater => upper,
+    }
+}
+
+/// Internal: normal-approximation p-value with a continuity correction of 0.5.
+fn corrected_normal_p_value(
+    statistic: f64,
+    expected: f64,
+    sigma: f64,
+    alternative: Alternative,
+) -> f64 {
+    if sigma <= 0.0 || sigma.is_nan() {
+        return f64::NAN;
+    }
+    let diff = statistic - expected;
+    let correction = match alternative {
+        Alternative::TwoSided if diff > 0.0 => 0.5,
+        Alternative::TwoSided if diff < 0.0 => -0.5,
+        Alternative::TwoSided => 0.0,
+        Alternative::Less => -0.5,
+        Alternative::Greater => 0.5,
+    };
+    z_p_value((diff - correction) / sigma, alternative)
+}
+
+// =============================================================================
+// Rank Tests
+// =============================================================================
+
+/// Mann-Whitney U test (Wilcoxon rank-sum test) for two independent samples.
+///
+/// The statistic is `U` for `x`: the number of pairs `(x_i, y_j)` with `x_i > y_j`, ties
+/// counting one half (R's `W`). `Alternative::Greater` tests whether `x` tends to be larger
+/// than `y`.
+///
+/// When both samples have fewer than 50 observations and there are no ties, the p-value comes
+/// from the exact null distribution of `U`. Otherwise a normal approximation with continuity
+/// correction and tie-corrected variance is used.
+///
+/// Returns NaN for empty samples or if any value is NaN.
+pub fn mann_whitney_u(x: &[f64], y: &[f64], alternative: Alternative) -> TestResult {
+    let (n1, n2) = (x.len(), y.len());
+    if n1 == 0 || n2 == 0 || x.iter().chain(y).any(|v| v.is_nan()) {
+        return rank_test_nan();
+    }
+
+    let combined: Vec<f64> = x.iter().chain(y).copied().collect();
+    let (ranks, ties) = rank_with_ties(&combined);
+    let (m, n) = (n1 as f64, n2 as f64);
+    let rank_sum: f64 = ranks[..n1].iter().sum();
+    let u = rank_sum - m * (m + 1.0) / 2.0;
+
+    let p_value = if n1 < EXACT_LIMIT && n2 < EXACT_LIMIT && ties == 0.0 {
+        exact_p_value(&mann_whitney_counts(n1, n2), u, alternative)
+    } else {
+        let total = m + n;
+        let sigma = (m * n / 12.0 * ((total + 1.0) - ties / (total * (total - 1.0)))).sqrt();
+        corrected_normal_p_value(u, m * n / 2.0, sigma, alternative)
+    };
+
+    TestResult {
+        statistic: u,
+        p_value,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Wilcoxon signed-rank test of whether `data` is symmetric about `mu0`.
+///
+/// Zero differences `data[i] - mu0` are discarded and the remaining absolute differences are
+/// ranked with averaged ties. The statistic `V` is the sum of ranks of the positive
+/// differences (R's `V`); `Alternative::Greater` tests whether the location exceeds `mu0`.
+///
+/// The exact null distribution is used when fewer than 50 non-zero differences remain and there
+/// are neither ties nor zeros; otherwise a normal approximation with continuity correction
+/// and tie-corrected variance is used.
+///
+/// Returns NaN for empty input, if any value is NaN, or if all differences are zero.
+pub fn wilcoxon_signed_rank(data: &[f64], mu0: f64, alternative: Alternative) -> TestResult {
+    if data.is_empty() || mu0.is_nan() || data.iter().any(|v| v.is_nan()) {
+        return rank_test_nan();
+    }
+    let diffs: Vec<f64> = data.iter().map(|&v| v - mu0).collect();
+    signed_rank_from_differences(&diffs, alternative)
+}
+
+/// Paired Wilcoxon signed-rank test on the differences `x[i] - y[i]`.
+///
+/// See [`wilcoxon_signed_rank`] for the statistic and p-value conventions.
+/// Returns NaN if the samples are empty, have different lengths, or contain NaN.
+pub fn wilcoxon_signed_rank_paired(x: &[f64], y: &[f64], alternative: Alternative) -> TestResult {
+    if x.is_empty() || x.len() != y.len() || x.iter().chain(y).any(|v| v.is_nan()) {
+        return rank_test_nan();
+    }
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(&a, &b)| a - b).collect();
+    signed_rank_from_differences(&diffs, alternative)
+}
+
+/// Internal: signed-rank statistic and p-value for a set of differences.
+fn signed_rank_from_differences(diffs: &[f64], alternative: Alternative) -> TestResult {
+    let nonzero: Vec<f64> = diffs.iter().copied().filter(|&d| d != 0.0).collect();
+    let has_zeros = nonzero.len() != diffs.len();
+    let n_usize = nonzero.len();
+    if n_usize == 0 {
+        return rank_test_nan();
+    }
+
+    let abs: Vec<f64> = nonzero.iter().map(|d| d.abs()).collect();
+    let (ranks, ties) = rank_with_ties(&abs);
+    let v: f64 = ranks
+        .iter()
+        .zip(&nonzero)
+        .filter(|(_, &d)| d > 0.0)
+        .map(|(&r, _)| r)
+        .sum();
+
+    let n = n_usize as f64;
+    let p_value = if n_usize < EXACT_LIMIT && ties == 0.0 && !has_zeros {
+        exact_p_value(&signed_rank_counts(n_usize), v, alternative)
+    } else {
+        let sigma = (n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - ties / 48.0).sqrt();
+        corrected_normal_p_value(v, n * (n + 1.0) / 4.0, sigma, alternative)
+    };
+
+    TestResult {
+        statistic: v,
+        p_value,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Kruskal-Wallis H test for equality of distributions across `k` independent groups.
+///
+/// The statistic is corrected for ties and compared with a chi-square distribution with
+/// `k - 1` degrees of freedom, which is the rank-based counterpart of [`anova`](crate::anova).
+///
+/// Returns NaN for fewer than two groups, any empty group, any NaN value, or when all
+/// observations are tied.
+pub fn kruskal_wallis(groups: &[&[f64]]) -> TestResult {
+    let k = groups.len();
+    if k < 2
+        || groups
+            .iter()
+            .any(|g| g.is_empty() || g.iter().any(|v| v.is_nan()))
+    {
+        return rank_test_nan();
+    }
+
+    let combined: Vec<f64> = groups.iter().flat_map(|g| g.iter().copied()).collect();
+    let total = combined.len() as f64;
+    let df = (k - 1) as f64;
+    let (ranks, ties) = rank_with_ties(&combined);
+
+    let mut offset = 0;
+    let mut weighted_sq = 0.0;
+    for group in groups {
+        let rank_sum: f64 = ranks[offset..offset + group.len()].iter().sum();
+        weighted_sq += rank_sum * rank_sum / group.len() as f64;
+        offset += group.len();
+    }
+
+    let correction = 1.0 - ties / (total * total * total - total);
+    if correction <= 0.0 {
+        return TestResult {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+            df: Some(df),
+            ci: None,
+        };
+    }
+    let h = (12.0 / (total * (total + 1.0)) * weighted_sq - 3.0 * (total + 1.0)) / correction;
+
+    let p_value = match crate::distributions::chi_squared_cdf(h, df) {
+        Ok(cdf) => 1.0 - cdf,
+        Err(_) => f64::NAN,
+    };
+
+    TestResult {
+        statistic: h,
+        p_value,
+        df: Some(df),
+        ci: None,
+    }
+}
@@ -12,7 +12,21 @@ pub fn median(data: &[f64]) -> f64 {
         return f64::NAN;
     }
 
-    let mut values = data.to_vec();
+    median_in_place(&mut data.to_vec())
+}
+
+/// Internal: the `k`-th smallest (0-based) of NaN-free `values`, by selection.
+///
+/// Reorders `values` so that everything before index `k` is `<=` the result and everything
+/// after is `>=` it.
+pub(crate) fn select_kth(values: &mut [f64], k: usize) -> f64 {
+    *values
+        .select_nth_unstable_by(k, |a, b| a.partial_cmp(b).unwrap())
+        .1
+}
+
+/// Internal: median of non-empty, NaN-free `values` by selection, reordering them.
+pub(crate) fn median_in_place(values: &mut [f64]) -> f64 {
     let len = values.len();
     let mid = len / 2;
 
@@ -143,6 +157,20 @@ pub fn percentile_exclusive(data: &[f64], k: f64) -> f64 {
     percentile(data, k, true)
 }
 
+/// Internal: R7 (linear interpolation) quantile of ascending, NaN-free values, for callers
+/// that already hold sorted data.
+///
+/// Returns NaN for empty input or `p` outside `[0, 1]`.
+pub(crate) fn sorted_quantile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    let h = p * (sorted.len() - 1) as f64;
+    let lo = h.floor() as usize;
+    let hi = (lo + 1).min(sorted.len() - 1);
+    sorted[lo] + (h - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
 /// Calculate multiple quantiles at once (more efficient than calling percentile multiple times).
 ///
 /// # Arguments
@@ -440,15 +468,22 @@ pub fn qtest(data: &[f64], score: f64, q_lower: f64, q_upper: f64) -> bool {
 
 /// Calculate the ranks of elements, averaging ties.
 pub fn rank(data: &[f64]) -> Vec<f64> {
+    rank_with_ties(data).0
+}
+
+/// Internal: average ranks together with the tie term `sum(t^3 - t)` over tie groups,
+/// as needed by the tie corrections of rank-based tests.
+pub(crate) fn rank_with_ties(data: &[f64]) -> (Vec<f64>, f64) {
     let len = data.len();
     if len == 0 {
-        return Vec::new();
+        return (Vec::new(), 0.0);
     }
 
     let mut indexed: Vec<(usize, f64)> = data.iter().copied().enumerate().collect();
     indexed.sort_by(|a, b| a.1.total_cmp(&b.1));
 
     let mut ranks = vec![0.0; len];
+    let mut ties = 0.0;
     let mut i = 0;
 
     while i < len {
@@ -461,8 +496,10 @@ pub fn rank(data: &[f64]) -> Vec<f64> {
         for k in i..j {
             ranks[indexed[k].0] = avg_rank;
         }
+        let t = (j - i) as f64;
+        ties += t * t * t - t;
         i = j;
     }
 
-    ranks
+    (ranks, ties)
 }
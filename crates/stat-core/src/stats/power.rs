@@ -0,0 +1,294 @@
+use crate::distributions::{
+    chi_squared_isf, fisher_f_isf, noncentral_chi_squared_sf, noncentral_f_sf, noncentral_t_cdf,
+    noncentral_t_sf, normal_cdf, normal_isf, student_t_isf,
+};
+use crate::stats::effect_size::cohens_h;
+use crate::stats::statistical_tests::Alternative;
+
+/// Sample-size searches give up (returning NaN) beyond this many observations.
+const MAX_SAMPLE_SIZE: f64 = 1e9;
+
+/// Design of a t-test for [`power_t_test`] and [`sample_size_t_test`].
+///
+/// - `OneSample`: `n` observations, effect size `(mean - mu0) / sd`.
+/// - `TwoSample`: `n` observations per group, effect size Cohen's d with pooled sd.
+/// - `Paired`: `n` pairs, effect size `d_z` (mean difference / sd of differences).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TTestDesign {
+    #[default]
+    OneSample,
+    TwoSample,
+    Paired,
+}
+
+#[inline]
+fn valid_alpha(alpha: f64) -> bool {
+    alpha > 0.0 && alpha < 1.0
+}
+
+/// Internal: power of a test whose statistic is normal with mean `shift` and unit variance.
+fn normal_power(shift: f64, alpha: f64, alternative: Alternative) -> f64 {
+    let phi = |x: f64| normal_cdf(x, 0.0, 1.0).unwrap_or(f64::NAN);
+    let crit = |a: f64| normal_isf(a, 0.0, 1.0).unwrap_or(f64::NAN);
+    match alternative {
+        Alternative::TwoSided => {
+            let z = crit(alpha / 2.0);
+            phi(shift - z) + phi(-shift - z)
+        }
+        Alternative::Greater => phi(shift - crit(alpha)),
+        Alternative::Less => phi(-shift - crit(alpha)),
+    }
+}
+
+/// Internal: smallest integer `n >= n_min` with `power(n) >= target`, by doubling then bisection.
+///
+/// Returns NaN if `target` is not a probability above `alpha`, if the effect is not finite, or
+/// if it cannot reach the target (`effect == 0`, or an effect against a one-sided alternative).
+fn smallest_n<F: Fn(f64) -> f64>(
+    power: F,
+    n_min: f64,
+    target: f64,
+    alpha: f64,
+    effect: f64,
+    alternative: Alternative,
+) -> f64 {
+    let wrong_side = match alternative {
+        Alternative::TwoSided => effect == 0.0,
+        Alternative::Greater => effect <= 0.0,
+        Alternative::Less => effect >= 0.0,
+    };
+    if wrong_side || !effect.is_finite() || !(target > alpha && target < 1.0) {
+        return f64::NAN;
+    }
+    if power(n_min) >= target {
+        return n_min;
+    }
+    let (mut lo, mut hi) = (n_min, 2.0 * n_min);
+    while power(hi) < target {
+        if hi > MAX_SAMPLE_SIZE {
+            return f64::NAN;
+        }
+        lo = hi;
+        hi *= 2.0;
+    }
+    while hi - lo > 1.0 {
+        let mid = ((lo + hi) / 2.0).floor();
+        if power(mid) >= target {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+// =============================================================================
+// Z-Test
+// =============================================================================
+
+/// Power of a one-sample z-test with standardized effect `(mean - mu0) / sigma` and `n`
+/// observations.
+///
+/// Returns NaN for `n < 1` or `alpha` outside `(0, 1)`.
+pub fn power_z_test(effect_size: f64, n: f64, alpha: f64, alternative: Alternative) -> f64 {
+    if n < 1.0 || !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    normal_power(effect_size * n.sqrt(), alpha, alternative)
+}
+
+/// Smallest `n` for which [`power_z_test`] reaches `power`.
+///
+/// Returns NaN if the target cannot be reached, e.g. a zero effect or an effect against a
+/// one-sided alternative.
+pub fn sample_size_z_test(
+    effect_size: f64,
+    power: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> f64 {
+    if !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    smallest_n(
+        |n| power_z_test(effect_size, n, alpha, alternative),
+        1.0,
+        power,
+        alpha,
+        effect_size,
+        alternative,
+    )
+}
+
+// =============================================================================
+// T-Tests
+// =============================================================================
+
+/// Power of a t-test from the noncentral t distribution.
+///
+/// `n` is the sample size (one-sample), the number of pairs (paired) or the size of each group
+/// (two-sample). The two-sided power counts rejections in both tails.
+///
+/// Returns NaN for `n < 2` or `alpha` outside `(0, 1)`.
+pub fn power_t_test(
+    effect_size: f64,
+    n: f64,
+    alpha: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> f64 {
+    if n < 2.0 || !valid_alpha(alpha) || !effect_size.is_finite() {
+        return f64::NAN;
+    }
+    let (df, ncp) = match design {
+        TTestDesign::OneSample | TTestDesign::Paired => (n - 1.0, effect_size * n.sqrt()),
+        TTestDesign::TwoSample => (2.0 * n - 2.0, effect_size * (n / 2.0).sqrt()),
+    };
+    let crit = |a: f64| student_t_isf(a, 0.0, 1.0, df).unwrap_or(f64::NAN);
+    let upper = |t: f64| noncentral_t_sf(t, df, ncp).unwrap_or(f64::NAN);
+    let lower = |t: f64| noncentral_t_cdf(t, df, ncp).unwrap_or(f64::NAN);
+    match alternative {
+        Alternative::TwoSided => {
+            let t = crit(alpha / 2.0);
+            upper(t) + lower(-t)
+        }
+        Alternative::Greater => upper(crit(alpha)),
+        Alternative::Less => lower(-crit(alpha)),
+    }
+}
+
+/// Smallest `n` (per group for two-sample designs) for which [`power_t_test`] reaches `power`.
+///
+/// Returns NaN if the target cannot be reached; see [`sample_size_z_test`].
+pub fn sample_size_t_test(
+    effect_size: f64,
+    power: f64,
+    alpha: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> f64 {
+    if !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    smallest_n(
+        |n| power_t_test(effect_size, n, alpha, design, alternative),
+        2.0,
+        power,
+        alpha,
+        effect_size,
+        alternative,
+    )
+}
+
+// =============================================================================
+// One-Way ANOVA
+// =============================================================================
+
+/// Power of a balanced one-way ANOVA with Cohen's `f` effect size, `groups` groups and `n`
+/// observations per group.
+///
+/// The F statistic follows a noncentral F with noncentrality `groups * n * f^2`.
+/// Returns NaN for fewer than 2 groups, `n < 2` or `alpha` outside `(0, 1)`.
+pub fn power_anova(effect_size: f64, groups: usize, n: f64, alpha: f64) -> f64 {
+    if groups < 2 || n < 2.0 || !valid_alpha(alpha) || !effect_size.is_finite() {
+        return f64::NAN;
+    }
+    let k = groups as f64;
+    let (df1, df2) = (k - 1.0, k * (n - 1.0));
+    let crit = fisher_f_isf(alpha, df1, df2).unwrap_or(f64::NAN);
+    noncentral_f_sf(crit, df1, df2, k * n * effect_size * effect_size).unwrap_or(f64::NAN)
+}
+
+/// Smallest per-group `n` for which [`power_anova`] reaches `power`.
+///
+/// Cohen's `f` relates to eta squared by `f^2 = eta^2 / (1 - eta^2)`.
+pub fn sample_size_anova(effect_size: f64, groups: usize, power: f64, alpha: f64) -> f64 {
+    if groups < 2 || !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    smallest_n(
+        |n| power_anova(effect_size, groups, n, alpha),
+        2.0,
+        power,
+        alpha,
+        effect_size.abs(),
+        Alternative::TwoSided,
+    )
+}
+
+// =============================================================================
+// Chi-Square Tests
+// =============================================================================
+
+/// Power of a chi-square test with Cohen's `w` effect size, `n` total observations and `df`
+/// degrees of freedom.
+///
+/// The statistic follows a noncentral chi-squared with noncentrality `n * w^2`. For a
+/// contingency table `w` equals Cramér's V times `sqrt(min(rows, cols) - 1)`.
+/// Returns NaN for `n < 1`, `df <= 0` or `alpha` outside `(0, 1)`.
+pub fn power_chi_square(effect_size: f64, n: f64, df: f64, alpha: f64) -> f64 {
+    if n < 1.0 || df.is_nan() || df <= 0.0 || !valid_alpha(alpha) || !effect_size.is_finite() {
+        return f64::NAN;
+    }
+    let crit = chi_squared_isf(alpha, df).unwrap_or(f64::NAN);
+    noncentral_chi_squared_sf(crit, df, n * effect_size * effect_size).unwrap_or(f64::NAN)
+}
+
+/// Smallest total `n` for which [`power_chi_square`] reaches `power`.
+pub fn sample_size_chi_square(effect_size: f64, df: f64, power: f64, alpha: f64) -> f64 {
+    if df.is_nan() || df <= 0.0 || !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    smallest_n(
+        |n| power_chi_square(effect_size, n, df, alpha),
+        1.0,
+        power,
+        alpha,
+        effect_size.abs(),
+        Alternative::TwoSided,
+    )
+}
+
+// =============================================================================
+// Two Proportions
+// =============================================================================
+
+/// Power of a two-sample test of proportions with `n` observations per group.
+///
+/// Uses the arcsine transformation: with Cohen's [`h`](cohens_h) the statistic is approximately
+/// normal with mean `h sqrt(n / 2)`. `Greater` means `p1 > p2`.
+/// Returns NaN for proportions outside `[0, 1]`, `n < 1` or `alpha` outside `(0, 1)`.
+pub fn power_two_proportions(
+    p1: f64,
+    p2: f64,
+    n: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> f64 {
+    let h = cohens_h(p1, p2);
+    if h.is_nan() || n < 1.0 || !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    normal_power(h * (n / 2.0).sqrt(), alpha, alternative)
+}
+
+/// Smallest per-group `n` for which [`power_two_proportions`] reaches `power`.
+pub fn sample_size_two_proportions(
+    p1: f64,
+    p2: f64,
+    power: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> f64 {
+    if !valid_alpha(alpha) {
+        return f64::NAN;
+    }
+    smallest_n(
+        |n| power_two_proportions(p1, p2, n, alpha, alternative),
+        1.0,
+        power,
+        alpha,
+        cohens_h(p1, p2),
+        alternative,
+    )
+}
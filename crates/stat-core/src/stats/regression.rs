@@ -1,5 +1,6 @@
-use crate::stats::basic::sum;
+use crate::stats::basic::{sum, sum_squared_deviations};
 use crate::stats::simd::SimdAccum4;
+use crate::stats::statistical_tests::{t_interval, t_p_value, Alternative};
 use core::mem::size_of_val;
 #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
 use wide::f32x4;
@@ -659,22 +660,70 @@ pub fn regress_naive(x: &[f64], y: &[f64]) -> RegressionResult {
     }
 }
 
-/// SIMD-optimized linear regression (fused sums, single-pass for statistics).
-/// Uses the same SIMD pattern as corrcoeff for maximum efficiency.
-pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
-    if x.len() != y.len() || x.len() < 2 {
-        return regression_invalid();
+/// Internal: raw sums needed for a simple linear regression, from one fused pass.
+///
+/// The sums are of `x - shift_x` and `y - shift_y`; shifting by a data point keeps the
+/// centered sums accurate when the data sit far from zero (e.g. timestamps).
+#[derive(Debug, Clone, Copy)]
+struct RegressionSums {
+    n: f64,
+    shift_x: f64,
+    shift_y: f64,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xx: f64,
+    sum_yy: f64,
+    sum_xy: f64,
+}
+
+impl RegressionSums {
+    /// `n * Sxx`, `n * Syy` and `n * Sxy` (centered sums scaled by n).
+    #[inline(always)]
+    fn scaled_centered(&self) -> (f64, f64, f64) {
+        let denom_x = self.n * self.sum_xx - self.sum_x * self.sum_x;
+        let denom_y = self.n * self.sum_yy - self.sum_y * self.sum_y;
+        let numerator = self.n * self.sum_xy - self.sum_x * self.sum_y;
+        (denom_x, denom_y, numerator)
+    }
+
+    fn coeffs(&self) -> RegressionCoeffs {
+        // Compute slope and intercept using closed-form OLS
+        let (denom_x, denom_y, numerator) = self.scaled_centered();
+        if denom_x <= 0.0 || denom_x.is_nan() {
+            return regression_invalid();
+        }
+
+        let slope = numerator / denom_x;
+        let intercept =
+            (self.sum_y - slope * self.sum_x) / self.n + self.shift_y - slope * self.shift_x;
+
+        // Compute R² from the same sums (no need for corrcoeff)
+        let r_squared = if denom_y > 0.0 && !denom_y.is_nan() {
+            (numerator * numerator) / (denom_x * denom_y)
+        } else {
+            f64::NAN
+        };
+
+        RegressionCoeffs {
+            slope,
+            intercept,
+            r_squared,
+        }
     }
+}
 
+/// Internal: single-pass SIMD accumulation of all regression sums of `x - shift_x` and
+/// `y - shift_y`.
+#[inline(always)]
+fn regression_sums(x: &[f64], y: &[f64], shift_x: f64, shift_y: f64) -> RegressionSums {
     #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
     {
-        return regress_simd_coeffs_wasm128(x, y);
+        return regression_sums_wasm128(x, y, shift_x, shift_y);
     }
 
     #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
     {
         let len = x.len();
-        let n = len as f64;
         let chunks = len / 4;
         let unrolled = chunks / 4;
 
@@ -684,6 +733,8 @@ pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
         let mut sxx = SimdAccum4::zero();
         let mut syy = SimdAccum4::zero();
         let mut sxy = SimdAccum4::zero();
+        let shift_xv = f64x4::splat(shift_x);
+        let shift_yv = f64x4::splat(shift_y);
 
         unsafe {
             let x_ptr = x.as_ptr() as *const f64x4;
@@ -693,14 +744,14 @@ pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
                 let bx = x_ptr.add(i * 4);
                 let by = y_ptr.add(i * 4);
 
-                let x1 = bx.read_unaligned();
-                let y1 = by.read_unaligned();
-                let x2 = bx.add(1).read_unaligned();
-                let y2 = by.add(1).read_unaligned();
-                let x3 = bx.add(2).read_unaligned();
-                let y3 = by.add(2).read_unaligned();
-                let x4 = bx.add(3).read_unaligned();
-                let y4 = by.add(3).read_unaligned();
+                let x1 = bx.read_unaligned() - shift_xv;
+                let y1 = by.read_unaligned() - shift_yv;
+                let x2 = bx.add(1).read_unaligned() - shift_xv;
+                let y2 = by.add(1).read_unaligned() - shift_yv;
+                let x3 = bx.add(2).read_unaligned() - shift_xv;
+                let y3 = by.add(2).read_unaligned() - shift_yv;
+                let x4 = bx.add(3).read_unaligned() - shift_xv;
+                let y4 = by.add(3).read_unaligned() - shift_yv;
 
                 sx.v1 += x1;
                 sy.v1 += y1;
@@ -728,8 +779,8 @@ pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
             }
 
             for i in (unrolled * 4)..chunks {
-                let xv = x_ptr.add(i).read_unaligned();
-                let yv = y_ptr.add(i).read_unaligned();
+                let xv = x_ptr.add(i).read_unaligned() - shift_xv;
+                let yv = y_ptr.add(i).read_unaligned() - shift_yv;
                 sx.v1 += xv;
                 sy.v1 += yv;
                 sxx.v1 += xv * xv;
@@ -746,8 +797,8 @@ pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
 
         // Handle remaining elements
         for i in (chunks * 4)..len {
-            let xi = x[i];
-            let yi = y[i];
+            let xi = x[i] - shift_x;
+            let yi = y[i] - shift_y;
             sum_x += xi;
             sum_y += yi;
             sum_xx = xi.mul_add(xi, sum_xx);
@@ -755,38 +806,23 @@ pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
             sum_xy = xi.mul_add(yi, sum_xy);
         }
 
-        // Compute slope and intercept using closed-form OLS
-        let numerator = n * sum_xy - sum_x * sum_y;
-        let denom_x = n * sum_xx - sum_x * sum_x;
-
-        if denom_x <= 0.0 || denom_x.is_nan() {
-            return regression_invalid();
-        }
-
-        let slope = numerator / denom_x;
-        let intercept = (sum_y - slope * sum_x) / n;
-
-        // Compute R² from the same sums (no need for corrcoeff)
-        let denom_y = n * sum_yy - sum_y * sum_y;
-        let r_squared = if denom_y > 0.0 && !denom_y.is_nan() {
-            (numerator * numerator) / (denom_x * denom_y)
-        } else {
-            f64::NAN
-        };
-
-        RegressionCoeffs {
-            slope,
-            intercept,
-            r_squared,
+        RegressionSums {
+            n: len as f64,
+            shift_x,
+            shift_y,
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_yy,
+            sum_xy,
         }
     }
 }
 
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline(always)]
-fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
+fn regression_sums_wasm128(x: &[f64], y: &[f64], shift_x: f64, shift_y: f64) -> RegressionSums {
     let len = x.len();
-    let n = len as f64;
     let chunks = len / 2; // f64x2
     let unrolled = chunks / 4; // 8 elements per iter
 
@@ -801,6 +837,8 @@ fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
     let mut syy1: v128 = f64x2_splat(0.0);
     let mut sxy0: v128 = f64x2_splat(0.0);
     let mut sxy1: v128 = f64x2_splat(0.0);
+    let shift_xv = f64x2_splat(shift_x);
+    let shift_yv = f64x2_splat(shift_y);
 
     unsafe {
         let x_ptr = x.as_ptr();
@@ -809,14 +847,14 @@ fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
         for i in 0..unrolled {
             let base = i * 8;
 
-            let xv0 = v128_load(x_ptr.add(base) as *const v128);
-            let yv0 = v128_load(y_ptr.add(base) as *const v128);
-            let xv1 = v128_load(x_ptr.add(base + 2) as *const v128);
-            let yv1 = v128_load(y_ptr.add(base + 2) as *const v128);
-            let xv2 = v128_load(x_ptr.add(base + 4) as *const v128);
-            let yv2 = v128_load(y_ptr.add(base + 4) as *const v128);
-            let xv3 = v128_load(x_ptr.add(base + 6) as *const v128);
-            let yv3 = v128_load(y_ptr.add(base + 6) as *const v128);
+            let xv0 = f64x2_sub(v128_load(x_ptr.add(base) as *const v128), shift_xv);
+            let yv0 = f64x2_sub(v128_load(y_ptr.add(base) as *const v128), shift_yv);
+            let xv1 = f64x2_sub(v128_load(x_ptr.add(base + 2) as *const v128), shift_xv);
+            let yv1 = f64x2_sub(v128_load(y_ptr.add(base + 2) as *const v128), shift_yv);
+            let xv2 = f64x2_sub(v128_load(x_ptr.add(base + 4) as *const v128), shift_xv);
+            let yv2 = f64x2_sub(v128_load(y_ptr.add(base + 4) as *const v128), shift_yv);
+            let xv3 = f64x2_sub(v128_load(x_ptr.add(base + 6) as *const v128), shift_xv);
+            let yv3 = f64x2_sub(v128_load(y_ptr.add(base + 6) as *const v128), shift_yv);
 
             // Interleave updates across 2 accumulators.
             sx0 = f64x2_add(sx0, xv0);
@@ -852,8 +890,8 @@ fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
 
         for i in (unrolled * 4)..chunks {
             let base = i * 2;
-            let xv = v128_load(x_ptr.add(base) as *const v128);
-            let yv = v128_load(y_ptr.add(base) as *const v128);
+            let xv = f64x2_sub(v128_load(x_ptr.add(base) as *const v128), shift_xv);
+            let yv = f64x2_sub(v128_load(y_ptr.add(base) as *const v128), shift_yv);
             sx = f64x2_add(sx, xv);
             sy = f64x2_add(sy, yv);
             sxx = f64x2_add(sxx, f64x2_mul(xv, xv));
@@ -869,8 +907,8 @@ fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
         let mut sum_xy = f64x2_extract_lane::<0>(sxy) + f64x2_extract_lane::<1>(sxy);
 
         for i in (chunks * 2)..len {
-            let xi = x[i];
-            let yi = y[i];
+            let xi = x[i] - shift_x;
+            let yi = y[i] - shift_y;
             sum_x += xi;
             sum_y += yi;
             sum_xx = xi.mul_add(xi, sum_xx);
@@ -878,28 +916,26 @@ fn regress_simd_coeffs_wasm128(x: &[f64], y: &[f64]) -> RegressionCoeffs {
             sum_xy = xi.mul_add(yi, sum_xy);
         }
 
-        let numerator = n * sum_xy - sum_x * sum_y;
-        let denom_x = n * sum_xx - sum_x * sum_x;
-        if denom_x <= 0.0 || denom_x.is_nan() {
-            return regression_invalid();
+        RegressionSums {
+            n: len as f64,
+            shift_x,
+            shift_y,
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_yy,
+            sum_xy,
         }
+    }
+}
 
-        let slope = numerator / denom_x;
-        let intercept = (sum_y - slope * sum_x) / n;
-
-        let denom_y = n * sum_yy - sum_y * sum_y;
-        let r_squared = if denom_y > 0.0 && !denom_y.is_nan() {
-            (numerator * numerator) / (denom_x * denom_y)
-        } else {
-            f64::NAN
-        };
-
-        return RegressionCoeffs {
-            slope,
-            intercept,
-            r_squared,
-        };
+/// SIMD-optimized linear regression (fused sums, single-pass for statistics).
+/// Uses the same SIMD pattern as corrcoeff for maximum efficiency.
+pub fn regress_simd_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
+    if x.len() != y.len() || x.len() < 2 {
+        return regression_invalid();
     }
+    regression_sums(x, y, 0.0, 0.0).coeffs()
 }
 
 pub fn regress_simd(x: &[f64], y: &[f64]) -> RegressionResult {
@@ -919,6 +955,152 @@ pub fn regress_simd(x: &[f64], y: &[f64]) -> RegressionResult {
     }
 }
 
+/// Simple linear regression with the usual inference statistics.
+///
+/// Produced by [`regress_stats`]. Besides the fitted line it carries everything needed to
+/// draw confidence and prediction bands (see [`predict_interval`](Self::predict_interval)).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionStats {
+    pub slope: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+    /// Standard error of the slope
+    pub slope_se: f64,
+    /// Standard error of the intercept
+    pub intercept_se: f64,
+    /// t-statistic for `slope == 0`
+    pub slope_t: f64,
+    /// t-statistic for `intercept == 0`
+    pub intercept_t: f64,
+    /// Two-sided p-value of the slope
+    pub slope_p: f64,
+    /// Two-sided p-value of the intercept
+    pub intercept_p: f64,
+    /// Residual standard error
+    pub residual_se: f64,
+    /// Residual degrees of freedom (`n - 2`)
+    pub df: f64,
+    /// F-statistic of the regression (equal to `slope_t²`)
+    pub f_statistic: f64,
+    /// p-value of the F-statistic
+    pub f_p_value: f64,
+    n: f64,
+    mean_x: f64,
+    sxx: f64,
+}
+
+impl RegressionStats {
+    fn invalid() -> Self {
+        RegressionStats {
+            slope: f64::NAN,
+            intercept: f64::NAN,
+            r_squared: f64::NAN,
+            slope_se: f64::NAN,
+            intercept_se: f64::NAN,
+            slope_t: f64::NAN,
+            intercept_t: f64::NAN,
+            slope_p: f64::NAN,
+            intercept_p: f64::NAN,
+            residual_se: f64::NAN,
+            df: f64::NAN,
+            f_statistic: f64::NAN,
+            f_p_value: f64::NAN,
+            n: f64::NAN,
+            mean_x: f64::NAN,
+            sxx: f64::NAN,
+        }
+    }
+
+    /// Fitted value `slope * x + intercept`.
+    #[inline]
+    pub fn predict(&self, x: f64) -> f64 {
+        self.slope.mul_add(x, self.intercept)
+    }
+
+    /// Confidence interval for the mean response at `x_new` with the given `level` (e.g. 0.95).
+    pub fn confidence_interval(&self, x_new: f64, level: f64) -> [f64; 2] {
+        self.interval(x_new, level, 0.0)
+    }
+
+    /// Prediction interval for a new observation at `x_new` with the given `level` (e.g. 0.95).
+    pub fn predict_interval(&self, x_new: f64, level: f64) -> [f64; 2] {
+        self.interval(x_new, level, 1.0)
+    }
+
+    fn interval(&self, x_new: f64, level: f64, extra: f64) -> [f64; 2] {
+        let dx = x_new - self.mean_x;
+        let se = self.residual_se * (extra + 1.0 / self.n + dx * dx / self.sxx).sqrt();
+        t_interval(
+            self.predict(x_new),
+            se,
+            self.df,
+            1.0 - level,
+            Alternative::TwoSided,
+        )
+    }
+}
+
+/// Simple linear regression with standard errors, t/p-values, the residual standard error
+/// and the F-statistic.
+///
+/// All statistics are derived from the same fused single-pass SIMD sums as
+/// [`regress_simd_coeffs`], taken about the first point so that data far from zero (such as
+/// timestamps) keep their precision. Requires at least 3 points for the inference statistics; the
+/// fit itself is NaN for fewer than 2 points or constant `x`.
+pub fn regress_stats(x: &[f64], y: &[f64]) -> RegressionStats {
+    if x.len() != y.len() || x.len() < 2 {
+        return RegressionStats::invalid();
+    }
+
+    // Sums about the first point: raw sums of offset data (e.g. timestamps) would cancel.
+    let sums = regression_sums(x, y, x[0], y[0]);
+    let coeffs = sums.coeffs();
+    if coeffs.slope.is_nan() {
+        return RegressionStats::invalid();
+    }
+
+    let n = sums.n;
+    let (denom_x, denom_y, numerator) = sums.scaled_centered();
+    let sxx = denom_x / n;
+    let syy = denom_y / n;
+    let sxy = numerator / n;
+    let mean_x = sums.sum_x / n + sums.shift_x;
+
+    let df = n - 2.0;
+    let rss = (syy - sxy * sxy / sxx).max(0.0);
+    let sigma2 = if df > 0.0 { rss / df } else { f64::NAN };
+    let residual_se = sigma2.sqrt();
+
+    let slope_se = (sigma2 / sxx).sqrt();
+    let intercept_se = (sigma2 * (1.0 / n + mean_x * mean_x / sxx)).sqrt();
+    let slope_t = coeffs.slope / slope_se;
+    let intercept_t = coeffs.intercept / intercept_se;
+    let f_statistic = (syy - rss) / sigma2;
+    let f_p_value = match crate::distributions::fisher_f_cdf(f_statistic, 1.0, df) {
+        Ok(cdf) => 1.0 - cdf,
+        Err(_) => f64::NAN,
+    };
+
+    RegressionStats {
+        slope: coeffs.slope,
+        intercept: coeffs.intercept,
+        r_squared: coeffs.r_squared,
+        slope_se,
+        intercept_se,
+        slope_t,
+        intercept_t,
+        slope_p: t_p_value(slope_t, df, Alternative::TwoSided),
+        intercept_p: t_p_value(intercept_t, df, Alternative::TwoSided),
+        residual_se,
+        df,
+        f_statistic,
+        f_p_value,
+        n,
+        mean_x,
+        sxx,
+    }
+}
+
 /// BLAS-like kernels-based linear regression.
 /// Uses minimal kernel operations (dot product, sum, axpy-style residuals).
 pub fn regress_kernels_coeffs(x: &[f64], y: &[f64]) -> RegressionCoeffs {
@@ -1024,3 +1206,267 @@ fn dot_product(a: &[f64], b: &[f64]) -> f64 {
 pub fn regress(x: &[f64], y: &[f64]) -> RegressionResult {
     regress_simd(x, y)
 }
+
+// =============================================================================
+// Multiple Linear Regression (OLS)
+// =============================================================================
+
+/// Result of a multiple linear regression fitted by [`ols`].
+///
+/// Coefficient vectors are ordered as the design matrix columns, preceded by the intercept
+/// when one was requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OlsResult {
+    /// Estimated coefficients
+    pub coefficients: Vec<f64>,
+    /// Standard errors of the coefficients
+    pub std_errors: Vec<f64>,
+    /// t-statistics (`coefficient / std_error`)
+    pub t_values: Vec<f64>,
+    /// Two-sided p-values of the t-statistics
+    pub p_values: Vec<f64>,
+    /// Confidence interval lower bounds (at `conf_level`)
+    pub ci_lower: Vec<f64>,
+    /// Confidence interval upper bounds (at `conf_level`)
+    pub ci_upper: Vec<f64>,
+    /// Confidence level of the coefficient intervals
+    pub conf_level: f64,
+    /// Coefficient of determination
+    pub r_squared: f64,
+    /// R² adjusted for the number of predictors
+    pub adj_r_squared: f64,
+    /// Overall F-statistic (all slopes zero)
+    pub f_statistic: f64,
+    /// p-value of the F-statistic
+    pub f_p_value: f64,
+    /// Model degrees of freedom (number of non-intercept coefficients)
+    pub df_model: f64,
+    /// Residual degrees of freedom (`n - p`)
+    pub df_residual: f64,
+    /// Residual standard error
+    pub sigma: f64,
+    /// Gaussian log-likelihood at the estimates
+    pub log_likelihood: f64,
+    /// Akaike information criterion (counting the error variance as a parameter, as R does)
+    pub aic: f64,
+    /// Bayesian information criterion
+    pub bic: f64,
+    /// Residuals `y - X * coefficients`
+    pub residuals: Vec<f64>,
+}
+
+fn ols_invalid(p: usize, n: usize, conf_level: f64) -> OlsResult {
+    OlsResult {
+        coefficients: vec![f64::NAN; p],
+        std_errors: vec![f64::NAN; p],
+        t_values: vec![f64::NAN; p],
+        p_values: vec![f64::NAN; p],
+        ci_lower: vec![f64::NAN; p],
+        ci_upper: vec![f64::NAN; p],
+        conf_level,
+        r_squared: f64::NAN,
+        adj_r_squared: f64::NAN,
+        f_statistic: f64::NAN,
+        f_p_value: f64::NAN,
+        df_model: f64::NAN,
+        df_residual: f64::NAN,
+        sigma: f64::NAN,
+        log_likelihood: f64::NAN,
+        aic: f64::NAN,
+        bic: f64::NAN,
+        residuals: vec![f64::NAN; n],
+    }
+}
+
+/// Ordinary least squares fit of `y` on a design matrix, with a 95% inference table.
+///
+/// See [`ols_with_confidence`] for the argument layout.
+pub fn ols(x: &[f64], n_cols: usize, y: &[f64], intercept: bool) -> OlsResult {
+    ols_with_confidence(x, n_cols, y, intercept, 0.95)
+}
+
+/// Ordinary least squares fit of `y` on a design matrix.
+///
+/// # Arguments
+/// * `x` - Design matrix in row-major order: `y.len()` rows of `n_cols` predictors
+/// * `n_cols` - Number of predictor columns in `x`
+/// * `y` - Response vector
+/// * `intercept` - If true, an intercept column is added in front of the predictors
+/// * `conf_level` - Confidence level of the coefficient intervals (e.g. 0.95)
+///
+/// The fit uses a Householder QR decomposition of the design matrix, which avoids forming
+/// `XᵀX`. R², the F-statistic and the information criteria follow R's `lm` conventions:
+/// without an intercept, R² and F are computed against the uncentered total sum of squares.
+///
+/// Returns a result filled with NaN when dimensions are inconsistent, there are no more
+/// observations than coefficients, or the design matrix is rank deficient.
+pub fn ols_with_confidence(
+    x: &[f64],
+    n_cols: usize,
+    y: &[f64],
+    intercept: bool,
+    conf_level: f64,
+) -> OlsResult {
+    let n = y.len();
+    let p = n_cols + intercept as usize;
+    if p == 0 || n <= p || x.len() != n * n_cols {
+        return ols_invalid(p, n, conf_level);
+    }
+
+    // Column-major copy of the design matrix so each column is a contiguous slice.
+    let mut a = vec![0.0; n * p];
+    let offset = if intercept {
+        a[..n].fill(1.0);
+        1
+    } else {
+        0
+    };
+    for (i, row) in x.chunks_exact(n_cols.max(1)).take(n).enumerate() {
+        for (j, &value) in row.iter().enumerate().take(n_cols) {
+            a[(j + offset) * n + i] = value;
+        }
+    }
+    let col_norms: Vec<f64> = a
+        .chunks_exact(n)
+        .map(|c| dot_product(c, c).sqrt())
+        .collect();
+
+    // Householder QR: R overwrites the upper triangle of `a`, `qty` becomes Qᵀy.
+    let mut qty = y.to_vec();
+    let mut v = vec![0.0; n];
+    for k in 0..p {
+        let col = &a[k * n + k..(k + 1) * n];
+        let norm = dot_product(col, col).sqrt();
+        // Same relative tolerance as R's lm (1e-7) for detecting rank deficiency.
+        if norm.is_nan() || norm <= 1e-7 * col_norms[k] {
+            return ols_invalid(p, n, conf_level);
+        }
+        let alpha = if col[0] > 0.0 { -norm } else { norm };
+
+        let v = &mut v[..n - k];
+        v.copy_from_slice(col);
+        v[0] -= alpha;
+        let v_norm2 = dot_product(v, v);
+
+        a[k * n + k] = alpha;
+        a[k * n + k + 1..(k + 1) * n].fill(0.0);
+        for j in (k + 1)..p {
+            let target = &mut a[j * n + k..(j + 1) * n];
+            let scale = 2.0 * dot_product(v, target) / v_norm2;
+            for (t, &vi) in target.iter_mut().zip(v.iter()) {
+                *t -= scale * vi;
+            }
+        }
+        let target = &mut qty[k..];
+        let scale = 2.0 * dot_product(v, target) / v_norm2;
+        for (t, &vi) in target.iter_mut().zip(v.iter()) {
+            *t -= scale * vi;
+        }
+    }
+    let r = |i: usize, j: usize| a[j * n + i];
+
+    // Back-substitution for the coefficients.
+    let mut coefficients = vec![0.0; p];
+    for i in (0..p).rev() {
+        let mut acc = qty[i];
+        for j in (i + 1)..p {
+            acc -= r(i, j) * coefficients[j];
+        }
+        coefficients[i] = acc / r(i, i);
+    }
+
+    // (XᵀX)⁻¹ = R⁻¹ R⁻ᵀ; only its diagonal is needed for the standard errors.
+    let mut r_inv = vec![0.0; p * p];
+    for j in 0..p {
+        r_inv[j * p + j] = 1.0 / r(j, j);
+        for i in (0..j).rev() {
+            let mut acc = 0.0;
+            for k in (i + 1)..=j {
+                acc += r(i, k) * r_inv[k * p + j];
+            }
+            r_inv[i * p + j] = -acc / r(i, i);
+        }
+    }
+
+    // Residuals from the original row-major rows.
+    let slopes = &coefficients[offset..];
+    let base = if intercept { coefficients[0] } else { 0.0 };
+    let residuals: Vec<f64> = if n_cols == 0 {
+        y.iter().map(|&yi| yi - base).collect()
+    } else {
+        x.chunks_exact(n_cols)
+            .zip(y)
+            .map(|(row, &yi)| yi - base - dot_product(row, slopes))
+            .collect()
+    };
+
+    let n_f = n as f64;
+    let df_residual = (n - p) as f64;
+    let df_model = (p - offset) as f64;
+    let rss = dot_product(&residuals, &residuals);
+    let tss = if intercept {
+        sum_squared_deviations(y, sum(y) / n_f)
+    } else {
+        dot_product(y, y)
+    };
+    let sigma2 = rss / df_residual;
+    let sigma = sigma2.sqrt();
+
+    let r_squared = 1.0 - rss / tss;
+    let adj_r_squared = 1.0 - (1.0 - r_squared) * ((n_f - offset as f64) / df_residual);
+    let (f_statistic, f_p_value) = if df_model > 0.0 {
+        let f = ((tss - rss) / df_model) / sigma2;
+        let p_value = match crate::distributions::fisher_f_cdf(f, df_model, df_residual) {
+            Ok(cdf) => 1.0 - cdf,
+            Err(_) => f64::NAN,
+        };
+        (f, p_value)
+    } else {
+        (f64::NAN, f64::NAN)
+    };
+
+    let alpha = 1.0 - conf_level;
+    let mut std_errors = Vec::with_capacity(p);
+    let mut t_values = Vec::with_capacity(p);
+    let mut p_values = Vec::with_capacity(p);
+    let mut ci_lower = Vec::with_capacity(p);
+    let mut ci_upper = Vec::with_capacity(p);
+    for (i, &beta) in coefficients.iter().enumerate() {
+        let row = &r_inv[i * p + i..(i + 1) * p];
+        let se = (sigma2 * dot_product(row, row)).sqrt();
+        let t = beta / se;
+        let ci = t_interval(beta, se, df_residual, alpha, Alternative::TwoSided);
+        std_errors.push(se);
+        t_values.push(t);
+        p_values.push(t_p_value(t, df_residual, Alternative::TwoSided));
+        ci_lower.push(ci[0]);
+        ci_upper.push(ci[1]);
+    }
+
+    let log_likelihood =
+        -0.5 * n_f * ((2.0 * std::f64::consts::PI).ln() + 1.0 - n_f.ln() + rss.ln());
+    let n_params = (p + 1) as f64;
+    let aic = -2.0 * log_likelihood + 2.0 * n_params;
+    let bic = -2.0 * log_likelihood + n_f.ln() * n_params;
+
+    OlsResult {
+        coefficients,
+        std_errors,
+        t_values,
+        p_values,
+        ci_lower,
+        ci_upper,
+        conf_level,
+        r_squared,
+        adj_r_squared,
+        f_statistic,
+        f_p_value,
+        df_model,
+        df_residual,
+        sigma,
+        log_likelihood,
+        aic,
+        bic,
+        residuals,
+    }
+}
@@ -0,0 +1,413 @@
+use crate::distributions::{normal_cdf, normal_inv};
+use crate::random::{RandomSource, Xoshiro256PlusPlus};
+use crate::stats::order::sorted_quantile;
+use crate::stats::statistical_tests::{Alternative, TestResult};
+
+// Resampling
+//
+// Bootstrap intervals and permutation tests for arbitrary statistics. Every function takes a
+// `seed`, so the same inputs always give the same result natively and in WASM. Replicates are
+// drawn into buffers allocated once per call; the statistic sees a borrowed slice of that
+// buffer and must not rely on it outliving the call.
+
+/// Confidence interval construction for [`bootstrap_ci`].
+///
+/// - `Percentile`: the `alpha / 2` and `1 - alpha / 2` quantiles of the replicates.
+/// - `Basic`: the percentile interval reflected about the estimate,
+///   `[2 t - q(1 - alpha / 2), 2 t - q(alpha / 2)]`.
+/// - `Bca`: bias-corrected and accelerated (Efron 1987), with the acceleration estimated by
+///   the jackknife. Second-order accurate, at the cost of `n` extra statistic evaluations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BootstrapMethod {
+    #[default]
+    Percentile,
+    Basic,
+    Bca,
+}
+
+/// Result of a bootstrap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BootstrapResult {
+    /// Statistic of the original sample
+    pub estimate: f64,
+    /// Standard deviation of the replicates
+    pub std_error: f64,
+    /// Mean of the replicates minus `estimate`
+    pub bias: f64,
+    /// `1 - alpha` confidence interval for the statistic
+    pub ci: [f64; 2],
+}
+
+#[inline(always)]
+fn bootstrap_nan() -> BootstrapResult {
+    BootstrapResult {
+        estimate: f64::NAN,
+        std_error: f64::NAN,
+        bias: f64::NAN,
+        ci: [f64::NAN, f64::NAN],
+    }
+}
+
+#[inline(always)]
+fn permutation_nan() -> TestResult {
+    TestResult {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df: None,
+        ci: None,
+    }
+}
+
+/// Internal: uniform index in `0..n` by multiply-shift (Lemire), without division.
+#[inline(always)]
+fn random_index<R: RandomSource>(rng: &mut R, n: usize) -> usize {
+    ((rng.next_u64() as u128 * n as u128) >> 64) as usize
+}
+
+/// Internal: in-place Fisher-Yates shuffle.
+fn shuffle<T, R: RandomSource>(values: &mut [T], rng: &mut R) {
+    for i in (1..values.len()).rev() {
+        values.swap(i, random_index(rng, i + 1));
+    }
+}
+
+/// Internal: turn replicates and (for BCa) jackknife values into a [`BootstrapResult`].
+///
+/// NaN replicates (e.g. a correlation of a constant resample) are dropped.
+fn summarize_bootstrap(
+    estimate: f64,
+    replicates: &mut Vec<f64>,
+    jackknife: &[f64],
+    method: BootstrapMethod,
+    alpha: f64,
+) -> BootstrapResult {
+    replicates.retain(|v| !v.is_nan());
+    if replicates.len() < 2 {
+        return bootstrap_nan();
+    }
+    replicates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let b = replicates.len() as f64;
+    let rep_mean = replicates.iter().sum::<f64>() / b;
+    let rep_var = replicates
+        .iter()
+        .map(|&v| (v - rep_mean) * (v - rep_mean))
+        .sum::<f64>()
+        / (b - 1.0);
+
+    let (lo_p, hi_p) = (alpha / 2.0, 1.0 - alpha / 2.0);
+    let ci = match method {
+        BootstrapMethod::Percentile => [
+            sorted_quantile(replicates, lo_p),
+            sorted_quantile(replicates, hi_p),
+        ],
+        BootstrapMethod::Basic => [
+            2.0 * estimate - sorted_quantile(replicates, hi_p),
+            2.0 * estimate - sorted_quantile(replicates, lo_p),
+        ],
+        BootstrapMethod::Bca => {
+            let std_normal_inv = |p: f64| normal_inv(p, 0.0, 1.0).unwrap_or(f64::NAN);
+            let std_normal_cdf = |z: f64| normal_cdf(z, 0.0, 1.0).unwrap_or(f64::NAN);
+
+            // Bias correction from the share of replicates below the estimate.
+            let below = replicates.partition_point(|&v| v < estimate) as f64;
+            let z0 = std_normal_inv(below / b);
+
+            // Acceleration from the skewness of the jackknife values.
+            let m = jackknife.len() as f64;
+            let jack_mean = jackknife.iter().sum::<f64>() / m;
+            let (mut num, mut den) = (0.0, 0.0);
+            for &v in jackknife {
+                let d = jack_mean - v;
+                num += d * d * d;
+                den += d * d;
+            }
+            let accel = if den > 0.0 {
+                num / (6.0 * den.powf(1.5))
+            } else {
+                0.0
+            };
+
+            let adjust = |p: f64| {
+                let z = z0 + std_normal_inv(p);
+                std_normal_cdf(z0 + z / (1.0 - accel * z))
+            };
+            if z0.is_finite() && accel.is_finite() {
+                [
+                    sorted_quantile(replicates, adjust(lo_p)),
+                    sorted_quantile(replicates, adjust(hi_p)),
+                ]
+            } else {
+                [f64::NAN, f64::NAN]
+            }
+        }
+    };
+
+    BootstrapResult {
+        estimate,
+        std_error: rep_var.sqrt(),
+        bias: rep_mean - estimate,
+        ci,
+    }
+}
+
+// =============================================================================
+// Bootstrap
+// =============================================================================
+
+/// Nonparametric bootstrap of `statistic` over `data`.
+///
+/// Draws `replicates` resamples of `data` with replacement, evaluates `statistic` on each,
+/// and builds a `1 - alpha` interval with `method`. Works with any statistic, including ones
+/// with no closed-form standard error such as [`median`](crate::median),
+/// [`iqr`](crate::iqr) or [`coeffvar`](crate::coeffvar).
+///
+/// Returns NaN if `data` has fewer than 2 values or contains NaN, `replicates < 2`, `alpha`
+/// is not in `(0, 1)`, or the statistic of `data` is NaN.
+///
+/// # Example
+/// ```rust
+/// use stat_core::{bootstrap_ci, median, BootstrapMethod};
+///
+/// let data = [2.1, 3.4, 1.9, 5.6, 4.4, 3.3, 2.8, 4.0, 3.9, 2.5];
+/// let res = bootstrap_ci(&data, median, BootstrapMethod::Bca, 2000, 0.05, 42);
+/// assert!(res.ci[0] <= res.estimate && res.estimate <= res.ci[1]);
+/// ```
+pub fn bootstrap_ci<F: Fn(&[f64]) -> f64>(
+    data: &[f64],
+    statistic: F,
+    method: BootstrapMethod,
+    replicates: usize,
+    alpha: f64,
+    seed: u64,
+) -> BootstrapResult {
+    let n = data.len();
+    if n < 2 || replicates < 2 || !(alpha > 0.0 && alpha < 1.0) || data.iter().any(|v| v.is_nan()) {
+        return bootstrap_nan();
+    }
+    let estimate = statistic(data);
+    if estimate.is_nan() {
+        return bootstrap_nan();
+    }
+
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut sample = vec![0.0; n];
+    let mut stats = Vec::with_capacity(replicates);
+    for _ in 0..replicates {
+        for v in sample.iter_mut() {
+            *v = data[random_index(&mut rng, n)];
+        }
+        stats.push(statistic(&sample));
+    }
+
+    let jackknife = if method == BootstrapMethod::Bca {
+        // Leave-one-out samples: start from data[1..] and swap each left-out value back in.
+        sample.truncate(n - 1);
+        sample.copy_from_slice(&data[1..]);
+        let mut values = Vec::with_capacity(n);
+        for i in 0..n {
+            if i > 0 {
+                sample[i - 1] = data[i - 1];
+            }
+            values.push(statistic(&sample));
+        }
+        values
+    } else {
+        Vec::new()
+    };
+
+    summarize_bootstrap(estimate, &mut stats, &jackknife, method, alpha)
+}
+
+/// Paired nonparametric bootstrap of a two-sample `statistic` such as
+/// [`spearmancoeff`](crate::spearmancoeff) or [`corrcoeff`](crate::corrcoeff).
+///
+/// Resamples `(x[i], y[i])` pairs with replacement, keeping each pair together; otherwise as
+/// [`bootstrap_ci`]. Returns NaN if `x` and `y` differ in length.
+pub fn bootstrap_ci_paired<F: Fn(&[f64], &[f64]) -> f64>(
+    x: &[f64],
+    y: &[f64],
+    statistic: F,
+    method: BootstrapMethod,
+    replicates: usize,
+    alpha: f64,
+    seed: u64,
+) -> BootstrapResult {
+    let n = x.len();
+    if n < 2
+        || y.len() != n
+        || replicates < 2
+        || !(alpha > 0.0 && alpha < 1.0)
+        || x.iter().chain(y).any(|v| v.is_nan())
+    {
+        return bootstrap_nan();
+    }
+    let estimate = statistic(x, y);
+    if estimate.is_nan() {
+        return bootstrap_nan();
+    }
+
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut indices = vec![0usize; n];
+    let (mut sx, mut sy) = (vec![0.0; n], vec![0.0; n]);
+    let mut stats = Vec::with_capacity(replicates);
+    for _ in 0..replicates {
+        for idx in indices.iter_mut() {
+            *idx = random_index(&mut rng, n);
+        }
+        for ((a, b), &i) in sx.iter_mut().zip(sy.iter_mut()).zip(&indices) {
+            *a = x[i];
+            *b = y[i];
+        }
+        stats.push(statistic(&sx, &sy));
+    }
+
+    let jackknife = if method == BootstrapMethod::Bca {
+        sx.truncate(n - 1);
+        sy.truncate(n - 1);
+        sx.copy_from_slice(&x[1..]);
+        sy.copy_from_slice(&y[1..]);
+        let mut values = Vec::with_capacity(n);
+        for i in 0..n {
+            if i > 0 {
+                sx[i - 1] = x[i - 1];
+                sy[i - 1] = y[i - 1];
+            }
+            values.push(statistic(&sx, &sy));
+        }
+        values
+    } else {
+        Vec::new()
+    };
+
+    summarize_bootstrap(estimate, &mut stats, &jackknife, method, alpha)
+}
+
+// =============================================================================
+// Permutation Tests
+// =============================================================================
+
+/// Relative tolerance under which a permuted statistic counts as a tie with the observed one.
+const TIE_TOLERANCE: f64 = 1e-7;
+
+/// Internal: Monte Carlo p-value `(1 + #{as or more extreme}) / (permutations + 1)`.
+///
+/// The two-sided p-value doubles the smaller one-sided p-value (capped at 1), which needs no
+/// assumption that the statistic is centred at zero. Values within [`TIE_TOLERANCE`] of the
+/// observed one, relative to the largest magnitude seen, count as ties, so relabellings that
+/// differ only in summation order are not lost to rounding (even when the observed value is 0).
+fn permutation_p_value(
+    observed: f64,
+    permuted: impl Iterator<Item = f64>,
+    alternative: Alternative,
+) -> f64 {
+    let permuted: Vec<f64> = permuted.filter(|v| !v.is_nan()).collect();
+    let scale = permuted
+        .iter()
+        .chain([&observed])
+        .filter(|v| v.is_finite())
+        .fold(0.0f64, |m, v| m.max(v.abs()));
+    let tolerance = TIE_TOLERANCE * scale;
+    let total = permuted.len();
+    let at_least = permuted
+        .iter()
+        .filter(|&&v| v >= observed - tolerance)
+        .count();
+    let at_most = permuted
+        .iter()
+        .filter(|&&v| v <= observed + tolerance)
+        .count();
+    let p = |hits: usize| (1 + hits) as f64 / (total + 1) as f64;
+    match alternative {
+        Alternative::Greater => p(at_least),
+        Alternative::Less => p(at_most),
+        Alternative::TwoSided => (2.0 * p(at_least).min(p(at_most))).min(1.0),
+    }
+}
+
+/// Two-sample permutation test of `statistic(x, y)`.
+///
+/// Pools `x` and `y`, reshuffles the pooled values `permutations` times, splits each shuffle
+/// into samples of the original sizes and compares the statistic with its observed value. Use
+/// e.g. `|a, b| mean(a) - mean(b)` for a difference in means or a difference in medians.
+/// `Alternative::Greater` asks whether the observed statistic is unusually large.
+///
+/// The statistic of the result is the observed value; `df` and `ci` are `None`.
+///
+/// Returns NaN if either sample is empty or contains NaN, `permutations` is 0, or the
+/// observed statistic is NaN.
+pub fn permutation_test<F: Fn(&[f64], &[f64]) -> f64>(
+    x: &[f64],
+    y: &[f64],
+    statistic: F,
+    alternative: Alternative,
+    permutations: usize,
+    seed: u64,
+) -> TestResult {
+    if x.is_empty() || y.is_empty() || permutations == 0 || x.iter().chain(y).any(|v| v.is_nan()) {
+        return permutation_nan();
+    }
+    let observed = statistic(x, y);
+    if observed.is_nan() {
+        return permutation_nan();
+    }
+
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut pooled: Vec<f64> = x.iter().chain(y).copied().collect();
+    let split = x.len();
+    let permuted = (0..permutations).map(|_| {
+        shuffle(&mut pooled, &mut rng);
+        let (a, b) = pooled.split_at(split);
+        statistic(a, b)
+    });
+
+    TestResult {
+        statistic: observed,
+        p_value: permutation_p_value(observed, permuted, alternative),
+        df: None,
+        ci: None,
+    }
+}
+
+/// Permutation test of association between paired samples, such as a correlation.
+///
+/// Shuffles `y` against a fixed `x` `permutations` times, breaking any dependence while
+/// keeping both marginal distributions. Use with [`corrcoeff`](crate::corrcoeff),
+/// [`spearmancoeff`](crate::spearmancoeff) or any other `statistic(x, y)`.
+///
+/// Returns NaN if `x` and `y` differ in length, have fewer than 2 values or contain NaN,
+/// `permutations` is 0, or the observed statistic is NaN.
+pub fn permutation_test_paired<F: Fn(&[f64], &[f64]) -> f64>(
+    x: &[f64],
+    y: &[f64],
+    statistic: F,
+    alternative: Alternative,
+    permutations: usize,
+    seed: u64,
+) -> TestResult {
+    if x.len() < 2
+        || y.len() != x.len()
+        || permutations == 0
+        || x.iter().chain(y).any(|v| v.is_nan())
+    {
+        return permutation_nan();
+    }
+    let observed = statistic(x, y);
+    if observed.is_nan() {
+        return permutation_nan();
+    }
+
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let mut shuffled = y.to_vec();
+    let permuted = (0..permutations).map(|_| {
+        shuffle(&mut shuffled, &mut rng);
+        statistic(x, &shuffled)
+    });
+
+    TestResult {
+        statistic: observed,
+        p_value: permutation_p_value(observed, permuted, alternative),
+        df: None,
+        ci: None,
+    }
+}
@@ -0,0 +1,498 @@
+// =============================================================================
+// Robust Location and Scale
+// =============================================================================
+
+use crate::stats::order::{median_in_place, select_kth};
+
+/// `1 / Phi^-1(3/4)`: makes the MAD a consistent estimator of the normal standard deviation.
+const MAD_CONSISTENCY: f64 = 1.482602218505602;
+
+/// Internal: a copy of `data`, or `None` if it is empty or holds a NaN.
+fn checked_copy(data: &[f64]) -> Option<Vec<f64>> {
+    if data.is_empty() || data.iter().any(|v| v.is_nan()) {
+        return None;
+    }
+    Some(data.to_vec())
+}
+
+/// Internal: an ascending copy of `data`, or `None` if it is empty or holds a NaN.
+fn sorted_copy(data: &[f64]) -> Option<Vec<f64>> {
+    let mut values = checked_copy(data)?;
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Some(values)
+}
+
+/// Internal: number of observations cut from each tail, `floor(n * proportion)`.
+fn tail_count(n: usize, proportion: f64) -> usize {
+    (n as f64 * proportion).floor() as usize
+}
+
+/// Internal: partition `values` so the `g` smallest come first and the `g` largest last,
+/// leaving the central `n - 2g` (unordered) in between. Requires `2g < n`.
+fn partition_tails(values: &mut [f64], g: usize) {
+    if g == 0 {
+        return;
+    }
+    let n = values.len();
+    select_kth(values, g - 1);
+    select_kth(&mut values[g..], n - 2 * g);
+}
+
+/// Internal: median and unscaled median absolute deviation of `data`, both by selection on
+/// one working copy, or `None` if it is empty or holds a NaN.
+fn median_and_meddev(data: &[f64]) -> Option<(f64, f64)> {
+    let mut values = checked_copy(data)?;
+    let center = median_in_place(&mut values);
+    for v in &mut values {
+        *v = (*v - center).abs();
+    }
+    Some((center, median_in_place(&mut values)))
+}
+
+/// Internal: sample variance of `values` (two-pass), NaN for fewer than two values.
+fn sample_variance_of(values: &[f64]) -> f64 {
+    let n = values.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    values.iter().map(|&v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1) as f64
+}
+
+/// Internal: the central values left after cutting `floor(n * proportion)` from each tail.
+fn trimmed(data: &[f64], proportion: f64) -> Option<Vec<f64>> {
+    if !(0.0..0.5).contains(&proportion) {
+        return None;
+    }
+    let mut values = checked_copy(data)?;
+    let g = tail_count(values.len(), proportion);
+    partition_tails(&mut values, g);
+    values.truncate(values.len() - g);
+    values.drain(..g);
+    Some(values)
+}
+
+/// Internal: `data` with the `floor(n * proportion)` smallest values raised to the next
+/// smallest and the as many largest lowered to the next largest.
+fn winsorized(data: &[f64], proportion: f64) -> Option<Vec<f64>> {
+    if !(0.0..0.5).contains(&proportion) {
+        return None;
+    }
+    let mut values = checked_copy(data)?;
+    let n = values.len();
+    let g = tail_count(n, proportion);
+    if g == 0 {
+        return Some(values);
+    }
+    let low = select_kth(&mut values, g);
+    let high = select_kth(&mut values, n - g - 1);
+    for v in &mut values {
+        *v = v.clamp(low, high);
+    }
+    Some(values)
+}
+
+// =============================================================================
+// Trimmed and Winsorized Estimators
+// =============================================================================
+
+/// Trimmed mean: the mean after discarding `floor(n * proportion)` observations from each
+/// end, as R's `mean(x, trim = proportion)`.
+///
+/// The tails are split off by selection rather than a full sort. `proportion = 0.5` gives
+/// the median.
+///
+/// # Arguments
+/// * `data` - Sample
+/// * `proportion` - Fraction trimmed from each tail, in `[0, 0.5]`
+///
+/// Returns NaN for empty input, any NaN element, or `proportion` outside `[0, 0.5]`.
+///
+/// # Example
+/// ```rust
+/// use stat_core::trimmed_mean;
+/// let latencies = [12.0, 13.0, 11.0, 14.0, 12.0, 13.0, 12.0, 11.0, 13.0, 950.0];
+/// assert_eq!(trimmed_mean(&latencies, 0.1), 12.5);
+/// ```
+pub fn trimmed_mean(data: &[f64], proportion: f64) -> f64 {
+    if proportion == 0.5 {
+        return match checked_copy(data) {
+            Some(mut values) => median_in_place(&mut values),
+            None => f64::NAN,
+        };
+    }
+    match trimmed(data, proportion) {
+        Some(central) => central.iter().sum::<f64>() / central.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Trimmed variance: the sample variance (denominator `m - 1`) of the `m = n - 2g` values
+/// left after discarding `g = floor(n * proportion)` observations from each end.
+///
+/// Returns NaN for empty input, any NaN element, `proportion` outside `[0, 0.5)`, or fewer
+/// than two retained values.
+pub fn trimmed_variance(data: &[f64], proportion: f64) -> f64 {
+    match trimmed(data, proportion) {
+        Some(central) => sample_variance_of(&central),
+        None => f64::NAN,
+    }
+}
+
+/// Winsorized mean: the mean after replacing the `g = floor(n * proportion)` smallest
+/// values by the `(g + 1)`-th smallest and the `g` largest by the `(g + 1)`-th largest.
+///
+/// Returns NaN for empty input, any NaN element, or `proportion` outside `[0, 0.5)`.
+pub fn winsorized_mean(data: &[f64], proportion: f64) -> f64 {
+    match winsorized(data, proportion) {
+        Some(values) => values.iter().sum::<f64>() / values.len() as f64,
+        None => f64::NAN,
+    }
+}
+
+/// Winsorized variance: the sample variance (denominator `n - 1`) of the winsorized sample,
+/// as Wilcox's `winvar`. `winsorized_variance / (1 - 2 * proportion)^2 / n` is the squared
+/// standard error of the trimmed mean.
+///
+/// Returns NaN for fewer than two values, any NaN element, or `proportion` outside
+/// `[0, 0.5)`.
+pub fn winsorized_variance(data: &[f64], proportion: f64) -> f64 {
+    match winsorized(data, proportion) {
+        Some(values) => sample_variance_of(&values),
+        None => f64::NAN,
+    }
+}
+
+// =============================================================================
+// Scale Estimators
+// =============================================================================
+
+/// Median absolute deviation scaled to estimate the normal standard deviation,
+/// `1.4826 * median(|x - median(x)|)`, as R's `mad`.
+///
+/// See [`meddev`](crate::stats::meddev) for the unscaled version and [`mad_with_constant`]
+/// for another constant.
+///
+/// Returns NaN for empty input or any NaN element.
+pub fn mad(data: &[f64]) -> f64 {
+    mad_with_constant(data, MAD_CONSISTENCY)
+}
+
+/// Median absolute deviation times `constant`, `constant * median(|x - median(x)|)`.
+///
+/// Both medians are found by selection on a single working copy.
+///
+/// Returns NaN for empty input or any NaN element.
+pub fn mad_with_constant(data: &[f64], constant: f64) -> f64 {
+    match median_and_meddev(data) {
+        Some((_, deviation)) => constant * deviation,
+        None => f64::NAN,
+    }
+}
+
+/// Rousseeuw-Croux `Qn` scale estimator: the `k`-th smallest of the `n (n - 1) / 2`
+/// pairwise distances `|x_i - x_j|`, `k = C(floor(n / 2) + 1, 2)`, times the normal
+/// consistency constant 2.2219 and the finite-sample factors of Croux and Rousseeuw (1992).
+///
+/// `Qn` has a 50% breakdown point and 82% Gaussian efficiency and, unlike the MAD, does not
+/// assume a symmetric distribution. The order statistic is found without forming the
+/// pairwise distances, in `O(n log^2 n)` time and `O(n)` memory.
+///
+/// Returns NaN for fewer than two values or any NaN element.
+pub fn qn_scale(data: &[f64]) -> f64 {
+    let Some(x) = sorted_copy(data) else {
+        return f64::NAN;
+    };
+    let n = x.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let h = n / 2 + 1;
+    let k = h * (h - 1) / 2;
+    let distance = kth_pairwise(n, n, |i| i + 1, |i, j| x[j] - x[i], k);
+    let factor = match n {
+        2..=9 => [0.399, 0.994, 0.512, 0.844, 0.611, 0.857, 0.669, 0.872][n - 2],
+        _ if n % 2 == 1 => n as f64 / (n as f64 + 1.4),
+        _ => n as f64 / (n as f64 + 3.8),
+    };
+    2.2219 * factor * distance
+}
+
+/// Rousseeuw-Croux `Sn` scale estimator,
+/// `1.1926 * lomed_i himed_j |x_i - x_j|` with the finite-sample factors of Croux and
+/// Rousseeuw (1992).
+///
+/// Like [`qn_scale`] it has a 50% breakdown point without assuming symmetry (58% Gaussian
+/// efficiency). Each inner median is an order statistic of two sorted runs, found by binary
+/// search, and the outer one by selection: `O(n log n)` overall.
+///
+/// Returns NaN for fewer than two values or any NaN element.
+pub fn sn_scale(data: &[f64]) -> f64 {
+    let Some(x) = sorted_copy(data) else {
+        return f64::NAN;
+    };
+    let n = x.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    // himed over all n distances, the first of which is the zero distance to itself.
+    let rank = n / 2;
+    let mut inner: Vec<f64> = (0..n)
+        .map(|i| {
+            kth_of_two_sorted(
+                i,
+                |t| x[i] - x[i - 1 - t],
+                n - 1 - i,
+                |t| x[i + 1 + t] - x[i],
+                rank,
+            )
+        })
+        .collect();
+    let outer = select_kth(&mut inner, n.div_ceil(2) - 1);
+    let factor = match n {
+        2..=9 => [0.743, 1.851, 0.954, 1.351, 0.993, 1.198, 1.005, 1.131][n - 2],
+        _ if n % 2 == 1 => n as f64 / (n as f64 - 0.9),
+        _ => 1.0,
+    };
+    1.1926 * factor * outer
+}
+
+// =============================================================================
+// M-Estimators of Location
+// =============================================================================
+
+/// Huber M-estimator of location with the scale fixed at [`mad`], as `MASS::huber`.
+///
+/// Starting from the median, the mean of the sample clipped to `mu +/- k * mad` is iterated
+/// until it moves by less than `1e-6 * mad`. `k = 1.345` gives 95% efficiency at the normal;
+/// `MASS` uses `k = 1.5`. If the MAD is zero (more than half the sample at one value) the
+/// median is returned.
+///
+/// Returns NaN for empty input, any NaN element, or non-positive `k`.
+pub fn huber_location(data: &[f64], k: f64) -> f64 {
+    if k.is_nan() || k <= 0.0 {
+        return f64::NAN;
+    }
+    let Some((mut mu, deviation)) = median_and_meddev(data) else {
+        return f64::NAN;
+    };
+    let s = MAD_CONSISTENCY * deviation;
+    if s == 0.0 {
+        return mu;
+    }
+    let n = data.len() as f64;
+    for _ in 0..1000 {
+        let (low, high) = (mu - k * s, mu + k * s);
+        let next = data.iter().map(|v| v.clamp(low, high)).sum::<f64>() / n;
+        if (mu - next).abs() < 1e-6 * s {
+            break;
+        }
+        mu = next;
+    }
+    mu
+}
+
+/// Tukey biweight (bisquare) M-estimator of location with the scale fixed at [`mad`].
+///
+/// Iteratively reweighted mean from the median with weights `(1 - u^2)^2` for
+/// `u = (x - mu) / (c * mad)` inside `(-1, 1)` and zero outside, so gross outliers are
+/// ignored entirely. `c = 4.685` gives 95% efficiency at the normal. If the MAD is zero the
+/// median is returned.
+///
+/// Returns NaN for empty input, any NaN element, or non-positive `c`.
+pub fn biweight_location(data: &[f64], c: f64) -> f64 {
+    if c.is_nan() || c <= 0.0 {
+        return f64::NAN;
+    }
+    let Some((mut mu, deviation)) = median_and_meddev(data) else {
+        return f64::NAN;
+    };
+    let s = MAD_CONSISTENCY * deviation;
+    if s == 0.0 {
+        return mu;
+    }
+    let cutoff = c * s;
+    for _ in 0..1000 {
+        let (mut weighted, mut total) = (0.0, 0.0);
+        for &v in data {
+            let u = (v - mu) / cutoff;
+            if u.abs() < 1.0 {
+                let w = (1.0 - u * u) * (1.0 - u * u);
+                weighted += w * v;
+                total += w;
+            }
+        }
+        if total == 0.0 {
+            break;
+        }
+        let next = weighted / total;
+        let done = (next - mu).abs() < 1e-10 * s;
+        mu = next;
+        if done {
+            break;
+        }
+    }
+    mu
+}
+
+// =============================================================================
+// Hodges-Lehmann
+// =============================================================================
+
+/// Hodges-Lehmann estimator of location: the median of the `n (n + 1) / 2` Walsh averages
+/// `(x_i + x_j) / 2`, `i <= j`, the estimate behind the one-sample Wilcoxon signed-rank test.
+///
+/// It has a 29% breakdown point and 95% Gaussian efficiency. The median is found without
+/// forming the averages, in `O(n log^2 n)` time and `O(n)` memory.
+///
+/// Returns NaN for empty input or any NaN element.
+pub fn hodges_lehmann(data: &[f64]) -> f64 {
+    let Some(x) = sorted_copy(data) else {
+        return f64::NAN;
+    };
+    let n = x.len();
+    let sum = |k| kth_pairwise(n, n, |i| i, |i, j| x[i] + x[j], k);
+    let m = n * (n + 1) / 2;
+    if m % 2 == 1 {
+        sum(m / 2 + 1) / 2.0
+    } else {
+        (sum(m / 2) + sum(m / 2 + 1)) / 4.0
+    }
+}
+
+/// Two-sample Hodges-Lehmann shift estimator: the median of the `n_x * n_y` differences
+/// `x_i - y_j`, the estimate behind the Wilcoxon rank-sum (Mann-Whitney) test.
+///
+/// Found without forming the differences, in `O(n_x)` memory.
+///
+/// Returns NaN if either sample is empty or holds a NaN.
+pub fn hodges_lehmann_shift(x: &[f64], y: &[f64]) -> f64 {
+    let (Some(x), Some(mut y)) = (sorted_copy(x), sorted_copy(y)) else {
+        return f64::NAN;
+    };
+    // Descending y makes every row x_i - y_j non-decreasing in j.
+    y.reverse();
+    let (nx, ny) = (x.len(), y.len());
+    let diff = |k| kth_pairwise(nx, ny, |_| 0, |i, j| x[i] - y[j], k);
+    let m = nx * ny;
+    if m % 2 == 1 {
+        diff(m / 2 + 1)
+    } else {
+        (diff(m / 2) + diff(m / 2 + 1)) / 2.0
+    }
+}
+
+// =============================================================================
+// Order Statistics of Implicit Sets
+// =============================================================================
+
+/// Internal: `k`-th smallest (1-based) entry of an implicit matrix with `rows` rows whose row
+/// `i` holds `value(i, j)` for `j` in `first(i)..cols`, non-decreasing in `j`.
+///
+/// Each round probes the weighted median of the row midpoints (weighted by the rows'
+/// remaining lengths), counts the entries below and at the probe by binary search, and keeps
+/// only the side holding the answer. At least a quarter of the candidates go each round, so
+/// this runs in `O(rows log^2)` time and `O(rows)` memory (Johnson and Mizoguchi, 1978).
+fn kth_pairwise<F, V>(rows: usize, cols: usize, first: F, value: V, k: usize) -> f64
+where
+    F: Fn(usize) -> usize,
+    V: Fn(usize, usize) -> f64,
+{
+    let mut lo: Vec<usize> = (0..rows).map(|i| first(i).min(cols)).collect();
+    let mut hi = vec![cols; rows];
+    let mut k = k;
+    let mut probes: Vec<(f64, usize)> = Vec::with_capacity(rows);
+    let mut below = vec![0usize; rows];
+    let mut at_most = vec![0usize; rows];
+    loop {
+        let remaining: usize = lo.iter().zip(&hi).map(|(l, h)| h - l).sum();
+        if remaining <= rows.max(32) {
+            let mut rest: Vec<f64> = (0..rows)
+                .flat_map(|i| (lo[i]..hi[i]).map(move |j| (i, j)))
+                .map(|(i, j)| value(i, j))
+                .collect();
+            return select_kth(&mut rest, k - 1);
+        }
+
+        probes.clear();
+        probes.extend(
+            (0..rows)
+                .filter(|&i| lo[i] < hi[i])
+                .map(|i| (value(i, (lo[i] + hi[i]) / 2), hi[i] - lo[i])),
+        );
+        probes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut cumulative = 0;
+        let trial = probes
+            .iter()
+            .find(|&&(_, w)| {
+                cumulative += w;
+                2 * cumulative >= remaining
+            })
+            .unwrap()
+            .0;
+
+        for i in 0..rows {
+            below[i] = count_while(lo[i], hi[i], |j| value(i, j) < trial);
+            at_most[i] = count_while(lo[i], hi[i], |j| value(i, j) <= trial);
+        }
+        let (n_below, n_at_most): (usize, usize) = (below.iter().sum(), at_most.iter().sum());
+        if k <= n_below {
+            for i in 0..rows {
+                hi[i] = lo[i] + below[i];
+            }
+        } else if k > n_at_most {
+            k -= n_at_most;
+            for i in 0..rows {
+                lo[i] += at_most[i];
+            }
+        } else {
+            return trial;
+        }
+    }
+}
+
+/// Internal: number of leading indices in `lo..hi` satisfying `pred`, which must hold for a
+/// prefix of the range.
+fn count_while<P: Fn(usize) -> bool>(lo: usize, hi: usize, pred: P) -> usize {
+    let (mut a, mut b) = (lo, hi);
+    while a < b {
+        let mid = a + (b - a) / 2;
+        if pred(mid) {
+            a = mid + 1;
+        } else {
+            b = mid;
+        }
+    }
+    a - lo
+}
+
+/// Internal: `k`-th smallest (1-based, `1 <= k <= a_len + b_len`) of the union of two
+/// ascending runs given by index functions, by binary search on the split.
+fn kth_of_two_sorted<A, B>(a_len: usize, a: A, b_len: usize, b: B, k: usize) -> f64
+where
+    A: Fn(usize) -> f64,
+    B: Fn(usize) -> f64,
+{
+    // Find how many of the k smallest come from `a`.
+    let (mut lo, mut hi) = (k.saturating_sub(b_len), k.min(a_len));
+    while lo < hi {
+        let from_a = lo + (hi - lo) / 2;
+        if a(from_a) < b(k - from_a - 1) {
+            lo = from_a + 1;
+        } else {
+            hi = from_a;
+        }
+    }
+    let from_a = lo;
+    let last_a = if from_a > 0 {
+        a(from_a - 1)
+    } else {
+        f64::NEG_INFINITY
+    };
+    let last_b = if from_a < k {
+        b(k - from_a - 1)
+    } else {
+        f64::NEG_INFINITY
+    };
+    last_a.max(last_b)
+}
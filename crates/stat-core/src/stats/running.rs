@@ -0,0 +1,262 @@
+use crate::stats::basic::mean;
+use crate::stats::simd::SimdAccum4;
+use wide::f64x4;
+
+// =============================================================================
+// Streaming Moments
+// =============================================================================
+
+/// Online accumulator for the mean, variance, skewness and kurtosis of a stream.
+///
+/// Values can be pushed one at a time ([`push`](Self::push)) or in slices
+/// ([`push_slice`](Self::push_slice)), and two accumulators can be combined with
+/// [`merge`](Self::merge). Updates use the Welford/Terriberry recurrences and the pairwise
+/// formulas of Pébay (2008), so merging partial results from different chunks gives the
+/// same moments as accumulating the concatenated data.
+///
+/// The statistics follow the same conventions as the slice functions: [`variance`](Self::variance)
+/// is the population variance, [`skewness`](Self::skewness) is `m3 / m2^(3/2)` and
+/// [`kurtosis`](Self::kurtosis) is the excess kurtosis `m4 / m2^2 - 3`.
+///
+/// # Example
+/// ```rust
+/// use stat_core::RunningMoments;
+///
+/// let mut left = RunningMoments::new();
+/// left.push_slice(&[1.0, 2.0, 3.0]);
+/// let mut right = RunningMoments::new();
+/// right.push(4.0);
+/// right.push(5.0);
+///
+/// left.merge(&right);
+/// assert_eq!(left.count(), 5);
+/// assert!((left.mean() - 3.0).abs() < 1e-12);
+/// assert!((left.variance() - 2.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RunningMoments {
+    n: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl RunningMoments {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an accumulator from a full slice.
+    pub fn from_slice(data: &[f64]) -> Self {
+        let mut acc = Self::new();
+        acc.push_slice(data);
+        acc
+    }
+
+    /// Reset the accumulator to the empty state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Add a single observation.
+    #[inline]
+    pub fn push(&mut self, x: f64) {
+        let n1 = self.n as f64;
+        self.n += 1;
+        let n = self.n as f64;
+
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    /// Add all observations in a slice.
+    ///
+    /// The slice is reduced with a SIMD two-pass kernel and then merged into the accumulator,
+    /// which is both faster and more accurate than pushing the values one by one.
+    pub fn push_slice(&mut self, data: &[f64]) {
+        if data.is_empty() {
+            return;
+        }
+        let chunk_mean = mean(data);
+        let (m2, m3, m4) = central_moment_sums(data, chunk_mean);
+        self.merge(&RunningMoments {
+            n: data.len() as u64,
+            mean: chunk_mean,
+            m2,
+            m3,
+            m4,
+        });
+    }
+
+    /// Combine another accumulator into this one.
+    pub fn merge(&mut self, other: &RunningMoments) {
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = *other;
+            return;
+        }
+
+        let na = self.n as f64;
+        let nb = other.n as f64;
+        let n = na + nb;
+
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let delta3 = delta2 * delta;
+        let delta4 = delta2 * delta2;
+
+        let mean = self.mean + delta * nb / n;
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta3 * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+
+        self.n += other.n;
+        self.mean = mean;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+
+    /// Number of observations seen so far.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.n
+    }
+
+    /// Mean of the observations. Returns NaN when empty.
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        if self.n == 0 {
+            f64::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// Population variance. Returns NaN when empty.
+    pub fn variance(&self) -> f64 {
+        if self.n == 0 {
+            return f64::NAN;
+        }
+        self.m2 / self.n as f64
+    }
+
+    /// Sample variance (Bessel's correction). Returns NaN for fewer than 2 observations.
+    pub fn sample_variance(&self) -> f64 {
+        if self.n < 2 {
+            return f64::NAN;
+        }
+        self.m2 / (self.n as f64 - 1.0)
+    }
+
+    /// Population standard deviation.
+    pub fn stdev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Sample standard deviation.
+    pub fn sample_stdev(&self) -> f64 {
+        self.sample_variance().sqrt()
+    }
+
+    /// Population skewness `m3 / m2^(3/2)` (requires at least 3 observations).
+    pub fn skewness(&self) -> f64 {
+        if self.n < 3 || self.m2 == 0.0 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        let m2 = self.m2 / n;
+        let m3 = self.m3 / n;
+        m3 / m2.sqrt().powi(3)
+    }
+
+    /// Excess kurtosis `m4 / m2^2 - 3` (requires at least 4 observations).
+    pub fn kurtosis(&self) -> f64 {
+        if self.n < 4 || self.m2 == 0.0 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        let m2 = self.m2 / n;
+        let m4 = self.m4 / n;
+        m4 / (m2 * m2) - 3.0
+    }
+}
+
+/// Internal: sums of 2nd, 3rd and 4th powers of deviations from `m` in one SIMD pass.
+fn central_moment_sums(data: &[f64], m: f64) -> (f64, f64, f64) {
+    let mean_vec = f64x4::splat(m);
+
+    let mut m2_acc = SimdAccum4::zero();
+    let mut m3_acc = SimdAccum4::zero();
+    let mut m4_acc = SimdAccum4::zero();
+    let mut m2_tail = 0.0;
+    let mut m3_tail = 0.0;
+    let mut m4_tail = 0.0;
+
+    simd_for_each_unrolled4_f64x4!(
+        data,
+        |base| {
+            let d1 = base.read_unaligned() - mean_vec;
+            let d2 = base.add(1).read_unaligned() - mean_vec;
+            let d3 = base.add(2).read_unaligned() - mean_vec;
+            let d4 = base.add(3).read_unaligned() - mean_vec;
+
+            let d1_sq = d1 * d1;
+            let d2_sq = d2 * d2;
+            let d3_sq = d3 * d3;
+            let d4_sq = d4 * d4;
+
+            m2_acc.v1 += d1_sq;
+            m3_acc.v1 += d1_sq * d1;
+            m4_acc.v1 += d1_sq * d1_sq;
+            m2_acc.v2 += d2_sq;
+            m3_acc.v2 += d2_sq * d2;
+            m4_acc.v2 += d2_sq * d2_sq;
+            m2_acc.v3 += d3_sq;
+            m3_acc.v3 += d3_sq * d3;
+            m4_acc.v3 += d3_sq * d3_sq;
+            m2_acc.v4 += d4_sq;
+            m3_acc.v4 += d4_sq * d4;
+            m4_acc.v4 += d4_sq * d4_sq;
+        },
+        |chunk| {
+            let diff = chunk - mean_vec;
+            let diff_sq = diff * diff;
+            m2_acc.v1 += diff_sq;
+            m3_acc.v1 += diff_sq * diff;
+            m4_acc.v1 += diff_sq * diff_sq;
+        },
+        |value| {
+            let diff = value - m;
+            let diff_sq = diff * diff;
+            m2_tail += diff_sq;
+            m3_tail += diff_sq * diff;
+            m4_tail += diff_sq * diff_sq;
+        }
+    );
+
+    (
+        m2_acc.reduce_add() + m2_tail,
+        m3_acc.reduce_add() + m3_tail,
+        m4_acc.reduce_add() + m4_tail,
+    )
+}
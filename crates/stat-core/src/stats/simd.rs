@@ -1,4 +1,4 @@
-use wide::f64x4;
+use wide::{f32x8, f64x4};
 
 // =============================================================================
 // SIMD Infrastructure
@@ -57,6 +57,62 @@ pub(crate) fn simd_reduce_max(vec: f64x4) -> f64 {
     arr[0].max(arr[1]).max(arr[2].max(arr[3]))
 }
 
+/// Number of unrolled `f32x8` iterations accumulated in f32 before flushing into f64.
+///
+/// Each lane sees at most this many additions per block, which keeps f32 rounding error
+/// bounded regardless of input length.
+pub(crate) const F32_FLUSH_BLOCK: usize = 64;
+
+/// SIMD loop state for f32 input with 4 `f32x8` accumulators.
+#[derive(Clone, Copy)]
+pub(crate) struct SimdAccum4F32 {
+    pub(crate) v1: f32x8,
+    pub(crate) v2: f32x8,
+    pub(crate) v3: f32x8,
+    pub(crate) v4: f32x8,
+}
+
+impl SimdAccum4F32 {
+    #[inline(always)]
+    pub(crate) fn new(init: f32x8) -> Self {
+        Self {
+            v1: init,
+            v2: init,
+            v3: init,
+            v4: init,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn zero() -> Self {
+        Self::new(f32x8::ZERO)
+    }
+
+    /// Sum all lanes in f64 and reset the accumulators to zero.
+    #[inline(always)]
+    pub(crate) fn flush_add(&mut self) -> f64 {
+        let lanes = [self.v1, self.v2, self.v3, self.v4];
+        *self = Self::zero();
+        lanes
+            .iter()
+            .flat_map(|v| v.to_array())
+            .map(|v| v as f64)
+            .sum()
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduce_min(self) -> f32 {
+        let arr = self.v1.min(self.v2).min(self.v3.min(self.v4)).to_array();
+        arr.iter().fold(f32::INFINITY, |m, &v| m.min(v))
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduce_max(self) -> f32 {
+        let arr = self.v1.max(self.v2).max(self.v3.max(self.v4)).to_array();
+        arr.iter().fold(f32::NEG_INFINITY, |m, &v| m.max(v))
+    }
+}
+
 /// Zero-overhead SIMD reduction macro.
 /// Generates inline code for 4x unrolled SIMD loops.
 macro_rules! simd_reduce {
@@ -245,3 +301,115 @@ macro_rules! simd_for_each_unrolled4_f64x4_zip {
         }
     }};
 }
+
+/// Iterate three equally-sized `&[f64]` slices as zipped `f64x4` with 4× unrolling and a scalar tail.
+macro_rules! simd_for_each_unrolled4_f64x4_zip3 {
+    ($x:expr, $y:expr, $z:expr, |$bx:ident, $by:ident, $bz:ident| $unrolled_body:expr, |$xv:ident, $yv:ident, $zv:ident| $chunk_body:expr, |$xs:ident, $ys:ident, $zs:ident| $tail_body:expr) => {{
+        let x: &[f64] = $x;
+        let y: &[f64] = $y;
+        let z: &[f64] = $z;
+        let len = x.len();
+        let chunks = len / 4;
+        let unrolled = chunks / 4;
+
+        unsafe {
+            let x_ptr = x.as_ptr() as *const f64x4;
+            let y_ptr = y.as_ptr() as *const f64x4;
+            let z_ptr = z.as_ptr() as *const f64x4;
+
+            for i in 0..unrolled {
+                let $bx = x_ptr.add(i * 4);
+                let $by = y_ptr.add(i * 4);
+                let $bz = z_ptr.add(i * 4);
+                $unrolled_body;
+            }
+
+            for i in (unrolled * 4)..chunks {
+                let $xv = x_ptr.add(i).read_unaligned();
+                let $yv = y_ptr.add(i).read_unaligned();
+                let $zv = z_ptr.add(i).read_unaligned();
+                $chunk_body;
+            }
+        }
+
+        for i in (chunks * 4)..len {
+            let $xs = x[i];
+            let $ys = y[i];
+            let $zs = z[i];
+            $tail_body;
+        }
+    }};
+}
+
+/// Iterate a `&[f32]` as `f32x8` with 4× unrolling and a scalar tail.
+///
+/// `$flush_body` runs after every [`F32_FLUSH_BLOCK`] unrolled iterations and once more
+/// after the SIMD loops, so callers can fold their f32 lane accumulators into f64 totals.
+macro_rules! simd_for_each_unrolled4_f32x8 {
+    ($data:expr, |$base:ident| $unrolled_body:expr, |$chunk:ident| $chunk_body:expr, || $flush_body:expr, |$val:ident| $tail_body:expr) => {{
+        let data: &[f32] = $data;
+        let len = data.len();
+        let chunks = len / 8;
+        let unrolled = chunks / 4;
+
+        unsafe {
+            let ptr = data.as_ptr() as *const f32x8;
+
+            for block in (0..unrolled).step_by(F32_FLUSH_BLOCK) {
+                for i in block..(block + F32_FLUSH_BLOCK).min(unrolled) {
+                    let $base = ptr.add(i * 4);
+                    $unrolled_body;
+                }
+                $flush_body;
+            }
+
+            for i in (unrolled * 4)..chunks {
+                let $chunk = ptr.add(i).read_unaligned();
+                $chunk_body;
+            }
+        }
+        $flush_body;
+
+        for &$val in &data[(chunks * 8)..] {
+            $tail_body;
+        }
+    }};
+}
+
+/// Iterate two equally-sized `&[f32]` slices as zipped `f32x8`; see [`simd_for_each_unrolled4_f32x8`].
+macro_rules! simd_for_each_unrolled4_f32x8_zip {
+    ($x:expr, $y:expr, |$bx:ident, $by:ident| $unrolled_body:expr, |$xv:ident, $yv:ident| $chunk_body:expr, || $flush_body:expr, |$xs:ident, $ys:ident| $tail_body:expr) => {{
+        let x: &[f32] = $x;
+        let y: &[f32] = $y;
+        let len = x.len();
+        let chunks = len / 8;
+        let unrolled = chunks / 4;
+
+        unsafe {
+            let x_ptr = x.as_ptr() as *const f32x8;
+            let y_ptr = y.as_ptr() as *const f32x8;
+
+            for block in (0..unrolled).step_by(F32_FLUSH_BLOCK) {
+                for i in block..(block + F32_FLUSH_BLOCK).min(unrolled) {
+                    let $bx = x_ptr.add(i * 4);
+                    let $by = y_ptr.add(i * 4);
+                    $unrolled_body;
+                }
+                $flush_body;
+            }
+
+            for i in (unrolled * 4)..chunks {
+                let $xv = x_ptr.add(i).read_unaligned();
+                let $yv = y_ptr.add(i).read_unaligned();
+                $chunk_body;
+            }
+        }
+        $flush_body;
+
+        for i in (chunks * 8)..len {
+            let $xs = x[i];
+            let $ys = y[i];
+            $tail_body;
+        }
+    }};
+}
@@ -1,4 +1,6 @@
-use crate::stats::basic::{mean, sample_stdev, sum, sum_squared_deviations};
+use crate::stats::basic::{
+    mean, pooledvariance, sample_stdev, sample_variance, sum, sum_squared_deviations,
+};
 use std::collections::HashMap;
 
 #[inline(always)]
@@ -7,6 +9,7 @@ fn test_result_nan(df: Option<f64>) -> TestResult {
         statistic: f64::NAN,
         p_value: f64::NAN,
         df,
+        ci: None,
     }
 }
 
@@ -124,13 +127,119 @@ pub struct TestResult {
     pub statistic: f64,
     pub p_value: f64,
     pub df: Option<f64>,
+    /// Confidence interval for the tested quantity (e.g. the mean difference), when the test
+    /// provides one. One-sided alternatives give a half-infinite interval.
+    pub ci: Option<[f64; 2]>,
+}
+
+/// Alternative hypothesis for a test.
+///
+/// - `TwoSided`: the parameter differs from its null value.
+/// - `Less`: the parameter is smaller than its null value.
+/// - `Greater`: the parameter is larger than its null value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alternative {
+    #[default]
+    TwoSided,
+    Less,
+    Greater,
+}
+
+/// Internal: p-value of a t statistic under the given alternative.
+///
+/// Tail probabilities are taken from the lower tail (`cdf(-|t|)`) so small p-values keep
+/// full precision instead of suffering cancellation in `1 - cdf`.
+pub(crate) fn t_p_value(t: f64, df: f64, alternative: Alternative) -> f64 {
+    let cdf = |x: f64| crate::distributions::student_t_cdf(x, 0.0, 1.0, df).unwrap_or(f64::NAN);
+    match alternative {
+        Alternative::TwoSided => (2.0 * cdf(-t.abs())).min(1.0),
+        Alternative::Less => cdf(t),
+        Alternative::Greater => cdf(-t),
+    }
+}
+
+/// Internal: p-value of a standard-normal statistic under the given alternative.
+pub(crate) fn z_p_value(z: f64, alternative: Alternative) -> f64 {
+    let cdf = |x: f64| crate::distributions::normal_cdf(x, 0.0, 1.0).unwrap_or(f64::NAN);
+    match alternative {
+        Alternative::TwoSided => (2.0 * cdf(-z.abs())).min(1.0),
+        Alternative::Less => cdf(z),
+        Alternative::Greater => cdf(-z),
+    }
+}
+
+/// Internal: quantile used for a `1 - alpha` interval under the given alternative.
+#[inline]
+fn interval_quantile(alpha: f64, alternative: Alternative) -> f64 {
+    match alternative {
+        Alternative::TwoSided => 1.0 - alpha / 2.0,
+        Alternative::Less | Alternative::Greater => 1.0 - alpha,
+    }
+}
+
+/// Internal: turn a critical-value margin into a (possibly half-infinite) interval.
+#[inline]
+fn interval_bounds(estimate: f64, margin: f64, alternative: Alternative) -> [f64; 2] {
+    match alternative {
+        Alternative::TwoSided => [estimate - margin, estimate + margin],
+        Alternative::Less => [f64::NEG_INFINITY, estimate + margin],
+        Alternative::Greater => [estimate - margin, f64::INFINITY],
+    }
+}
+
+/// Internal: confidence interval `estimate ± t_crit * se` for the given alternative.
+pub(crate) fn t_interval(
+    estimate: f64,
+    se: f64,
+    df: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> [f64; 2] {
+    if alpha <= 0.0 || alpha >= 1.0 || se.is_nan() || estimate.is_nan() {
+        return [f64::NAN, f64::NAN];
+    }
+    let q = interval_quantile(alpha, alternative);
+    let t = match crate::distributions::student_t_inv(q, 0.0, 1.0, df) {
+        Ok(t) => t,
+        Err(_) => return [f64::NAN, f64::NAN],
+    };
+    interval_bounds(estimate, t * se, alternative)
+}
+
+/// Internal: assemble a t-test result from an estimate, its standard error and df.
+fn t_test_result(
+    estimate: f64,
+    se: f64,
+    df: f64,
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if se == 0.0 || se.is_nan() || df.is_nan() || df <= 0.0 {
+        return test_result_nan(Some(df));
+    }
+    let t_stat = estimate / se;
+    TestResult {
+        statistic: t_stat,
+        p_value: t_p_value(t_stat, df, alternative),
+        df: Some(df),
+        ci: Some(t_interval(estimate, se, df, alpha, alternative)),
+    }
 }
 
 /// One-sample t-test: tests if sample mean equals a hypothesized value
 ///
 /// Returns a TestResult with t-statistic, p-value, and degrees of freedom.
-/// The p-value is two-tailed.
+/// The p-value is two-tailed; see [`ttest_with_alternative`] for one-sided tests.
 pub fn ttest(data: &[f64], mu0: f64) -> TestResult {
+    ttest_with_alternative(data, mu0, Alternative::TwoSided)
+}
+
+/// One-sample t-test with a selectable alternative hypothesis.
+///
+/// `Alternative::Less` tests `mean < mu0` and `Alternative::Greater` tests `mean > mu0`;
+/// one-sided p-values are taken from the corresponding tail, so a statistic with the
+/// "wrong" sign yields a p-value above 0.5 rather than half the two-sided value.
+pub fn ttest_with_alternative(data: &[f64], mu0: f64, alternative: Alternative) -> TestResult {
     if data.len() < 2 {
         return test_result_nan(None);
     }
@@ -138,30 +247,20 @@ pub fn ttest(data: &[f64], mu0: f64) -> TestResult {
     let n = data.len() as f64;
     let sample_mean = mean(data);
     let sample_stdev = sample_stdev(data);
+    let df = n - 1.0;
 
     if sample_stdev == 0.0 || sample_stdev.is_nan() {
-        return TestResult {
-            statistic: f64::NAN,
-            p_value: f64::NAN,
-            df: Some(n - 1.0),
-        };
+        return test_result_nan(Some(df));
     }
 
     let se = sample_stdev / n.sqrt();
     let t_stat = (sample_mean - mu0) / se;
-    let df = n - 1.0;
-
-    // Two-tailed p-value using Student's t-distribution
-    // We need to use the distributions module for the CDF
-    let p_value = match crate::distributions::student_t_cdf(t_stat.abs(), 0.0, 1.0, df) {
-        Ok(cdf) => 2.0 * (1.0 - cdf),
-        Err(_) => f64::NAN,
-    };
 
     TestResult {
         statistic: t_stat,
-        p_value,
+        p_value: t_p_value(t_stat, df, alternative),
         df: Some(df),
+        ci: None,
     }
 }
 
@@ -176,11 +275,36 @@ pub fn ttest_strict(data: &[f64], mu0: f64) -> TestResult {
     ttest(data, mu0)
 }
 
+/// Strict version of [`ttest_with_alternative`] that explicitly rejects `NaN` inputs.
+pub fn ttest_with_alternative_strict(
+    data: &[f64],
+    mu0: f64,
+    alternative: Alternative,
+) -> TestResult {
+    if data.len() < 2 {
+        return test_result_nan(None);
+    }
+    if any_nan(data) {
+        return test_result_nan(Some(data.len() as f64 - 1.0));
+    }
+    ttest_with_alternative(data, mu0, alternative)
+}
+
 /// One-sample z-test: tests if sample mean equals a hypothesized value with known population standard deviation
 ///
 /// Returns a TestResult with z-statistic and p-value.
-/// The p-value is two-tailed.
+/// The p-value is two-tailed; see [`ztest_with_alternative`] for one-sided tests.
 pub fn ztest(data: &[f64], mu0: f64, sigma: f64) -> TestResult {
+    ztest_with_alternative(data, mu0, sigma, Alternative::TwoSided)
+}
+
+/// One-sample z-test with a selectable alternative hypothesis.
+pub fn ztest_with_alternative(
+    data: &[f64],
+    mu0: f64,
+    sigma: f64,
+    alternative: Alternative,
+) -> TestResult {
     if data.is_empty() || sigma <= 0.0 || sigma.is_nan() {
         return test_result_nan(None);
     }
@@ -191,16 +315,11 @@ pub fn ztest(data: &[f64], mu0: f64, sigma: f64) -> TestResult {
     let se = sigma / n.sqrt();
     let z_stat = (sample_mean - mu0) / se;
 
-    // Two-tailed p-value using standard normal distribution
-    let p_value = match crate::distributions::normal_cdf(z_stat.abs(), 0.0, 1.0) {
-        Ok(cdf) => 2.0 * (1.0 - cdf),
-        Err(_) => f64::NAN,
-    };
-
     TestResult {
         statistic: z_stat,
-        p_value,
+        p_value: z_p_value(z_stat, alternative),
         df: None,
+        ci: None,
     }
 }
 
@@ -215,6 +334,134 @@ pub fn ztest_strict(data: &[f64], mu0: f64, sigma: f64) -> TestResult {
     ztest(data, mu0, sigma)
 }
 
+/// Strict version of [`ztest_with_alternative`] that explicitly rejects `NaN` inputs.
+pub fn ztest_with_alternative_strict(
+    data: &[f64],
+    mu0: f64,
+    sigma: f64,
+    alternative: Alternative,
+) -> TestResult {
+    if data.is_empty() || sigma <= 0.0 || sigma.is_nan() || any_nan(data) {
+        return test_result_nan(None);
+    }
+    ztest_with_alternative(data, mu0, sigma, alternative)
+}
+
+/// Independent two-sample t-test assuming equal variances (Student).
+///
+/// Tests whether the mean of `data1` differs from the mean of `data2`, using the pooled
+/// variance (see [`pooledvariance`](crate::pooledvariance)) and `n1 + n2 - 2` degrees of freedom.
+///
+/// `alternative` selects the direction of the test on `mean1 - mean2`, and `alpha` sets the
+/// confidence level (`1 - alpha`) of the interval returned in `ci` for the mean difference.
+pub fn ttest_ind(data1: &[f64], data2: &[f64], alternative: Alternative, alpha: f64) -> TestResult {
+    if data1.len() < 2 || data2.len() < 2 {
+        return test_result_nan(None);
+    }
+    let n1 = data1.len() as f64;
+    let n2 = data2.len() as f64;
+    let df = n1 + n2 - 2.0;
+    let pooled = pooledvariance(data1, data2);
+    let se = (pooled * (1.0 / n1 + 1.0 / n2)).sqrt();
+    t_test_result(mean(data1) - mean(data2), se, df, alternative, alpha)
+}
+
+/// Strict version of [`ttest_ind`] that explicitly rejects `NaN` inputs.
+pub fn ttest_ind_strict(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if data1.len() < 2 || data2.len() < 2 {
+        return test_result_nan(None);
+    }
+    if any_nan(data1) || any_nan(data2) {
+        return test_result_nan(Some((data1.len() + data2.len()) as f64 - 2.0));
+    }
+    ttest_ind(data1, data2, alternative, alpha)
+}
+
+/// Welch's unequal-variances two-sample t-test.
+///
+/// Uses the Welch–Satterthwaite approximation for the degrees of freedom, which are
+/// generally non-integer. See [`ttest_ind`] for the meaning of `alternative` and `alpha`.
+pub fn ttest_welch(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if data1.len() < 2 || data2.len() < 2 {
+        return test_result_nan(None);
+    }
+    let n1 = data1.len() as f64;
+    let n2 = data2.len() as f64;
+    let v1 = sample_variance(data1) / n1;
+    let v2 = sample_variance(data2) / n2;
+    let se2 = v1 + v2;
+    let df = se2 * se2 / (v1 * v1 / (n1 - 1.0) + v2 * v2 / (n2 - 1.0));
+    t_test_result(
+        mean(data1) - mean(data2),
+        se2.sqrt(),
+        df,
+        alternative,
+        alpha,
+    )
+}
+
+/// Strict version of [`ttest_welch`] that explicitly rejects `NaN` inputs.
+pub fn ttest_welch_strict(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if data1.len() < 2 || data2.len() < 2 {
+        return test_result_nan(None);
+    }
+    if any_nan(data1) || any_nan(data2) {
+        return test_result_nan(None);
+    }
+    ttest_welch(data1, data2, alternative, alpha)
+}
+
+/// Paired-samples t-test.
+///
+/// Tests whether the mean of the pairwise differences `data1[i] - data2[i]` is zero.
+/// Both slices must have the same length (at least 2). See [`ttest_ind`] for the meaning of
+/// `alternative` and `alpha`.
+pub fn ttest_paired(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if data1.len() != data2.len() || data1.len() < 2 {
+        return test_result_nan(None);
+    }
+    let diffs: Vec<f64> = data1.iter().zip(data2).map(|(a, b)| a - b).collect();
+    let n = diffs.len() as f64;
+    let se = sample_stdev(&diffs) / n.sqrt();
+    t_test_result(mean(&diffs), se, n - 1.0, alternative, alpha)
+}
+
+/// Strict version of [`ttest_paired`] that explicitly rejects `NaN` inputs.
+pub fn ttest_paired_strict(
+    data1: &[f64],
+    data2: &[f64],
+    alternative: Alternative,
+    alpha: f64,
+) -> TestResult {
+    if data1.len() != data2.len() || data1.len() < 2 {
+        return test_result_nan(None);
+    }
+    if any_nan(data1) || any_nan(data2) {
+        return test_result_nan(Some(data1.len() as f64 - 1.0));
+    }
+    ttest_paired(data1, data2, alternative, alpha)
+}
+
 // =============================================================================
 // Confidence Intervals
 // =============================================================================
@@ -224,18 +471,30 @@ pub fn ztest_strict(data: &[f64], mu0: f64, sigma: f64) -> TestResult {
 /// Returns [lower, upper] bounds for a confidence interval.
 /// alpha is the significance level (e.g., 0.05 for 95% confidence).
 pub fn normalci(alpha: f64, mean: f64, se: f64) -> [f64; 2] {
+    normalci_with_alternative(alpha, mean, se, Alternative::TwoSided)
+}
+
+/// Normal distribution confidence interval with a selectable alternative.
+///
+/// `Alternative::Less` returns the one-sided upper bound `[-inf, mean + z(1-alpha)*se]`
+/// and `Alternative::Greater` the one-sided lower bound `[mean - z(1-alpha)*se, inf]`.
+pub fn normalci_with_alternative(
+    alpha: f64,
+    mean: f64,
+    se: f64,
+    alternative: Alternative,
+) -> [f64; 2] {
     if alpha <= 0.0 || alpha >= 1.0 || se <= 0.0 || se.is_nan() || mean.is_nan() {
         return [f64::NAN, f64::NAN];
     }
 
-    // z-score for (1 - alpha/2) quantile
-    let z = match crate::distributions::normal_inv(1.0 - alpha / 2.0, 0.0, 1.0) {
+    let z = match crate::distributions::normal_inv(interval_quantile(alpha, alternative), 0.0, 1.0)
+    {
         Ok(z) => z,
         Err(_) => return [f64::NAN, f64::NAN],
     };
 
-    let margin = z * se;
-    [mean - margin, mean + margin]
+    interval_bounds(mean, z * se, alternative)
 }
 
 /// t-distribution confidence interval
@@ -244,21 +503,24 @@ pub fn normalci(alpha: f64, mean: f64, se: f64) -> [f64; 2] {
 /// alpha is the significance level (e.g., 0.05 for 95% confidence).
 /// n is the sample size (for degrees of freedom).
 pub fn tci(alpha: f64, mean: f64, stdev: f64, n: f64) -> [f64; 2] {
+    tci_with_alternative(alpha, mean, stdev, n, Alternative::TwoSided)
+}
+
+/// t-distribution confidence interval with a selectable alternative.
+///
+/// One-sided alternatives return a half-infinite interval, as in [`normalci_with_alternative`].
+pub fn tci_with_alternative(
+    alpha: f64,
+    mean: f64,
+    stdev: f64,
+    n: f64,
+    alternative: Alternative,
+) -> [f64; 2] {
     if alpha <= 0.0 || alpha >= 1.0 || stdev <= 0.0 || stdev.is_nan() || mean.is_nan() || n < 2.0 {
         return [f64::NAN, f64::NAN];
     }
 
-    let df = n - 1.0;
-    let se = stdev / n.sqrt();
-
-    // t-score for (1 - alpha/2) quantile
-    let t = match crate::distributions::student_t_inv(1.0 - alpha / 2.0, 0.0, 1.0, df) {
-        Ok(t) => t,
-        Err(_) => return [f64::NAN, f64::NAN],
-    };
-
-    let margin = t * se;
-    [mean - margin, mean + margin]
+    t_interval(mean, stdev / n.sqrt(), n - 1.0, alpha, alternative)
 }
 
 /// Chi-square test result
@@ -269,6 +531,56 @@ pub struct ChiSquareResult {
     pub df: usize,
 }
 
+/// Statistic used by the chi-square family of tests.
+///
+/// `Yates` applies the continuity correction `|O - E| - min(0.5, |O - E|)` when the test has
+/// one degree of freedom (2x2 tables, two-category goodness of fit) and is identical to
+/// `Pearson` otherwise. `GTest` uses the likelihood-ratio statistic `G = 2 Σ O ln(O / E)`,
+/// which has the same limiting chi-square distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChiSquareMethod {
+    #[default]
+    Pearson,
+    Yates,
+    GTest,
+}
+
+#[inline(always)]
+fn chi_square_nan() -> ChiSquareResult {
+    ChiSquareResult {
+        statistic: f64::NAN,
+        p_value: f64::NAN,
+        df: 0,
+    }
+}
+
+/// Internal: contribution of one cell to the chi-square statistic.
+#[inline]
+fn chi_square_cell(observed: f64, expected: f64, method: ChiSquareMethod, df: usize) -> f64 {
+    match method {
+        ChiSquareMethod::GTest if observed > 0.0 => 2.0 * observed * (observed / expected).ln(),
+        ChiSquareMethod::GTest => 0.0,
+        ChiSquareMethod::Yates if df == 1 => {
+            let diff = (observed - expected).abs();
+            let corrected = diff - diff.min(0.5);
+            corrected * corrected / expected
+        }
+        _ => {
+            let diff = observed - expected;
+            diff * diff / expected
+        }
+    }
+}
+
+/// Internal: result from a finished statistic with an upper-tail p-value.
+fn chi_square_result(statistic: f64, df: usize) -> ChiSquareResult {
+    ChiSquareResult {
+        statistic,
+        p_value: crate::distributions::chi_squared_sf(statistic, df as f64).unwrap_or(f64::NAN),
+        df,
+    }
+}
+
 /// Chi-square test of independence for two categorical variables
 ///
 /// Tests whether two categorical variables are independent.
@@ -280,10 +592,30 @@ pub fn chi_square_test(cat1: &[String], cat2: &[String]) -> ChiSquareResult {
     chi_square_test_with_cardinality(cat1, cat2, None, None)
 }
 
+/// Chi-square test of independence with a selectable tail.
+///
+/// The chi-square statistic is non-negative, so `Alternative::TwoSided` and
+/// `Alternative::Greater` both use the usual upper-tail p-value (evidence of association),
+/// while `Alternative::Less` uses the lower tail, i.e. tests whether the observed table
+/// fits the independence model suspiciously well.
+pub fn chi_square_test_with_alternative(
+    cat1: &[String],
+    cat2: &[String],
+    alternative: Alternative,
+) -> ChiSquareResult {
+    let mut result = chi_square_test_with_cardinality(cat1, cat2, None, None);
+    if alternative == Alternative::Less && result.statistic.is_finite() {
+        result.p_value = crate::distributions::chi_squared_cdf(result.statistic, result.df as f64)
+            .unwrap_or(f64::NAN);
+    }
+    result
+}
+
 /// Chi-square test with optional cardinality hints for optimization
 ///
-/// If cardinalities are provided, uses a faster array-based algorithm.
-/// Otherwise falls back to the HashMap-based approach.
+/// The labels are interned into integer codes and the test runs on the resulting contingency
+/// table (see [`chi_square_test_codes`]). Cardinalities, when provided, only size the
+/// interning maps up front; a wrong hint costs a reallocation but not correctness.
 pub fn chi_square_test_with_cardinality(
     cat1: &[String],
     cat2: &[String],
@@ -291,141 +623,185 @@ pub fn chi_square_test_with_cardinality(
     cardinality2: Option<usize>,
 ) -> ChiSquareResult {
     if cat1.len() != cat2.len() || cat1.is_empty() {
-        return ChiSquareResult {
-            statistic: f64::NAN,
-            p_value: f64::NAN,
-            df: 0,
-        };
+        return chi_square_nan();
     }
 
-    let n = cat1.len() as f64;
-
-    // Use optimized path if both cardinalities are provided
-    if let (Some(num_rows), Some(num_cols)) = (cardinality1, cardinality2) {
-        if num_rows < 2 || num_cols < 2 {
-            return ChiSquareResult {
-                statistic: f64::NAN,
-                p_value: f64::NAN,
-                df: 0,
-            };
-        }
-
-        // Build category -> index mappings
-        let mut row_map: HashMap<String, usize> = HashMap::with_capacity(num_rows);
-        let mut col_map: HashMap<String, usize> = HashMap::with_capacity(num_cols);
-        let mut row_idx = 0;
-        let mut col_idx = 0;
-
-        // Pre-allocate contingency table
-        let mut table = vec![vec![0usize; num_cols]; num_rows];
-        let mut row_counts = vec![0usize; num_rows];
-        let mut col_counts = vec![0usize; num_cols];
-
-        // Build contingency table using array indices
-        for (c1, c2) in cat1.iter().zip(cat2.iter()) {
-            let r_idx = *row_map.entry(c1.clone()).or_insert_with(|| {
-                let idx = row_idx;
-                row_idx += 1;
-                idx
-            });
-            let c_idx = *col_map.entry(c2.clone()).or_insert_with(|| {
-                let idx = col_idx;
-                col_idx += 1;
-                idx
-            });
+    let intern = |labels: &[String], capacity: Option<usize>| -> Vec<u32> {
+        let mut map: HashMap<&str, u32> = HashMap::with_capacity(capacity.unwrap_or(16));
+        labels
+            .iter()
+            .map(|label| {
+                let next = map.len() as u32;
+                *map.entry(label.as_str()).or_insert(next)
+            })
+            .collect()
+    };
 
-            table[r_idx][c_idx] += 1;
-            row_counts[r_idx] += 1;
-            col_counts[c_idx] += 1;
-        }
+    chi_square_test_codes(
+        &intern(cat1, cardinality1),
+        &intern(cat2, cardinality2),
+        ChiSquareMethod::Pearson,
+    )
+}
 
-        // Verify we didn't exceed expected cardinalities
-        if row_idx > num_rows || col_idx > num_cols {
-            // Fall back to standard algorithm if cardinality was wrong
-            return chi_square_test_with_cardinality(cat1, cat2, None, None);
+/// Internal: map arbitrary `u32` codes onto `0..k` for the `k` distinct values present.
+///
+/// Small code ranges use a lookup table; sparse or very large codes fall back to a HashMap.
+fn compact_codes(codes: &[u32]) -> (Vec<usize>, usize) {
+    const DENSE_LIMIT: usize = 1 << 20;
+    let max = codes.iter().copied().max().unwrap_or(0) as usize;
+
+    if max < DENSE_LIMIT {
+        let mut index = vec![usize::MAX; max + 1];
+        for &c in codes {
+            index[c as usize] = 0;
         }
-
-        // Calculate chi-square statistic
-        let mut chi_sq = 0.0;
-        for r_idx in 0..num_rows {
-            for (c_idx, _) in col_counts.iter().enumerate().take(num_cols) {
-                let observed = table[r_idx][c_idx] as f64;
-                if observed > 0.0 {
-                    let row_total = row_counts[r_idx] as f64;
-                    let col_total = col_counts[c_idx] as f64;
-                    let expected = (row_total * col_total) / n;
-
-                    if expected > 0.0 {
-                        let diff = observed - expected;
-                        chi_sq += (diff * diff) / expected;
-                    }
-                }
-            }
+        let mut k = 0;
+        for slot in index.iter_mut().filter(|slot| **slot == 0) {
+            *slot = k;
+            k += 1;
         }
-
-        let df = (num_rows - 1) * (num_cols - 1);
-
-        // Calculate p-value using chi-squared distribution
-        let p_value = match crate::distributions::chi_squared_cdf(chi_sq, df as f64) {
-            Ok(cdf) => 1.0 - cdf,
-            Err(_) => f64::NAN,
-        };
-
-        return ChiSquareResult {
-            statistic: chi_sq,
-            p_value,
-            df,
-        };
+        (codes.iter().map(|&c| index[c as usize]).collect(), k)
+    } else {
+        let mut map: HashMap<u32, usize> = HashMap::new();
+        let compact = codes
+            .iter()
+            .map(|&c| {
+                let next = map.len();
+                *map.entry(c).or_insert(next)
+            })
+            .collect();
+        (compact, map.len())
     }
+}
 
-    // Fallback to HashMap-based algorithm
-    let mut table: HashMap<(String, String), usize> = HashMap::new();
-    let mut row_counts: HashMap<String, usize> = HashMap::new();
-    let mut col_counts: HashMap<String, usize> = HashMap::new();
-
-    for (c1, c2) in cat1.iter().zip(cat2.iter()) {
-        *table.entry((c1.clone(), c2.clone())).or_insert(0) += 1;
-        *row_counts.entry(c1.clone()).or_insert(0) += 1;
-        *col_counts.entry(c2.clone()).or_insert(0) += 1;
+/// Chi-square test of independence over integer category codes.
+///
+/// `codes1[i]` and `codes2[i]` are the categories of observation `i`. Codes need not be
+/// contiguous; only the categories that actually occur count towards the degrees of freedom.
+/// This avoids the per-element `String` allocation of [`chi_square_test`] for large columns.
+///
+/// Returns NaN (with `df = 0`) for empty or mismatched inputs, or when either variable has
+/// fewer than two observed categories.
+pub fn chi_square_test_codes(
+    codes1: &[u32],
+    codes2: &[u32],
+    method: ChiSquareMethod,
+) -> ChiSquareResult {
+    if codes1.len() != codes2.len() || codes1.is_empty() {
+        return chi_square_nan();
     }
-
-    let num_rows = row_counts.len();
-    let num_cols = col_counts.len();
-
+    let (rows, num_rows) = compact_codes(codes1);
+    let (cols, num_cols) = compact_codes(codes2);
     if num_rows < 2 || num_cols < 2 {
-        return ChiSquareResult {
-            statistic: f64::NAN,
-            p_value: f64::NAN,
-            df: 0,
-        };
+        return chi_square_nan();
     }
 
-    // Calculate chi-square statistic
-    let mut chi_sq = 0.0;
-    for ((r, c), observed) in table.iter() {
-        let row_total = *row_counts.get(r).unwrap_or(&0) as f64;
-        let col_total = *col_counts.get(c).unwrap_or(&0) as f64;
-        let expected = (row_total * col_total) / n;
+    let mut table = vec![0.0; num_rows * num_cols];
+    for (&r, &c) in rows.iter().zip(&cols) {
+        table[r * num_cols + c] += 1.0;
+    }
+    chi_square_test_table(&table, num_cols, method)
+}
+
+/// Chi-square test of independence on a precomputed `rows x n_cols` contingency table.
+///
+/// `table` holds the counts in row-major order; non-integer (e.g. weighted) counts are
+/// allowed. Rows and columns whose totals are zero are dropped before the expected counts
+/// and `df = (rows - 1)(n_cols - 1)` are computed. Every remaining cell, including cells with
+/// zero observations, contributes to the statistic.
+///
+/// Returns NaN (with `df = 0`) if the table shape is invalid, any count is negative or
+/// non-finite, or fewer than two non-empty rows or columns remain.
+pub fn chi_square_test_table(
+    table: &[f64],
+    n_cols: usize,
+    method: ChiSquareMethod,
+) -> ChiSquareResult {
+    if n_cols == 0
+        || table.is_empty()
+        || !table.len().is_multiple_of(n_cols)
+        || table.iter().any(|&v| !v.is_finite() || v < 0.0)
+    {
+        return chi_square_nan();
+    }
+    let n_rows = table.len() / n_cols;
+
+    let mut row_totals = vec![0.0; n_rows];
+    let mut col_totals = vec![0.0; n_cols];
+    for (i, row) in table.chunks_exact(n_cols).enumerate() {
+        for (j, &v) in row.iter().enumerate() {
+            row_totals[i] += v;
+            col_totals[j] += v;
+        }
+    }
+    let kept_rows = row_totals.iter().filter(|&&t| t > 0.0).count();
+    let kept_cols = col_totals.iter().filter(|&&t| t > 0.0).count();
+    if kept_rows < 2 || kept_cols < 2 {
+        return chi_square_nan();
+    }
 
-        if expected > 0.0 {
-            let diff = *observed as f64 - expected;
-            chi_sq += (diff * diff) / expected;
+    let total: f64 = row_totals.iter().sum();
+    let df = (kept_rows - 1) * (kept_cols - 1);
+    let mut statistic = 0.0;
+    for (row, &row_total) in table.chunks_exact(n_cols).zip(&row_totals) {
+        if row_total <= 0.0 {
+            continue;
+        }
+        for (&observed, &col_total) in row.iter().zip(&col_totals) {
+            if col_total > 0.0 {
+                let expected = row_total * col_total / total;
+                statistic += chi_square_cell(observed, expected, method, df);
+            }
         }
     }
 
-    let df = (num_rows - 1) * (num_cols - 1);
+    chi_square_result(statistic, df)
+}
+
+/// Chi-square goodness-of-fit test of observed counts against expected frequencies.
+///
+/// `expected` may hold counts or probabilities; it is rescaled to the observed total, as R's
+/// `chisq.test(x, p = ..., rescale.p = TRUE)` does. An empty `expected` tests equal
+/// frequencies. `df = k - 1` for `k` categories.
+///
+/// Returns NaN (with `df = 0`) for fewer than two categories, mismatched lengths, negative or
+/// non-finite observed counts, non-positive expected frequencies, or an all-zero sample.
+pub fn chi_square_goodness_of_fit(
+    observed: &[f64],
+    expected: &[f64],
+    method: ChiSquareMethod,
+) -> ChiSquareResult {
+    let k = observed.len();
+    if k < 2
+        || (!expected.is_empty() && expected.len() != k)
+        || observed.iter().any(|&v| !v.is_finite() || v < 0.0)
+        || expected.iter().any(|&v| !v.is_finite() || v <= 0.0)
+    {
+        return chi_square_nan();
+    }
+    let total: f64 = observed.iter().sum();
+    if total <= 0.0 {
+        return chi_square_nan();
+    }
 
-    // Calculate p-value using chi-squared distribution
-    let p_value = match crate::distributions::chi_squared_cdf(chi_sq, df as f64) {
-        Ok(cdf) => 1.0 - cdf,
-        Err(_) => f64::NAN,
+    let df = k - 1;
+    let statistic = if expected.is_empty() {
+        let e = total / k as f64;
+        observed
+            .iter()
+            .map(|&o| chi_square_cell(o, e, method, df))
+            .sum()
+    } else {
+        let scale = total / expected.iter().sum::<f64>();
+        observed
+            .iter()
+            .zip(expected)
+            .map(|(&o, &e)| chi_square_cell(o, e * scale, method, df))
+            .sum()
     };
 
-    ChiSquareResult {
-        statistic: chi_sq,
-        p_value,
-        df,
-    }
+    chi_square_result(statistic, df)
 }
 
 /// ANOVA with categorical grouping
@@ -476,9 +852,9 @@ pub struct TukeyPairResult {
     pub q_statistic: f64,
     /// P-value for the comparison
     pub p_value: f64,
-    /// 95% confidence interval lower bound
+    /// Confidence interval lower bound (at the result's `conf_level`)
     pub ci_lower: f64,
-    /// 95% confidence interval upper bound
+    /// Confidence interval upper bound (at the result's `conf_level`)
     pub ci_upper: f64,
 }
 
@@ -493,6 +869,8 @@ pub struct TukeyHsdResult {
     pub df_within: usize,
     /// Mean square within (MSW)
     pub msw: f64,
+    /// Confidence level of the pairwise intervals (e.g. 0.95)
+    pub conf_level: f64,
 }
 
 /// Tukey HSD (Honestly Significant Difference) post-hoc test.
@@ -522,6 +900,16 @@ pub struct TukeyHsdResult {
 /// }
 /// ```
 pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
+    tukey_hsd_with_confidence(groups, 0.95)
+}
+
+/// Tukey HSD post-hoc test with a configurable confidence level for the pairwise intervals.
+///
+/// P-values and critical values come from the studentized range distribution
+/// ([`studentized_range_cdf`](crate::studentized_range_cdf) /
+/// [`studentized_range_inv`](crate::studentized_range_inv)), matching R's `TukeyHSD`.
+/// An invalid `conf_level` (outside `(0, 1)`) yields NaN interval bounds.
+pub fn tukey_hsd_with_confidence(groups: &[&[f64]], conf_level: f64) -> TukeyHsdResult {
     let k = groups.len();
 
     if k < 2 {
@@ -530,6 +918,7 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
             num_groups: k,
             df_within: 0,
             msw: f64::NAN,
+            conf_level,
         };
     }
 
@@ -547,6 +936,7 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
                 num_groups: k,
                 df_within: 0,
                 msw: f64::NAN,
+                conf_level,
             };
         }
 
@@ -566,11 +956,18 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
             num_groups: k,
             df_within: 0,
             msw: f64::NAN,
+            conf_level,
         };
     }
 
     let msw = ssw / (df_within as f64);
     let root_msw = msw.sqrt();
+    let q_crit = if conf_level > 0.0 && conf_level < 1.0 {
+        crate::distributions::studentized_range_inv(conf_level, k as f64, df_within as f64)
+            .unwrap_or(f64::NAN)
+    } else {
+        f64::NAN
+    };
 
     // Generate all pairwise comparisons
     let mut comparisons = Vec::with_capacity(k * (k - 1) / 2);
@@ -596,13 +993,17 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
                 }
             } else {
                 let q = mean_diff.abs() / se;
-                let p_value = studentized_range_p_value(q, k, df_within);
+                let p_value = match crate::distributions::studentized_range_cdf(
+                    q,
+                    k as f64,
+                    df_within as f64,
+                ) {
+                    Ok(cdf) => (1.0 - cdf).clamp(0.0, 1.0),
+                    Err(_) => f64::NAN,
+                };
                 (q, p_value)
             };
 
-            // 95% confidence interval
-            // Critical q value for alpha=0.05 (approximated)
-            let q_crit = studentized_range_critical(0.05, k, df_within);
             let margin = q_crit * se;
             let ci_lower = mean_diff - margin;
             let ci_upper = mean_diff + margin;
@@ -624,6 +1025,7 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
         num_groups: k,
         df_within,
         msw,
+        conf_level,
     }
 }
 
@@ -639,12 +1041,22 @@ pub fn tukey_hsd(groups: &[&[f64]]) -> TukeyHsdResult {
 /// A `TukeyHsdResult` containing all pairwise comparisons.
 /// Group indices in results correspond to the order groups were first encountered.
 pub fn tukey_hsd_categorical(labels: &[String], values: &[f64]) -> TukeyHsdResult {
+    tukey_hsd_categorical_with_confidence(labels, values, 0.95)
+}
+
+/// Tukey HSD with categorical grouping and a configurable confidence level.
+pub fn tukey_hsd_categorical_with_confidence(
+    labels: &[String],
+    values: &[f64],
+    conf_level: f64,
+) -> TukeyHsdResult {
     if labels.len() != values.len() || labels.is_empty() {
         return TukeyHsdResult {
             comparisons: vec![],
             num_groups: 0,
             df_within: 0,
             msw: f64::NAN,
+            conf_level,
         };
     }
 
@@ -661,6 +1073,7 @@ pub fn tukey_hsd_categorical(labels: &[String], values: &[f64]) -> TukeyHsdResul
             num_groups,
             df_within: 0,
             msw: f64::NAN,
+            conf_level,
         };
     }
 
@@ -668,109 +1081,7 @@ pub fn tukey_hsd_categorical(labels: &[String], values: &[f64]) -> TukeyHsdResul
     let group_vecs: Vec<Vec<f64>> = grouped_values.into_values().collect();
     let group_refs: Vec<&[f64]> = group_vecs.iter().map(|v| v.as_slice()).collect();
 
-    tukey_hsd(&group_refs)
-}
-
-/// Approximate p-value from studentized range distribution.
-///
-/// Uses a combination of methods for accuracy across different parameter ranges:
-/// - For large df, uses normal approximation
-/// - For smaller df, uses relationship with t-distribution and Sidak correction
-fn studentized_range_p_value(q: f64, k: usize, df: usize) -> f64 {
-    if q.is_nan() {
-        return f64::NAN;
-    }
-    if q <= 0.0 {
-        return 1.0;
-    }
-    if q.is_infinite() {
-        return 0.0;
-    }
-
-    let k_f = k as f64;
-    let df_f = df as f64;
-
-    // For large degrees of freedom, use asymptotic normal approximation
-    // The studentized range approaches a scaled maximum of k standard normals
-    if df > 120 {
-        // Use the relationship: q ≈ t * sqrt(2) for two groups
-        // For k groups, we use a Bonferroni-like adjustment
-        let num_comparisons = k_f * (k_f - 1.0) / 2.0;
-
-        // Convert q to equivalent z-score and apply Sidak correction
-        let t = q / 2.0_f64.sqrt();
-        let single_p = 2.0 * (1.0 - normal_cdf_approx(t));
-
-        // Sidak correction: 1 - (1 - alpha)^m
-        let family_p = 1.0 - (1.0 - single_p).powf(num_comparisons);
-        return family_p.clamp(0.0, 1.0);
-    }
-
-    // For smaller df, use the relationship with Student's t
-    // q ≈ sqrt(2) * t, and adjust for multiple comparisons
-    let num_comparisons = k_f * (k_f - 1.0) / 2.0;
-
-    // Convert q to t-statistic
-    let t = q / 2.0_f64.sqrt();
-
-    // Get single comparison p-value from t-distribution
-    let single_p = match crate::distributions::student_t_cdf(t, 0.0, 1.0, df_f) {
-        Ok(cdf) => 2.0 * (1.0 - cdf),
-        Err(_) => 2.0 * (1.0 - normal_cdf_approx(t)),
-    };
-
-    // Apply Sidak correction for family-wise error rate
-    let family_p = 1.0 - (1.0 - single_p).powf(num_comparisons);
-    family_p.clamp(0.0, 1.0)
-}
-
-/// Approximate critical value from studentized range distribution.
-///
-/// Uses interpolation and approximation for the critical q value.
-fn studentized_range_critical(alpha: f64, k: usize, df: usize) -> f64 {
-    if alpha <= 0.0 || alpha >= 1.0 {
-        return f64::NAN;
-    }
-
-    let k_f = k as f64;
-    let df_f = df as f64;
-
-    // For large df, use asymptotic approximation
-    // Based on Studentized Range Table approximations
-    if df > 120 {
-        // Asymptotic critical values for alpha=0.05
-        // q_crit ≈ sqrt(2) * z_crit * adjustment_factor
-        let z_crit = crate::distributions::normal_inv(1.0 - alpha / 2.0, 0.0, 1.0).unwrap_or(1.96);
-
-        // Adjustment for number of groups (empirical formula)
-        let group_factor = 1.0 + 0.1 * (k_f - 2.0).max(0.0);
-        return 2.0_f64.sqrt() * z_crit * group_factor;
-    }
-
-    // For finite df, use t-distribution with adjustment
-    let t_crit = crate::distributions::student_t_inv(1.0 - alpha / (2.0 * k_f), 0.0, 1.0, df_f)
-        .unwrap_or(2.0);
-
-    // Convert t to q with adjustment for multiple comparisons
-    let group_factor = 1.0 + 0.05 * (k_f - 2.0).max(0.0);
-    2.0_f64.sqrt() * t_crit * group_factor
-}
-
-/// Simple normal CDF approximation for internal use.
-fn normal_cdf_approx(x: f64) -> f64 {
-    // Abramowitz & Stegun approximation
-    let a1 = 0.254829592;
-    let a2 = -0.284496736;
-    let a3 = 1.421413741;
-    let a4 = -1.453152027;
-    let a5 = 1.061405429;
-    let p = 0.3275911;
-
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x_abs = x.abs() / 2.0_f64.sqrt();
-    let t = 1.0 / (1.0 + p * x_abs);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x_abs * x_abs).exp();
-    0.5 * (1.0 + sign * y)
+    tukey_hsd_with_confidence(&group_refs, conf_level)
 }
 
 /// ANOVA with categorical grouping - full result
@@ -140,3 +140,55 @@ fn test_stan_moment() {
 
     assert!(stan_moment(&[], 1).is_nan());
 }
+
+/// Deterministic f32 sensor-like data: large offset, small spread, long enough to flush blocks.
+fn sensor_f32(len: usize) -> Vec<f32> {
+    let mut state = 12345u32;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            1000.0 + (state >> 8) as f32 / (1u32 << 24) as f32
+        })
+        .collect()
+}
+
+#[test]
+fn test_f32_basic_matches_f64() {
+    assert_eq!(sum_f32(&[]), 0.0);
+    assert!(mean_f32(&[]).is_nan());
+    assert!(variance_f32(&[]).is_nan());
+    assert!(sample_variance_f32(&[1.0]).is_nan());
+    assert_eq!(sum_f32(&[1.0, 2.0, 3.0]), 6.0);
+    assert_relative_eq!(
+        variance_f32(&[1.0, 2.0, 3.0, 4.0, 5.0]),
+        2.0,
+        epsilon = 1e-12
+    );
+
+    for len in [7, 37, 1000, 10_003] {
+        let data = sensor_f32(len);
+        let wide: Vec<f64> = data.iter().map(|&v| v as f64).collect();
+        assert_relative_eq!(sum_f32(&data), sum(&wide), max_relative = 1e-6);
+        assert_relative_eq!(mean_f32(&data), mean(&wide), max_relative = 1e-6);
+        assert_relative_eq!(variance_f32(&data), variance(&wide), max_relative = 1e-5);
+        assert_relative_eq!(
+            sample_variance_f32(&data),
+            sample_variance(&wide),
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(stdev_f32(&data), stdev(&wide), max_relative = 1e-5);
+        assert_relative_eq!(
+            sample_stdev_f32(&data),
+            sample_stdev(&wide),
+            max_relative = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_sum_f32_long_input_keeps_precision() {
+    // A plain f32 running sum of 1e6 tenths is off by about 1%; block flushing into f64 keeps
+    // the error at the level of a single block, independent of length.
+    let data = vec![0.1f32; 1_000_000];
+    assert_relative_eq!(sum_f32(&data), 0.1f32 as f64 * 1e6, max_relative = 1e-5);
+}
@@ -73,3 +73,357 @@ fn test_corrcoeff_non_finite_inputs_return_nan() {
     let x2 = [1.0, 2.0, f64::NEG_INFINITY, 4.0];
     assert!(corrcoeff(&x2, &y).is_nan());
 }
+
+#[test]
+fn test_correlation_f32_match_f64() {
+    assert!(covariance_f32(&[], &[]).is_nan());
+    assert!(corrcoeff_f32(&[1.0, 2.0], &[1.0]).is_nan());
+    assert!(corrcoeff_f32(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_nan());
+    assert_relative_eq!(
+        corrcoeff_f32(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0, 4.0, 6.0, 8.0, 10.0]),
+        1.0,
+        epsilon = 1e-12
+    );
+
+    let x: Vec<f32> = (0..4099)
+        .map(|i| 250.0 + ((i * 31) % 97) as f32 * 0.01)
+        .collect();
+    let y: Vec<f32> = x
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| -3.0 * v + ((i * 13) % 17) as f32 * 0.02)
+        .collect();
+    let xw: Vec<f64> = x.iter().map(|&v| v as f64).collect();
+    let yw: Vec<f64> = y.iter().map(|&v| v as f64).collect();
+    assert_relative_eq!(
+        covariance_f32(&x, &y),
+        covariance(&xw, &yw),
+        max_relative = 1e-4
+    );
+    assert_relative_eq!(
+        corrcoeff_f32(&x, &y),
+        corrcoeff(&xw, &yw),
+        max_relative = 1e-5
+    );
+}
+
+/// Kendall's tau-b by direct O(n^2) pair counting.
+fn kendall_brute_force(x: &[f64], y: &[f64]) -> f64 {
+    let (mut concordant, mut discordant, mut x_only, mut y_only) = (0.0f64, 0.0, 0.0, 0.0);
+    for i in 0..x.len() {
+        for j in (i + 1)..x.len() {
+            let s = (x[i] - x[j]).signum() * (y[i] - y[j]).signum();
+            if x[i] == x[j] && y[i] != y[j] {
+                x_only += 1.0;
+            } else if y[i] == y[j] && x[i] != x[j] {
+                y_only += 1.0;
+            } else if x[i] != x[j] && s > 0.0 {
+                concordant += 1.0;
+            } else if x[i] != x[j] && s < 0.0 {
+                discordant += 1.0;
+            }
+        }
+    }
+    (concordant - discordant)
+        / ((concordant + discordant + x_only) * (concordant + discordant + y_only)).sqrt()
+}
+
+/// Deterministic, tie-heavy test columns.
+fn test_columns(n: usize) -> Vec<Vec<f64>> {
+    let base: Vec<f64> = (0..n).map(|i| ((i * 37 + 11) % 101) as f64).collect();
+    vec![
+        base.clone(),
+        base.iter().map(|v| (v / 10.0).floor()).collect(),
+        base.iter()
+            .enumerate()
+            .map(|(i, v)| v * 0.3 + (i % 7) as f64)
+            .collect(),
+        (0..n).map(|i| ((i * 13) % 17) as f64).collect(),
+        base.iter().map(|v| -(v * v)).collect(),
+        (0..n).map(|i| (i as f64).sin()).collect(),
+    ]
+}
+
+#[test]
+fn test_kendallcoeff() {
+    // cor(x, y, method = "kendall") in R
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [3.0, 1.0, 2.0, 5.0, 4.0];
+    assert_relative_eq!(kendallcoeff(&x, &y), 0.4, epsilon = 1e-12);
+    let x = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+    let y = [1.0, 3.0, 2.0, 2.0, 3.0, 3.0];
+    assert_relative_eq!(kendallcoeff(&x, &y), 6.0 / 11.0, epsilon = 1e-12);
+
+    let columns = test_columns(203);
+    for a in &columns {
+        for b in &columns {
+            assert_relative_eq!(
+                kendallcoeff(a, b),
+                kendall_brute_force(a, b),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    assert!(kendallcoeff(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_nan());
+    assert!(kendallcoeff(&[1.0, f64::NAN], &[1.0, 2.0]).is_nan());
+    assert!(kendallcoeff(&[1.0], &[1.0]).is_nan());
+}
+
+#[test]
+fn test_correlation_matrix_matches_pairwise() {
+    // Six columns exercise both the four-column blocks and the remainder; 203 rows leave a
+    // scalar tail after the SIMD chunks.
+    let columns = test_columns(203);
+    let refs: Vec<&[f64]> = columns.iter().map(|c| c.as_slice()).collect();
+    let p = refs.len();
+    for method in [
+        CorrelationMethod::Pearson,
+        CorrelationMethod::Spearman,
+        CorrelationMethod::Kendall,
+    ] {
+        let pair = match method {
+            CorrelationMethod::Pearson => corrcoeff,
+            CorrelationMethod::Spearman => spearmancoeff,
+            CorrelationMethod::Kendall => kendallcoeff,
+        };
+        let matrix = correlation_matrix(&refs, method);
+        assert_eq!(matrix.len(), p * p);
+        for i in 0..p {
+            assert_eq!(matrix[i * p + i], 1.0);
+            for j in 0..p {
+                assert_relative_eq!(matrix[i * p + j], pair(refs[i], refs[j]), epsilon = 1e-12);
+            }
+        }
+    }
+
+    let cov = covariance_matrix(&refs);
+    for i in 0..p {
+        for j in 0..p {
+            let expected = covariance(refs[i], refs[j]);
+            assert_relative_eq!(cov[i * p + j], expected, max_relative = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_correlation_matrix_pairwise_complete() {
+    let x = [1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0];
+    let y = [2.0, 1.0, 4.0, f64::NAN, 6.0, 5.0];
+    let z = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0];
+    let matrix = correlation_matrix(&[&x, &y, &z], CorrelationMethod::Pearson);
+    // x-y uses rows 0, 1, 4, 5; x-z drops row 2; y-z drops row 3.
+    let xy = corrcoeff(&[1.0, 2.0, 5.0, 6.0], &[2.0, 1.0, 6.0, 5.0]);
+    let xz = corrcoeff(&[1.0, 2.0, 4.0, 5.0, 6.0], &[1.0, 3.0, 5.0, 4.0, 6.0]);
+    let yz = corrcoeff(&[2.0, 1.0, 4.0, 6.0, 5.0], &[1.0, 3.0, 2.0, 4.0, 6.0]);
+    assert_relative_eq!(matrix[1], xy, epsilon = 1e-12);
+    assert_relative_eq!(matrix[3], xy, epsilon = 1e-12);
+    assert_relative_eq!(matrix[2], xz, epsilon = 1e-12);
+    assert_relative_eq!(matrix[5], yz, epsilon = 1e-12);
+    assert_relative_eq!(matrix[0], 1.0, epsilon = 1e-12);
+
+    let spearman = correlation_matrix(&[&x, &y, &z], CorrelationMethod::Spearman);
+    let expected = spearmancoeff(&[1.0, 2.0, 5.0, 6.0], &[2.0, 1.0, 6.0, 5.0]);
+    assert_relative_eq!(spearman[1], expected, epsilon = 1e-12);
+
+    let cov = covariance_matrix(&[&x, &z]);
+    assert_relative_eq!(
+        cov[0],
+        covariance(&[1.0, 2.0, 4.0, 5.0, 6.0], &[1.0, 2.0, 4.0, 5.0, 6.0])
+    );
+    assert_relative_eq!(
+        cov[1],
+        covariance(&[1.0, 2.0, 4.0, 5.0, 6.0], &[1.0, 3.0, 5.0, 4.0, 6.0])
+    );
+}
+
+#[test]
+fn test_correlation_matrix_invalid() {
+    let x = [1.0, 2.0, 3.0];
+    let constant = [2.0, 2.0, 2.0];
+    let matrix = correlation_matrix(&[&x, &constant], CorrelationMethod::Pearson);
+    assert_eq!(matrix[0], 1.0);
+    assert!(matrix[1..].iter().all(|v| v.is_nan()));
+
+    assert!(
+        correlation_matrix(&[&x, &x[..2]], CorrelationMethod::Pearson)
+            .iter()
+            .all(|v| v.is_nan())
+    );
+    assert!(covariance_matrix(&[]).is_empty());
+    assert!(covariance_matrix(&[&[], &[]]).iter().all(|v| v.is_nan()));
+}
+
+// Example data from R's `?cor.test`.
+const HAMILTON_X: [f64; 9] = [44.4, 45.9, 41.9, 53.3, 44.7, 44.1, 50.7, 45.2, 60.1];
+const HAMILTON_Y: [f64; 9] = [2.6, 3.1, 2.5, 5.0, 3.6, 4.0, 5.2, 2.8, 3.8];
+
+#[test]
+fn test_corrcoeff_test_matches_r() {
+    // cor.test(x, y): t = 1.8411, df = 7, p-value = 0.1082, CI (-0.1497426, 0.8955795)
+    let res = corrcoeff_test(&HAMILTON_X, &HAMILTON_Y, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(res.estimate, 0.571181557563829, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 1.84108263811534, epsilon = 1e-10);
+    assert_eq!(res.df, Some(7.0));
+    assert_relative_eq!(res.p_value, 0.108173054349494, epsilon = 1e-9);
+    let ci = res.ci.unwrap();
+    assert_relative_eq!(ci[0], -0.149742633036757, epsilon = 1e-9);
+    assert_relative_eq!(ci[1], 0.895579461838739, epsilon = 1e-9);
+
+    let greater = corrcoeff_test(&HAMILTON_X, &HAMILTON_Y, Alternative::Greater, 0.05);
+    assert_relative_eq!(greater.p_value, res.p_value / 2.0, epsilon = 1e-12);
+    assert_eq!(greater.ci.unwrap()[1], 1.0);
+
+    // Too few observations for the interval, and for the test.
+    assert_eq!(
+        corrcoeff_test(
+            &[1.0, 2.0, 3.0],
+            &[1.0, 3.0, 2.0],
+            Alternative::TwoSided,
+            0.05
+        )
+        .ci,
+        None
+    );
+    assert!(
+        corrcoeff_test(&[1.0, 2.0], &[1.0, 3.0], Alternative::TwoSided, 0.05)
+            .p_value
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_rank_correlation_tests() {
+    let res = spearmancoeff_test(&HAMILTON_X, &HAMILTON_Y, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(res.estimate, 0.6, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 1.98431348329844, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, 0.0876228290414025, epsilon = 1e-9);
+    let ci = res.ci.unwrap();
+    assert_relative_eq!(ci[0], -0.129921172974771, epsilon = 1e-9);
+    assert_relative_eq!(ci[1], 0.908165369930819, epsilon = 1e-9);
+
+    // cor.test(x, y, method = "kendall", alternative = "greater", exact = FALSE):
+    // z = 1.6681, p-value = 0.04765
+    let res = kendallcoeff_test(&HAMILTON_X, &HAMILTON_Y, Alternative::Greater, 0.05);
+    assert_relative_eq!(res.estimate, 4.0 / 9.0, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 1.66811531245660, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, 0.0476464190117283, epsilon = 1e-9);
+    assert_eq!(res.df, None);
+    let res = kendallcoeff_test(&HAMILTON_X, &HAMILTON_Y, Alternative::TwoSided, 0.05);
+    let ci = res.ci.unwrap();
+    assert_relative_eq!(ci[0], -0.101329099575817, epsilon = 1e-9);
+    assert_relative_eq!(ci[1], 0.784585811771994, epsilon = 1e-9);
+
+    // Tie-corrected variance.
+    let x = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0, 5.0];
+    let y = [2.0, 1.0, 2.0, 2.0, 3.0, 3.0, 5.0, 4.0, 4.0, 6.0];
+    let res = kendallcoeff_test(&x, &y, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(res.estimate, 0.784873009489842, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 2.91187158501896, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, 0.00359270302634112, epsilon = 1e-9);
+}
+
+#[test]
+fn test_pointbiserial() {
+    let group = [0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    assert_relative_eq!(
+        pointbiserial(&group, &HAMILTON_Y),
+        corrcoeff(&group, &HAMILTON_Y),
+        epsilon = 1e-12
+    );
+    // The test is the pooled two-sample t-test between the groups.
+    let res = pointbiserial_test(&group, &HAMILTON_Y, Alternative::TwoSided, 0.05);
+    let t = ttest_ind(
+        &HAMILTON_Y[4..],
+        &HAMILTON_Y[..4],
+        Alternative::TwoSided,
+        0.05,
+    );
+    assert_relative_eq!(res.statistic, t.statistic, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, t.p_value, epsilon = 1e-10);
+    assert_eq!(res.df, t.df);
+
+    assert!(pointbiserial(&[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0]).is_nan());
+    assert!(pointbiserial(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_nan());
+}
+
+#[test]
+fn test_partialcorr() {
+    let z = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0, 9.0];
+    let (rxy, rxz, ryz) = (
+        corrcoeff(&HAMILTON_X, &HAMILTON_Y),
+        corrcoeff(&HAMILTON_X, &z),
+        corrcoeff(&HAMILTON_Y, &z),
+    );
+    let expected = (rxy - rxz * ryz) / ((1.0 - rxz * rxz) * (1.0 - ryz * ryz)).sqrt();
+    assert_relative_eq!(
+        partialcorr(&HAMILTON_X, &HAMILTON_Y, &[&z]),
+        expected,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        partialcorr(&HAMILTON_X, &HAMILTON_Y, &[]),
+        rxy,
+        epsilon = 1e-12
+    );
+
+    let res = partialcorr_test(&HAMILTON_X, &HAMILTON_Y, &[&z], Alternative::TwoSided, 0.05);
+    assert_eq!(res.df, Some(6.0));
+    assert_relative_eq!(
+        res.statistic,
+        expected * (6.0 / (1.0 - expected * expected)).sqrt(),
+        epsilon = 1e-10
+    );
+    let ci = res.ci.unwrap();
+    assert!(ci[0] < expected && expected < ci[1]);
+
+    // Collinear controls.
+    let z2: Vec<f64> = z.iter().map(|v| 2.0 * v).collect();
+    assert!(partialcorr(&HAMILTON_X, &HAMILTON_Y, &[&z, &z2]).is_nan());
+}
+
+#[test]
+fn test_distancecorr() {
+    assert_relative_eq!(
+        distancecorr(&HAMILTON_X, &HAMILTON_Y),
+        0.710980992589489,
+        epsilon = 1e-12
+    );
+    // Non-monotone dependence that Pearson misses.
+    let x = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+    let y: Vec<f64> = x.iter().map(|v| v * v).collect();
+    assert_relative_eq!(corrcoeff(&x, &y), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(distancecorr(&x, &y), 0.505319749439745, epsilon = 1e-12);
+    assert_relative_eq!(distancecorr(&x, &x), 1.0, epsilon = 1e-12);
+    assert!(distancecorr(&x, &[1.0; 7]).is_nan());
+
+    let x: Vec<f64> = (0..40).map(|i| i as f64 / 4.0 - 5.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| v * v).collect();
+    let res = distancecorr_test(&x, &y, 999, 4);
+    assert_relative_eq!(res.estimate, distancecorr(&x, &y), epsilon = 1e-12);
+    assert!(res.p_value < 0.01);
+    assert_eq!(res.ci, None);
+}
+
+#[test]
+fn test_chatterjee_xi() {
+    let res = chatterjee_xi_test(&HAMILTON_X, &HAMILTON_Y);
+    assert_relative_eq!(res.estimate, 0.0625, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 0.296463530640786, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, 0.383438057054462, epsilon = 1e-9);
+
+    // With ties in y (XICOR's general variance).
+    let x = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0, 5.0];
+    let y = [2.0, 1.0, 2.0, 2.0, 3.0, 3.0, 5.0, 4.0, 4.0, 6.0];
+    let res = chatterjee_xi_test(&x, &y);
+    assert_relative_eq!(res.estimate, 0.527027027027027, epsilon = 1e-12);
+    assert_relative_eq!(res.statistic, 2.52481398649232, epsilon = 1e-10);
+    assert_relative_eq!(res.p_value, 0.00578797718389105, epsilon = 1e-9);
+
+    // y a non-monotone function of x: xi near 1 although Pearson is 0; not symmetric.
+    let x: Vec<f64> = (-50..=50).map(f64::from).collect();
+    let y: Vec<f64> = x.iter().map(|v| v * v).collect();
+    assert!(chatterjee_xi(&x, &y) > 0.9);
+    assert!(chatterjee_xi(&y, &x) < 0.1);
+    assert!(chatterjee_xi(&x, &[1.0; 101]).is_nan());
+}
@@ -0,0 +1,118 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+const X: [f64; 8] = [5.1, 4.9, 6.2, 5.8, 6.0, 5.5, 5.3, 6.1];
+const Y: [f64; 7] = [4.1, 4.5, 5.0, 4.8, 4.3, 4.9, 5.2];
+
+#[test]
+fn test_cohens_d_and_hedges_g() {
+    let d = cohens_d(&X, &Y, 0.05);
+    assert_relative_eq!(d.estimate, 2.073424174238203, epsilon = 1e-12);
+    assert_relative_eq!(d.ci[0], 0.8166606815323866, epsilon = 1e-9);
+    assert_relative_eq!(d.ci[1], 3.3301876669440196, epsilon = 1e-9);
+
+    // Exact J(13) = 0.94098..., close to the usual 1 - 3 / (4 df - 1).
+    let g = hedges_g(&X, &Y, 0.05);
+    assert_relative_eq!(g.estimate, 1.9510557955889923, epsilon = 1e-10);
+    assert_relative_eq!(g.ci[0], d.ci[0] * 0.9409824674711482, epsilon = 1e-9);
+
+    // Swapping the samples flips the sign.
+    assert_relative_eq!(
+        cohens_d(&Y, &X, 0.05).estimate,
+        -d.estimate,
+        epsilon = 1e-12
+    );
+
+    assert!(cohens_d(&[1.0], &Y, 0.05).estimate.is_nan());
+    assert!(cohens_d(&[1.0, 1.0], &[2.0, 2.0], 0.05).estimate.is_nan());
+}
+
+#[test]
+fn test_cohens_d_one_sample_and_paired() {
+    let d = cohens_d_one_sample(&X, 5.0, 0.05);
+    assert_relative_eq!(d.estimate, 1.2620533349137004, epsilon = 1e-12);
+    assert_relative_eq!(d.ci[0], 0.3332937077347612, epsilon = 1e-9);
+    assert_relative_eq!(d.ci[1], 2.1908129620926396, epsilon = 1e-9);
+
+    let shifted: Vec<f64> = X.iter().map(|v| v - 5.0).collect();
+    let paired = cohens_d_paired(&X, &vec![5.0; X.len()], 0.05);
+    assert_relative_eq!(paired.estimate, d.estimate, epsilon = 1e-12);
+    assert_relative_eq!(
+        cohens_d_one_sample(&shifted, 0.0, 0.05).estimate,
+        d.estimate,
+        epsilon = 1e-12
+    );
+    assert!(cohens_d_paired(&X, &Y, 0.05).estimate.is_nan());
+}
+
+#[test]
+fn test_anova_effect_size() {
+    // SS_between = 13.5, SS_within = 4, MS_within = 1.
+    let result = anova(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]);
+    let es = anova_effect_size(&result);
+    assert_relative_eq!(es.eta_squared, 13.5 / 17.5, epsilon = 1e-12);
+    assert_relative_eq!(es.partial_eta_squared, 13.5 / 17.5, epsilon = 1e-12);
+    assert_relative_eq!(es.omega_squared, 12.5 / 18.5, epsilon = 1e-12);
+
+    assert_relative_eq!(
+        partial_eta_squared(4.0, 2.0, 20.0),
+        8.0 / 28.0,
+        epsilon = 1e-12
+    );
+    assert!(anova_effect_size(&anova(&[&[1.0, 2.0]]))
+        .eta_squared
+        .is_nan());
+}
+
+#[test]
+fn test_contingency_effect_sizes() {
+    let table = [10.0, 0.0, 5.0, 3.0, 8.0, 4.0];
+    let chi = chi_square_test_table(&table, 3, ChiSquareMethod::Pearson);
+    let v = cramers_v_table(&table, 3);
+    assert_relative_eq!(v, (chi.statistic / 30.0).sqrt(), epsilon = 1e-12);
+    assert_relative_eq!(cramers_v(chi.statistic, 30.0, 2, 3), v, epsilon = 1e-12);
+
+    // |phi| equals Cramér's V for 2x2 tables.
+    let phi = phi_coefficient([12.0, 5.0, 7.0, 9.0]);
+    assert_relative_eq!(phi, 73.0 / 72352.0f64.sqrt(), epsilon = 1e-12);
+    assert_relative_eq!(
+        cramers_v_table(&[12.0, 5.0, 7.0, 9.0], 2),
+        phi,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        phi_coefficient([5.0, 12.0, 9.0, 7.0]),
+        -phi,
+        epsilon = 1e-12
+    );
+
+    assert!(phi_coefficient([0.0, 0.0, 3.0, 4.0]).is_nan());
+    assert!(cramers_v(1.0, 10.0, 1, 3).is_nan());
+}
+
+#[test]
+fn test_rank_biserial() {
+    assert_relative_eq!(rank_biserial(&[4.0, 5.0, 6.0], &[1.0, 2.0, 3.0]), 1.0);
+    assert_relative_eq!(rank_biserial(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), -1.0);
+    assert_relative_eq!(rank_biserial(&[1.0, 2.0, 3.0, 4.0], &[2.5]), 0.0);
+
+    // Agrees with the Mann-Whitney U statistic.
+    let u = mann_whitney_u(&X, &Y, Alternative::TwoSided).statistic;
+    assert_relative_eq!(rank_biserial(&X, &Y), 2.0 * u / 56.0 - 1.0, epsilon = 1e-12);
+
+    // Signed ranks 1, -2, 3, 4 out of 10.
+    assert_relative_eq!(
+        rank_biserial_one_sample(&[1.0, -2.0, 3.0, 4.0, 0.0], 0.0),
+        0.6,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        rank_biserial_paired(&[2.0, 0.0, 5.0, 6.0], &[1.0, 2.0, 2.0, 2.0]),
+        0.6,
+        epsilon = 1e-12
+    );
+
+    assert!(rank_biserial(&[], &[1.0]).is_nan());
+    assert!(rank_biserial_one_sample(&[0.0, 0.0], 0.0).is_nan());
+    assert!(rank_biserial_paired(&[1.0], &[1.0, 2.0]).is_nan());
+}
@@ -0,0 +1,126 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+/// R's `fisher.test` documentation example (job satisfaction by income), row-major.
+const JOB: [u64; 16] = [1, 3, 10, 6, 2, 3, 10, 7, 1, 6, 14, 12, 0, 1, 9, 11];
+
+#[test]
+fn test_fisher_exact_tea_tasting() {
+    // R: fisher.test(matrix(c(3, 1, 1, 3), 2), alternative = "greater")
+    //    p-value = 0.2429, odds ratio 6.408309, CI [0.3135693, Inf]
+    let r = fisher_exact([3, 1, 1, 3], Alternative::Greater, 0.05);
+    assert_relative_eq!(r.p_value, 17.0 / 70.0, epsilon = 1e-12);
+    assert_relative_eq!(r.odds_ratio, 6.408309, max_relative = 1e-4);
+    assert_relative_eq!(r.ci[0], 0.3135693, max_relative = 1e-4);
+    assert!(r.ci[1].is_infinite());
+
+    // Two-sided: p-value = 0.4857, CI [0.2117329, 621.9337]. R's root finder stops at a
+    // loose tolerance, so the upper limit is only compared loosely.
+    let r = fisher_exact([3, 1, 1, 3], Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.p_value, 34.0 / 70.0, epsilon = 1e-12);
+    assert_relative_eq!(r.ci[0], 0.2117329, max_relative = 1e-4);
+    assert_relative_eq!(r.ci[1], 621.9337, max_relative = 1e-2);
+
+    let r = fisher_exact([3, 1, 1, 3], Alternative::Less, 0.05);
+    assert_relative_eq!(r.p_value, 69.0 / 70.0, epsilon = 1e-12);
+    assert_eq!(r.ci[0], 0.0);
+}
+
+#[test]
+fn test_fisher_exact_boundaries() {
+    // A zero cell on the diagonal pins the estimate to 0 or infinity.
+    let r = fisher_exact([0, 5, 5, 0], Alternative::TwoSided, 0.05);
+    assert_eq!(r.odds_ratio, 0.0);
+    assert_eq!(r.ci[0], 0.0);
+    assert_relative_eq!(r.p_value, 2.0 / 252.0, epsilon = 1e-12);
+    let r = fisher_exact([5, 0, 0, 5], Alternative::TwoSided, 0.05);
+    assert!(r.odds_ratio.is_infinite());
+    assert!(r.ci[1].is_infinite());
+
+    assert!(fisher_exact([0, 0, 3, 4], Alternative::TwoSided, 0.05)
+        .p_value
+        .is_nan());
+    assert!(fisher_exact([1, 2, 3, 4], Alternative::TwoSided, 1.5)
+        .p_value
+        .is_nan());
+}
+
+#[test]
+fn test_fisher_exact_table_matches_enumeration() {
+    // Agrees with the 2x2 test.
+    let r = fisher_exact_table(&[3, 1, 1, 3], 2);
+    assert_relative_eq!(r.p_value, 34.0 / 70.0, epsilon = 1e-12);
+    assert_relative_eq!(r.statistic, 16.0 / 70.0, epsilon = 1e-12);
+
+    // Brute-force enumeration of all 3x3 tables with these margins.
+    let r = fisher_exact_table(&[3, 1, 4, 2, 5, 0, 1, 2, 6], 3);
+    assert_relative_eq!(r.p_value, 0.03248463500669216, epsilon = 1e-12);
+    assert_relative_eq!(r.statistic, 0.00025158703067145333, epsilon = 1e-15);
+
+    // Empty rows are dropped.
+    let padded = fisher_exact_table(&[3, 1, 4, 0, 0, 0, 2, 5, 0, 1, 2, 6], 3);
+    assert_relative_eq!(padded.p_value, r.p_value, epsilon = 1e-12);
+
+    assert!(fisher_exact_table(&[1, 2, 3], 2).p_value.is_nan());
+    assert!(fisher_exact_table(&[1, 2, 0, 0], 2).p_value.is_nan());
+}
+
+#[test]
+fn test_fisher_exact_table_job_satisfaction() {
+    // R: fisher.test(Job) -> p-value = 0.7827
+    let r = fisher_exact_table(&JOB, 4);
+    assert_relative_eq!(r.p_value, 0.7827, epsilon = 5e-5);
+
+    let sim = fisher_exact_table_simulated(&JOB, 4, 5000, 42);
+    assert!((sim.p_value - r.p_value).abs() < 0.03);
+    assert_eq!(sim, fisher_exact_table_simulated(&JOB, 4, 5000, 42));
+    assert_relative_eq!(sim.statistic, r.statistic, epsilon = 1e-15);
+    assert!(fisher_exact_table_simulated(&JOB, 4, 0, 42)
+        .p_value
+        .is_nan());
+}
+
+#[test]
+fn test_binomial_test_matches_r() {
+    // R: binom.test(682, 925, p = 0.75)
+    //    p-value = 0.3825, CI [0.7076683, 0.7654066]
+    let r = binomial_test(682, 925, 0.75, Alternative::TwoSided, 0.05);
+    assert_eq!(r.statistic, 682.0);
+    assert_relative_eq!(r.p_value, 0.3825, epsilon = 5e-5);
+    let ci = r.ci.unwrap();
+    assert_relative_eq!(ci[0], 0.7076683, epsilon = 1e-7);
+    assert_relative_eq!(ci[1], 0.7654066, epsilon = 1e-7);
+
+    // Symmetric case: two-sided doubles the tail, P(X <= 2 | n = 10) = 56 / 1024.
+    let r = binomial_test(2, 10, 0.5, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.p_value, 112.0 / 1024.0, epsilon = 1e-12);
+    let r = binomial_test(2, 10, 0.5, Alternative::Less, 0.05);
+    assert_relative_eq!(r.p_value, 56.0 / 1024.0, epsilon = 1e-12);
+    assert_eq!(r.ci.unwrap()[0], 0.0);
+    let r = binomial_test(2, 10, 0.5, Alternative::Greater, 0.05);
+    assert_relative_eq!(r.p_value, 1013.0 / 1024.0, epsilon = 1e-12);
+    assert_eq!(r.ci.unwrap()[1], 1.0);
+}
+
+#[test]
+fn test_binomial_test_edges() {
+    // R: binom.test(0, 10) -> p-value = 0.001953, CI [0, 0.3084971]
+    let r = binomial_test(0, 10, 0.5, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.p_value, 2.0 / 1024.0, epsilon = 1e-12);
+    let ci = r.ci.unwrap();
+    assert_eq!(ci[0], 0.0);
+    assert_relative_eq!(ci[1], 0.3084971, epsilon = 1e-7);
+
+    let r = binomial_test(5, 10, 0.5, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.p_value, 1.0);
+
+    assert!(binomial_test(3, 0, 0.5, Alternative::TwoSided, 0.05)
+        .p_value
+        .is_nan());
+    assert!(binomial_test(11, 10, 0.5, Alternative::TwoSided, 0.05)
+        .p_value
+        .is_nan());
+    assert!(binomial_test(3, 10, 1.5, Alternative::TwoSided, 0.05)
+        .p_value
+        .is_nan());
+}
@@ -0,0 +1,180 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+use crate::distributions::{ContinuousDistribution, Exp, Normal, Uniform};
+
+const WEIGHTS: [f64; 11] = [
+    148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0,
+];
+
+fn normal_sample(n: usize, seed: u64) -> Vec<f64> {
+    Normal::new(10.0, 2.0).unwrap().sample(n, seed)
+}
+
+#[test]
+fn test_ks_exact_small_samples() {
+    let u = Uniform::new(0.0, 1.0).unwrap();
+
+    // n = 1: P(D >= d) = 2 - 2d.
+    let r = ks_test(&[0.8], &u, Alternative::TwoSided);
+    assert_relative_eq!(r.statistic, 0.8, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 0.4, epsilon = 1e-12);
+    assert!(r.df.is_none());
+
+    // n = 2, 1/4 <= d <= 1/2: P(D < d) = 2 (2d - 1/2)^2.
+    let r = ks_test(&[0.1, 0.7], &u, Alternative::TwoSided);
+    assert_relative_eq!(r.statistic, 0.4, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 0.82, epsilon = 1e-12);
+
+    // One-sided, n = 1: P(D+ >= d) = 1 - d.
+    let r = ks_test(&[0.3], &u, Alternative::Greater);
+    assert_relative_eq!(r.statistic, 0.7, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 0.3, epsilon = 1e-12);
+    let r = ks_test(&[0.3], &u, Alternative::Less);
+    assert_relative_eq!(r.statistic, 0.3, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 0.7, epsilon = 1e-12);
+}
+
+#[test]
+fn test_ks_exact_close_to_asymptotic_near_cutoff() {
+    let dist = Normal::new(10.0, 2.0).unwrap();
+    let shifted = Normal::new(10.5, 2.0).unwrap();
+    let exact = ks_test(&normal_sample(99, 7), &shifted, Alternative::TwoSided);
+    let asymptotic = ks_test(&normal_sample(100, 7), &shifted, Alternative::TwoSided);
+    assert!((exact.p_value - asymptotic.p_value).abs() < 0.05);
+
+    let r = ks_test(&normal_sample(500, 3), &dist, Alternative::TwoSided);
+    assert!(r.p_value > 0.05);
+    let r = ks_test(&normal_sample(500, 3), &shifted, Alternative::TwoSided);
+    assert!(r.p_value < 1e-3);
+}
+
+#[test]
+fn test_ks_accepts_trait_objects() {
+    let dist: Box<dyn ContinuousDistribution> = Box::new(Exp::new(1.0).unwrap());
+    let data = Exp::new(1.0).unwrap().sample(50, 11);
+    let r = ks_test(&data, dist.as_ref(), Alternative::TwoSided);
+    assert!(r.p_value > 0.01 && r.p_value <= 1.0);
+}
+
+#[test]
+fn test_ks_2samp_exact_separation() {
+    let (x, y) = ([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    let r = ks_test_2samp(&x, &y, Alternative::TwoSided);
+    assert_relative_eq!(r.statistic, 1.0);
+    assert_relative_eq!(r.p_value, 2.0 / 20.0, epsilon = 1e-12);
+
+    // F_x lies above F_y everywhere, so only the `Greater` statistic is non-zero.
+    let r = ks_test_2samp(&x, &y, Alternative::Greater);
+    assert_relative_eq!(r.p_value, 1.0 / 20.0, epsilon = 1e-12);
+    let r = ks_test_2samp(&x, &y, Alternative::Less);
+    assert_relative_eq!(r.statistic, 0.0);
+    assert_relative_eq!(r.p_value, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_ks_2samp_ties_use_asymptotic() {
+    let x = [1.0, 2.0, 2.0, 3.0, 4.0];
+    let y = [2.0, 3.0, 5.0, 6.0, 6.0];
+    let r = ks_test_2samp(&x, &y, Alternative::TwoSided);
+    assert_relative_eq!(r.statistic, 0.6, epsilon = 1e-12);
+    assert!(r.p_value > 0.0 && r.p_value < 1.0);
+}
+
+#[test]
+fn test_anderson_darling_normal_weights() {
+    // nortest::ad.test(weights): A = 0.94677, p-value = 0.01045
+    let r = anderson_darling_normal(&WEIGHTS);
+    assert_relative_eq!(r.statistic, 0.9467718795988873, epsilon = 1e-8);
+    assert_relative_eq!(r.p_value, 0.010454, epsilon = 1e-5);
+
+    let r = anderson_darling_normal(&normal_sample(200, 5));
+    assert!(r.p_value > 0.05);
+    assert!(anderson_darling_normal(&[1.0; 10]).p_value.is_nan());
+
+    // A large, strongly skewed sample: the statistic is far beyond the fitted range, where the
+    // quadratic would turn back up and report p = 1.
+    let skewed: Vec<f64> = (0..5000)
+        .map(|i| -(1.0 - (i as f64 + 0.5) / 5000.0).ln())
+        .map(|e: f64| e * e * e)
+        .collect();
+    let r = anderson_darling_normal(&skewed);
+    assert!(r.statistic > 307.0);
+    assert_eq!(r.p_value, 3.7e-24);
+    assert!(anderson_darling_normal(&WEIGHTS[..7]).statistic.is_nan());
+}
+
+#[test]
+fn test_anderson_darling_specified_distribution() {
+    let dist = Normal::new(10.0, 2.0).unwrap();
+    let r = anderson_darling(&normal_sample(300, 9), &dist);
+    assert!(r.statistic > 0.0);
+    assert!(r.p_value > 0.05);
+
+    let r = anderson_darling(&normal_sample(300, 9), &Normal::new(11.0, 2.0).unwrap());
+    assert!(r.p_value < 1e-4);
+
+    // Small n with the limiting p-value in the middle (c <= x < 0.8) correction range.
+    // Marsaglia & Marsaglia's `AD(8, z)`, as ADGofTest::ad.test(u, punif).
+    let u = [0.03, 0.1, 0.18, 0.3, 0.42, 0.55, 0.66, 0.8];
+    let r = anderson_darling(&u, &Uniform::new(0.0, 1.0).unwrap());
+    assert_relative_eq!(r.statistic, 0.8024732936095091, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 0.47487267787884857, epsilon = 1e-9);
+
+    // Observations beyond the support give an infinite statistic.
+    let r = anderson_darling(&[0.2, 0.5, 1.5], &Uniform::new(0.0, 1.0).unwrap());
+    assert!(r.statistic.is_infinite());
+    assert_eq!(r.p_value, 0.0);
+}
+
+#[test]
+fn test_shapiro_wilk_matches_r() {
+    // R: shapiro.test(weights) -> W = 0.78881, p-value = 0.006704
+    let r = shapiro_wilk(&WEIGHTS);
+    assert_relative_eq!(r.statistic, 0.78881, epsilon = 1e-5);
+    assert_relative_eq!(r.p_value, 0.006704, epsilon = 1e-6);
+    assert!(r.df.is_none());
+}
+
+#[test]
+fn test_shapiro_wilk_sizes() {
+    // n = 3 has an exact distribution; equally spaced points give W = 1.
+    let r = shapiro_wilk(&[1.0, 2.0, 3.0]);
+    assert_relative_eq!(r.statistic, 1.0, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, 1.0, epsilon = 1e-9);
+
+    let r = shapiro_wilk(&normal_sample(1000, 4));
+    assert!(r.statistic > 0.99);
+    assert!(r.p_value > 0.05);
+
+    let skewed = Exp::new(1.0).unwrap().sample(100, 4);
+    assert!(shapiro_wilk(&skewed).p_value < 1e-4);
+
+    assert!(shapiro_wilk(&[1.0, 2.0]).statistic.is_nan());
+    assert!(shapiro_wilk(&[5.0; 10]).statistic.is_nan());
+    assert!(shapiro_wilk(&[1.0, f64::NAN, 3.0]).statistic.is_nan());
+}
+
+#[test]
+fn test_jarque_bera() {
+    let r = jarque_bera(&WEIGHTS);
+    assert_relative_eq!(r.statistic, 6.982848237344646, epsilon = 1e-10);
+    assert_relative_eq!(r.p_value, 0.030457466224581886, epsilon = 1e-10);
+    assert_eq!(r.df, Some(2.0));
+
+    assert!(jarque_bera(&normal_sample(2000, 8)).p_value > 0.01);
+    assert!(jarque_bera(&[1.0, 2.0, 3.0]).statistic.is_nan());
+}
+
+#[test]
+fn test_goodness_of_fit_invalid_input() {
+    let u = Uniform::new(0.0, 1.0).unwrap();
+    assert!(ks_test(&[], &u, Alternative::TwoSided).statistic.is_nan());
+    assert!(ks_test(&[0.5, f64::NAN], &u, Alternative::TwoSided)
+        .p_value
+        .is_nan());
+    assert!(ks_test_2samp(&[], &[1.0], Alternative::TwoSided)
+        .statistic
+        .is_nan());
+    assert!(anderson_darling(&[], &u).statistic.is_nan());
+}
@@ -80,3 +80,18 @@ fn test_histogram_edges_invalid_edges_return_empty() {
         Vec::<usize>::new()
     );
 }
+
+#[test]
+fn test_histogram_f32_matches_f64() {
+    let data: Vec<f32> = (0..1000).map(|i| (i % 37) as f32 * 0.3 - 2.0).collect();
+    let wide: Vec<f64> = data.iter().map(|&v| v as f64).collect();
+    assert_eq!(histogram_f32(&data, 7), histogram(&wide, 7));
+    assert_eq!(histogram_f32(&[], 3), vec![0, 0, 0]);
+    assert_eq!(histogram_f32(&[f32::NAN, 1.0, 2.0], 2), vec![1, 1]);
+
+    let edges = [-2.0, 0.0, 3.5, 9.0];
+    assert_eq!(
+        histogram_edges_f32(&data, &edges),
+        histogram_edges(&wide, &edges)
+    );
+}
@@ -0,0 +1,134 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+const KERNELS: [Kernel; 5] = [
+    Kernel::Gaussian,
+    Kernel::Epanechnikov,
+    Kernel::Rectangular,
+    Kernel::Triangular,
+    Kernel::Biweight,
+];
+
+/// Deterministic bimodal sample.
+fn bimodal(n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| {
+            let u = (i as f64 + 0.5) / n as f64;
+            let spread = (u * 2.0 - 1.0) * 1.5;
+            if i % 3 == 0 {
+                4.0 + 0.5 * spread
+            } else {
+                spread
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_kernels_have_unit_mass_and_variance() {
+    let step = 1e-3;
+    for kernel in KERNELS {
+        let (mut mass, mut var) = (0.0, 0.0);
+        let mut u = -10.0;
+        while u <= 10.0 {
+            let k = kernel.evaluate(u);
+            mass += k * step;
+            var += u * u * k * step;
+            u += step;
+        }
+        assert_relative_eq!(mass, 1.0, epsilon = 1e-3);
+        assert_relative_eq!(var, 1.0, epsilon = 1e-3);
+    }
+}
+
+#[test]
+fn test_kde_bandwidth_rules() {
+    // R: bw.nrd0(1:10) = 1.719286, bw.nrd(1:10) = 2.024937
+    let data: Vec<f64> = (1..=10).map(|v| v as f64).collect();
+    assert_relative_eq!(
+        kde_bandwidth(&data, BandwidthRule::Silverman),
+        1.719286,
+        epsilon = 1e-6
+    );
+    assert_relative_eq!(
+        kde_bandwidth(&data, BandwidthRule::Scott),
+        2.024937,
+        epsilon = 1e-6
+    );
+    // Non-finite values are ignored; too few values give NaN.
+    let mut with_nan = data.clone();
+    with_nan.push(f64::NAN);
+    assert_eq!(
+        kde_bandwidth(&with_nan, BandwidthRule::Silverman),
+        kde_bandwidth(&data, BandwidthRule::Silverman)
+    );
+    assert!(kde_bandwidth(&[1.0], BandwidthRule::Scott).is_nan());
+    // Constant data falls back to |x| like R.
+    assert!(kde_bandwidth(&[2.0; 5], BandwidthRule::Silverman) > 0.0);
+}
+
+#[test]
+fn test_kde_evaluate() {
+    let d = kde_evaluate(&[0.0], &[0.0, 1.0], Kernel::Gaussian, 2.0);
+    assert_relative_eq!(
+        d[0],
+        1.0 / (2.0 * (2.0 * std::f64::consts::PI).sqrt()),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(d[1], d[0] * (-0.125f64).exp(), epsilon = 1e-12);
+
+    // Compact kernels vanish outside their support.
+    let d = kde_evaluate(&[0.0], &[2.0, 3.0], Kernel::Epanechnikov, 1.0);
+    assert!(d[0] > 0.0);
+    assert_eq!(d[1], 0.0);
+
+    assert!(kde_evaluate(&[], &[0.0], Kernel::Gaussian, 1.0)[0].is_nan());
+    assert!(kde_evaluate(&[1.0], &[0.0], Kernel::Gaussian, 0.0)[0].is_nan());
+}
+
+#[test]
+fn test_kde_grid_matches_exact_evaluation() {
+    let data = bimodal(3000);
+    for kernel in KERNELS {
+        let res = kde_grid(&data, kernel, BandwidthRule::Silverman, None, 256);
+        assert_eq!(res.x.len(), 256);
+        assert_eq!(res.density.len(), 256);
+        assert_relative_eq!(
+            res.bandwidth,
+            kde_bandwidth(&data, BandwidthRule::Silverman),
+            epsilon = 1e-12
+        );
+
+        let exact = kde_evaluate(&data, &res.x, kernel, res.bandwidth);
+        let peak = exact.iter().cloned().fold(0.0, f64::max);
+        for (binned, exact) in res.density.iter().zip(&exact) {
+            assert!((binned - exact).abs() < 0.02 * peak, "{kernel:?}");
+        }
+
+        // Riemann sum on the grid; the rectangular kernel's jumps cost about one grid step.
+        let delta = res.x[1] - res.x[0];
+        let mass: f64 = res.density.iter().sum::<f64>() * delta;
+        assert!((mass - 1.0).abs() < 2e-2, "{kernel:?}: {mass}");
+    }
+}
+
+#[test]
+fn test_kde_grid_edge_cases() {
+    let res = kde_grid(&[], Kernel::Gaussian, BandwidthRule::Scott, None, 0);
+    assert!(res.x.is_empty() && res.density.is_empty());
+
+    let res = kde_grid(&[1.0], Kernel::Gaussian, BandwidthRule::Scott, Some(0.5), 0);
+    assert_eq!(res.x.len(), 512);
+    assert_eq!(res.bandwidth, 0.5);
+    assert_relative_eq!(res.x[0], -0.5, epsilon = 1e-12);
+    assert_relative_eq!(res.x[511], 2.5, epsilon = 1e-12);
+
+    let res = kde_grid(
+        &[1.0, 2.0],
+        Kernel::Gaussian,
+        BandwidthRule::Scott,
+        Some(-1.0),
+        16,
+    );
+    assert!(res.density.is_empty());
+}
@@ -26,3 +26,26 @@ fn test_range() {
     assert!(range(&[]).is_nan());
     assert_eq!(range(&[1.0, 5.0, 3.0, 9.0, 2.0]), 8.0);
 }
+
+#[test]
+fn test_minmax_f32() {
+    assert!(min_f32(&[]).is_nan());
+    assert!(max_f32(&[]).is_nan());
+    assert!(range_f32(&[]).is_nan());
+
+    let data: Vec<f32> = (0..1003)
+        .map(|i| ((i * 37) % 1003) as f32 - 500.0)
+        .collect();
+    assert_eq!(min_f32(&data), -500.0);
+    assert_eq!(max_f32(&data), 502.0);
+    assert_eq!(range_f32(&data), 1002.0);
+    // Extremes in the scalar tail.
+    assert_eq!(
+        min_f32(&[3.0, 2.0, 5.0, 4.0, 6.0, 7.0, 8.0, 9.0, -1.0]),
+        -1.0
+    );
+    assert_eq!(
+        max_f32(&[3.0, 2.0, 5.0, 4.0, 6.0, 7.0, 8.0, 9.0, 10.5]),
+        10.5
+    );
+}
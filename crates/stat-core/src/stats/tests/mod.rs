@@ -1,9 +1,20 @@
 mod basic;
 mod correlation;
+mod effect_size;
+mod exact_tests;
+mod goodness_of_fit;
 mod histogram;
+mod kde;
 mod minmax;
 mod moments;
+mod multiple_testing;
+mod nonparametric;
 mod order;
+mod power;
 mod regression;
+mod resampling;
+mod robust;
+mod running;
 mod statistical_tests;
 mod transform;
+mod weighted;
@@ -34,3 +34,20 @@ fn test_moments_degenerate_and_non_finite() {
     assert!(skewness(&[1.0, f64::INFINITY, 2.0]).is_nan());
     assert!(kurtosis(&[1.0, 2.0, 3.0, f64::NEG_INFINITY]).is_nan());
 }
+
+#[test]
+fn test_moments_f32_match_f64() {
+    assert!(skewness_f32(&[1.0, 2.0]).is_nan());
+    assert!(kurtosis_f32(&[1.0, 2.0, 3.0]).is_nan());
+    assert!(skewness_f32(&[1.0, f32::NAN, 3.0]).is_nan());
+    assert!(kurtosis_f32(&[2.0; 10]).is_nan());
+
+    // Right-skewed data on a large offset, long enough to cross several flush blocks.
+    let data: Vec<f32> = (0..5003)
+        .map(|i| 500.0 + ((i * 7919) % 5003) as f32 / 5003.0)
+        .map(|v| 500.0 + (v - 500.0) * (v - 500.0))
+        .collect();
+    let wide: Vec<f64> = data.iter().map(|&v| v as f64).collect();
+    assert_relative_eq!(skewness_f32(&data), skewness(&wide), max_relative = 1e-4);
+    assert_relative_eq!(kurtosis_f32(&data), kurtosis(&wide), max_relative = 1e-4);
+}
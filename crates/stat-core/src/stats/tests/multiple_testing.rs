@@ -0,0 +1,131 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+const P: [f64; 8] = [0.04, 0.001, 0.3, 0.012, 0.02, 0.8, 0.045, 0.0005];
+
+fn assert_all_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (&a, &e) in actual.iter().zip(expected) {
+        assert_relative_eq!(a, e, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_p_adjust_matches_r() {
+    // p.adjust(p, method = ...) in R
+    let cases = [
+        (
+            PAdjustMethod::Bonferroni,
+            [0.32, 0.008, 1.0, 0.096, 0.16, 1.0, 0.36, 0.004],
+        ),
+        (
+            PAdjustMethod::Holm,
+            [0.16, 0.007, 0.6, 0.072, 0.1, 0.8, 0.16, 0.004],
+        ),
+        (
+            PAdjustMethod::Hochberg,
+            [0.135, 0.007, 0.6, 0.072, 0.1, 0.8, 0.135, 0.004],
+        ),
+        (
+            PAdjustMethod::Hommel,
+            [0.12, 0.007, 0.6, 0.06, 0.08, 0.8, 0.135, 0.004],
+        ),
+        (
+            PAdjustMethod::BenjaminiHochberg,
+            [
+                0.06,
+                0.004,
+                0.342857142857143,
+                0.032,
+                0.04,
+                0.8,
+                0.06,
+                0.004,
+            ],
+        ),
+        (
+            PAdjustMethod::BenjaminiYekutieli,
+            [
+                0.163071428571429,
+                0.0108714285714286,
+                0.931836734693877,
+                0.0869714285714286,
+                0.108714285714286,
+                1.0,
+                0.163071428571429,
+                0.0108714285714286,
+            ],
+        ),
+    ];
+    for (method, expected) in cases {
+        assert_all_close(&p_adjust(&P, method), &expected);
+    }
+}
+
+#[test]
+fn test_p_adjust_evenly_spaced() {
+    let p = [0.01, 0.02, 0.03, 0.04, 0.05];
+    assert_all_close(
+        &p_adjust(&p, PAdjustMethod::Holm),
+        &[0.05, 0.08, 0.09, 0.09, 0.09],
+    );
+    for method in [
+        PAdjustMethod::Hochberg,
+        PAdjustMethod::Hommel,
+        PAdjustMethod::BenjaminiHochberg,
+    ] {
+        assert_all_close(&p_adjust(&p, method), &[0.05; 5]);
+    }
+    assert_all_close(
+        &p_adjust(&p, PAdjustMethod::BenjaminiYekutieli),
+        &[0.05 * 137.0 / 60.0; 5],
+    );
+}
+
+#[test]
+fn test_p_adjust_skips_invalid_entries() {
+    // Invalid entries are NaN and excluded from the number of tests, like NA in R.
+    let p = [0.01, f64::NAN, 0.04, 1.5, 0.02];
+    let adjusted = p_adjust(&p, PAdjustMethod::Bonferroni);
+    assert!(adjusted[1].is_nan() && adjusted[3].is_nan());
+    assert_relative_eq!(adjusted[0], 0.03, epsilon = 1e-12);
+    assert_relative_eq!(adjusted[4], 0.06, epsilon = 1e-12);
+    let hommel = p_adjust(&p, PAdjustMethod::Hommel);
+    let valid = p_adjust(&[0.01, 0.04, 0.02], PAdjustMethod::Hommel);
+    assert_all_close(&[hommel[0], hommel[2], hommel[4]], &valid);
+
+    assert!(p_adjust(&[], PAdjustMethod::Holm).is_empty());
+    assert_all_close(&p_adjust(&[0.3], PAdjustMethod::Hommel), &[0.3]);
+}
+
+#[test]
+fn test_storey_q_values() {
+    // pi0 = #{p > 0.5} / (m * 0.5) = 1 / 4
+    assert_relative_eq!(storey_pi0(&P, 0.5), 0.25, epsilon = 1e-12);
+    assert_relative_eq!(storey_pi0(&P, 0.25), 1.0 / 3.0, epsilon = 1e-12);
+    assert_relative_eq!(storey_pi0(&P, 0.0), 1.0, epsilon = 1e-12);
+    assert!(storey_pi0(&P, 1.0).is_nan());
+
+    let result = q_values(&P, 0.5);
+    assert_relative_eq!(result.pi0, 0.25, epsilon = 1e-12);
+    assert_all_close(
+        &result.q_values,
+        &[
+            0.015,
+            0.001,
+            0.0857142857142857,
+            0.008,
+            0.01,
+            0.2,
+            0.015,
+            0.001,
+        ],
+    );
+
+    // lambda = 0 reduces to Benjamini-Hochberg.
+    let bh = p_adjust(&P, PAdjustMethod::BenjaminiHochberg);
+    assert_all_close(&q_values(&P, 0.0).q_values, &bh);
+
+    let invalid = q_values(&P, -0.1);
+    assert!(invalid.pi0.is_nan() && invalid.q_values.iter().all(|q| q.is_nan()));
+}
@@ -0,0 +1,113 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_mann_whitney_exact_matches_r() {
+    // R: wilcox.test(x, y, alternative = "greater") -> W = 35, p-value = 0.1272
+    let x = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+    let y = [1.15, 0.88, 0.90, 0.74, 1.21];
+    let r = mann_whitney_u(&x, &y, Alternative::Greater);
+    assert_eq!(r.statistic, 35.0);
+    assert_relative_eq!(r.p_value, 0.1272061, epsilon = 1e-6);
+    assert!(r.df.is_none());
+
+    // Complete separation: P(U = 9) = 1 / C(6, 3).
+    let r = mann_whitney_u(&[4.0, 5.0, 6.0], &[1.0, 2.0, 3.0], Alternative::Greater);
+    assert_relative_eq!(r.p_value, 1.0 / 20.0, epsilon = 1e-12);
+    let two = mann_whitney_u(&[4.0, 5.0, 6.0], &[1.0, 2.0, 3.0], Alternative::TwoSided);
+    assert_relative_eq!(two.p_value, 2.0 / 20.0, epsilon = 1e-12);
+    let less = mann_whitney_u(&[4.0, 5.0, 6.0], &[1.0, 2.0, 3.0], Alternative::Less);
+    assert_relative_eq!(less.p_value, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_mann_whitney_ties_use_corrected_normal() {
+    // R: wilcox.test(c(1,2,2,3,4), c(2,3,5,6,6), exact = FALSE) -> W = 4.5
+    let r = mann_whitney_u(
+        &[1.0, 2.0, 2.0, 3.0, 4.0],
+        &[2.0, 3.0, 5.0, 6.0, 6.0],
+        Alternative::TwoSided,
+    );
+    assert_eq!(r.statistic, 4.5);
+    assert_relative_eq!(r.p_value, 0.11049202405566808, epsilon = 1e-9);
+}
+
+#[test]
+fn test_mann_whitney_exact_close_to_normal_for_moderate_n() {
+    let x: Vec<f64> = (0..40).map(|i| (i as f64 * 1.37).sin() + 0.3).collect();
+    let y: Vec<f64> = (0..45).map(|i| (i as f64 * 0.91).cos()).collect();
+    let exact = mann_whitney_u(&x, &y, Alternative::TwoSided);
+
+    let (m, n) = (40.0, 45.0);
+    let sigma = (m * n * (m + n + 1.0) / 12.0_f64).sqrt();
+    let z = (exact.statistic - m * n / 2.0).abs() - 0.5;
+    let normal = 2.0 * crate::distributions::normal_cdf(-z / sigma, 0.0, 1.0).unwrap();
+    assert_relative_eq!(exact.p_value, normal, epsilon = 5e-3);
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_wilcoxon_signed_rank_exact_matches_r() {
+    // R: wilcox.test(x, y, paired = TRUE, alternative = "greater") -> V = 40, p-value = 0.01953
+    let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+    let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+    let r = wilcoxon_signed_rank_paired(&x, &y, Alternative::Greater);
+    assert_eq!(r.statistic, 40.0);
+    assert_relative_eq!(r.p_value, 10.0 / 512.0, epsilon = 1e-12);
+
+    // The one-sample form on the differences is identical.
+    let d: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a - b).collect();
+    assert_eq!(wilcoxon_signed_rank(&d, 0.0, Alternative::Greater), r);
+
+    // All differences positive: P(V = 15) = 1 / 2^5.
+    let r = wilcoxon_signed_rank(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.0, Alternative::Greater);
+    assert_relative_eq!(r.p_value, 1.0 / 32.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_wilcoxon_signed_rank_zeros_and_ties() {
+    // Zeros are dropped and ties force the normal approximation.
+    let d = [1.5, -0.5, 2.0, 0.0, 3.0, -2.0, 1.5, 4.0];
+    let r = wilcoxon_signed_rank(&d, 0.0, Alternative::Greater);
+    assert_eq!(r.statistic, 22.5);
+    assert_relative_eq!(r.p_value, 0.08737435587527664, epsilon = 1e-9);
+}
+
+#[test]
+fn test_kruskal_wallis_matches_r() {
+    // R: kruskal.test(list(x, y, z)) -> chi-squared = 0.77143, df = 2, p-value = 0.68
+    let x = [2.9, 3.0, 2.5, 2.6, 3.2];
+    let y = [3.8, 2.7, 4.0, 2.4];
+    let z = [2.8, 3.4, 3.7, 2.2, 2.0];
+    let r = kruskal_wallis(&[&x, &y, &z]);
+    assert_relative_eq!(r.statistic, 0.7714286, epsilon = 1e-6);
+    assert_eq!(r.df, Some(2.0));
+    assert_relative_eq!(r.p_value, 0.6799648, epsilon = 1e-6);
+}
+
+#[test]
+fn test_rank_tests_invalid_inputs() {
+    assert!(mann_whitney_u(&[], &[1.0], Alternative::TwoSided)
+        .p_value
+        .is_nan());
+    assert!(
+        mann_whitney_u(&[1.0, f64::NAN], &[1.0], Alternative::TwoSided)
+            .statistic
+            .is_nan()
+    );
+    assert!(
+        wilcoxon_signed_rank(&[2.0, 2.0], 2.0, Alternative::TwoSided)
+            .p_value
+            .is_nan()
+    );
+    assert!(
+        wilcoxon_signed_rank_paired(&[1.0, 2.0], &[1.0], Alternative::TwoSided)
+            .p_value
+            .is_nan()
+    );
+    assert!(kruskal_wallis(&[&[1.0, 2.0]]).statistic.is_nan());
+    assert!(kruskal_wallis(&[&[1.0, 1.0], &[1.0, 1.0]])
+        .statistic
+        .is_nan());
+    assert!(kruskal_wallis(&[&[1.0, 2.0], &[]]).statistic.is_nan());
+}
@@ -0,0 +1,176 @@
+use crate::distributions::normal_cdf;
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+#[test]
+fn test_power_t_test_matches_pwr() {
+    // pwr.t.test(n = 64, d = 0.5) -> power = 0.8014596
+    let p = power_t_test(
+        0.5,
+        64.0,
+        0.05,
+        TTestDesign::TwoSample,
+        Alternative::TwoSided,
+    );
+    assert_relative_eq!(p, 0.801459557922254, epsilon = 1e-9);
+    // pwr.t.test(n = 20, d = 0.5, type = "one.sample") -> power = 0.5645044
+    let p = power_t_test(
+        0.5,
+        20.0,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided,
+    );
+    assert_relative_eq!(p, 0.5645044184390203, epsilon = 1e-9);
+    let p = power_t_test(0.5, 20.0, 0.05, TTestDesign::Paired, Alternative::Greater);
+    assert_relative_eq!(p, 0.6951493382443406, epsilon = 1e-9);
+
+    // One-sided power mirrors under a sign flip and falls below alpha in the wrong direction.
+    let less = power_t_test(-0.5, 20.0, 0.05, TTestDesign::OneSample, Alternative::Less);
+    assert_relative_eq!(less, 0.6951493382443406, epsilon = 1e-9);
+    assert!(power_t_test(0.5, 20.0, 0.05, TTestDesign::OneSample, Alternative::Less) < 0.05);
+
+    // Zero effect gives the size of the test.
+    let p = power_t_test(
+        0.0,
+        15.0,
+        0.05,
+        TTestDesign::TwoSample,
+        Alternative::TwoSided,
+    );
+    assert_relative_eq!(p, 0.05, epsilon = 1e-9);
+
+    assert!(power_t_test(
+        0.5,
+        1.0,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided
+    )
+    .is_nan());
+    assert!(power_t_test(
+        0.5,
+        10.0,
+        1.0,
+        TTestDesign::OneSample,
+        Alternative::TwoSided
+    )
+    .is_nan());
+}
+
+#[test]
+fn test_sample_size_t_test() {
+    // The classic 64 per group for d = 0.5 at 80% power (n = 63 gives 0.795).
+    let n = sample_size_t_test(
+        0.5,
+        0.8,
+        0.05,
+        TTestDesign::TwoSample,
+        Alternative::TwoSided,
+    );
+    assert_eq!(n, 64.0);
+    let n = sample_size_t_test(
+        0.5,
+        0.9,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided,
+    );
+    let power = |n: f64| power_t_test(0.5, n, 0.05, TTestDesign::OneSample, Alternative::TwoSided);
+    assert!(power(n) >= 0.9 && power(n - 1.0) < 0.9);
+
+    // Unreachable targets.
+    assert!(sample_size_t_test(
+        0.0,
+        0.8,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided
+    )
+    .is_nan());
+    assert!(sample_size_t_test(0.5, 0.8, 0.05, TTestDesign::OneSample, Alternative::Less).is_nan());
+    assert!(sample_size_t_test(
+        0.5,
+        0.01,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided
+    )
+    .is_nan());
+    // Non-finite effects have no defined power.
+    assert!(sample_size_t_test(
+        f64::INFINITY,
+        0.8,
+        0.05,
+        TTestDesign::OneSample,
+        Alternative::TwoSided
+    )
+    .is_nan());
+    assert!(sample_size_anova(f64::INFINITY, 3, 0.8, 0.05).is_nan());
+}
+
+#[test]
+fn test_power_z_test() {
+    let z = 1.959963984540054;
+    let p = power_z_test(0.5, 32.0, 0.05, Alternative::TwoSided);
+    let expected = normal_cdf(0.5 * 32f64.sqrt() - z, 0.0, 1.0).unwrap()
+        + normal_cdf(-0.5 * 32f64.sqrt() - z, 0.0, 1.0).unwrap();
+    assert_relative_eq!(p, expected, epsilon = 1e-12);
+
+    // ((1.96 + 0.8416) / 0.5)^2 = 31.4 -> 32.
+    assert_eq!(
+        sample_size_z_test(0.5, 0.8, 0.05, Alternative::TwoSided),
+        32.0
+    );
+    assert_eq!(
+        sample_size_z_test(5.0, 0.8, 0.05, Alternative::Greater),
+        1.0
+    );
+    assert!(power_z_test(0.5, 0.0, 0.05, Alternative::TwoSided).is_nan());
+}
+
+#[test]
+fn test_power_anova_and_chi_square() {
+    // pwr.anova.test(k = 4, n = 20, f = 0.25) -> power = 0.4203901
+    assert_relative_eq!(
+        power_anova(0.25, 4, 20.0, 0.05),
+        0.42039009037704286,
+        epsilon = 1e-9
+    );
+    // Cohen's table: 45 per group for f = 0.25 with four groups.
+    assert_eq!(sample_size_anova(0.25, 4, 0.8, 0.05), 45.0);
+    assert_relative_eq!(power_anova(0.0, 3, 10.0, 0.05), 0.05, epsilon = 1e-9);
+    assert!(power_anova(0.25, 1, 20.0, 0.05).is_nan());
+
+    // pwr.chisq.test(w = 0.3, N = 100, df = 2) -> power = 0.7706831
+    assert_relative_eq!(
+        power_chi_square(0.3, 100.0, 2.0, 0.05),
+        0.7706830777241321,
+        epsilon = 1e-9
+    );
+    assert_eq!(sample_size_chi_square(0.3, 1.0, 0.8, 0.05), 88.0);
+    assert!(power_chi_square(0.3, 100.0, 0.0, 0.05).is_nan());
+}
+
+#[test]
+fn test_power_two_proportions() {
+    let h = cohens_h(0.65, 0.45);
+    assert_relative_eq!(
+        h,
+        2.0 * 0.65f64.sqrt().asin() - 2.0 * 0.45f64.sqrt().asin(),
+        epsilon = 1e-15
+    );
+    assert_relative_eq!(
+        power_two_proportions(0.65, 0.45, 50.0, 0.05, Alternative::Greater),
+        power_z_test(h, 25.0, 0.05, Alternative::Greater),
+        epsilon = 1e-12
+    );
+
+    // Per-group n is the z-test n for h scaled by 2.
+    let n = sample_size_two_proportions(0.65, 0.45, 0.8, 0.05, Alternative::TwoSided);
+    let power = |n: f64| power_two_proportions(0.65, 0.45, n, 0.05, Alternative::TwoSided);
+    assert!(power(n) >= 0.8 && power(n - 1.0) < 0.8);
+
+    assert!(cohens_h(1.2, 0.5).is_nan());
+    assert!(sample_size_two_proportions(0.5, 0.5, 0.8, 0.05, Alternative::TwoSided).is_nan());
+}
@@ -122,3 +122,187 @@ fn test_regress_rejects_nan_inputs() {
     assert!(r.r_squared.is_nan());
     assert!(r.residuals.is_empty());
 }
+
+#[test]
+fn test_ols_matches_r_lm() {
+    // R: lm(weight ~ group) for the ctl/trt example in ?lm
+    let ctl = [4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14];
+    let trt = [4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69];
+    let y: Vec<f64> = ctl.iter().chain(&trt).copied().collect();
+    let x: Vec<f64> = (0..20).map(|i| if i < 10 { 0.0 } else { 1.0 }).collect();
+
+    let r = ols(&x, 1, &y, true);
+    assert_relative_eq!(r.coefficients[0], 5.032, epsilon = 1e-10);
+    assert_relative_eq!(r.coefficients[1], -0.371, epsilon = 1e-10);
+    assert_relative_eq!(r.std_errors[0], 0.2202177, epsilon = 1e-6);
+    assert_relative_eq!(r.std_errors[1], 0.3114349, epsilon = 1e-6);
+    assert_relative_eq!(r.t_values[1], -1.191260, epsilon = 1e-5);
+    assert_relative_eq!(r.p_values[1], 0.2490232, epsilon = 1e-6);
+    assert_relative_eq!(r.sigma, 0.6963895, epsilon = 1e-6);
+    assert_relative_eq!(r.r_squared, 0.07308, epsilon = 1e-5);
+    assert_relative_eq!(r.adj_r_squared, 0.02158, epsilon = 1e-5);
+    assert_relative_eq!(r.f_statistic, 1.419101, epsilon = 1e-5);
+    assert_relative_eq!(r.f_p_value, r.p_values[1], epsilon = 1e-10);
+    assert_eq!((r.df_model, r.df_residual), (1.0, 18.0));
+    // R: logLik(lm.D9) -> -20.08824 (df = 3); AIC = 46.17648
+    assert_relative_eq!(r.log_likelihood, -20.08824, epsilon = 1e-5);
+    assert_relative_eq!(r.aic, 46.17648, epsilon = 1e-5);
+    assert_relative_eq!(r.bic, r.aic - 6.0 + 3.0 * 20.0_f64.ln(), epsilon = 1e-10);
+
+    // Confidence interval is estimate ± t(0.975, 18) * se.
+    let t_crit = crate::distributions::student_t_inv(0.975, 0.0, 1.0, 18.0).unwrap();
+    assert_relative_eq!(
+        r.ci_upper[1],
+        -0.371 + t_crit * r.std_errors[1],
+        epsilon = 1e-10
+    );
+
+    // One predictor reproduces the simple regression.
+    let simple = regress(&x, &y);
+    assert_relative_eq!(r.coefficients[1], simple.slope, epsilon = 1e-10);
+    assert_relative_eq!(r.r_squared, simple.r_squared, epsilon = 1e-10);
+    for (a, b) in r.residuals.iter().zip(&simple.residuals) {
+        assert_relative_eq!(a, b, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_ols_multiple_predictors() {
+    let n = 37;
+    let mut x = Vec::with_capacity(n * 3);
+    let mut y = Vec::with_capacity(n);
+    for i in 0..n {
+        let t = i as f64;
+        let (x1, x2, x3) = (t * 0.3, (t * 0.7).sin(), (t * 0.11).cos() * 2.0);
+        x.extend_from_slice(&[x1, x2, x3]);
+        y.push(1.5 + 2.0 * x1 - 3.0 * x2 + 0.5 * x3 + 0.1 * (t * 1.9).sin());
+    }
+
+    let r = ols(&x, 3, &y, true);
+    assert_eq!(r.coefficients.len(), 4);
+    assert_relative_eq!(r.coefficients[1], 2.0, epsilon = 0.05);
+    assert_relative_eq!(r.coefficients[2], -3.0, epsilon = 0.1);
+    assert!(r.r_squared > 0.99 && r.adj_r_squared < r.r_squared);
+
+    // Residuals are orthogonal to every column of the design (normal equations).
+    assert!(r.residuals.iter().sum::<f64>().abs() < 1e-9);
+    for j in 0..3 {
+        let dot: f64 = (0..n).map(|i| x[i * 3 + j] * r.residuals[i]).sum();
+        assert!(dot.abs() < 1e-8, "column {j}: {dot}");
+    }
+
+    // Without an intercept the intercept column can be supplied explicitly.
+    let with_ones: Vec<f64> = x
+        .chunks(3)
+        .flat_map(|row| std::iter::once(1.0).chain(row.iter().copied()))
+        .collect();
+    let explicit = ols(&with_ones, 4, &y, false);
+    for (a, b) in explicit.coefficients.iter().zip(&r.coefficients) {
+        assert_relative_eq!(a, b, epsilon = 1e-10);
+    }
+    for (a, b) in explicit.std_errors.iter().zip(&r.std_errors) {
+        assert_relative_eq!(a, b, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_ols_invalid_inputs() {
+    // Too few observations.
+    assert!(ols(&[1.0, 2.0], 1, &[1.0, 2.0], true).coefficients[0].is_nan());
+    // Dimension mismatch.
+    assert!(ols(&[1.0, 2.0, 3.0], 2, &[1.0, 2.0], true).sigma.is_nan());
+    // Collinear columns.
+    let x = [1.0, 2.0, 2.0, 4.0, 3.0, 6.0, 4.0, 8.0];
+    let r = ols(&x, 2, &[1.0, 3.0, 2.0, 5.0], true);
+    assert!(r.coefficients.iter().all(|c| c.is_nan()));
+    assert_eq!(r.residuals.len(), 4);
+}
+
+#[test]
+fn test_regress_stats_matches_r_cars() {
+    // R: summary(lm(dist ~ speed, data = cars))
+    let speed = [
+        4.0, 4.0, 7.0, 7.0, 8.0, 9.0, 10.0, 10.0, 10.0, 11.0, 11.0, 12.0, 12.0, 12.0, 12.0, 13.0,
+        13.0, 13.0, 13.0, 14.0, 14.0, 14.0, 14.0, 15.0, 15.0, 15.0, 16.0, 16.0, 17.0, 17.0, 17.0,
+        18.0, 18.0, 18.0, 18.0, 19.0, 19.0, 19.0, 20.0, 20.0, 20.0, 20.0, 20.0, 22.0, 23.0, 24.0,
+        24.0, 24.0, 24.0, 25.0,
+    ];
+    let dist = [
+        2.0, 10.0, 4.0, 22.0, 16.0, 10.0, 18.0, 26.0, 34.0, 17.0, 28.0, 14.0, 20.0, 24.0, 28.0,
+        26.0, 34.0, 34.0, 46.0, 26.0, 36.0, 60.0, 80.0, 20.0, 26.0, 54.0, 32.0, 40.0, 32.0, 40.0,
+        50.0, 42.0, 56.0, 76.0, 84.0, 36.0, 46.0, 68.0, 32.0, 48.0, 52.0, 56.0, 64.0, 66.0, 54.0,
+        70.0, 92.0, 93.0, 120.0, 85.0,
+    ];
+    let r = regress_stats(&speed, &dist);
+    assert_relative_eq!(r.intercept, -17.5791, epsilon = 1e-4);
+    assert_relative_eq!(r.slope, 3.9324, epsilon = 1e-4);
+    assert_relative_eq!(r.intercept_se, 6.7584, epsilon = 1e-4);
+    assert_relative_eq!(r.slope_se, 0.4155, epsilon = 1e-4);
+    assert_relative_eq!(r.intercept_t, -2.601, epsilon = 1e-3);
+    assert_relative_eq!(r.slope_t, 9.464, epsilon = 1e-3);
+    assert_relative_eq!(r.intercept_p, 0.0123, epsilon = 1e-4);
+    assert_relative_eq!(r.slope_p, 1.49e-12, max_relative = 1e-2);
+    assert_relative_eq!(r.residual_se, 15.38, epsilon = 1e-2);
+    assert_relative_eq!(r.r_squared, 0.6511, epsilon = 1e-4);
+    assert_relative_eq!(r.f_statistic, 89.57, epsilon = 1e-2);
+    assert_eq!(r.df, 48.0);
+
+    // Agrees with the QR-based multiple regression.
+    let full = ols(&speed, 1, &dist, true);
+    assert_relative_eq!(r.slope_se, full.std_errors[1], epsilon = 1e-9);
+    assert_relative_eq!(r.intercept_se, full.std_errors[0], epsilon = 1e-9);
+    assert_relative_eq!(r.f_p_value, full.f_p_value, epsilon = 1e-12);
+
+    // Prediction interval: fit ± t(0.975, 48) * sqrt(s² (1 + 1/n) + (x - x̄)² se(slope)²)
+    let pi = r.predict_interval(21.0, 0.95);
+    let fit = r.predict(21.0);
+    assert_relative_eq!(fit, -17.579095 + 3.932409 * 21.0, epsilon = 1e-5);
+    let t_crit = crate::distributions::student_t_inv(0.975, 0.0, 1.0, 48.0).unwrap();
+    let dx = 21.0 - 15.4;
+    let se_pred = (r.residual_se.powi(2) * (1.0 + 1.0 / 50.0) + (dx * r.slope_se).powi(2)).sqrt();
+    assert_relative_eq!(pi[0], fit - t_crit * se_pred, epsilon = 1e-9);
+    assert_relative_eq!(pi[1], fit + t_crit * se_pred, epsilon = 1e-9);
+    let ci = r.confidence_interval(21.0, 0.95);
+    assert!(ci[0] > pi[0] && ci[1] < pi[1]);
+    // The mean-response band is narrowest at mean(x).
+    let at_mean = r.confidence_interval(15.4, 0.95);
+    assert!(at_mean[1] - at_mean[0] < ci[1] - ci[0]);
+}
+
+#[test]
+fn test_regress_stats_offset_x_matches_ols() {
+    // Trend line over Unix timestamps: raw sums of x² would cancel catastrophically.
+    let x: Vec<f64> = (0..1000).map(|i| 1.7e9 + i as f64).collect();
+    let y: Vec<f64> = (0..1000)
+        .map(|i| 0.5 * i as f64 + 0.3 * (i as f64 * 1.7).sin() + 0.2 * (i as f64 * 0.31).cos())
+        .collect();
+    let r = regress_stats(&x, &y);
+    let full = ols(&x, 1, &y, true);
+    assert_relative_eq!(r.slope, full.coefficients[1], max_relative = 1e-9);
+    assert_relative_eq!(r.intercept, full.coefficients[0], max_relative = 1e-6);
+    assert_relative_eq!(r.residual_se, full.sigma, max_relative = 1e-6);
+    assert_relative_eq!(r.slope_se, full.std_errors[1], max_relative = 1e-6);
+    assert_relative_eq!(r.slope_t, full.t_values[1], max_relative = 1e-6);
+    assert_relative_eq!(r.r_squared, full.r_squared, max_relative = 1e-9);
+    assert_relative_eq!(r.f_statistic, full.f_statistic, max_relative = 1e-6);
+
+    // Bands at a timestamp inside the data match the same computation about centered x.
+    let centered: Vec<f64> = x.iter().map(|v| v - 1.7e9).collect();
+    let reference = regress_stats(&centered, &y);
+    let pi = r.predict_interval(1.7e9 + 500.0, 0.95);
+    let pi_ref = reference.predict_interval(500.0, 0.95);
+    assert_relative_eq!(pi[0], pi_ref[0], epsilon = 1e-6);
+    assert_relative_eq!(pi[1], pi_ref[1], epsilon = 1e-6);
+}
+
+#[test]
+fn test_regress_stats_degenerate() {
+    assert!(regress_stats(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0])
+        .slope
+        .is_nan());
+    // Two points: the line is defined but there are no residual degrees of freedom.
+    let r = regress_stats(&[1.0, 2.0], &[1.0, 3.0]);
+    assert_relative_eq!(r.slope, 2.0, epsilon = 1e-12);
+    assert!(r.slope_se.is_nan());
+    assert!(r.predict_interval(3.0, 0.95)[0].is_nan());
+}
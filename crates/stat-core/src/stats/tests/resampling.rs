@@ -0,0 +1,193 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+const DATA: [f64; 12] = [2.1, 3.4, 1.9, 5.6, 4.4, 3.3, 2.8, 4.0, 3.9, 2.5, 6.8, 3.1];
+
+#[test]
+fn test_bootstrap_is_deterministic() {
+    let a = bootstrap_ci(&DATA, median, BootstrapMethod::Bca, 500, 0.05, 7);
+    let b = bootstrap_ci(&DATA, median, BootstrapMethod::Bca, 500, 0.05, 7);
+    let c = bootstrap_ci(&DATA, median, BootstrapMethod::Bca, 500, 0.05, 8);
+    assert_eq!(a, b);
+    assert_ne!(a.std_error, c.std_error);
+    assert_eq!(a.estimate, median(&DATA));
+}
+
+#[test]
+fn test_bootstrap_mean_standard_error() {
+    // The bootstrap standard error of the mean tends to the plug-in value sqrt(var_pop / n).
+    let data: Vec<f64> = (1..=20).map(f64::from).collect();
+    let res = bootstrap_ci(&data, mean, BootstrapMethod::Percentile, 20_000, 0.05, 1);
+    assert_relative_eq!(res.std_error, (33.25f64 / 20.0).sqrt(), max_relative = 0.03);
+    assert!(res.bias.abs() < 0.05);
+    // ...and the percentile interval to the normal interval.
+    assert_relative_eq!(res.ci[0], 10.5 - 1.959964 * 1.289, epsilon = 0.1);
+    assert_relative_eq!(res.ci[1], 10.5 + 1.959964 * 1.289, epsilon = 0.1);
+}
+
+#[test]
+fn test_bootstrap_methods_relate() {
+    let percentile = bootstrap_ci(&DATA, coeffvar, BootstrapMethod::Percentile, 2000, 0.1, 3);
+    let basic = bootstrap_ci(&DATA, coeffvar, BootstrapMethod::Basic, 2000, 0.1, 3);
+    let bca = bootstrap_ci(&DATA, coeffvar, BootstrapMethod::Bca, 2000, 0.1, 3);
+    // Same seed, same replicates: the basic interval reflects the percentile one.
+    let t = percentile.estimate;
+    assert_relative_eq!(basic.ci[0], 2.0 * t - percentile.ci[1], epsilon = 1e-12);
+    assert_relative_eq!(basic.ci[1], 2.0 * t - percentile.ci[0], epsilon = 1e-12);
+    assert_eq!(bca.std_error, percentile.std_error);
+    assert!(bca.ci[0] < t && t < bca.ci[1]);
+    assert!(bca.ci[0] != percentile.ci[0]);
+}
+
+#[test]
+fn test_bootstrap_paired() {
+    let x: Vec<f64> = (0..15).map(f64::from).collect();
+    let y: Vec<f64> = x.iter().map(|v| v * v).collect();
+    // Every resample of a monotone relationship has Spearman correlation 1.
+    let res = bootstrap_ci_paired(
+        &x,
+        &y,
+        spearmancoeff,
+        BootstrapMethod::Percentile,
+        500,
+        0.05,
+        9,
+    );
+    assert_relative_eq!(res.estimate, 1.0, epsilon = 1e-12);
+    assert_relative_eq!(res.ci[0], 1.0, epsilon = 1e-12);
+    assert_relative_eq!(res.ci[1], 1.0, epsilon = 1e-12);
+
+    let noisy: Vec<f64> = y
+        .iter()
+        .zip(DATA.iter().cycle())
+        .map(|(a, b)| a + 40.0 * b)
+        .collect();
+    let res = bootstrap_ci_paired(&x, &noisy, corrcoeff, BootstrapMethod::Bca, 2000, 0.05, 9);
+    assert!(res.ci[0] < res.estimate && res.estimate < res.ci[1]);
+    assert!(res.ci[1] <= 1.0);
+}
+
+#[test]
+fn test_bootstrap_invalid_inputs() {
+    assert!(
+        bootstrap_ci(&[1.0], mean, BootstrapMethod::Percentile, 100, 0.05, 0)
+            .estimate
+            .is_nan()
+    );
+    assert!(bootstrap_ci(&DATA, mean, BootstrapMethod::Percentile, 1, 0.05, 0).ci[0].is_nan());
+    assert!(bootstrap_ci(&DATA, mean, BootstrapMethod::Percentile, 100, 1.0, 0).ci[0].is_nan());
+    assert!(
+        bootstrap_ci(&[1.0, f64::NAN], mean, BootstrapMethod::Basic, 100, 0.05, 0)
+            .bias
+            .is_nan()
+    );
+    assert!(bootstrap_ci_paired(
+        &DATA,
+        &DATA[1..],
+        corrcoeff,
+        BootstrapMethod::Bca,
+        100,
+        0.05,
+        0
+    )
+    .estimate
+    .is_nan());
+}
+
+#[test]
+fn test_permutation_test_two_sample() {
+    let x = [5.1, 4.8, 6.0, 5.7, 6.3, 5.5, 4.9, 6.1];
+    let y = [4.2, 4.6, 5.0, 3.9, 4.4, 5.2, 4.1, 4.7];
+    let diff = |a: &[f64], b: &[f64]| mean(a) - mean(b);
+    let res = permutation_test(&x, &y, diff, Alternative::TwoSided, 20_000, 5);
+    assert_relative_eq!(res.statistic, mean(&x) - mean(&y), epsilon = 1e-12);
+    // Close to the pooled t-test for roughly normal data.
+    let t = ttest_ind(&x, &y, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(res.p_value, t.p_value, epsilon = 0.003);
+
+    let greater = permutation_test(&x, &y, diff, Alternative::Greater, 20_000, 5);
+    let less = permutation_test(&x, &y, diff, Alternative::Less, 20_000, 5);
+    assert!(greater.p_value < 0.01 && less.p_value > 0.99);
+    assert_relative_eq!(res.p_value, 2.0 * greater.p_value, epsilon = 1e-12);
+}
+
+#[test]
+fn test_permutation_test_separated_samples() {
+    // Only 2 of the C(10, 5) = 252 splits are as extreme as complete separation.
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [6.0, 7.0, 8.0, 9.0, 10.0];
+    let res = permutation_test(
+        &x,
+        &y,
+        |a, b| mean(a) - mean(b),
+        Alternative::TwoSided,
+        50_000,
+        11,
+    );
+    assert_relative_eq!(res.p_value, 2.0 / 252.0, epsilon = 2e-3);
+    assert_eq!(res.df, None);
+    assert_eq!(res.ci, None);
+}
+
+#[test]
+fn test_permutation_test_counts_rounding_ties() {
+    // Relabellings with the same split sum in a different order, which can shift the
+    // statistic by an ulp (0.3 + 0.2 + 0.1 != 0.1 + 0.2 + 0.3). They must still count as ties:
+    // exactly 1 of the C(6, 3) = 20 splits is as small as the observed one.
+    let x = [0.3, 0.2, 0.1];
+    let y = [0.4, 0.5, 0.6];
+    let sum_diff = |a: &[f64], b: &[f64]| a.iter().sum::<f64>() - b.iter().sum::<f64>();
+    let res = permutation_test(&x, &y, sum_diff, Alternative::Less, 50_000, 3);
+    assert_relative_eq!(res.p_value, 1.0 / 20.0, epsilon = 3e-3);
+
+    // Observed statistic of exactly 0: the 8 of 20 splits with equal sums still tie, so
+    // P(D >= 0) = P(D <= 0) = 14 / 20 however their rounding falls.
+    let x = [0.3, 0.2, 0.1];
+    let y = [0.3, 0.2, 0.1];
+    for alternative in [Alternative::Greater, Alternative::Less] {
+        let res = permutation_test(&x, &y, sum_diff, alternative, 50_000, 4);
+        assert_eq!(res.statistic, 0.0);
+        assert_relative_eq!(res.p_value, 0.7, epsilon = 8e-3);
+    }
+}
+
+#[test]
+fn test_permutation_test_paired_correlation() {
+    let x: Vec<f64> = (0..30).map(f64::from).collect();
+    let y: Vec<f64> = x
+        .iter()
+        .zip(DATA.iter().cycle())
+        .map(|(a, b)| a + 5.0 * b)
+        .collect();
+    let res = permutation_test_paired(&x, &y, corrcoeff, Alternative::Greater, 5000, 2);
+    assert_relative_eq!(res.statistic, corrcoeff(&x, &y), epsilon = 1e-12);
+    assert_relative_eq!(res.p_value, 1.0 / 5001.0, epsilon = 1e-12);
+
+    // No association: the p-value is roughly uniform, so not tiny.
+    let shuffled: Vec<f64> = DATA.iter().chain(DATA.iter().rev()).copied().collect();
+    let res = permutation_test_paired(
+        &x[..24],
+        &shuffled,
+        spearmancoeff,
+        Alternative::TwoSided,
+        5000,
+        2,
+    );
+    assert!(res.p_value > 0.05);
+
+    assert!(
+        permutation_test_paired(&x, &y[1..], corrcoeff, Alternative::TwoSided, 100, 0)
+            .p_value
+            .is_nan()
+    );
+    assert!(permutation_test(
+        &[],
+        &y,
+        |a, b| mean(a) - mean(b),
+        Alternative::TwoSided,
+        100,
+        0
+    )
+    .p_value
+    .is_nan());
+}
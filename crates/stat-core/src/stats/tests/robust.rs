@@ -0,0 +1,235 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+const LATENCIES: [f64; 17] = [
+    12.1, 14.3, 11.8, 13.5, 250.0, 12.9, 13.1, 11.2, 15.7, 12.4, 13.8, 980.0, 12.0, 14.9, 11.5,
+    13.3, 12.7,
+];
+
+/// Deterministic samples with ties and a wide range, for checking the fast order-statistic
+/// searches against their definitions.
+fn pseudo_random(n: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 40) as f64 - 10.0 + if state.is_multiple_of(7) { 500.0 } else { 0.0 }
+        })
+        .collect()
+}
+
+fn kth(mut values: Vec<f64>, k: usize) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values[k - 1]
+}
+
+#[test]
+fn test_trimmed_mean() {
+    // R: mean(x, trim = 0.1) and mean(x, trim = 0.2)
+    assert_relative_eq!(
+        trimmed_mean(&LATENCIES, 0.1),
+        28.933333333333334,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        trimmed_mean(&LATENCIES, 0.2),
+        13.181818181818182,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        trimmed_mean(&LATENCIES, 0.0),
+        mean(&LATENCIES),
+        epsilon = 1e-12
+    );
+    assert_eq!(trimmed_mean(&LATENCIES, 0.5), median(&LATENCIES));
+
+    assert!(trimmed_mean(&[], 0.1).is_nan());
+    assert!(trimmed_mean(&[1.0, f64::NAN], 0.1).is_nan());
+    assert!(trimmed_mean(&LATENCIES, 0.6).is_nan());
+    assert!(trimmed_mean(&LATENCIES, -0.1).is_nan());
+}
+
+#[test]
+fn test_trimmed_and_winsorized_variance() {
+    assert_relative_eq!(
+        trimmed_variance(&LATENCIES, 0.2),
+        0.819636363636364,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        winsorized_mean(&LATENCIES, 0.2),
+        13.276470588235295,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        winsorized_variance(&LATENCIES, 0.2),
+        1.3181617647058825,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        winsorized_variance(&LATENCIES, 0.0),
+        sample_variance(&LATENCIES),
+        epsilon = 1e-9
+    );
+
+    assert!(trimmed_variance(&[1.0, 2.0, 3.0], 0.4).is_nan());
+    assert!(winsorized_variance(&[1.0], 0.1).is_nan());
+    assert!(winsorized_mean(&LATENCIES, 0.5).is_nan());
+}
+
+#[test]
+fn test_mad() {
+    // R: mad(x)
+    assert_relative_eq!(mad(&LATENCIES), 1.6308624403561618, epsilon = 1e-12);
+    assert_relative_eq!(
+        mad_with_constant(&LATENCIES, 1.0),
+        meddev(&LATENCIES),
+        epsilon = 1e-12
+    );
+    assert_eq!(mad(&[5.0, 5.0, 5.0, 9.0]), 0.0);
+    assert!(mad(&[]).is_nan());
+    assert!(mad(&[1.0, f64::NAN]).is_nan());
+}
+
+#[test]
+fn test_qn_and_sn_scale() {
+    assert_relative_eq!(qn_scale(&LATENCIES), 1.8475581521739104, epsilon = 1e-12);
+    assert_relative_eq!(sn_scale(&LATENCIES), 1.7629739130434787, epsilon = 1e-12);
+
+    // Location and scale equivariance.
+    let shifted: Vec<f64> = LATENCIES.iter().map(|v| 3.0 * v - 40.0).collect();
+    assert_relative_eq!(
+        qn_scale(&shifted),
+        3.0 * qn_scale(&LATENCIES),
+        epsilon = 1e-9
+    );
+    assert_relative_eq!(
+        sn_scale(&shifted),
+        3.0 * sn_scale(&LATENCIES),
+        epsilon = 1e-9
+    );
+
+    assert!(qn_scale(&[1.0]).is_nan());
+    assert!(sn_scale(&[1.0]).is_nan());
+    assert!(qn_scale(&[1.0, f64::NAN, 2.0]).is_nan());
+}
+
+#[test]
+fn test_qn_and_sn_match_definitions() {
+    for n in (2..40).chain([97, 250]) {
+        let x = pseudo_random(n, n as u64);
+        let h = n / 2 + 1;
+        let distances: Vec<f64> = (0..n)
+            .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+            .map(|(i, j)| (x[i] - x[j]).abs())
+            .collect();
+        let qn_raw = kth(distances, h * (h - 1) / 2);
+        let qn_factor = qn_scale(&x) / 2.2219;
+        if qn_raw == 0.0 {
+            assert_eq!(qn_factor, 0.0);
+        } else {
+            let factor = qn_factor / qn_raw;
+            assert!(factor > 0.39 && factor <= 1.0, "n = {n}");
+        }
+
+        let inner: Vec<f64> = x
+            .iter()
+            .map(|&a| kth(x.iter().map(|&b| (a - b).abs()).collect(), n / 2 + 1))
+            .collect();
+        let sn_raw = kth(inner, n.div_ceil(2));
+        let sn_factor = sn_scale(&x) / 1.1926;
+        if sn_raw == 0.0 {
+            assert_eq!(sn_factor, 0.0);
+        } else {
+            let factor = sn_factor / sn_raw;
+            assert!(factor > 0.74 && factor < 1.86, "n = {n}");
+        }
+        if n > 9 {
+            assert_relative_eq!(
+                qn_factor,
+                qn_raw * n as f64 / (n as f64 + if n % 2 == 1 { 1.4 } else { 3.8 }),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                sn_factor,
+                sn_raw
+                    * if n % 2 == 1 {
+                        n as f64 / (n as f64 - 0.9)
+                    } else {
+                        1.0
+                    },
+                epsilon = 1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn test_huber_location() {
+    // MASS::huber(x, k = 1.345)$mu and MASS::huber(x)$mu
+    assert_relative_eq!(
+        huber_location(&LATENCIES, 1.345),
+        13.29146540430709,
+        epsilon = 1e-9
+    );
+    assert_relative_eq!(
+        huber_location(&LATENCIES, 1.5),
+        13.33950498258872,
+        epsilon = 1e-9
+    );
+    assert_eq!(huber_location(&[2.0, 2.0, 2.0, 7.0], 1.345), 2.0);
+    assert!(huber_location(&LATENCIES, 0.0).is_nan());
+    assert!(huber_location(&[], 1.345).is_nan());
+}
+
+#[test]
+fn test_biweight_location() {
+    assert_relative_eq!(
+        biweight_location(&LATENCIES, 4.685),
+        12.974700797949899,
+        epsilon = 1e-9
+    );
+    // Symmetric data: the estimate is the center of symmetry.
+    assert_relative_eq!(
+        biweight_location(&[1.0, 2.0, 3.0, 4.0, 5.0], 4.685),
+        3.0,
+        epsilon = 1e-12
+    );
+    assert!(biweight_location(&LATENCIES, -1.0).is_nan());
+    assert!(biweight_location(&[1.0, f64::NAN], 4.685).is_nan());
+}
+
+#[test]
+fn test_hodges_lehmann() {
+    assert_relative_eq!(hodges_lehmann(&LATENCIES), 13.3, epsilon = 1e-12);
+    let y = [10.2, 11.9, 9.8, 10.7, 12.3, 10.1, 300.0, 11.4];
+    assert_relative_eq!(hodges_lehmann_shift(&LATENCIES, &y), 2.0, epsilon = 1e-12);
+    assert_eq!(hodges_lehmann(&[4.0]), 4.0);
+    assert!(hodges_lehmann(&[]).is_nan());
+    assert!(hodges_lehmann_shift(&LATENCIES, &[]).is_nan());
+}
+
+#[test]
+fn test_hodges_lehmann_matches_definition() {
+    for n in (1..40).chain([101, 256]) {
+        let x = pseudo_random(n, 3 * n as u64 + 1);
+        let walsh: Vec<f64> = (0..n)
+            .flat_map(|i| (i..n).map(move |j| (i, j)))
+            .map(|(i, j)| (x[i] + x[j]) / 2.0)
+            .collect();
+        assert_relative_eq!(hodges_lehmann(&x), median(&walsh), epsilon = 1e-12);
+
+        let y = pseudo_random(n / 2 + 1, n as u64 + 17);
+        let diffs: Vec<f64> = x
+            .iter()
+            .flat_map(|&a| y.iter().map(move |&b| a - b))
+            .collect();
+        assert_relative_eq!(
+            hodges_lehmann_shift(&x, &y),
+            median(&diffs),
+            epsilon = 1e-12
+        );
+    }
+}
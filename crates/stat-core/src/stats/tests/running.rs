@@ -0,0 +1,90 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+fn sample_data() -> Vec<f64> {
+    (0..257)
+        .map(|i| {
+            let x = i as f64;
+            (x * 0.37).sin() * 10.0 + x * 0.05 + if i % 7 == 0 { 4.0 } else { 0.0 }
+        })
+        .collect()
+}
+
+#[test]
+fn test_running_moments_push_matches_slice_functions() {
+    let data = sample_data();
+    let mut acc = RunningMoments::new();
+    for &x in &data {
+        acc.push(x);
+    }
+
+    assert_eq!(acc.count(), data.len() as u64);
+    assert_relative_eq!(acc.mean(), mean(&data), epsilon = 1e-10);
+    assert_relative_eq!(acc.variance(), variance(&data), epsilon = 1e-10);
+    assert_relative_eq!(
+        acc.sample_variance(),
+        sample_variance(&data),
+        epsilon = 1e-10
+    );
+    assert_relative_eq!(acc.stdev(), stdev(&data), epsilon = 1e-10);
+    assert_relative_eq!(acc.skewness(), skewness(&data), epsilon = 1e-10);
+    assert_relative_eq!(acc.kurtosis(), kurtosis(&data), epsilon = 1e-10);
+}
+
+#[test]
+fn test_running_moments_push_slice_matches_push() {
+    let data = sample_data();
+    let by_slice = RunningMoments::from_slice(&data);
+    let mut by_value = RunningMoments::new();
+    for &x in &data {
+        by_value.push(x);
+    }
+
+    assert_eq!(by_slice.count(), by_value.count());
+    assert_relative_eq!(by_slice.mean(), by_value.mean(), epsilon = 1e-10);
+    assert_relative_eq!(by_slice.variance(), by_value.variance(), epsilon = 1e-10);
+    assert_relative_eq!(by_slice.skewness(), by_value.skewness(), epsilon = 1e-10);
+    assert_relative_eq!(by_slice.kurtosis(), by_value.kurtosis(), epsilon = 1e-10);
+}
+
+#[test]
+fn test_running_moments_merge_chunks() {
+    let data = sample_data();
+    let mut merged = RunningMoments::new();
+    for chunk in data.chunks(37) {
+        merged.merge(&RunningMoments::from_slice(chunk));
+    }
+
+    assert_eq!(merged.count(), data.len() as u64);
+    assert_relative_eq!(merged.mean(), mean(&data), epsilon = 1e-10);
+    assert_relative_eq!(merged.variance(), variance(&data), epsilon = 1e-10);
+    assert_relative_eq!(merged.skewness(), skewness(&data), epsilon = 1e-10);
+    assert_relative_eq!(merged.kurtosis(), kurtosis(&data), epsilon = 1e-10);
+
+    // Merging into / from an empty accumulator is the identity.
+    let mut empty = RunningMoments::new();
+    empty.merge(&merged);
+    assert_eq!(empty, merged);
+    merged.merge(&RunningMoments::new());
+    assert_eq!(empty, merged);
+}
+
+#[test]
+fn test_running_moments_degenerate() {
+    let mut acc = RunningMoments::new();
+    assert_eq!(acc.count(), 0);
+    assert!(acc.mean().is_nan());
+    assert!(acc.variance().is_nan());
+
+    acc.push(5.0);
+    assert_eq!(acc.mean(), 5.0);
+    assert_eq!(acc.variance(), 0.0);
+    assert!(acc.sample_variance().is_nan());
+
+    acc.push_slice(&[5.0, 5.0, 5.0]);
+    assert!(acc.skewness().is_nan());
+    assert!(acc.kurtosis().is_nan());
+
+    acc.reset();
+    assert_eq!(acc, RunningMoments::default());
+}
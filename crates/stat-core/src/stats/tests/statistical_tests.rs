@@ -213,6 +213,152 @@ fn test_chi_square_test_with_cardinality() {
     );
 }
 
+#[test]
+fn test_chi_square_counts_zero_cells() {
+    // R: chisq.test(matrix(c(10, 0, 0, 10), 2), correct = FALSE) -> X-squared = 20
+    let cat1: Vec<String> = (0..20)
+        .map(|i| if i < 10 { "A" } else { "B" }.to_string())
+        .collect();
+    let cat2: Vec<String> = (0..20)
+        .map(|i| if i < 10 { "X" } else { "Y" }.to_string())
+        .collect();
+    let result = chi_square_test(&cat1, &cat2);
+    assert_relative_eq!(result.statistic, 20.0, epsilon = 1e-10);
+    assert_relative_eq!(result.p_value, 7.744216431044084e-6, epsilon = 1e-15);
+
+    let hinted = chi_square_test_with_cardinality(&cat1, &cat2, Some(2), Some(2));
+    assert_eq!(hinted, result);
+
+    // 2x3 table with an empty cell.
+    let table = [10.0, 0.0, 5.0, 3.0, 8.0, 4.0];
+    let result = chi_square_test_table(&table, 3, ChiSquareMethod::Pearson);
+    assert_relative_eq!(result.statistic, 11.88034188034188, epsilon = 1e-10);
+    assert_relative_eq!(result.p_value, 0.002631579769867211, epsilon = 1e-12);
+    assert_eq!(result.df, 2);
+}
+
+#[test]
+fn test_chi_square_table_methods() {
+    // R: chisq.test(matrix(c(12, 7, 5, 9), 2)) with and without correction; G-test by hand.
+    let table = [12.0, 5.0, 7.0, 9.0];
+    let pearson = chi_square_test_table(&table, 2, ChiSquareMethod::Pearson);
+    assert_relative_eq!(pearson.statistic, 2.430575519681557, epsilon = 1e-10);
+    assert_relative_eq!(pearson.p_value, 0.11898920553214525, epsilon = 1e-10);
+
+    let yates = chi_square_test_table(&table, 2, ChiSquareMethod::Yates);
+    assert_relative_eq!(yates.statistic, 1.4559963788146838, epsilon = 1e-10);
+    assert_relative_eq!(yates.p_value, 0.2275682145758098, epsilon = 1e-10);
+
+    let g = chi_square_test_table(&table, 2, ChiSquareMethod::GTest);
+    assert_relative_eq!(g.statistic, 2.460043007730717, epsilon = 1e-10);
+    assert_relative_eq!(g.p_value, 0.116775584678496, epsilon = 1e-10);
+    assert_eq!(g.df, 1);
+
+    // Yates only applies with one degree of freedom.
+    let big = [10.0, 0.0, 5.0, 3.0, 8.0, 4.0];
+    assert_eq!(
+        chi_square_test_table(&big, 3, ChiSquareMethod::Yates),
+        chi_square_test_table(&big, 3, ChiSquareMethod::Pearson)
+    );
+
+    // Empty rows and columns are dropped.
+    let padded = [12.0, 0.0, 5.0, 0.0, 0.0, 0.0, 7.0, 0.0, 9.0];
+    let result = chi_square_test_table(&padded, 3, ChiSquareMethod::Pearson);
+    assert_relative_eq!(result.statistic, pearson.statistic, epsilon = 1e-12);
+    assert_eq!(result.df, 1);
+
+    assert!(chi_square_test_table(&table, 3, ChiSquareMethod::Pearson)
+        .statistic
+        .is_nan());
+    assert!(
+        chi_square_test_table(&[1.0, -1.0, 2.0, 3.0], 2, ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+    assert!(
+        chi_square_test_table(&[1.0, 2.0], 2, ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_chi_square_codes_match_strings() {
+    let labels1 = ["a", "b", "c", "a", "b", "c", "a", "a", "b", "c", "c", "c"];
+    let labels2 = ["x", "x", "y", "y", "x", "y", "x", "x", "y", "y", "x", "y"];
+    let cat1: Vec<String> = labels1.iter().map(|s| s.to_string()).collect();
+    let cat2: Vec<String> = labels2.iter().map(|s| s.to_string()).collect();
+    let expected = chi_square_test(&cat1, &cat2);
+
+    let code = |s: &str| match s {
+        "a" | "x" => 3,
+        "b" | "y" => 70,
+        _ => 5_000_000,
+    };
+    let codes1: Vec<u32> = labels1.iter().map(|s| code(s)).collect();
+    let codes2: Vec<u32> = labels2.iter().map(|s| code(s)).collect();
+    let result = chi_square_test_codes(&codes1, &codes2, ChiSquareMethod::Pearson);
+    assert_relative_eq!(result.statistic, expected.statistic, epsilon = 1e-12);
+    assert_relative_eq!(result.p_value, expected.p_value, epsilon = 1e-12);
+    assert_eq!(result.df, 2);
+
+    assert!(
+        chi_square_test_codes(&[1, 1], &[0, 1], ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+    assert!(
+        chi_square_test_codes(&[0, 1], &[0], ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_chi_square_goodness_of_fit() {
+    // R: chisq.test(c(89, 37, 30, 28, 2), p = c(40, 20, 20, 15, 5), rescale.p = TRUE)
+    let observed = [89.0, 37.0, 30.0, 28.0, 2.0];
+    let expected = [40.0, 20.0, 20.0, 15.0, 5.0];
+    let result = chi_square_goodness_of_fit(&observed, &expected, ChiSquareMethod::Pearson);
+    assert_relative_eq!(result.statistic, 9.990143369175627, epsilon = 1e-10);
+    assert_relative_eq!(result.p_value, 0.04059404334478121, epsilon = 1e-10);
+    assert_eq!(result.df, 4);
+
+    let g = chi_square_goodness_of_fit(&observed, &expected, ChiSquareMethod::GTest);
+    assert_relative_eq!(g.statistic, 12.641399706057529, epsilon = 1e-10);
+    assert_relative_eq!(g.p_value, 0.013167627532045818, epsilon = 1e-10);
+
+    // Empty expected means equal frequencies; probabilities and counts are interchangeable.
+    let uniform = chi_square_goodness_of_fit(&[10.0, 20.0, 30.0], &[], ChiSquareMethod::Pearson);
+    assert_relative_eq!(uniform.statistic, 10.0, epsilon = 1e-12);
+    let probs = chi_square_goodness_of_fit(
+        &[10.0, 20.0, 30.0],
+        &[1.0 / 3.0; 3],
+        ChiSquareMethod::Pearson,
+    );
+    assert_relative_eq!(probs.statistic, 10.0, epsilon = 1e-12);
+
+    // Two categories with Yates: (|15 - 10| - 0.5)^2 / 10 * 2.
+    let yates = chi_square_goodness_of_fit(&[15.0, 5.0], &[], ChiSquareMethod::Yates);
+    assert_relative_eq!(yates.statistic, 4.05, epsilon = 1e-12);
+
+    assert!(
+        chi_square_goodness_of_fit(&[1.0], &[], ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+    assert!(
+        chi_square_goodness_of_fit(&[1.0, 2.0], &[1.0, 0.0], ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+    assert!(
+        chi_square_goodness_of_fit(&[1.0, 2.0], &[1.0], ChiSquareMethod::Pearson)
+            .statistic
+            .is_nan()
+    );
+}
+
 #[test]
 fn test_anova_f_score_categorical() {
     // Test equivalent to regular anova_f_score
@@ -424,3 +570,212 @@ fn test_tukey_hsd_degenerate_se_zero_nonzero_mean_diff() {
     assert_relative_eq!(cmp.ci_lower, -1.0, epsilon = 0.0);
     assert_relative_eq!(cmp.ci_upper, -1.0, epsilon = 0.0);
 }
+
+// R's `sleep` dataset: extra hours of sleep for two drugs on the same 10 patients.
+const SLEEP_G1: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+const SLEEP_G2: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+#[test]
+fn test_ttest_ind_matches_r() {
+    // t.test(g1, g2, var.equal = TRUE)
+    let r = ttest_ind(&SLEEP_G1, &SLEEP_G2, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.statistic, -1.860813, epsilon = 1e-5);
+    assert_eq!(r.df, Some(18.0));
+    assert_relative_eq!(r.p_value, 0.07918671, epsilon = 1e-6);
+    let ci = r.ci.unwrap();
+    assert_relative_eq!(ci[0], -3.363874, epsilon = 1e-5);
+    assert_relative_eq!(ci[1], 0.203874, epsilon = 1e-5);
+}
+
+#[test]
+fn test_ttest_welch_matches_r() {
+    // t.test(g1, g2)
+    let r = ttest_welch(&SLEEP_G1, &SLEEP_G2, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.statistic, -1.860813, epsilon = 1e-5);
+    assert_relative_eq!(r.df.unwrap(), 17.77647, epsilon = 1e-4);
+    assert_relative_eq!(r.p_value, 0.07939414, epsilon = 1e-6);
+    let ci = r.ci.unwrap();
+    assert_relative_eq!(ci[0], -3.3654832, epsilon = 1e-5);
+    assert_relative_eq!(ci[1], 0.2054832, epsilon = 1e-5);
+}
+
+#[test]
+fn test_ttest_paired_matches_r() {
+    // t.test(g1, g2, paired = TRUE)
+    let r = ttest_paired(&SLEEP_G1, &SLEEP_G2, Alternative::TwoSided, 0.05);
+    assert_relative_eq!(r.statistic, -4.062128, epsilon = 1e-5);
+    assert_eq!(r.df, Some(9.0));
+    assert_relative_eq!(r.p_value, 0.002832890, epsilon = 1e-7);
+    let ci = r.ci.unwrap();
+    assert_relative_eq!(ci[0], -2.4598858, epsilon = 1e-5);
+    assert_relative_eq!(ci[1], -0.7001142, epsilon = 1e-5);
+
+    // Paired test is the one-sample test on the differences.
+    let diffs: Vec<f64> = SLEEP_G1.iter().zip(&SLEEP_G2).map(|(a, b)| a - b).collect();
+    let one = ttest(&diffs, 0.0);
+    assert_relative_eq!(r.statistic, one.statistic, epsilon = 1e-12);
+    assert_relative_eq!(r.p_value, one.p_value, epsilon = 1e-12);
+}
+
+#[test]
+fn test_two_sample_ttest_one_sided() {
+    let two = ttest_welch(&SLEEP_G1, &SLEEP_G2, Alternative::TwoSided, 0.05);
+    let less = ttest_welch(&SLEEP_G1, &SLEEP_G2, Alternative::Less, 0.05);
+    let greater = ttest_welch(&SLEEP_G1, &SLEEP_G2, Alternative::Greater, 0.05);
+
+    // t < 0: the "less" p-value is half the two-sided one, "greater" is its complement.
+    assert_relative_eq!(less.p_value, two.p_value / 2.0, epsilon = 1e-12);
+    assert_relative_eq!(greater.p_value, 1.0 - less.p_value, epsilon = 1e-12);
+
+    let less_ci = less.ci.unwrap();
+    assert_eq!(less_ci[0], f64::NEG_INFINITY);
+    assert!(less_ci[1] < two.ci.unwrap()[1]);
+    let greater_ci = greater.ci.unwrap();
+    assert_eq!(greater_ci[1], f64::INFINITY);
+    assert!(greater_ci[0] > two.ci.unwrap()[0]);
+}
+
+#[test]
+fn test_two_sample_ttest_edge_cases() {
+    assert!(ttest_ind(&[1.0], &[1.0, 2.0], Alternative::TwoSided, 0.05)
+        .statistic
+        .is_nan());
+    assert!(
+        ttest_paired(&[1.0, 2.0], &[1.0], Alternative::TwoSided, 0.05)
+            .p_value
+            .is_nan()
+    );
+    // Zero variance in both groups -> undefined statistic.
+    assert!(
+        ttest_welch(&[1.0, 1.0], &[2.0, 2.0], Alternative::TwoSided, 0.05)
+            .statistic
+            .is_nan()
+    );
+
+    let with_nan = [1.0, f64::NAN, 3.0];
+    let r = ttest_ind_strict(&with_nan, &SLEEP_G2, Alternative::TwoSided, 0.05);
+    assert!(r.statistic.is_nan());
+    assert_eq!(r.df, Some(11.0));
+    assert!(
+        ttest_welch_strict(&with_nan, &SLEEP_G2, Alternative::TwoSided, 0.05)
+            .p_value
+            .is_nan()
+    );
+    assert!(
+        ttest_paired_strict(&with_nan, &[1.0, 2.0, 3.0], Alternative::Less, 0.05)
+            .p_value
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_one_sample_tests_alternative() {
+    // Statistic is positive: "greater" halves the two-sided p-value, "less" must not.
+    let two = ttest(&SLEEP_G2, 0.0);
+    let less = ttest_with_alternative(&SLEEP_G2, 0.0, Alternative::Less);
+    let greater = ttest_with_alternative(&SLEEP_G2, 0.0, Alternative::Greater);
+    assert!(two.statistic > 0.0);
+    assert_relative_eq!(greater.p_value, two.p_value / 2.0, epsilon = 1e-12);
+    assert_relative_eq!(less.p_value + greater.p_value, 1.0, epsilon = 1e-12);
+    assert!(less.p_value > 0.99);
+    assert_eq!(
+        ttest_with_alternative(&SLEEP_G2, 0.0, Alternative::TwoSided),
+        two
+    );
+
+    let z_two = ztest(&SLEEP_G2, 3.0, 2.0);
+    let z_less = ztest_with_alternative(&SLEEP_G2, 3.0, 2.0, Alternative::Less);
+    let z_greater = ztest_with_alternative(&SLEEP_G2, 3.0, 2.0, Alternative::Greater);
+    assert!(z_two.statistic < 0.0);
+    assert_relative_eq!(z_less.p_value, z_two.p_value / 2.0, epsilon = 1e-12);
+    assert_relative_eq!(z_greater.p_value, 1.0 - z_less.p_value, epsilon = 1e-12);
+
+    let with_nan = [1.0, f64::NAN, 3.0];
+    assert!(
+        ttest_with_alternative_strict(&with_nan, 0.0, Alternative::Less)
+            .p_value
+            .is_nan()
+    );
+    assert!(
+        ztest_with_alternative_strict(&with_nan, 0.0, 1.0, Alternative::Greater)
+            .p_value
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_confidence_intervals_alternative() {
+    // One-sided 95% normal bounds use z = 1.644854 instead of 1.959964.
+    let upper = normalci_with_alternative(0.05, 10.0, 2.0, Alternative::Less);
+    assert_eq!(upper[0], f64::NEG_INFINITY);
+    assert_relative_eq!(upper[1], 10.0 + 1.6448536269514722 * 2.0, epsilon = 1e-9);
+    let lower = normalci_with_alternative(0.05, 10.0, 2.0, Alternative::Greater);
+    assert_relative_eq!(lower[0], 10.0 - 1.6448536269514722 * 2.0, epsilon = 1e-9);
+    assert_eq!(lower[1], f64::INFINITY);
+    assert_eq!(
+        normalci(0.05, 10.0, 2.0),
+        normalci_with_alternative(0.05, 10.0, 2.0, Alternative::TwoSided)
+    );
+
+    // R: t.test(sleep$extra[11:20], alternative = "greater")$conf.int -> 1.169334
+    let sd = sample_stdev(&SLEEP_G2);
+    let ci = tci_with_alternative(0.05, mean(&SLEEP_G2), sd, 10.0, Alternative::Greater);
+    assert_relative_eq!(ci[0], 1.169334, epsilon = 1e-6);
+    assert_eq!(ci[1], f64::INFINITY);
+    assert!(tci_with_alternative(0.05, 1.0, 1.0, 1.0, Alternative::Less)[0].is_nan());
+}
+
+#[test]
+fn test_chi_square_alternative() {
+    let cat1: Vec<String> = ["a", "a", "b", "b", "a", "b", "a", "b"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let cat2: Vec<String> = ["x", "y", "x", "y", "x", "y", "y", "y"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let upper = chi_square_test(&cat1, &cat2);
+    let greater = chi_square_test_with_alternative(&cat1, &cat2, Alternative::Greater);
+    let less = chi_square_test_with_alternative(&cat1, &cat2, Alternative::Less);
+    assert_eq!(greater, upper);
+    assert_eq!(less.statistic, upper.statistic);
+    assert_relative_eq!(less.p_value + upper.p_value, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_tukey_hsd_matches_r_plant_growth() {
+    // R: TukeyHSD(aov(weight ~ group, data = PlantGrowth))
+    let ctrl = [4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14];
+    let trt1 = [4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69];
+    let trt2 = [6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26];
+    let r = tukey_hsd(&[&ctrl, &trt1, &trt2]);
+    assert_eq!(r.conf_level, 0.95);
+
+    // (group1, group2, diff = group2 - group1, lwr, upr, p adj) as printed by R.
+    let expected = [
+        (0, 1, -0.371, -1.0622161, 0.3202161, 0.3908711),
+        (0, 2, 0.494, -0.1972161, 1.1852161, 0.1979960),
+        (1, 2, 0.865, 0.1737839, 1.5562161, 0.0120064),
+    ];
+    for (cmp, &(g1, g2, diff, lwr, upr, p)) in r.comparisons.iter().zip(&expected) {
+        assert_eq!((cmp.group1, cmp.group2), (g1, g2));
+        // Our mean_diff is group1 - group2, so the interval is mirrored.
+        assert_relative_eq!(cmp.mean_diff, -diff, epsilon = 1e-10);
+        assert_relative_eq!(cmp.ci_lower, -upr, epsilon = 1e-6);
+        assert_relative_eq!(cmp.ci_upper, -lwr, epsilon = 1e-6);
+        assert_relative_eq!(cmp.p_value, p, epsilon = 1e-6);
+    }
+
+    let r99 = tukey_hsd_with_confidence(&[&ctrl, &trt1, &trt2], 0.99);
+    assert_eq!(r99.conf_level, 0.99);
+    let width95 = r.comparisons[0].ci_upper - r.comparisons[0].ci_lower;
+    let width99 = r99.comparisons[0].ci_upper - r99.comparisons[0].ci_lower;
+    assert!(width99 > width95);
+    assert_eq!(r99.comparisons[0].p_value, r.comparisons[0].p_value);
+    assert!(
+        tukey_hsd_with_confidence(&[&ctrl, &trt1], 1.5).comparisons[0]
+            .ci_lower
+            .is_nan()
+    );
+}
@@ -0,0 +1,112 @@
+use crate::stats::*;
+use approx::assert_relative_eq;
+
+fn expand(data: &[f64], counts: &[f64]) -> Vec<f64> {
+    data.iter()
+        .zip(counts)
+        .flat_map(|(&x, &c)| std::iter::repeat_n(x, c as usize))
+        .collect()
+}
+
+#[test]
+fn test_weighted_equal_weights_match_unweighted() {
+    let data: Vec<f64> = (0..23)
+        .map(|i| ((i * 7) % 11) as f64 + 0.5 * i as f64)
+        .collect();
+    let y: Vec<f64> = data.iter().map(|v| v * 1.5 - (v * 0.3).sin()).collect();
+    let w = vec![2.5; data.len()];
+
+    assert_relative_eq!(weighted_mean(&data, &w), mean(&data), epsilon = 1e-12);
+    assert_relative_eq!(
+        weighted_variance(&data, &w),
+        variance(&data),
+        epsilon = 1e-10
+    );
+    assert_relative_eq!(weighted_stdev(&data, &w), stdev(&data), epsilon = 1e-10);
+    assert_relative_eq!(
+        weighted_skewness(&data, &w),
+        skewness(&data),
+        epsilon = 1e-10
+    );
+    assert_relative_eq!(
+        weighted_kurtosis(&data, &w),
+        kurtosis(&data),
+        epsilon = 1e-10
+    );
+    assert_relative_eq!(
+        weighted_covariance(&data, &y, &w),
+        covariance(&data, &y),
+        epsilon = 1e-10
+    );
+    assert_relative_eq!(
+        weighted_corrcoeff(&data, &y, &w),
+        corrcoeff(&data, &y),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_weighted_frequency_weights_match_expanded_data() {
+    let data = [1.0, 4.0, 2.5, 9.0, 3.0, 7.0];
+    let counts = [3.0, 1.0, 2.0, 1.0, 4.0, 2.0];
+    let expanded = expand(&data, &counts);
+
+    assert_relative_eq!(
+        weighted_mean(&data, &counts),
+        mean(&expanded),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        weighted_sample_variance(&data, &counts, WeightKind::Frequency),
+        sample_variance(&expanded),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        weighted_sample_stdev(&data, &counts, WeightKind::Frequency),
+        sample_stdev(&expanded),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        weighted_skewness(&data, &counts),
+        skewness(&expanded),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_weighted_reliability_variance() {
+    // numpy: np.cov([1, 2, 3, 4], aweights=[1, 2, 3, 4]) -> 10/7 (reliability convention)
+    let data = [1.0, 2.0, 3.0, 4.0];
+    let w = [1.0, 2.0, 3.0, 4.0];
+    assert_relative_eq!(
+        weighted_sample_variance(&data, &w, WeightKind::Reliability),
+        10.0 / 7.0,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        weighted_sample_covariance(&data, &data, &w, WeightKind::Reliability),
+        10.0 / 7.0,
+        epsilon = 1e-12
+    );
+    // Reliability weights are scale-invariant.
+    let w10: Vec<f64> = w.iter().map(|v| v * 10.0).collect();
+    assert_relative_eq!(
+        weighted_sample_variance(&data, &w10, WeightKind::Reliability),
+        10.0 / 7.0,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_weighted_invalid_inputs() {
+    assert!(weighted_mean(&[], &[]).is_nan());
+    assert!(weighted_mean(&[1.0, 2.0], &[1.0]).is_nan());
+    assert!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]).is_nan());
+    assert!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]).is_nan());
+    assert!(weighted_mean(&[1.0, 2.0], &[f64::NAN, 1.0]).is_nan());
+    assert!(weighted_sample_variance(&[1.0, 2.0], &[0.5, 0.4], WeightKind::Frequency).is_nan());
+    assert!(weighted_skewness(&[1.0, 2.0], &[1.0, 1.0]).is_nan());
+    assert!(weighted_kurtosis(&[5.0, 5.0, 5.0, 5.0], &[1.0, 2.0, 3.0, 4.0]).is_nan());
+    assert!(weighted_corrcoeff(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], &[1.0, 1.0, 1.0]).is_nan());
+    assert!(weighted_covariance(&[1.0, 2.0], &[1.0], &[1.0, 1.0]).is_nan());
+}
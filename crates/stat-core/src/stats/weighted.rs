@@ -0,0 +1,396 @@
+use crate::stats::simd::SimdAccum4;
+use wide::f64x4;
+
+// =============================================================================
+// Weighted Descriptive Statistics
+// =============================================================================
+
+/// Convention used to turn weights into an unbiased (sample) estimate.
+///
+/// - `Frequency`: each weight is a repeat count; the denominator is `V1 - 1` where `V1 = Σw`.
+/// - `Reliability`: weights are relative importances (e.g. inverse variances, survey weights);
+///   the denominator is `V1 - V2 / V1` where `V2 = Σw²`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightKind {
+    Frequency,
+    Reliability,
+}
+
+/// Internal: validate a data/weights pair (same length, non-empty, weights finite-or-inf and >= 0).
+#[inline]
+fn weights_valid(data: &[f64], weights: &[f64]) -> bool {
+    !data.is_empty()
+        && data.len() == weights.len()
+        && weights.iter().all(|w| !w.is_nan() && *w >= 0.0)
+}
+
+/// Internal: `(Σw, Σw·x)` in one SIMD pass.
+#[inline(always)]
+fn weighted_sums(data: &[f64], weights: &[f64]) -> (f64, f64) {
+    let mut sw = SimdAccum4::zero();
+    let mut swx = SimdAccum4::zero();
+    let mut tail_w = 0.0;
+    let mut tail_wx = 0.0;
+
+    simd_for_each_unrolled4_f64x4_zip!(
+        data,
+        weights,
+        |bx, bw| {
+            let w1 = bw.read_unaligned();
+            let w2 = bw.add(1).read_unaligned();
+            let w3 = bw.add(2).read_unaligned();
+            let w4 = bw.add(3).read_unaligned();
+            sw.v1 += w1;
+            sw.v2 += w2;
+            sw.v3 += w3;
+            sw.v4 += w4;
+            swx.v1 += w1 * bx.read_unaligned();
+            swx.v2 += w2 * bx.add(1).read_unaligned();
+            swx.v3 += w3 * bx.add(2).read_unaligned();
+            swx.v4 += w4 * bx.add(3).read_unaligned();
+        },
+        |xv, wv| {
+            sw.v1 += wv;
+            swx.v1 += wv * xv;
+        },
+        |xs, ws| {
+            tail_w += ws;
+            tail_wx = ws.mul_add(xs, tail_wx);
+        }
+    );
+
+    (sw.reduce_add() + tail_w, swx.reduce_add() + tail_wx)
+}
+
+/// Internal: `(Σw², Σw·(x-m)²)` in one SIMD pass.
+#[inline(always)]
+fn weighted_squared_deviations(data: &[f64], weights: &[f64], m: f64) -> (f64, f64) {
+    let mean_vec = f64x4::splat(m);
+    let mut sww = SimdAccum4::zero();
+    let mut s2 = SimdAccum4::zero();
+    let mut tail_ww = 0.0;
+    let mut tail_2 = 0.0;
+
+    simd_for_each_unrolled4_f64x4_zip!(
+        data,
+        weights,
+        |bx, bw| {
+            let w1 = bw.read_unaligned();
+            let w2 = bw.add(1).read_unaligned();
+            let w3 = bw.add(2).read_unaligned();
+            let w4 = bw.add(3).read_unaligned();
+            let d1 = bx.read_unaligned() - mean_vec;
+            let d2 = bx.add(1).read_unaligned() - mean_vec;
+            let d3 = bx.add(2).read_unaligned() - mean_vec;
+            let d4 = bx.add(3).read_unaligned() - mean_vec;
+            sww.v1 += w1 * w1;
+            sww.v2 += w2 * w2;
+            sww.v3 += w3 * w3;
+            sww.v4 += w4 * w4;
+            s2.v1 += w1 * d1 * d1;
+            s2.v2 += w2 * d2 * d2;
+            s2.v3 += w3 * d3 * d3;
+            s2.v4 += w4 * d4 * d4;
+        },
+        |xv, wv| {
+            let d = xv - mean_vec;
+            sww.v1 += wv * wv;
+            s2.v1 += wv * d * d;
+        },
+        |xs, ws| {
+            let d = xs - m;
+            tail_ww = ws.mul_add(ws, tail_ww);
+            tail_2 = (ws * d).mul_add(d, tail_2);
+        }
+    );
+
+    (sww.reduce_add() + tail_ww, s2.reduce_add() + tail_2)
+}
+
+/// Internal: `(Σw·d², Σw·d³, Σw·d⁴)` with `d = x - m`, in one SIMD pass.
+#[inline(always)]
+fn weighted_higher_moments(data: &[f64], weights: &[f64], m: f64) -> (f64, f64, f64) {
+    let mean_vec = f64x4::splat(m);
+    let mut s2 = SimdAccum4::zero();
+    let mut s3 = SimdAccum4::zero();
+    let mut s4 = SimdAccum4::zero();
+    let mut tail_2 = 0.0;
+    let mut tail_3 = 0.0;
+    let mut tail_4 = 0.0;
+
+    simd_for_each_unrolled4_f64x4_zip!(
+        data,
+        weights,
+        |bx, bw| {
+            let w1 = bw.read_unaligned();
+            let w2 = bw.add(1).read_unaligned();
+            let w3 = bw.add(2).read_unaligned();
+            let w4 = bw.add(3).read_unaligned();
+            let d1 = bx.read_unaligned() - mean_vec;
+            let d2 = bx.add(1).read_unaligned() - mean_vec;
+            let d3 = bx.add(2).read_unaligned() - mean_vec;
+            let d4 = bx.add(3).read_unaligned() - mean_vec;
+            let wd1 = w1 * d1 * d1;
+            let wd2 = w2 * d2 * d2;
+            let wd3 = w3 * d3 * d3;
+            let wd4 = w4 * d4 * d4;
+            s2.v1 += wd1;
+            s3.v1 += wd1 * d1;
+            s4.v1 += wd1 * d1 * d1;
+            s2.v2 += wd2;
+            s3.v2 += wd2 * d2;
+            s4.v2 += wd2 * d2 * d2;
+            s2.v3 += wd3;
+            s3.v3 += wd3 * d3;
+            s4.v3 += wd3 * d3 * d3;
+            s2.v4 += wd4;
+            s3.v4 += wd4 * d4;
+            s4.v4 += wd4 * d4 * d4;
+        },
+        |xv, wv| {
+            let d = xv - mean_vec;
+            let wd = wv * d * d;
+            s2.v1 += wd;
+            s3.v1 += wd * d;
+            s4.v1 += wd * d * d;
+        },
+        |xs, ws| {
+            let d = xs - m;
+            let wd = ws * d * d;
+            tail_2 += wd;
+            tail_3 += wd * d;
+            tail_4 += wd * d * d;
+        }
+    );
+
+    (
+        s2.reduce_add() + tail_2,
+        s3.reduce_add() + tail_3,
+        s4.reduce_add() + tail_4,
+    )
+}
+
+/// Internal: unbiased denominator for the given weight convention.
+#[inline]
+fn weighted_denominator(v1: f64, v2: f64, kind: WeightKind) -> f64 {
+    match kind {
+        WeightKind::Frequency => v1 - 1.0,
+        WeightKind::Reliability => v1 - v2 / v1,
+    }
+}
+
+/// Internal: `(Σw, weighted mean)`, or `None` for invalid inputs or zero total weight.
+#[inline]
+fn weighted_total_and_mean(data: &[f64], weights: &[f64]) -> Option<(f64, f64)> {
+    if !weights_valid(data, weights) {
+        return None;
+    }
+    let (sum_w, sum_wx) = weighted_sums(data, weights);
+    if sum_w <= 0.0 {
+        return None;
+    }
+    Some((sum_w, sum_wx / sum_w))
+}
+
+/// Calculate the weighted mean `Σw·x / Σw`.
+///
+/// Returns NaN for empty or mismatched inputs, for negative or NaN weights,
+/// or when the total weight is zero.
+///
+/// # Example
+/// ```rust
+/// use stat_core::weighted_mean;
+/// let m = weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 1.0, 2.0]);
+/// assert!((m - 2.25).abs() < 1e-12);
+/// ```
+pub fn weighted_mean(data: &[f64], weights: &[f64]) -> f64 {
+    weighted_total_and_mean(data, weights).map_or(f64::NAN, |(_, m)| m)
+}
+
+/// Calculate the weighted population variance `Σw·(x-m)² / Σw`.
+pub fn weighted_variance(data: &[f64], weights: &[f64]) -> f64 {
+    let Some((sum_w, m)) = weighted_total_and_mean(data, weights) else {
+        return f64::NAN;
+    };
+    let (_, ss) = weighted_squared_deviations(data, weights, m);
+    ss / sum_w
+}
+
+/// Calculate the weighted sample (unbiased) variance.
+///
+/// The denominator depends on `kind`: `Σw - 1` for frequency weights, or
+/// `Σw - Σw²/Σw` for reliability weights. Returns NaN when the denominator is not positive.
+pub fn weighted_sample_variance(data: &[f64], weights: &[f64], kind: WeightKind) -> f64 {
+    let Some((sum_w, m)) = weighted_total_and_mean(data, weights) else {
+        return f64::NAN;
+    };
+    let (sum_ww, ss) = weighted_squared_deviations(data, weights, m);
+    let denom = weighted_denominator(sum_w, sum_ww, kind);
+    if denom <= 0.0 || denom.is_nan() {
+        return f64::NAN;
+    }
+    ss / denom
+}
+
+/// Calculate the weighted population standard deviation.
+pub fn weighted_stdev(data: &[f64], weights: &[f64]) -> f64 {
+    weighted_variance(data, weights).sqrt()
+}
+
+/// Calculate the weighted sample standard deviation (see [`weighted_sample_variance`]).
+pub fn weighted_sample_stdev(data: &[f64], weights: &[f64], kind: WeightKind) -> f64 {
+    weighted_sample_variance(data, weights, kind).sqrt()
+}
+
+/// Calculate the weighted skewness (requires at least 3 elements).
+/// Uses the population formula `m3 / m2^(3/2)` with weighted central moments.
+pub fn weighted_skewness(data: &[f64], weights: &[f64]) -> f64 {
+    if data.len() < 3 || data.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let Some((sum_w, m)) = weighted_total_and_mean(data, weights) else {
+        return f64::NAN;
+    };
+    let (s2, s3, _) = weighted_higher_moments(data, weights, m);
+    let m2 = s2 / sum_w;
+    let m3 = s3 / sum_w;
+    if m2 == 0.0 {
+        return f64::NAN;
+    }
+    m3 / m2.sqrt().powi(3)
+}
+
+/// Calculate the weighted excess kurtosis (requires at least 4 elements).
+/// Uses the population formula `m4 / m2^2 - 3` with weighted central moments.
+pub fn weighted_kurtosis(data: &[f64], weights: &[f64]) -> f64 {
+    if data.len() < 4 || data.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let Some((sum_w, m)) = weighted_total_and_mean(data, weights) else {
+        return f64::NAN;
+    };
+    let (s2, _, s4) = weighted_higher_moments(data, weights, m);
+    let m2 = s2 / sum_w;
+    let m4 = s4 / sum_w;
+    if m2 == 0.0 {
+        return f64::NAN;
+    }
+    m4 / (m2 * m2) - 3.0
+}
+
+/// Internal: `(Σw, Σw², Σw·dx·dx, Σw·dy·dy, Σw·dx·dy)` around the weighted means.
+fn weighted_cross_sums(x: &[f64], y: &[f64], weights: &[f64]) -> Option<[f64; 5]> {
+    if x.len() != y.len() {
+        return None;
+    }
+    let (sum_w, mean_x) = weighted_total_and_mean(x, weights)?;
+    let (_, sum_wy) = weighted_sums(y, weights);
+    let mean_y = sum_wy / sum_w;
+    let mean_x_vec = f64x4::splat(mean_x);
+    let mean_y_vec = f64x4::splat(mean_y);
+
+    let mut sww = SimdAccum4::zero();
+    let mut sxx = SimdAccum4::zero();
+    let mut syy = SimdAccum4::zero();
+    let mut sxy = SimdAccum4::zero();
+    let mut tail = [0.0f64; 4];
+
+    simd_for_each_unrolled4_f64x4_zip3!(
+        x,
+        y,
+        weights,
+        |bx, by, bw| {
+            let w1 = bw.read_unaligned();
+            let w2 = bw.add(1).read_unaligned();
+            let w3 = bw.add(2).read_unaligned();
+            let w4 = bw.add(3).read_unaligned();
+            let dx1 = bx.read_unaligned() - mean_x_vec;
+            let dy1 = by.read_unaligned() - mean_y_vec;
+            let dx2 = bx.add(1).read_unaligned() - mean_x_vec;
+            let dy2 = by.add(1).read_unaligned() - mean_y_vec;
+            let dx3 = bx.add(2).read_unaligned() - mean_x_vec;
+            let dy3 = by.add(2).read_unaligned() - mean_y_vec;
+            let dx4 = bx.add(3).read_unaligned() - mean_x_vec;
+            let dy4 = by.add(3).read_unaligned() - mean_y_vec;
+
+            sww.v1 += w1 * w1;
+            sxx.v1 += w1 * dx1 * dx1;
+            syy.v1 += w1 * dy1 * dy1;
+            sxy.v1 += w1 * dx1 * dy1;
+            sww.v2 += w2 * w2;
+            sxx.v2 += w2 * dx2 * dx2;
+            syy.v2 += w2 * dy2 * dy2;
+            sxy.v2 += w2 * dx2 * dy2;
+            sww.v3 += w3 * w3;
+            sxx.v3 += w3 * dx3 * dx3;
+            syy.v3 += w3 * dy3 * dy3;
+            sxy.v3 += w3 * dx3 * dy3;
+            sww.v4 += w4 * w4;
+            sxx.v4 += w4 * dx4 * dx4;
+            syy.v4 += w4 * dy4 * dy4;
+            sxy.v4 += w4 * dx4 * dy4;
+        },
+        |xv, yv, wv| {
+            let dx = xv - mean_x_vec;
+            let dy = yv - mean_y_vec;
+            let wdx = wv * dx;
+            sww.v1 += wv * wv;
+            sxx.v1 += wdx * dx;
+            syy.v1 += wv * dy * dy;
+            sxy.v1 += wdx * dy;
+        },
+        |xs, ys, ws| {
+            let dx = xs - mean_x;
+            let dy = ys - mean_y;
+            tail[0] = ws.mul_add(ws, tail[0]);
+            tail[1] = (ws * dx).mul_add(dx, tail[1]);
+            tail[2] = (ws * dy).mul_add(dy, tail[2]);
+            tail[3] = (ws * dx).mul_add(dy, tail[3]);
+        }
+    );
+
+    Some([
+        sum_w,
+        sww.reduce_add() + tail[0],
+        sxx.reduce_add() + tail[1],
+        syy.reduce_add() + tail[2],
+        sxy.reduce_add() + tail[3],
+    ])
+}
+
+/// Calculate the weighted population covariance `Σw·(x-mx)·(y-my) / Σw`.
+pub fn weighted_covariance(x: &[f64], y: &[f64], weights: &[f64]) -> f64 {
+    match weighted_cross_sums(x, y, weights) {
+        Some([sum_w, _, _, _, sum_xy]) => sum_xy / sum_w,
+        None => f64::NAN,
+    }
+}
+
+/// Calculate the weighted sample (unbiased) covariance using the given weight convention.
+pub fn weighted_sample_covariance(x: &[f64], y: &[f64], weights: &[f64], kind: WeightKind) -> f64 {
+    match weighted_cross_sums(x, y, weights) {
+        Some([sum_w, sum_ww, _, _, sum_xy]) => {
+            let denom = weighted_denominator(sum_w, sum_ww, kind);
+            if denom <= 0.0 || denom.is_nan() {
+                f64::NAN
+            } else {
+                sum_xy / denom
+            }
+        }
+        None => f64::NAN,
+    }
+}
+
+/// Calculate the weighted Pearson correlation coefficient.
+/// Uses a centered (weighted-mean-based) two-pass algorithm for numerical stability.
+pub fn weighted_corrcoeff(x: &[f64], y: &[f64], weights: &[f64]) -> f64 {
+    match weighted_cross_sums(x, y, weights) {
+        Some([_, _, sum_xx, sum_yy, sum_xy]) => {
+            if sum_xx <= 0.0 || sum_yy <= 0.0 {
+                return f64::NAN;
+            }
+            sum_xy / (sum_xx * sum_yy).sqrt()
+        }
+        None => f64::NAN,
+    }
+}
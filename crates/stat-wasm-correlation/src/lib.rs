@@ -25,10 +25,23 @@ pub unsafe extern "C" fn free_f64(ptr: *mut f64, len: usize) {
     free_bytes(ptr as *mut u8, len * 8);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn alloc_f32(len: usize) -> *mut f32 {
+    alloc_bytes(len * 4) as *mut f32
+}
+#[no_mangle]
+pub unsafe extern "C" fn free_f32(ptr: *mut f32, len: usize) {
+    free_bytes(ptr as *mut u8, len * 4);
+}
+
 fn slice_from<'a>(ptr: *const f64, len: usize) -> &'a [f64] {
     unsafe { std::slice::from_raw_parts(ptr, len) }
 }
 
+fn slice_from_f32<'a>(ptr: *const f32, len: usize) -> &'a [f32] {
+    unsafe { std::slice::from_raw_parts(ptr, len) }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn covariance_f64(
     x_ptr: *const f64,
@@ -54,6 +67,30 @@ pub unsafe extern "C" fn corrcoeff_f64(
     stat_core::corrcoeff(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
 }
 #[no_mangle]
+pub unsafe extern "C" fn covariance_f32(
+    x_ptr: *const f32,
+    x_len: usize,
+    y_ptr: *const f32,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::covariance_f32(slice_from_f32(x_ptr, x_len), slice_from_f32(y_ptr, y_len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn corrcoeff_f32(
+    x_ptr: *const f32,
+    x_len: usize,
+    y_ptr: *const f32,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::corrcoeff_f32(slice_from_f32(x_ptr, x_len), slice_from_f32(y_ptr, y_len))
+}
+#[no_mangle]
 pub unsafe extern "C" fn spearmancoeff_f64(
     x_ptr: *const f64,
     x_len: usize,
@@ -65,3 +102,306 @@ pub unsafe extern "C" fn spearmancoeff_f64(
     }
     stat_core::spearmancoeff(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
 }
+#[no_mangle]
+pub unsafe extern "C" fn kendallcoeff_f64(
+    x_ptr: *const f64,
+    x_len: usize,
+    y_ptr: *const f64,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::kendallcoeff(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pointbiserial_f64(
+    x_ptr: *const f64,
+    x_len: usize,
+    y_ptr: *const f64,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::pointbiserial(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn distancecorr_f64(
+    x_ptr: *const f64,
+    x_len: usize,
+    y_ptr: *const f64,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::distancecorr(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn chatterjee_xi_f64(
+    x_ptr: *const f64,
+    x_len: usize,
+    y_ptr: *const f64,
+    y_len: usize,
+) -> f64 {
+    if x_len != y_len {
+        return f64::NAN;
+    }
+    stat_core::chatterjee_xi(slice_from(x_ptr, x_len), slice_from(y_ptr, y_len))
+}
+
+/// Split a flat buffer of `cols` contiguous columns of `rows` values each.
+fn columns_from<'a>(data_ptr: *const f64, rows: usize, cols: usize) -> Vec<&'a [f64]> {
+    let data = slice_from(data_ptr, rows * cols);
+    (0..cols).map(|c| &data[c * rows..(c + 1) * rows]).collect()
+}
+
+/// Map a correlation method code from JS: 0 = Pearson, 1 = Spearman, 2 = Kendall.
+fn correlation_method_from(code: i32) -> stat_core::CorrelationMethod {
+    match code {
+        1 => stat_core::CorrelationMethod::Spearman,
+        2 => stat_core::CorrelationMethod::Kendall,
+        _ => stat_core::CorrelationMethod::Pearson,
+    }
+}
+
+/// Write the row-major `cols x cols` covariance matrix to `out_ptr`; returns `cols * cols`.
+#[no_mangle]
+pub unsafe extern "C" fn covariance_matrix_f64(
+    data_ptr: *const f64,
+    rows: usize,
+    cols: usize,
+    out_ptr: *mut f64,
+) -> isize {
+    let matrix = stat_core::covariance_matrix(&columns_from(data_ptr, rows, cols));
+    std::slice::from_raw_parts_mut(out_ptr, matrix.len()).copy_from_slice(&matrix);
+    matrix.len() as isize
+}
+
+/// Write the row-major `cols x cols` correlation matrix to `out_ptr`; returns `cols * cols`.
+#[no_mangle]
+pub unsafe extern "C" fn correlation_matrix_f64(
+    data_ptr: *const f64,
+    rows: usize,
+    cols: usize,
+    method: i32,
+    out_ptr: *mut f64,
+) -> isize {
+    let matrix = stat_core::correlation_matrix(
+        &columns_from(data_ptr, rows, cols),
+        correlation_method_from(method),
+    );
+    std::slice::from_raw_parts_mut(out_ptr, matrix.len()).copy_from_slice(&matrix);
+    matrix.len() as isize
+}
+
+/// Map an alternative hypothesis code from JS: 0 = two-sided, 1 = less, 2 = greater.
+fn alternative_from(code: i32) -> stat_core::Alternative {
+    match code {
+        1 => stat_core::Alternative::Less,
+        2 => stat_core::Alternative::Greater,
+        _ => stat_core::Alternative::TwoSided,
+    }
+}
+
+/// Write `[estimate, statistic, p_value, df, ci_lower, ci_upper]` for a correlation test,
+/// with NaN for a missing df or interval.
+fn write_correlation_test(res: &stat_core::CorrelationTestResult, out_ptr: *mut f64) -> isize {
+    let out = unsafe { std::slice::from_raw_parts_mut(out_ptr, 6) };
+    let ci = res.ci.unwrap_or([f64::NAN, f64::NAN]);
+    out[0] = res.estimate;
+    out[1] = res.statistic;
+    out[2] = res.p_value;
+    out[3] = res.df.unwrap_or(f64::NAN);
+    out[4] = ci[0];
+    out[5] = ci[1];
+    6
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn corrcoeff_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    alt: i32,
+    alpha: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::corrcoeff_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        alternative_from(alt),
+        alpha,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn spearmancoeff_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    alt: i32,
+    alpha: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::spearmancoeff_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        alternative_from(alt),
+        alpha,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn kendallcoeff_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    alt: i32,
+    alpha: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::kendallcoeff_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        alternative_from(alt),
+        alpha,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pointbiserial_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    alt: i32,
+    alpha: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::pointbiserial_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        alternative_from(alt),
+        alpha,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+/// Partial correlation; `controls_ptr` holds `n_controls` contiguous columns of `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn partialcorr_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    controls_ptr: *const f64,
+    n_controls: usize,
+) -> f64 {
+    stat_core::partialcorr(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        &columns_from(controls_ptr, len, n_controls),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn partialcorr_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    controls_ptr: *const f64,
+    n_controls: usize,
+    alt: i32,
+    alpha: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::partialcorr_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        &columns_from(controls_ptr, len, n_controls),
+        alternative_from(alt),
+        alpha,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+/// Distance correlation permutation test; `seed` is a non-negative integer below 2^53.
+#[no_mangle]
+pub unsafe extern "C" fn distancecorr_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    permutations: usize,
+    seed: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::distancecorr_test(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        permutations,
+        seed as u64,
+    );
+    write_correlation_test(&res, out_ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn chatterjee_xi_test_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    len: usize,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::chatterjee_xi_test(slice_from(x_ptr, len), slice_from(y_ptr, len));
+    write_correlation_test(&res, out_ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn weighted_covariance_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    w_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::weighted_covariance(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        slice_from(w_ptr, len),
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_sample_covariance_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    w_ptr: *const f64,
+    len: usize,
+    reliability: bool,
+) -> f64 {
+    let kind = if reliability {
+        stat_core::WeightKind::Reliability
+    } else {
+        stat_core::WeightKind::Frequency
+    };
+    stat_core::weighted_sample_covariance(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        slice_from(w_ptr, len),
+        kind,
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_corrcoeff_f64(
+    x_ptr: *const f64,
+    y_ptr: *const f64,
+    w_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::weighted_corrcoeff(
+        slice_from(x_ptr, len),
+        slice_from(y_ptr, len),
+        slice_from(w_ptr, len),
+    )
+}
@@ -14,8 +14,82 @@ export function free_f64(ptr, len) {
   free(ptr, len * 8);
 }
 
+export function alloc_f32(len) {
+  return alloc(len * 4);
+}
+
+export function free_f32(ptr, len) {
+  free(ptr, len * 4);
+}
+
 // Correlation functions
 export function covariance_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().covariance_f64(xPtr, xLen, yPtr, yLen); }
 export function corrcoeff_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().corrcoeff_f64(xPtr, xLen, yPtr, yLen); }
+export function covariance_f32(xPtr, xLen, yPtr, yLen) { return wasmExports().covariance_f32(xPtr, xLen, yPtr, yLen); }
+export function corrcoeff_f32(xPtr, xLen, yPtr, yLen) { return wasmExports().corrcoeff_f32(xPtr, xLen, yPtr, yLen); }
 export function spearmancoeff_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().spearmancoeff_f64(xPtr, xLen, yPtr, yLen); }
+export function kendallcoeff_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().kendallcoeff_f64(xPtr, xLen, yPtr, yLen); }
+export function pointbiserial_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().pointbiserial_f64(xPtr, xLen, yPtr, yLen); }
+export function distancecorr_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().distancecorr_f64(xPtr, xLen, yPtr, yLen); }
+export function chatterjee_xi_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().chatterjee_xi_f64(xPtr, xLen, yPtr, yLen); }
+// `controlsPtr` holds `nControls` columns of `len` values stored back to back.
+export function partialcorr_f64(xPtr, yPtr, len, controlsPtr, nControls) { return wasmExports().partialcorr_f64(xPtr, yPtr, len, controlsPtr, nControls); }
 
+// Matrices over `cols` columns stored back to back (`rows` values each). The row-major
+// `cols x cols` result is written to `outPtr`. Correlation `method`: 0 = Pearson,
+// 1 = Spearman, 2 = Kendall. NaN values are dropped pairwise.
+export function covariance_matrix_f64(dataPtr, rows, cols, outPtr) { return wasmExports().covariance_matrix_f64(dataPtr, rows, cols, outPtr); }
+export function correlation_matrix_f64(dataPtr, rows, cols, method, outPtr) { return wasmExports().correlation_matrix_f64(dataPtr, rows, cols, method, outPtr); }
+
+export function weighted_covariance_f64(xPtr, yPtr, wPtr, len) { return wasmExports().weighted_covariance_f64(xPtr, yPtr, wPtr, len); }
+export function weighted_sample_covariance_f64(xPtr, yPtr, wPtr, len, reliability) { return wasmExports().weighted_sample_covariance_f64(xPtr, yPtr, wPtr, len, reliability); }
+export function weighted_corrcoeff_f64(xPtr, yPtr, wPtr, len) { return wasmExports().weighted_corrcoeff_f64(xPtr, yPtr, wPtr, len); }
+
+// Correlation tests. Alternative codes: 0 = two-sided, 1 = less, 2 = greater.
+function readCorrelationTest(outPtr) {
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 6);
+  const res = {
+    estimate: view[0],
+    statistic: view[1],
+    p_value: view[2],
+    df: isNaN(view[3]) ? undefined : view[3],
+    ci: isNaN(view[4]) && isNaN(view[5]) ? undefined : [view[4], view[5]],
+  };
+  free(outPtr, 6 * 8);
+  return res;
+}
+export function corrcoeff_test_f64(xPtr, yPtr, len, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().corrcoeff_test_f64(xPtr, yPtr, len, alt, alpha, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function spearmancoeff_test_f64(xPtr, yPtr, len, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().spearmancoeff_test_f64(xPtr, yPtr, len, alt, alpha, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function kendallcoeff_test_f64(xPtr, yPtr, len, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().kendallcoeff_test_f64(xPtr, yPtr, len, alt, alpha, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function pointbiserial_test_f64(xPtr, yPtr, len, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().pointbiserial_test_f64(xPtr, yPtr, len, alt, alpha, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function partialcorr_test_f64(xPtr, yPtr, len, controlsPtr, nControls, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().partialcorr_test_f64(xPtr, yPtr, len, controlsPtr, nControls, alt, alpha, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function distancecorr_test_f64(xPtr, yPtr, len, permutations = 999, seed = 0) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().distancecorr_test_f64(xPtr, yPtr, len, permutations, seed, outPtr);
+  return readCorrelationTest(outPtr);
+}
+export function chatterjee_xi_test_f64(xPtr, yPtr, len) {
+  const outPtr = alloc(6 * 8);
+  wasmExports().chatterjee_xi_test_f64(xPtr, yPtr, len, outPtr);
+  return readCorrelationTest(outPtr);
+}
@@ -72,6 +72,15 @@ define_scalar_fn!(normal_cdf_scalar, normal_cdf, (mean: f64, sd: f64));
 define_scalar_fn!(normal_inv_scalar, normal_inv, [mean: f64, sd: f64]);
 define_array_fn!(normal_pdf_inplace, normal_pdf_array, [mean: f64, sd: f64]);
 define_array_fn!(normal_cdf_inplace, normal_cdf_array, [mean: f64, sd: f64]);
+define_scalar_fn!(normal_logpdf_scalar, normal_logpdf, (mean: f64, sd: f64));
+define_scalar_fn!(normal_logcdf_scalar, normal_logcdf, (mean: f64, sd: f64));
+define_scalar_fn!(normal_sf_scalar, normal_sf, (mean: f64, sd: f64));
+define_scalar_fn!(normal_logsf_scalar, normal_logsf, (mean: f64, sd: f64));
+define_scalar_fn!(normal_isf_scalar, normal_isf, [mean: f64, sd: f64]);
+define_array_fn!(normal_logpdf_inplace, normal_logpdf_array, [mean: f64, sd: f64]);
+define_array_fn!(normal_logcdf_inplace, normal_logcdf_array, [mean: f64, sd: f64]);
+define_array_fn!(normal_sf_inplace, normal_sf_array, [mean: f64, sd: f64]);
+define_array_fn!(normal_logsf_inplace, normal_logsf_array, [mean: f64, sd: f64]);
 
 // Gamma distribution (shape, rate)
 define_scalar_fn!(gamma_pdf_scalar, gamma_pdf, (shape: f64, rate: f64));
@@ -79,6 +88,15 @@ define_scalar_fn!(gamma_cdf_scalar, gamma_cdf, (shape: f64, rate: f64));
 define_scalar_fn!(gamma_inv_scalar, gamma_inv, [shape: f64, rate: f64]);
 define_array_fn!(gamma_pdf_inplace, gamma_pdf_array, [shape: f64, rate: f64]);
 define_array_fn!(gamma_cdf_inplace, gamma_cdf_array, [shape: f64, rate: f64]);
+define_scalar_fn!(gamma_logpdf_scalar, gamma_logpdf, (shape: f64, rate: f64));
+define_scalar_fn!(gamma_logcdf_scalar, gamma_logcdf, (shape: f64, rate: f64));
+define_scalar_fn!(gamma_sf_scalar, gamma_sf, (shape: f64, rate: f64));
+define_scalar_fn!(gamma_logsf_scalar, gamma_logsf, (shape: f64, rate: f64));
+define_scalar_fn!(gamma_isf_scalar, gamma_isf, [shape: f64, rate: f64]);
+define_array_fn!(gamma_logpdf_inplace, gamma_logpdf_array, [shape: f64, rate: f64]);
+define_array_fn!(gamma_logcdf_inplace, gamma_logcdf_array, [shape: f64, rate: f64]);
+define_array_fn!(gamma_sf_inplace, gamma_sf_array, [shape: f64, rate: f64]);
+define_array_fn!(gamma_logsf_inplace, gamma_logsf_array, [shape: f64, rate: f64]);
 
 // Beta distribution (alpha, beta)
 define_scalar_fn!(beta_pdf_scalar, beta_pdf, (alpha: f64, beta: f64));
@@ -86,6 +104,15 @@ define_scalar_fn!(beta_cdf_scalar, beta_cdf, (alpha: f64, beta: f64));
 define_scalar_fn!(beta_inv_scalar, beta_inv, [alpha: f64, beta: f64]);
 define_array_fn!(beta_pdf_inplace, beta_pdf_array, [alpha: f64, beta: f64]);
 define_array_fn!(beta_cdf_inplace, beta_cdf_array, [alpha: f64, beta: f64]);
+define_scalar_fn!(beta_logpdf_scalar, beta_logpdf, (alpha: f64, beta: f64));
+define_scalar_fn!(beta_logcdf_scalar, beta_logcdf, (alpha: f64, beta: f64));
+define_scalar_fn!(beta_sf_scalar, beta_sf, (alpha: f64, beta: f64));
+define_scalar_fn!(beta_logsf_scalar, beta_logsf, (alpha: f64, beta: f64));
+define_scalar_fn!(beta_isf_scalar, beta_isf, [alpha: f64, beta: f64]);
+define_array_fn!(beta_logpdf_inplace, beta_logpdf_array, [alpha: f64, beta: f64]);
+define_array_fn!(beta_logcdf_inplace, beta_logcdf_array, [alpha: f64, beta: f64]);
+define_array_fn!(beta_sf_inplace, beta_sf_array, [alpha: f64, beta: f64]);
+define_array_fn!(beta_logsf_inplace, beta_logsf_array, [alpha: f64, beta: f64]);
 
 // Student's t distribution
 define_scalar_fn!(
@@ -113,6 +140,15 @@ define_array_fn!(
     student_t_cdf_array,
     [mean: f64, scale: f64, dof: f64]
 );
+define_scalar_fn!(student_t_logpdf_scalar, student_t_logpdf, (mean: f64, scale: f64, dof: f64));
+define_scalar_fn!(student_t_logcdf_scalar, student_t_logcdf, (mean: f64, scale: f64, dof: f64));
+define_scalar_fn!(student_t_sf_scalar, student_t_sf, (mean: f64, scale: f64, dof: f64));
+define_scalar_fn!(student_t_logsf_scalar, student_t_logsf, (mean: f64, scale: f64, dof: f64));
+define_scalar_fn!(student_t_isf_scalar, student_t_isf, [mean: f64, scale: f64, dof: f64]);
+define_array_fn!(student_t_logpdf_inplace, student_t_logpdf_array, [mean: f64, scale: f64, dof: f64]);
+define_array_fn!(student_t_logcdf_inplace, student_t_logcdf_array, [mean: f64, scale: f64, dof: f64]);
+define_array_fn!(student_t_sf_inplace, student_t_sf_array, [mean: f64, scale: f64, dof: f64]);
+define_array_fn!(student_t_logsf_inplace, student_t_logsf_array, [mean: f64, scale: f64, dof: f64]);
 
 // Chi-squared distribution
 define_scalar_fn!(chi_squared_pdf_scalar, chi_squared_pdf, (dof: f64));
@@ -120,6 +156,15 @@ define_scalar_fn!(chi_squared_cdf_scalar, chi_squared_cdf, (dof: f64));
 define_scalar_fn!(chi_squared_inv_scalar, chi_squared_inv, [dof: f64]);
 define_array_fn!(chi_squared_pdf_inplace, chi_squared_pdf_array, [dof: f64]);
 define_array_fn!(chi_squared_cdf_inplace, chi_squared_cdf_array, [dof: f64]);
+define_scalar_fn!(chi_squared_logpdf_scalar, chi_squared_logpdf, (dof: f64));
+define_scalar_fn!(chi_squared_logcdf_scalar, chi_squared_logcdf, (dof: f64));
+define_scalar_fn!(chi_squared_sf_scalar, chi_squared_sf, (dof: f64));
+define_scalar_fn!(chi_squared_logsf_scalar, chi_squared_logsf, (dof: f64));
+define_scalar_fn!(chi_squared_isf_scalar, chi_squared_isf, [dof: f64]);
+define_array_fn!(chi_squared_logpdf_inplace, chi_squared_logpdf_array, [dof: f64]);
+define_array_fn!(chi_squared_logcdf_inplace, chi_squared_logcdf_array, [dof: f64]);
+define_array_fn!(chi_squared_sf_inplace, chi_squared_sf_array, [dof: f64]);
+define_array_fn!(chi_squared_logsf_inplace, chi_squared_logsf_array, [dof: f64]);
 
 // Fisher F distribution
 define_scalar_fn!(fisher_f_pdf_scalar, fisher_f_pdf, (df1: f64, df2: f64));
@@ -135,6 +180,15 @@ define_array_fn!(
     fisher_f_cdf_array,
     [df1: f64, df2: f64]
 );
+define_scalar_fn!(fisher_f_logpdf_scalar, fisher_f_logpdf, (df1: f64, df2: f64));
+define_scalar_fn!(fisher_f_logcdf_scalar, fisher_f_logcdf, (df1: f64, df2: f64));
+define_scalar_fn!(fisher_f_sf_scalar, fisher_f_sf, (df1: f64, df2: f64));
+define_scalar_fn!(fisher_f_logsf_scalar, fisher_f_logsf, (df1: f64, df2: f64));
+define_scalar_fn!(fisher_f_isf_scalar, fisher_f_isf, [df1: f64, df2: f64]);
+define_array_fn!(fisher_f_logpdf_inplace, fisher_f_logpdf_array, [df1: f64, df2: f64]);
+define_array_fn!(fisher_f_logcdf_inplace, fisher_f_logcdf_array, [df1: f64, df2: f64]);
+define_array_fn!(fisher_f_sf_inplace, fisher_f_sf_array, [df1: f64, df2: f64]);
+define_array_fn!(fisher_f_logsf_inplace, fisher_f_logsf_array, [df1: f64, df2: f64]);
 
 // Exponential distribution
 define_scalar_fn!(exponential_pdf_scalar, exponential_pdf, (rate: f64));
@@ -150,6 +204,15 @@ define_array_fn!(
     exponential_cdf_array,
     [rate: f64]
 );
+define_scalar_fn!(exponential_logpdf_scalar, exponential_logpdf, (rate: f64));
+define_scalar_fn!(exponential_logcdf_scalar, exponential_logcdf, (rate: f64));
+define_scalar_fn!(exponential_sf_scalar, exponential_sf, (rate: f64));
+define_scalar_fn!(exponential_logsf_scalar, exponential_logsf, (rate: f64));
+define_scalar_fn!(exponential_isf_scalar, exponential_isf, [rate: f64]);
+define_array_fn!(exponential_logpdf_inplace, exponential_logpdf_array, [rate: f64]);
+define_array_fn!(exponential_logcdf_inplace, exponential_logcdf_array, [rate: f64]);
+define_array_fn!(exponential_sf_inplace, exponential_sf_array, [rate: f64]);
+define_array_fn!(exponential_logsf_inplace, exponential_logsf_array, [rate: f64]);
 
 // Poisson distribution (discrete - uses pmf instead of pdf)
 define_scalar_fn!(poisson_pmf_scalar, poisson_pmf, (lambda: f64));
@@ -157,6 +220,15 @@ define_scalar_fn!(poisson_cdf_scalar, poisson_cdf, (lambda: f64));
 define_scalar_fn!(poisson_inv_scalar, poisson_inv, [lambda: f64]);
 define_array_fn!(poisson_pmf_inplace, poisson_pmf_array, [lambda: f64]);
 define_array_fn!(poisson_cdf_inplace, poisson_cdf_array, [lambda: f64]);
+define_scalar_fn!(poisson_logpmf_scalar, poisson_logpmf, (lambda: f64));
+define_scalar_fn!(poisson_logcdf_scalar, poisson_logcdf, (lambda: f64));
+define_scalar_fn!(poisson_sf_scalar, poisson_sf, (lambda: f64));
+define_scalar_fn!(poisson_logsf_scalar, poisson_logsf, (lambda: f64));
+define_scalar_fn!(poisson_isf_scalar, poisson_isf, [lambda: f64]);
+define_array_fn!(poisson_logpmf_inplace, poisson_logpmf_array, [lambda: f64]);
+define_array_fn!(poisson_logcdf_inplace, poisson_logcdf_array, [lambda: f64]);
+define_array_fn!(poisson_sf_inplace, poisson_sf_array, [lambda: f64]);
+define_array_fn!(poisson_logsf_inplace, poisson_logsf_array, [lambda: f64]);
 
 // Binomial distribution (discrete - uses pmf instead of pdf)
 define_scalar_fn!(binomial_pmf_scalar, binomial_pmf, (n: f64, p: f64));
@@ -168,6 +240,19 @@ pub unsafe extern "C" fn binomial_inv_scalar(prob: f64, n: f64, p: f64) -> f64 {
 }
 define_array_fn!(binomial_pmf_inplace, binomial_pmf_array, [n: f64, p: f64]);
 define_array_fn!(binomial_cdf_inplace, binomial_cdf_array, [n: f64, p: f64]);
+define_scalar_fn!(binomial_logpmf_scalar, binomial_logpmf, (n: f64, p: f64));
+define_scalar_fn!(binomial_logcdf_scalar, binomial_logcdf, (n: f64, p: f64));
+define_scalar_fn!(binomial_sf_scalar, binomial_sf, (n: f64, p: f64));
+define_scalar_fn!(binomial_logsf_scalar, binomial_logsf, (n: f64, p: f64));
+
+#[no_mangle]
+pub unsafe extern "C" fn binomial_isf_scalar(prob: f64, n: f64, p: f64) -> f64 {
+    stat_core::binomial_isf(prob, n, p).unwrap_or(f64::NAN)
+}
+define_array_fn!(binomial_logpmf_inplace, binomial_logpmf_array, [n: f64, p: f64]);
+define_array_fn!(binomial_logcdf_inplace, binomial_logcdf_array, [n: f64, p: f64]);
+define_array_fn!(binomial_sf_inplace, binomial_sf_array, [n: f64, p: f64]);
+define_array_fn!(binomial_logsf_inplace, binomial_logsf_array, [n: f64, p: f64]);
 
 // Uniform distribution
 define_scalar_fn!(uniform_pdf_scalar, uniform_pdf, (min: f64, max: f64));
@@ -175,6 +260,15 @@ define_scalar_fn!(uniform_cdf_scalar, uniform_cdf, (min: f64, max: f64));
 define_scalar_fn!(uniform_inv_scalar, uniform_inv, [min: f64, max: f64]);
 define_array_fn!(uniform_pdf_inplace, uniform_pdf_array, [min: f64, max: f64]);
 define_array_fn!(uniform_cdf_inplace, uniform_cdf_array, [min: f64, max: f64]);
+define_scalar_fn!(uniform_logpdf_scalar, uniform_logpdf, (min: f64, max: f64));
+define_scalar_fn!(uniform_logcdf_scalar, uniform_logcdf, (min: f64, max: f64));
+define_scalar_fn!(uniform_sf_scalar, uniform_sf, (min: f64, max: f64));
+define_scalar_fn!(uniform_logsf_scalar, uniform_logsf, (min: f64, max: f64));
+define_scalar_fn!(uniform_isf_scalar, uniform_isf, [min: f64, max: f64]);
+define_array_fn!(uniform_logpdf_inplace, uniform_logpdf_array, [min: f64, max: f64]);
+define_array_fn!(uniform_logcdf_inplace, uniform_logcdf_array, [min: f64, max: f64]);
+define_array_fn!(uniform_sf_inplace, uniform_sf_array, [min: f64, max: f64]);
+define_array_fn!(uniform_logsf_inplace, uniform_logsf_array, [min: f64, max: f64]);
 
 // Cauchy distribution
 define_scalar_fn!(cauchy_pdf_scalar, cauchy_pdf, (location: f64, scale: f64));
@@ -182,6 +276,15 @@ define_scalar_fn!(cauchy_cdf_scalar, cauchy_cdf, (location: f64, scale: f64));
 define_scalar_fn!(cauchy_inv_scalar, cauchy_inv, [location: f64, scale: f64]);
 define_array_fn!(cauchy_pdf_inplace, cauchy_pdf_array, [location: f64, scale: f64]);
 define_array_fn!(cauchy_cdf_inplace, cauchy_cdf_array, [location: f64, scale: f64]);
+define_scalar_fn!(cauchy_logpdf_scalar, cauchy_logpdf, (location: f64, scale: f64));
+define_scalar_fn!(cauchy_logcdf_scalar, cauchy_logcdf, (location: f64, scale: f64));
+define_scalar_fn!(cauchy_sf_scalar, cauchy_sf, (location: f64, scale: f64));
+define_scalar_fn!(cauchy_logsf_scalar, cauchy_logsf, (location: f64, scale: f64));
+define_scalar_fn!(cauchy_isf_scalar, cauchy_isf, [location: f64, scale: f64]);
+define_array_fn!(cauchy_logpdf_inplace, cauchy_logpdf_array, [location: f64, scale: f64]);
+define_array_fn!(cauchy_logcdf_inplace, cauchy_logcdf_array, [location: f64, scale: f64]);
+define_array_fn!(cauchy_sf_inplace, cauchy_sf_array, [location: f64, scale: f64]);
+define_array_fn!(cauchy_logsf_inplace, cauchy_logsf_array, [location: f64, scale: f64]);
 
 // Laplace distribution
 define_scalar_fn!(laplace_pdf_scalar, laplace_pdf, (location: f64, scale: f64));
@@ -189,6 +292,15 @@ define_scalar_fn!(laplace_cdf_scalar, laplace_cdf, (location: f64, scale: f64));
 define_scalar_fn!(laplace_inv_scalar, laplace_inv, [location: f64, scale: f64]);
 define_array_fn!(laplace_pdf_inplace, laplace_pdf_array, [location: f64, scale: f64]);
 define_array_fn!(laplace_cdf_inplace, laplace_cdf_array, [location: f64, scale: f64]);
+define_scalar_fn!(laplace_logpdf_scalar, laplace_logpdf, (location: f64, scale: f64));
+define_scalar_fn!(laplace_logcdf_scalar, laplace_logcdf, (location: f64, scale: f64));
+define_scalar_fn!(laplace_sf_scalar, laplace_sf, (location: f64, scale: f64));
+define_scalar_fn!(laplace_logsf_scalar, laplace_logsf, (location: f64, scale: f64));
+define_scalar_fn!(laplace_isf_scalar, laplace_isf, [location: f64, scale: f64]);
+define_array_fn!(laplace_logpdf_inplace, laplace_logpdf_array, [location: f64, scale: f64]);
+define_array_fn!(laplace_logcdf_inplace, laplace_logcdf_array, [location: f64, scale: f64]);
+define_array_fn!(laplace_sf_inplace, laplace_sf_array, [location: f64, scale: f64]);
+define_array_fn!(laplace_logsf_inplace, laplace_logsf_array, [location: f64, scale: f64]);
 
 // Log-normal distribution
 define_scalar_fn!(lognormal_pdf_scalar, lognormal_pdf, (mean: f64, sd: f64));
@@ -196,6 +308,15 @@ define_scalar_fn!(lognormal_cdf_scalar, lognormal_cdf, (mean: f64, sd: f64));
 define_scalar_fn!(lognormal_inv_scalar, lognormal_inv, [mean: f64, sd: f64]);
 define_array_fn!(lognormal_pdf_inplace, lognormal_pdf_array, [mean: f64, sd: f64]);
 define_array_fn!(lognormal_cdf_inplace, lognormal_cdf_array, [mean: f64, sd: f64]);
+define_scalar_fn!(lognormal_logpdf_scalar, lognormal_logpdf, (mean: f64, sd: f64));
+define_scalar_fn!(lognormal_logcdf_scalar, lognormal_logcdf, (mean: f64, sd: f64));
+define_scalar_fn!(lognormal_sf_scalar, lognormal_sf, (mean: f64, sd: f64));
+define_scalar_fn!(lognormal_logsf_scalar, lognormal_logsf, (mean: f64, sd: f64));
+define_scalar_fn!(lognormal_isf_scalar, lognormal_isf, [mean: f64, sd: f64]);
+define_array_fn!(lognormal_logpdf_inplace, lognormal_logpdf_array, [mean: f64, sd: f64]);
+define_array_fn!(lognormal_logcdf_inplace, lognormal_logcdf_array, [mean: f64, sd: f64]);
+define_array_fn!(lognormal_sf_inplace, lognormal_sf_array, [mean: f64, sd: f64]);
+define_array_fn!(lognormal_logsf_inplace, lognormal_logsf_array, [mean: f64, sd: f64]);
 
 // Weibull distribution
 define_scalar_fn!(weibull_pdf_scalar, weibull_pdf, (shape: f64, scale: f64));
@@ -203,6 +324,15 @@ define_scalar_fn!(weibull_cdf_scalar, weibull_cdf, (shape: f64, scale: f64));
 define_scalar_fn!(weibull_inv_scalar, weibull_inv, [shape: f64, scale: f64]);
 define_array_fn!(weibull_pdf_inplace, weibull_pdf_array, [shape: f64, scale: f64]);
 define_array_fn!(weibull_cdf_inplace, weibull_cdf_array, [shape: f64, scale: f64]);
+define_scalar_fn!(weibull_logpdf_scalar, weibull_logpdf, (shape: f64, scale: f64));
+define_scalar_fn!(weibull_logcdf_scalar, weibull_logcdf, (shape: f64, scale: f64));
+define_scalar_fn!(weibull_sf_scalar, weibull_sf, (shape: f64, scale: f64));
+define_scalar_fn!(weibull_logsf_scalar, weibull_logsf, (shape: f64, scale: f64));
+define_scalar_fn!(weibull_isf_scalar, weibull_isf, [shape: f64, scale: f64]);
+define_array_fn!(weibull_logpdf_inplace, weibull_logpdf_array, [shape: f64, scale: f64]);
+define_array_fn!(weibull_logcdf_inplace, weibull_logcdf_array, [shape: f64, scale: f64]);
+define_array_fn!(weibull_sf_inplace, weibull_sf_array, [shape: f64, scale: f64]);
+define_array_fn!(weibull_logsf_inplace, weibull_logsf_array, [shape: f64, scale: f64]);
 
 // Pareto distribution
 define_scalar_fn!(pareto_pdf_scalar, pareto_pdf, (scale: f64, shape: f64));
@@ -210,6 +340,15 @@ define_scalar_fn!(pareto_cdf_scalar, pareto_cdf, (scale: f64, shape: f64));
 define_scalar_fn!(pareto_inv_scalar, pareto_inv, [scale: f64, shape: f64]);
 define_array_fn!(pareto_pdf_inplace, pareto_pdf_array, [scale: f64, shape: f64]);
 define_array_fn!(pareto_cdf_inplace, pareto_cdf_array, [scale: f64, shape: f64]);
+define_scalar_fn!(pareto_logpdf_scalar, pareto_logpdf, (scale: f64, shape: f64));
+define_scalar_fn!(pareto_logcdf_scalar, pareto_logcdf, (scale: f64, shape: f64));
+define_scalar_fn!(pareto_sf_scalar, pareto_sf, (scale: f64, shape: f64));
+define_scalar_fn!(pareto_logsf_scalar, pareto_logsf, (scale: f64, shape: f64));
+define_scalar_fn!(pareto_isf_scalar, pareto_isf, [scale: f64, shape: f64]);
+define_array_fn!(pareto_logpdf_inplace, pareto_logpdf_array, [scale: f64, shape: f64]);
+define_array_fn!(pareto_logcdf_inplace, pareto_logcdf_array, [scale: f64, shape: f64]);
+define_array_fn!(pareto_sf_inplace, pareto_sf_array, [scale: f64, shape: f64]);
+define_array_fn!(pareto_logsf_inplace, pareto_logsf_array, [scale: f64, shape: f64]);
 
 // Triangular distribution
 define_scalar_fn!(triangular_pdf_scalar, triangular_pdf, (min: f64, max: f64, mode: f64));
@@ -217,6 +356,15 @@ define_scalar_fn!(triangular_cdf_scalar, triangular_cdf, (min: f64, max: f64, mo
 define_scalar_fn!(triangular_inv_scalar, triangular_inv, [min: f64, max: f64, mode: f64]);
 define_array_fn!(triangular_pdf_inplace, triangular_pdf_array, [min: f64, max: f64, mode: f64]);
 define_array_fn!(triangular_cdf_inplace, triangular_cdf_array, [min: f64, max: f64, mode: f64]);
+define_scalar_fn!(triangular_logpdf_scalar, triangular_logpdf, (min: f64, max: f64, mode: f64));
+define_scalar_fn!(triangular_logcdf_scalar, triangular_logcdf, (min: f64, max: f64, mode: f64));
+define_scalar_fn!(triangular_sf_scalar, triangular_sf, (min: f64, max: f64, mode: f64));
+define_scalar_fn!(triangular_logsf_scalar, triangular_logsf, (min: f64, max: f64, mode: f64));
+define_scalar_fn!(triangular_isf_scalar, triangular_isf, [min: f64, max: f64, mode: f64]);
+define_array_fn!(triangular_logpdf_inplace, triangular_logpdf_array, [min: f64, max: f64, mode: f64]);
+define_array_fn!(triangular_logcdf_inplace, triangular_logcdf_array, [min: f64, max: f64, mode: f64]);
+define_array_fn!(triangular_sf_inplace, triangular_sf_array, [min: f64, max: f64, mode: f64]);
+define_array_fn!(triangular_logsf_inplace, triangular_logsf_array, [min: f64, max: f64, mode: f64]);
 
 // Inverse gamma distribution
 define_scalar_fn!(invgamma_pdf_scalar, invgamma_pdf, (shape: f64, rate: f64));
@@ -224,6 +372,15 @@ define_scalar_fn!(invgamma_cdf_scalar, invgamma_cdf, (shape: f64, rate: f64));
 define_scalar_fn!(invgamma_inv_scalar, invgamma_inv, [shape: f64, rate: f64]);
 define_array_fn!(invgamma_pdf_inplace, invgamma_pdf_array, [shape: f64, rate: f64]);
 define_array_fn!(invgamma_cdf_inplace, invgamma_cdf_array, [shape: f64, rate: f64]);
+define_scalar_fn!(invgamma_logpdf_scalar, invgamma_logpdf, (shape: f64, rate: f64));
+define_scalar_fn!(invgamma_logcdf_scalar, invgamma_logcdf, (shape: f64, rate: f64));
+define_scalar_fn!(invgamma_sf_scalar, invgamma_sf, (shape: f64, rate: f64));
+define_scalar_fn!(invgamma_logsf_scalar, invgamma_logsf, (shape: f64, rate: f64));
+define_scalar_fn!(invgamma_isf_scalar, invgamma_isf, [shape: f64, rate: f64]);
+define_array_fn!(invgamma_logpdf_inplace, invgamma_logpdf_array, [shape: f64, rate: f64]);
+define_array_fn!(invgamma_logcdf_inplace, invgamma_logcdf_array, [shape: f64, rate: f64]);
+define_array_fn!(invgamma_sf_inplace, invgamma_sf_array, [shape: f64, rate: f64]);
+define_array_fn!(invgamma_logsf_inplace, invgamma_logsf_array, [shape: f64, rate: f64]);
 
 // Negative binomial distribution (discrete)
 define_scalar_fn!(negbin_pmf_scalar, negbin_pmf, (r: f64, p: f64));
@@ -235,3 +392,369 @@ pub unsafe extern "C" fn negbin_inv_scalar(prob: f64, r: f64, p: f64) -> f64 {
 }
 define_array_fn!(negbin_pmf_inplace, negbin_pmf_array, [r: f64, p: f64]);
 define_array_fn!(negbin_cdf_inplace, negbin_cdf_array, [r: f64, p: f64]);
+define_scalar_fn!(negbin_logpmf_scalar, negbin_logpmf, (r: f64, p: f64));
+define_scalar_fn!(negbin_logcdf_scalar, negbin_logcdf, (r: f64, p: f64));
+define_scalar_fn!(negbin_sf_scalar, negbin_sf, (r: f64, p: f64));
+define_scalar_fn!(negbin_logsf_scalar, negbin_logsf, (r: f64, p: f64));
+
+#[no_mangle]
+pub unsafe extern "C" fn negbin_isf_scalar(prob: f64, r: f64, p: f64) -> f64 {
+    stat_core::negbin_isf(prob, r, p).unwrap_or(f64::NAN)
+}
+define_array_fn!(negbin_logpmf_inplace, negbin_logpmf_array, [r: f64, p: f64]);
+define_array_fn!(negbin_logcdf_inplace, negbin_logcdf_array, [r: f64, p: f64]);
+define_array_fn!(negbin_sf_inplace, negbin_sf_array, [r: f64, p: f64]);
+define_array_fn!(negbin_logsf_inplace, negbin_logsf_array, [r: f64, p: f64]);
+
+// Gumbel distribution (location, scale)
+define_scalar_fn!(gumbel_pdf_scalar, gumbel_pdf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_cdf_scalar, gumbel_cdf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_inv_scalar, gumbel_inv, [location: f64, scale: f64]);
+define_array_fn!(gumbel_pdf_inplace, gumbel_pdf_array, [location: f64, scale: f64]);
+define_array_fn!(gumbel_cdf_inplace, gumbel_cdf_array, [location: f64, scale: f64]);
+define_scalar_fn!(gumbel_logpdf_scalar, gumbel_logpdf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_logcdf_scalar, gumbel_logcdf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_sf_scalar, gumbel_sf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_logsf_scalar, gumbel_logsf, (location: f64, scale: f64));
+define_scalar_fn!(gumbel_isf_scalar, gumbel_isf, [location: f64, scale: f64]);
+define_array_fn!(gumbel_logpdf_inplace, gumbel_logpdf_array, [location: f64, scale: f64]);
+define_array_fn!(gumbel_logcdf_inplace, gumbel_logcdf_array, [location: f64, scale: f64]);
+define_array_fn!(gumbel_sf_inplace, gumbel_sf_array, [location: f64, scale: f64]);
+define_array_fn!(gumbel_logsf_inplace, gumbel_logsf_array, [location: f64, scale: f64]);
+
+// Generalized extreme value distribution (location, scale, shape)
+define_scalar_fn!(gev_pdf_scalar, gev_pdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_cdf_scalar, gev_cdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_inv_scalar, gev_inv, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_pdf_inplace, gev_pdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_cdf_inplace, gev_cdf_array, [location: f64, scale: f64, shape: f64]);
+define_scalar_fn!(gev_logpdf_scalar, gev_logpdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_logcdf_scalar, gev_logcdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_sf_scalar, gev_sf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_logsf_scalar, gev_logsf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(gev_isf_scalar, gev_isf, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_logpdf_inplace, gev_logpdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_logcdf_inplace, gev_logcdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_sf_inplace, gev_sf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(gev_logsf_inplace, gev_logsf_array, [location: f64, scale: f64, shape: f64]);
+
+// Logistic distribution (location, scale)
+define_scalar_fn!(logistic_pdf_scalar, logistic_pdf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_cdf_scalar, logistic_cdf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_inv_scalar, logistic_inv, [location: f64, scale: f64]);
+define_array_fn!(logistic_pdf_inplace, logistic_pdf_array, [location: f64, scale: f64]);
+define_array_fn!(logistic_cdf_inplace, logistic_cdf_array, [location: f64, scale: f64]);
+define_scalar_fn!(logistic_logpdf_scalar, logistic_logpdf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_logcdf_scalar, logistic_logcdf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_sf_scalar, logistic_sf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_logsf_scalar, logistic_logsf, (location: f64, scale: f64));
+define_scalar_fn!(logistic_isf_scalar, logistic_isf, [location: f64, scale: f64]);
+define_array_fn!(logistic_logpdf_inplace, logistic_logpdf_array, [location: f64, scale: f64]);
+define_array_fn!(logistic_logcdf_inplace, logistic_logcdf_array, [location: f64, scale: f64]);
+define_array_fn!(logistic_sf_inplace, logistic_sf_array, [location: f64, scale: f64]);
+define_array_fn!(logistic_logsf_inplace, logistic_logsf_array, [location: f64, scale: f64]);
+
+// Log-logistic distribution (scale, shape)
+define_scalar_fn!(loglogistic_pdf_scalar, loglogistic_pdf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_cdf_scalar, loglogistic_cdf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_inv_scalar, loglogistic_inv, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_pdf_inplace, loglogistic_pdf_array, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_cdf_inplace, loglogistic_cdf_array, [scale: f64, shape: f64]);
+define_scalar_fn!(loglogistic_logpdf_scalar, loglogistic_logpdf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_logcdf_scalar, loglogistic_logcdf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_sf_scalar, loglogistic_sf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_logsf_scalar, loglogistic_logsf, (scale: f64, shape: f64));
+define_scalar_fn!(loglogistic_isf_scalar, loglogistic_isf, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_logpdf_inplace, loglogistic_logpdf_array, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_logcdf_inplace, loglogistic_logcdf_array, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_sf_inplace, loglogistic_sf_array, [scale: f64, shape: f64]);
+define_array_fn!(loglogistic_logsf_inplace, loglogistic_logsf_array, [scale: f64, shape: f64]);
+
+// Rayleigh distribution (scale)
+define_scalar_fn!(rayleigh_pdf_scalar, rayleigh_pdf, (scale: f64));
+define_scalar_fn!(rayleigh_cdf_scalar, rayleigh_cdf, (scale: f64));
+define_scalar_fn!(rayleigh_inv_scalar, rayleigh_inv, [scale: f64]);
+define_array_fn!(rayleigh_pdf_inplace, rayleigh_pdf_array, [scale: f64]);
+define_array_fn!(rayleigh_cdf_inplace, rayleigh_cdf_array, [scale: f64]);
+define_scalar_fn!(rayleigh_logpdf_scalar, rayleigh_logpdf, (scale: f64));
+define_scalar_fn!(rayleigh_logcdf_scalar, rayleigh_logcdf, (scale: f64));
+define_scalar_fn!(rayleigh_sf_scalar, rayleigh_sf, (scale: f64));
+define_scalar_fn!(rayleigh_logsf_scalar, rayleigh_logsf, (scale: f64));
+define_scalar_fn!(rayleigh_isf_scalar, rayleigh_isf, [scale: f64]);
+define_array_fn!(rayleigh_logpdf_inplace, rayleigh_logpdf_array, [scale: f64]);
+define_array_fn!(rayleigh_logcdf_inplace, rayleigh_logcdf_array, [scale: f64]);
+define_array_fn!(rayleigh_sf_inplace, rayleigh_sf_array, [scale: f64]);
+define_array_fn!(rayleigh_logsf_inplace, rayleigh_logsf_array, [scale: f64]);
+
+// Erlang distribution (integer shape, rate)
+define_scalar_fn!(erlang_pdf_scalar, erlang_pdf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_cdf_scalar, erlang_cdf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_inv_scalar, erlang_inv, [shape: f64, rate: f64]);
+define_array_fn!(erlang_pdf_inplace, erlang_pdf_array, [shape: f64, rate: f64]);
+define_array_fn!(erlang_cdf_inplace, erlang_cdf_array, [shape: f64, rate: f64]);
+define_scalar_fn!(erlang_logpdf_scalar, erlang_logpdf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_logcdf_scalar, erlang_logcdf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_sf_scalar, erlang_sf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_logsf_scalar, erlang_logsf, (shape: f64, rate: f64));
+define_scalar_fn!(erlang_isf_scalar, erlang_isf, [shape: f64, rate: f64]);
+define_array_fn!(erlang_logpdf_inplace, erlang_logpdf_array, [shape: f64, rate: f64]);
+define_array_fn!(erlang_logcdf_inplace, erlang_logcdf_array, [shape: f64, rate: f64]);
+define_array_fn!(erlang_sf_inplace, erlang_sf_array, [shape: f64, rate: f64]);
+define_array_fn!(erlang_logsf_inplace, erlang_logsf_array, [shape: f64, rate: f64]);
+
+// Von Mises distribution (mu, kappa)
+define_scalar_fn!(vonmises_pdf_scalar, vonmises_pdf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_cdf_scalar, vonmises_cdf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_inv_scalar, vonmises_inv, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_pdf_inplace, vonmises_pdf_array, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_cdf_inplace, vonmises_cdf_array, [mu: f64, kappa: f64]);
+define_scalar_fn!(vonmises_logpdf_scalar, vonmises_logpdf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_logcdf_scalar, vonmises_logcdf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_sf_scalar, vonmises_sf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_logsf_scalar, vonmises_logsf, (mu: f64, kappa: f64));
+define_scalar_fn!(vonmises_isf_scalar, vonmises_isf, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_logpdf_inplace, vonmises_logpdf_array, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_logcdf_inplace, vonmises_logcdf_array, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_sf_inplace, vonmises_sf_array, [mu: f64, kappa: f64]);
+define_array_fn!(vonmises_logsf_inplace, vonmises_logsf_array, [mu: f64, kappa: f64]);
+
+// Skew-normal distribution (location, scale, shape)
+define_scalar_fn!(skewnormal_pdf_scalar, skewnormal_pdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_cdf_scalar, skewnormal_cdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_inv_scalar, skewnormal_inv, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_pdf_inplace, skewnormal_pdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_cdf_inplace, skewnormal_cdf_array, [location: f64, scale: f64, shape: f64]);
+define_scalar_fn!(skewnormal_logpdf_scalar, skewnormal_logpdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_logcdf_scalar, skewnormal_logcdf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_sf_scalar, skewnormal_sf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_logsf_scalar, skewnormal_logsf, (location: f64, scale: f64, shape: f64));
+define_scalar_fn!(skewnormal_isf_scalar, skewnormal_isf, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_logpdf_inplace, skewnormal_logpdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_logcdf_inplace, skewnormal_logcdf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_sf_inplace, skewnormal_sf_array, [location: f64, scale: f64, shape: f64]);
+define_array_fn!(skewnormal_logsf_inplace, skewnormal_logsf_array, [location: f64, scale: f64, shape: f64]);
+
+// Truncated normal distribution (location, scale, lower, upper)
+define_scalar_fn!(truncnormal_pdf_scalar, truncnormal_pdf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_cdf_scalar, truncnormal_cdf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_inv_scalar, truncnormal_inv, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_pdf_inplace, truncnormal_pdf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_cdf_inplace, truncnormal_cdf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_scalar_fn!(truncnormal_logpdf_scalar, truncnormal_logpdf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_logcdf_scalar, truncnormal_logcdf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_sf_scalar, truncnormal_sf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_logsf_scalar, truncnormal_logsf, (location: f64, scale: f64, lower: f64, upper: f64));
+define_scalar_fn!(truncnormal_isf_scalar, truncnormal_isf, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_logpdf_inplace, truncnormal_logpdf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_logcdf_inplace, truncnormal_logcdf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_sf_inplace, truncnormal_sf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_array_fn!(truncnormal_logsf_inplace, truncnormal_logsf_array, [location: f64, scale: f64, lower: f64, upper: f64]);
+
+// Hypergeometric distribution (discrete; population, successes, draws)
+define_scalar_fn!(hypergeom_pmf_scalar, hypergeom_pmf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_cdf_scalar, hypergeom_cdf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_inv_scalar, hypergeom_inv, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_pmf_inplace, hypergeom_pmf_array, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_cdf_inplace, hypergeom_cdf_array, [population: f64, successes: f64, draws: f64]);
+define_scalar_fn!(hypergeom_logpmf_scalar, hypergeom_logpmf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_logcdf_scalar, hypergeom_logcdf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_sf_scalar, hypergeom_sf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_logsf_scalar, hypergeom_logsf, (population: f64, successes: f64, draws: f64));
+define_scalar_fn!(hypergeom_isf_scalar, hypergeom_isf, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_logpmf_inplace, hypergeom_logpmf_array, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_logcdf_inplace, hypergeom_logcdf_array, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_sf_inplace, hypergeom_sf_array, [population: f64, successes: f64, draws: f64]);
+define_array_fn!(hypergeom_logsf_inplace, hypergeom_logsf_array, [population: f64, successes: f64, draws: f64]);
+
+// Geometric distribution (discrete; failures before the first success)
+define_scalar_fn!(geometric_pmf_scalar, geometric_pmf, (p: f64));
+define_scalar_fn!(geometric_cdf_scalar, geometric_cdf, (p: f64));
+
+#[no_mangle]
+pub unsafe extern "C" fn geometric_inv_scalar(prob: f64, p: f64) -> f64 {
+    stat_core::geometric_inv(prob, p).unwrap_or(f64::NAN)
+}
+define_array_fn!(geometric_pmf_inplace, geometric_pmf_array, [p: f64]);
+define_array_fn!(geometric_cdf_inplace, geometric_cdf_array, [p: f64]);
+define_scalar_fn!(geometric_logpmf_scalar, geometric_logpmf, (p: f64));
+define_scalar_fn!(geometric_logcdf_scalar, geometric_logcdf, (p: f64));
+define_scalar_fn!(geometric_sf_scalar, geometric_sf, (p: f64));
+define_scalar_fn!(geometric_logsf_scalar, geometric_logsf, (p: f64));
+
+#[no_mangle]
+pub unsafe extern "C" fn geometric_isf_scalar(prob: f64, p: f64) -> f64 {
+    stat_core::geometric_isf(prob, p).unwrap_or(f64::NAN)
+}
+define_array_fn!(geometric_logpmf_inplace, geometric_logpmf_array, [p: f64]);
+define_array_fn!(geometric_logcdf_inplace, geometric_logcdf_array, [p: f64]);
+define_array_fn!(geometric_sf_inplace, geometric_sf_array, [p: f64]);
+define_array_fn!(geometric_logsf_inplace, geometric_logsf_array, [p: f64]);
+// Studentized range distribution (nmeans, df)
+define_scalar_fn!(studentized_range_cdf_scalar, studentized_range_cdf, (nmeans: f64, df: f64));
+define_scalar_fn!(studentized_range_inv_scalar, studentized_range_inv, [nmeans: f64, df: f64]);
+define_array_fn!(studentized_range_cdf_inplace, studentized_range_cdf_array, [nmeans: f64, df: f64]);
+
+// Noncentral chi-squared distribution (dof, ncp)
+define_scalar_fn!(noncentral_chi_squared_pdf_scalar, noncentral_chi_squared_pdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_cdf_scalar, noncentral_chi_squared_cdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_sf_scalar, noncentral_chi_squared_sf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_inv_scalar, noncentral_chi_squared_inv, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_pdf_inplace, noncentral_chi_squared_pdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_cdf_inplace, noncentral_chi_squared_cdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_sf_inplace, noncentral_chi_squared_sf_array, [dof: f64, ncp: f64]);
+define_scalar_fn!(noncentral_chi_squared_logpdf_scalar, noncentral_chi_squared_logpdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_logcdf_scalar, noncentral_chi_squared_logcdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_logsf_scalar, noncentral_chi_squared_logsf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_chi_squared_isf_scalar, noncentral_chi_squared_isf, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_logpdf_inplace, noncentral_chi_squared_logpdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_logcdf_inplace, noncentral_chi_squared_logcdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_chi_squared_logsf_inplace, noncentral_chi_squared_logsf_array, [dof: f64, ncp: f64]);
+
+// Noncentral t distribution (dof, ncp)
+define_scalar_fn!(noncentral_t_pdf_scalar, noncentral_t_pdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_cdf_scalar, noncentral_t_cdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_sf_scalar, noncentral_t_sf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_inv_scalar, noncentral_t_inv, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_pdf_inplace, noncentral_t_pdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_cdf_inplace, noncentral_t_cdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_sf_inplace, noncentral_t_sf_array, [dof: f64, ncp: f64]);
+define_scalar_fn!(noncentral_t_logpdf_scalar, noncentral_t_logpdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_logcdf_scalar, noncentral_t_logcdf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_logsf_scalar, noncentral_t_logsf, (dof: f64, ncp: f64));
+define_scalar_fn!(noncentral_t_isf_scalar, noncentral_t_isf, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_logpdf_inplace, noncentral_t_logpdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_logcdf_inplace, noncentral_t_logcdf_array, [dof: f64, ncp: f64]);
+define_array_fn!(noncentral_t_logsf_inplace, noncentral_t_logsf_array, [dof: f64, ncp: f64]);
+
+// Noncentral F distribution (df1, df2, ncp)
+define_scalar_fn!(noncentral_f_pdf_scalar, noncentral_f_pdf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_cdf_scalar, noncentral_f_cdf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_sf_scalar, noncentral_f_sf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_inv_scalar, noncentral_f_inv, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_pdf_inplace, noncentral_f_pdf_array, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_cdf_inplace, noncentral_f_cdf_array, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_sf_inplace, noncentral_f_sf_array, [df1: f64, df2: f64, ncp: f64]);
+define_scalar_fn!(noncentral_f_logpdf_scalar, noncentral_f_logpdf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_logcdf_scalar, noncentral_f_logcdf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_logsf_scalar, noncentral_f_logsf, (df1: f64, df2: f64, ncp: f64));
+define_scalar_fn!(noncentral_f_isf_scalar, noncentral_f_isf, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_logpdf_inplace, noncentral_f_logpdf_array, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_logcdf_inplace, noncentral_f_logcdf_array, [df1: f64, df2: f64, ncp: f64]);
+define_array_fn!(noncentral_f_logsf_inplace, noncentral_f_logsf_array, [df1: f64, df2: f64, ncp: f64]);
+
+// Multivariate densities. Vectors and row-major `dim x dim` matrices are passed as pointers;
+// invalid parameters return NaN.
+#[no_mangle]
+pub unsafe extern "C" fn multinomial_pmf_scalar(
+    counts_ptr: *const f64,
+    probs_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::multinomial_pmf(slice_from(counts_ptr, len), slice_from(probs_ptr, len))
+        .unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn multinomial_logpmf_scalar(
+    counts_ptr: *const f64,
+    probs_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::multinomial_logpmf(slice_from(counts_ptr, len), slice_from(probs_ptr, len))
+        .unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dirichlet_pdf_scalar(
+    x_ptr: *const f64,
+    alpha_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::dirichlet_pdf(slice_from(x_ptr, len), slice_from(alpha_ptr, len)).unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dirichlet_logpdf_scalar(
+    x_ptr: *const f64,
+    alpha_ptr: *const f64,
+    len: usize,
+) -> f64 {
+    stat_core::dirichlet_logpdf(slice_from(x_ptr, len), slice_from(alpha_ptr, len))
+        .unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wishart_pdf_scalar(
+    x_ptr: *const f64,
+    scale_ptr: *const f64,
+    dim: usize,
+    dof: f64,
+) -> f64 {
+    let len = dim * dim;
+    stat_core::wishart_pdf(slice_from(x_ptr, len), slice_from(scale_ptr, len), dim, dof)
+        .unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wishart_logpdf_scalar(
+    x_ptr: *const f64,
+    scale_ptr: *const f64,
+    dim: usize,
+    dof: f64,
+) -> f64 {
+    let len = dim * dim;
+    stat_core::wishart_logpdf(slice_from(x_ptr, len), slice_from(scale_ptr, len), dim, dof)
+        .unwrap_or(f64::NAN)
+}
+
+// Random sampling. Seeds are passed as f64 so JS callers can use plain numbers; integral
+// values up to 2^53 map one-to-one onto u64 seeds. Invalid parameters fill the output with NaN.
+macro_rules! define_sample_fn {
+    ($name:ident, $rust_fn:ident, [$($param:ident : f64),*]) => {
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            len: usize,
+            $($param: f64,)*
+            seed: f64,
+            output_ptr: *mut f64,
+        ) {
+            let output = slice_from_mut(output_ptr, len);
+            if stat_core::$rust_fn(output, $($param,)* seed as u64).is_err() {
+                output.fill(f64::NAN);
+            }
+        }
+    };
+}
+
+define_sample_fn!(normal_sample_into, normal_sample_into, [mean: f64, sd: f64]);
+define_sample_fn!(gamma_sample_into, gamma_sample_into, [shape: f64, rate: f64]);
+define_sample_fn!(beta_sample_into, beta_sample_into, [alpha: f64, beta: f64]);
+define_sample_fn!(student_t_sample_into, student_t_sample_into, [mean: f64, scale: f64, dof: f64]);
+define_sample_fn!(chi_squared_sample_into, chi_squared_sample_into, [dof: f64]);
+define_sample_fn!(fisher_f_sample_into, fisher_f_sample_into, [df1: f64, df2: f64]);
+define_sample_fn!(exponential_sample_into, exponential_sample_into, [rate: f64]);
+define_sample_fn!(poisson_sample_into, poisson_sample_into, [lambda: f64]);
+define_sample_fn!(binomial_sample_into, binomial_sample_into, [n: f64, p: f64]);
+define_sample_fn!(uniform_sample_into, uniform_sample_into, [min: f64, max: f64]);
+define_sample_fn!(cauchy_sample_into, cauchy_sample_into, [location: f64, scale: f64]);
+define_sample_fn!(laplace_sample_into, laplace_sample_into, [location: f64, scale: f64]);
+define_sample_fn!(lognormal_sample_into, lognormal_sample_into, [mean: f64, sd: f64]);
+define_sample_fn!(weibull_sample_into, weibull_sample_into, [shape: f64, scale: f64]);
+define_sample_fn!(pareto_sample_into, pareto_sample_into, [scale: f64, shape: f64]);
+define_sample_fn!(triangular_sample_into, triangular_sample_into, [min: f64, max: f64, mode: f64]);
+define_sample_fn!(invgamma_sample_into, invgamma_sample_into, [shape: f64, rate: f64]);
+define_sample_fn!(negbin_sample_into, negbin_sample_into, [r: f64, p: f64]);
+define_sample_fn!(gumbel_sample_into, gumbel_sample_into, [location: f64, scale: f64]);
+define_sample_fn!(gev_sample_into, gev_sample_into, [location: f64, scale: f64, shape: f64]);
+define_sample_fn!(logistic_sample_into, logistic_sample_into, [location: f64, scale: f64]);
+define_sample_fn!(loglogistic_sample_into, loglogistic_sample_into, [scale: f64, shape: f64]);
+define_sample_fn!(rayleigh_sample_into, rayleigh_sample_into, [scale: f64]);
+define_sample_fn!(erlang_sample_into, erlang_sample_into, [shape: f64, rate: f64]);
+define_sample_fn!(vonmises_sample_into, vonmises_sample_into, [mu: f64, kappa: f64]);
+define_sample_fn!(skewnormal_sample_into, skewnormal_sample_into, [location: f64, scale: f64, shape: f64]);
+define_sample_fn!(truncnormal_sample_into, truncnormal_sample_into, [location: f64, scale: f64, lower: f64, upper: f64]);
+define_sample_fn!(hypergeom_sample_into, hypergeom_sample_into, [population: f64, successes: f64, draws: f64]);
+define_sample_fn!(geometric_sample_into, geometric_sample_into, [p: f64]);
+define_sample_fn!(noncentral_chi_squared_sample_into, noncentral_chi_squared_sample_into, [dof: f64, ncp: f64]);
+define_sample_fn!(noncentral_t_sample_into, noncentral_t_sample_into, [dof: f64, ncp: f64]);
+define_sample_fn!(noncentral_f_sample_into, noncentral_f_sample_into, [df1: f64, df2: f64, ncp: f64]);
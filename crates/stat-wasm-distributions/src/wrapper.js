@@ -20,106 +20,520 @@ export function normal_cdf_scalar(x, mean, sd) { return wasmExports().normal_cdf
 export function normal_inv_scalar(p, mean, sd) { return wasmExports().normal_inv_scalar(p, mean, sd); }
 export function normal_pdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_pdf_inplace(inputPtr, len, mean, sd, outputPtr); }
 export function normal_cdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_cdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function normal_logpdf_scalar(x, mean, sd) { return wasmExports().normal_logpdf_scalar(x, mean, sd); }
+export function normal_logcdf_scalar(x, mean, sd) { return wasmExports().normal_logcdf_scalar(x, mean, sd); }
+export function normal_sf_scalar(x, mean, sd) { return wasmExports().normal_sf_scalar(x, mean, sd); }
+export function normal_logsf_scalar(x, mean, sd) { return wasmExports().normal_logsf_scalar(x, mean, sd); }
+export function normal_isf_scalar(p, mean, sd) { return wasmExports().normal_isf_scalar(p, mean, sd); }
+export function normal_logpdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_logpdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function normal_logcdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_logcdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function normal_sf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_sf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function normal_logsf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().normal_logsf_inplace(inputPtr, len, mean, sd, outputPtr); }
 
 export function gamma_pdf_scalar(x, shape, rate) { return wasmExports().gamma_pdf_scalar(x, shape, rate); }
 export function gamma_cdf_scalar(x, shape, rate) { return wasmExports().gamma_cdf_scalar(x, shape, rate); }
 export function gamma_inv_scalar(p, shape, rate) { return wasmExports().gamma_inv_scalar(p, shape, rate); }
 export function gamma_pdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_pdf_inplace(inputPtr, len, shape, rate, outputPtr); }
 export function gamma_cdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_cdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function gamma_logpdf_scalar(x, shape, rate) { return wasmExports().gamma_logpdf_scalar(x, shape, rate); }
+export function gamma_logcdf_scalar(x, shape, rate) { return wasmExports().gamma_logcdf_scalar(x, shape, rate); }
+export function gamma_sf_scalar(x, shape, rate) { return wasmExports().gamma_sf_scalar(x, shape, rate); }
+export function gamma_logsf_scalar(x, shape, rate) { return wasmExports().gamma_logsf_scalar(x, shape, rate); }
+export function gamma_isf_scalar(p, shape, rate) { return wasmExports().gamma_isf_scalar(p, shape, rate); }
+export function gamma_logpdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_logpdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function gamma_logcdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_logcdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function gamma_sf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_sf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function gamma_logsf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().gamma_logsf_inplace(inputPtr, len, shape, rate, outputPtr); }
 
 export function beta_pdf_scalar(x, alpha, beta) { return wasmExports().beta_pdf_scalar(x, alpha, beta); }
 export function beta_cdf_scalar(x, alpha, beta) { return wasmExports().beta_cdf_scalar(x, alpha, beta); }
 export function beta_inv_scalar(p, alpha, beta) { return wasmExports().beta_inv_scalar(p, alpha, beta); }
 export function beta_pdf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_pdf_inplace(inputPtr, len, alpha, beta, outputPtr); }
 export function beta_cdf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_cdf_inplace(inputPtr, len, alpha, beta, outputPtr); }
+export function beta_logpdf_scalar(x, alpha, beta) { return wasmExports().beta_logpdf_scalar(x, alpha, beta); }
+export function beta_logcdf_scalar(x, alpha, beta) { return wasmExports().beta_logcdf_scalar(x, alpha, beta); }
+export function beta_sf_scalar(x, alpha, beta) { return wasmExports().beta_sf_scalar(x, alpha, beta); }
+export function beta_logsf_scalar(x, alpha, beta) { return wasmExports().beta_logsf_scalar(x, alpha, beta); }
+export function beta_isf_scalar(p, alpha, beta) { return wasmExports().beta_isf_scalar(p, alpha, beta); }
+export function beta_logpdf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_logpdf_inplace(inputPtr, len, alpha, beta, outputPtr); }
+export function beta_logcdf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_logcdf_inplace(inputPtr, len, alpha, beta, outputPtr); }
+export function beta_sf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_sf_inplace(inputPtr, len, alpha, beta, outputPtr); }
+export function beta_logsf_inplace(inputPtr, len, alpha, beta, outputPtr) { wasmExports().beta_logsf_inplace(inputPtr, len, alpha, beta, outputPtr); }
 
 export function student_t_pdf_scalar(x, mean, scale, dof) { return wasmExports().student_t_pdf_scalar(x, mean, scale, dof); }
 export function student_t_cdf_scalar(x, mean, scale, dof) { return wasmExports().student_t_cdf_scalar(x, mean, scale, dof); }
 export function student_t_inv_scalar(p, mean, scale, dof) { return wasmExports().student_t_inv_scalar(p, mean, scale, dof); }
 export function student_t_pdf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_pdf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
 export function student_t_cdf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_cdf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
+export function student_t_logpdf_scalar(x, mean, scale, dof) { return wasmExports().student_t_logpdf_scalar(x, mean, scale, dof); }
+export function student_t_logcdf_scalar(x, mean, scale, dof) { return wasmExports().student_t_logcdf_scalar(x, mean, scale, dof); }
+export function student_t_sf_scalar(x, mean, scale, dof) { return wasmExports().student_t_sf_scalar(x, mean, scale, dof); }
+export function student_t_logsf_scalar(x, mean, scale, dof) { return wasmExports().student_t_logsf_scalar(x, mean, scale, dof); }
+export function student_t_isf_scalar(p, mean, scale, dof) { return wasmExports().student_t_isf_scalar(p, mean, scale, dof); }
+export function student_t_logpdf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_logpdf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
+export function student_t_logcdf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_logcdf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
+export function student_t_sf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_sf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
+export function student_t_logsf_inplace(inputPtr, len, mean, scale, dof, outputPtr) { wasmExports().student_t_logsf_inplace(inputPtr, len, mean, scale, dof, outputPtr); }
 
 export function chi_squared_pdf_scalar(x, dof) { return wasmExports().chi_squared_pdf_scalar(x, dof); }
 export function chi_squared_cdf_scalar(x, dof) { return wasmExports().chi_squared_cdf_scalar(x, dof); }
 export function chi_squared_inv_scalar(p, dof) { return wasmExports().chi_squared_inv_scalar(p, dof); }
 export function chi_squared_pdf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_pdf_inplace(inputPtr, len, dof, outputPtr); }
 export function chi_squared_cdf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_cdf_inplace(inputPtr, len, dof, outputPtr); }
+export function chi_squared_logpdf_scalar(x, dof) { return wasmExports().chi_squared_logpdf_scalar(x, dof); }
+export function chi_squared_logcdf_scalar(x, dof) { return wasmExports().chi_squared_logcdf_scalar(x, dof); }
+export function chi_squared_sf_scalar(x, dof) { return wasmExports().chi_squared_sf_scalar(x, dof); }
+export function chi_squared_logsf_scalar(x, dof) { return wasmExports().chi_squared_logsf_scalar(x, dof); }
+export function chi_squared_isf_scalar(p, dof) { return wasmExports().chi_squared_isf_scalar(p, dof); }
+export function chi_squared_logpdf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_logpdf_inplace(inputPtr, len, dof, outputPtr); }
+export function chi_squared_logcdf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_logcdf_inplace(inputPtr, len, dof, outputPtr); }
+export function chi_squared_sf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_sf_inplace(inputPtr, len, dof, outputPtr); }
+export function chi_squared_logsf_inplace(inputPtr, len, dof, outputPtr) { wasmExports().chi_squared_logsf_inplace(inputPtr, len, dof, outputPtr); }
 
 export function fisher_f_pdf_scalar(x, df1, df2) { return wasmExports().fisher_f_pdf_scalar(x, df1, df2); }
 export function fisher_f_cdf_scalar(x, df1, df2) { return wasmExports().fisher_f_cdf_scalar(x, df1, df2); }
 export function fisher_f_inv_scalar(p, df1, df2) { return wasmExports().fisher_f_inv_scalar(p, df1, df2); }
 export function fisher_f_pdf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_pdf_inplace(inputPtr, len, df1, df2, outputPtr); }
 export function fisher_f_cdf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_cdf_inplace(inputPtr, len, df1, df2, outputPtr); }
+export function fisher_f_logpdf_scalar(x, df1, df2) { return wasmExports().fisher_f_logpdf_scalar(x, df1, df2); }
+export function fisher_f_logcdf_scalar(x, df1, df2) { return wasmExports().fisher_f_logcdf_scalar(x, df1, df2); }
+export function fisher_f_sf_scalar(x, df1, df2) { return wasmExports().fisher_f_sf_scalar(x, df1, df2); }
+export function fisher_f_logsf_scalar(x, df1, df2) { return wasmExports().fisher_f_logsf_scalar(x, df1, df2); }
+export function fisher_f_isf_scalar(p, df1, df2) { return wasmExports().fisher_f_isf_scalar(p, df1, df2); }
+export function fisher_f_logpdf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_logpdf_inplace(inputPtr, len, df1, df2, outputPtr); }
+export function fisher_f_logcdf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_logcdf_inplace(inputPtr, len, df1, df2, outputPtr); }
+export function fisher_f_sf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_sf_inplace(inputPtr, len, df1, df2, outputPtr); }
+export function fisher_f_logsf_inplace(inputPtr, len, df1, df2, outputPtr) { wasmExports().fisher_f_logsf_inplace(inputPtr, len, df1, df2, outputPtr); }
 
 export function exponential_pdf_scalar(x, rate) { return wasmExports().exponential_pdf_scalar(x, rate); }
 export function exponential_cdf_scalar(x, rate) { return wasmExports().exponential_cdf_scalar(x, rate); }
 export function exponential_inv_scalar(p, rate) { return wasmExports().exponential_inv_scalar(p, rate); }
 export function exponential_pdf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_pdf_inplace(inputPtr, len, rate, outputPtr); }
 export function exponential_cdf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_cdf_inplace(inputPtr, len, rate, outputPtr); }
+export function exponential_logpdf_scalar(x, rate) { return wasmExports().exponential_logpdf_scalar(x, rate); }
+export function exponential_logcdf_scalar(x, rate) { return wasmExports().exponential_logcdf_scalar(x, rate); }
+export function exponential_sf_scalar(x, rate) { return wasmExports().exponential_sf_scalar(x, rate); }
+export function exponential_logsf_scalar(x, rate) { return wasmExports().exponential_logsf_scalar(x, rate); }
+export function exponential_isf_scalar(p, rate) { return wasmExports().exponential_isf_scalar(p, rate); }
+export function exponential_logpdf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_logpdf_inplace(inputPtr, len, rate, outputPtr); }
+export function exponential_logcdf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_logcdf_inplace(inputPtr, len, rate, outputPtr); }
+export function exponential_sf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_sf_inplace(inputPtr, len, rate, outputPtr); }
+export function exponential_logsf_inplace(inputPtr, len, rate, outputPtr) { wasmExports().exponential_logsf_inplace(inputPtr, len, rate, outputPtr); }
 
 export function poisson_pmf_scalar(k, lambda) { return wasmExports().poisson_pmf_scalar(k, lambda); }
 export function poisson_cdf_scalar(k, lambda) { return wasmExports().poisson_cdf_scalar(k, lambda); }
 export function poisson_inv_scalar(p, lambda) { return wasmExports().poisson_inv_scalar(p, lambda); }
 export function poisson_pmf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_pmf_inplace(inputPtr, len, lambda, outputPtr); }
 export function poisson_cdf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_cdf_inplace(inputPtr, len, lambda, outputPtr); }
+export function poisson_logpmf_scalar(k, lambda) { return wasmExports().poisson_logpmf_scalar(k, lambda); }
+export function poisson_logcdf_scalar(k, lambda) { return wasmExports().poisson_logcdf_scalar(k, lambda); }
+export function poisson_sf_scalar(k, lambda) { return wasmExports().poisson_sf_scalar(k, lambda); }
+export function poisson_logsf_scalar(k, lambda) { return wasmExports().poisson_logsf_scalar(k, lambda); }
+export function poisson_isf_scalar(p, lambda) { return wasmExports().poisson_isf_scalar(p, lambda); }
+export function poisson_logpmf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_logpmf_inplace(inputPtr, len, lambda, outputPtr); }
+export function poisson_logcdf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_logcdf_inplace(inputPtr, len, lambda, outputPtr); }
+export function poisson_sf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_sf_inplace(inputPtr, len, lambda, outputPtr); }
+export function poisson_logsf_inplace(inputPtr, len, lambda, outputPtr) { wasmExports().poisson_logsf_inplace(inputPtr, len, lambda, outputPtr); }
 
 export function binomial_pmf_scalar(k, n, p) { return wasmExports().binomial_pmf_scalar(k, n, p); }
 export function binomial_cdf_scalar(k, n, p) { return wasmExports().binomial_cdf_scalar(k, n, p); }
 export function binomial_inv_scalar(prob, n, p) { return wasmExports().binomial_inv_scalar(prob, n, p); }
 export function binomial_pmf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_pmf_inplace(inputPtr, len, n, p, outputPtr); }
 export function binomial_cdf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_cdf_inplace(inputPtr, len, n, p, outputPtr); }
+export function binomial_logpmf_scalar(k, n, p) { return wasmExports().binomial_logpmf_scalar(k, n, p); }
+export function binomial_logcdf_scalar(k, n, p) { return wasmExports().binomial_logcdf_scalar(k, n, p); }
+export function binomial_sf_scalar(k, n, p) { return wasmExports().binomial_sf_scalar(k, n, p); }
+export function binomial_logsf_scalar(k, n, p) { return wasmExports().binomial_logsf_scalar(k, n, p); }
+export function binomial_isf_scalar(prob, n, p) { return wasmExports().binomial_isf_scalar(prob, n, p); }
+export function binomial_logpmf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_logpmf_inplace(inputPtr, len, n, p, outputPtr); }
+export function binomial_logcdf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_logcdf_inplace(inputPtr, len, n, p, outputPtr); }
+export function binomial_sf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_sf_inplace(inputPtr, len, n, p, outputPtr); }
+export function binomial_logsf_inplace(inputPtr, len, n, p, outputPtr) { wasmExports().binomial_logsf_inplace(inputPtr, len, n, p, outputPtr); }
 
 export function uniform_pdf_scalar(x, min, max) { return wasmExports().uniform_pdf_scalar(x, min, max); }
 export function uniform_cdf_scalar(x, min, max) { return wasmExports().uniform_cdf_scalar(x, min, max); }
 export function uniform_inv_scalar(p, min, max) { return wasmExports().uniform_inv_scalar(p, min, max); }
 export function uniform_pdf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_pdf_inplace(inputPtr, len, min, max, outputPtr); }
 export function uniform_cdf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_cdf_inplace(inputPtr, len, min, max, outputPtr); }
+export function uniform_logpdf_scalar(x, min, max) { return wasmExports().uniform_logpdf_scalar(x, min, max); }
+export function uniform_logcdf_scalar(x, min, max) { return wasmExports().uniform_logcdf_scalar(x, min, max); }
+export function uniform_sf_scalar(x, min, max) { return wasmExports().uniform_sf_scalar(x, min, max); }
+export function uniform_logsf_scalar(x, min, max) { return wasmExports().uniform_logsf_scalar(x, min, max); }
+export function uniform_isf_scalar(p, min, max) { return wasmExports().uniform_isf_scalar(p, min, max); }
+export function uniform_logpdf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_logpdf_inplace(inputPtr, len, min, max, outputPtr); }
+export function uniform_logcdf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_logcdf_inplace(inputPtr, len, min, max, outputPtr); }
+export function uniform_sf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_sf_inplace(inputPtr, len, min, max, outputPtr); }
+export function uniform_logsf_inplace(inputPtr, len, min, max, outputPtr) { wasmExports().uniform_logsf_inplace(inputPtr, len, min, max, outputPtr); }
 
 export function cauchy_pdf_scalar(x, location, scale) { return wasmExports().cauchy_pdf_scalar(x, location, scale); }
 export function cauchy_cdf_scalar(x, location, scale) { return wasmExports().cauchy_cdf_scalar(x, location, scale); }
 export function cauchy_inv_scalar(p, location, scale) { return wasmExports().cauchy_inv_scalar(p, location, scale); }
 export function cauchy_pdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_pdf_inplace(inputPtr, len, location, scale, outputPtr); }
 export function cauchy_cdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_cdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function cauchy_logpdf_scalar(x, location, scale) { return wasmExports().cauchy_logpdf_scalar(x, location, scale); }
+export function cauchy_logcdf_scalar(x, location, scale) { return wasmExports().cauchy_logcdf_scalar(x, location, scale); }
+export function cauchy_sf_scalar(x, location, scale) { return wasmExports().cauchy_sf_scalar(x, location, scale); }
+export function cauchy_logsf_scalar(x, location, scale) { return wasmExports().cauchy_logsf_scalar(x, location, scale); }
+export function cauchy_isf_scalar(p, location, scale) { return wasmExports().cauchy_isf_scalar(p, location, scale); }
+export function cauchy_logpdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_logpdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function cauchy_logcdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_logcdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function cauchy_sf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_sf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function cauchy_logsf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().cauchy_logsf_inplace(inputPtr, len, location, scale, outputPtr); }
 
 export function laplace_pdf_scalar(x, location, scale) { return wasmExports().laplace_pdf_scalar(x, location, scale); }
 export function laplace_cdf_scalar(x, location, scale) { return wasmExports().laplace_cdf_scalar(x, location, scale); }
 export function laplace_inv_scalar(p, location, scale) { return wasmExports().laplace_inv_scalar(p, location, scale); }
 export function laplace_pdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_pdf_inplace(inputPtr, len, location, scale, outputPtr); }
 export function laplace_cdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_cdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function laplace_logpdf_scalar(x, location, scale) { return wasmExports().laplace_logpdf_scalar(x, location, scale); }
+export function laplace_logcdf_scalar(x, location, scale) { return wasmExports().laplace_logcdf_scalar(x, location, scale); }
+export function laplace_sf_scalar(x, location, scale) { return wasmExports().laplace_sf_scalar(x, location, scale); }
+export function laplace_logsf_scalar(x, location, scale) { return wasmExports().laplace_logsf_scalar(x, location, scale); }
+export function laplace_isf_scalar(p, location, scale) { return wasmExports().laplace_isf_scalar(p, location, scale); }
+export function laplace_logpdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_logpdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function laplace_logcdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_logcdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function laplace_sf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_sf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function laplace_logsf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().laplace_logsf_inplace(inputPtr, len, location, scale, outputPtr); }
 
 export function lognormal_pdf_scalar(x, mean, sd) { return wasmExports().lognormal_pdf_scalar(x, mean, sd); }
 export function lognormal_cdf_scalar(x, mean, sd) { return wasmExports().lognormal_cdf_scalar(x, mean, sd); }
 export function lognormal_inv_scalar(p, mean, sd) { return wasmExports().lognormal_inv_scalar(p, mean, sd); }
 export function lognormal_pdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_pdf_inplace(inputPtr, len, mean, sd, outputPtr); }
 export function lognormal_cdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_cdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function lognormal_logpdf_scalar(x, mean, sd) { return wasmExports().lognormal_logpdf_scalar(x, mean, sd); }
+export function lognormal_logcdf_scalar(x, mean, sd) { return wasmExports().lognormal_logcdf_scalar(x, mean, sd); }
+export function lognormal_sf_scalar(x, mean, sd) { return wasmExports().lognormal_sf_scalar(x, mean, sd); }
+export function lognormal_logsf_scalar(x, mean, sd) { return wasmExports().lognormal_logsf_scalar(x, mean, sd); }
+export function lognormal_isf_scalar(p, mean, sd) { return wasmExports().lognormal_isf_scalar(p, mean, sd); }
+export function lognormal_logpdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_logpdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function lognormal_logcdf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_logcdf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function lognormal_sf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_sf_inplace(inputPtr, len, mean, sd, outputPtr); }
+export function lognormal_logsf_inplace(inputPtr, len, mean, sd, outputPtr) { wasmExports().lognormal_logsf_inplace(inputPtr, len, mean, sd, outputPtr); }
 
 export function weibull_pdf_scalar(x, shape, scale) { return wasmExports().weibull_pdf_scalar(x, shape, scale); }
 export function weibull_cdf_scalar(x, shape, scale) { return wasmExports().weibull_cdf_scalar(x, shape, scale); }
 export function weibull_inv_scalar(p, shape, scale) { return wasmExports().weibull_inv_scalar(p, shape, scale); }
 export function weibull_pdf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_pdf_inplace(inputPtr, len, shape, scale, outputPtr); }
 export function weibull_cdf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_cdf_inplace(inputPtr, len, shape, scale, outputPtr); }
+export function weibull_logpdf_scalar(x, shape, scale) { return wasmExports().weibull_logpdf_scalar(x, shape, scale); }
+export function weibull_logcdf_scalar(x, shape, scale) { return wasmExports().weibull_logcdf_scalar(x, shape, scale); }
+export function weibull_sf_scalar(x, shape, scale) { return wasmExports().weibull_sf_scalar(x, shape, scale); }
+export function weibull_logsf_scalar(x, shape, scale) { return wasmExports().weibull_logsf_scalar(x, shape, scale); }
+export function weibull_isf_scalar(p, shape, scale) { return wasmExports().weibull_isf_scalar(p, shape, scale); }
+export function weibull_logpdf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_logpdf_inplace(inputPtr, len, shape, scale, outputPtr); }
+export function weibull_logcdf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_logcdf_inplace(inputPtr, len, shape, scale, outputPtr); }
+export function weibull_sf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_sf_inplace(inputPtr, len, shape, scale, outputPtr); }
+export function weibull_logsf_inplace(inputPtr, len, shape, scale, outputPtr) { wasmExports().weibull_logsf_inplace(inputPtr, len, shape, scale, outputPtr); }
 
 export function pareto_pdf_scalar(x, scale, shape) { return wasmExports().pareto_pdf_scalar(x, scale, shape); }
 export function pareto_cdf_scalar(x, scale, shape) { return wasmExports().pareto_cdf_scalar(x, scale, shape); }
 export function pareto_inv_scalar(p, scale, shape) { return wasmExports().pareto_inv_scalar(p, scale, shape); }
 export function pareto_pdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_pdf_inplace(inputPtr, len, scale, shape, outputPtr); }
 export function pareto_cdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_cdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function pareto_logpdf_scalar(x, scale, shape) { return wasmExports().pareto_logpdf_scalar(x, scale, shape); }
+export function pareto_logcdf_scalar(x, scale, shape) { return wasmExports().pareto_logcdf_scalar(x, scale, shape); }
+export function pareto_sf_scalar(x, scale, shape) { return wasmExports().pareto_sf_scalar(x, scale, shape); }
+export function pareto_logsf_scalar(x, scale, shape) { return wasmExports().pareto_logsf_scalar(x, scale, shape); }
+export function pareto_isf_scalar(p, scale, shape) { return wasmExports().pareto_isf_scalar(p, scale, shape); }
+export function pareto_logpdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_logpdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function pareto_logcdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_logcdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function pareto_sf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_sf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function pareto_logsf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().pareto_logsf_inplace(inputPtr, len, scale, shape, outputPtr); }
 
 export function triangular_pdf_scalar(x, min, max, mode) { return wasmExports().triangular_pdf_scalar(x, min, max, mode); }
 export function triangular_cdf_scalar(x, min, max, mode) { return wasmExports().triangular_cdf_scalar(x, min, max, mode); }
 export function triangular_inv_scalar(p, min, max, mode) { return wasmExports().triangular_inv_scalar(p, min, max, mode); }
 export function triangular_pdf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_pdf_inplace(inputPtr, len, min, max, mode, outputPtr); }
 export function triangular_cdf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_cdf_inplace(inputPtr, len, min, max, mode, outputPtr); }
+export function triangular_logpdf_scalar(x, min, max, mode) { return wasmExports().triangular_logpdf_scalar(x, min, max, mode); }
+export function triangular_logcdf_scalar(x, min, max, mode) { return wasmExports().triangular_logcdf_scalar(x, min, max, mode); }
+export function triangular_sf_scalar(x, min, max, mode) { return wasmExports().triangular_sf_scalar(x, min, max, mode); }
+export function triangular_logsf_scalar(x, min, max, mode) { return wasmExports().triangular_logsf_scalar(x, min, max, mode); }
+export function triangular_isf_scalar(p, min, max, mode) { return wasmExports().triangular_isf_scalar(p, min, max, mode); }
+export function triangular_logpdf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_logpdf_inplace(inputPtr, len, min, max, mode, outputPtr); }
+export function triangular_logcdf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_logcdf_inplace(inputPtr, len, min, max, mode, outputPtr); }
+export function triangular_sf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_sf_inplace(inputPtr, len, min, max, mode, outputPtr); }
+export function triangular_logsf_inplace(inputPtr, len, min, max, mode, outputPtr) { wasmExports().triangular_logsf_inplace(inputPtr, len, min, max, mode, outputPtr); }
 
 export function invgamma_pdf_scalar(x, shape, rate) { return wasmExports().invgamma_pdf_scalar(x, shape, rate); }
 export function invgamma_cdf_scalar(x, shape, rate) { return wasmExports().invgamma_cdf_scalar(x, shape, rate); }
 export function invgamma_inv_scalar(p, shape, rate) { return wasmExports().invgamma_inv_scalar(p, shape, rate); }
 export function invgamma_pdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_pdf_inplace(inputPtr, len, shape, rate, outputPtr); }
 export function invgamma_cdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_cdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function invgamma_logpdf_scalar(x, shape, rate) { return wasmExports().invgamma_logpdf_scalar(x, shape, rate); }
+export function invgamma_logcdf_scalar(x, shape, rate) { return wasmExports().invgamma_logcdf_scalar(x, shape, rate); }
+export function invgamma_sf_scalar(x, shape, rate) { return wasmExports().invgamma_sf_scalar(x, shape, rate); }
+export function invgamma_logsf_scalar(x, shape, rate) { return wasmExports().invgamma_logsf_scalar(x, shape, rate); }
+export function invgamma_isf_scalar(p, shape, rate) { return wasmExports().invgamma_isf_scalar(p, shape, rate); }
+export function invgamma_logpdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_logpdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function invgamma_logcdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_logcdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function invgamma_sf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_sf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function invgamma_logsf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().invgamma_logsf_inplace(inputPtr, len, shape, rate, outputPtr); }
 
 export function negbin_pmf_scalar(k, r, p) { return wasmExports().negbin_pmf_scalar(k, r, p); }
 export function negbin_cdf_scalar(k, r, p) { return wasmExports().negbin_cdf_scalar(k, r, p); }
 export function negbin_inv_scalar(prob, r, p) { return wasmExports().negbin_inv_scalar(prob, r, p); }
 export function negbin_pmf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_pmf_inplace(inputPtr, len, r, p, outputPtr); }
 export function negbin_cdf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_cdf_inplace(inputPtr, len, r, p, outputPtr); }
+export function negbin_logpmf_scalar(k, r, p) { return wasmExports().negbin_logpmf_scalar(k, r, p); }
+export function negbin_logcdf_scalar(k, r, p) { return wasmExports().negbin_logcdf_scalar(k, r, p); }
+export function negbin_sf_scalar(k, r, p) { return wasmExports().negbin_sf_scalar(k, r, p); }
+export function negbin_logsf_scalar(k, r, p) { return wasmExports().negbin_logsf_scalar(k, r, p); }
+export function negbin_isf_scalar(prob, r, p) { return wasmExports().negbin_isf_scalar(prob, r, p); }
+export function negbin_logpmf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_logpmf_inplace(inputPtr, len, r, p, outputPtr); }
+export function negbin_logcdf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_logcdf_inplace(inputPtr, len, r, p, outputPtr); }
+export function negbin_sf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_sf_inplace(inputPtr, len, r, p, outputPtr); }
+export function negbin_logsf_inplace(inputPtr, len, r, p, outputPtr) { wasmExports().negbin_logsf_inplace(inputPtr, len, r, p, outputPtr); }
 
+export function gumbel_pdf_scalar(x, location, scale) { return wasmExports().gumbel_pdf_scalar(x, location, scale); }
+export function gumbel_cdf_scalar(x, location, scale) { return wasmExports().gumbel_cdf_scalar(x, location, scale); }
+export function gumbel_inv_scalar(p, location, scale) { return wasmExports().gumbel_inv_scalar(p, location, scale); }
+export function gumbel_pdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_pdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function gumbel_cdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_cdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function gumbel_logpdf_scalar(x, location, scale) { return wasmExports().gumbel_logpdf_scalar(x, location, scale); }
+export function gumbel_logcdf_scalar(x, location, scale) { return wasmExports().gumbel_logcdf_scalar(x, location, scale); }
+export function gumbel_sf_scalar(x, location, scale) { return wasmExports().gumbel_sf_scalar(x, location, scale); }
+export function gumbel_logsf_scalar(x, location, scale) { return wasmExports().gumbel_logsf_scalar(x, location, scale); }
+export function gumbel_isf_scalar(p, location, scale) { return wasmExports().gumbel_isf_scalar(p, location, scale); }
+export function gumbel_logpdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_logpdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function gumbel_logcdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_logcdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function gumbel_sf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_sf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function gumbel_logsf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().gumbel_logsf_inplace(inputPtr, len, location, scale, outputPtr); }
+
+export function gev_pdf_scalar(x, location, scale, shape) { return wasmExports().gev_pdf_scalar(x, location, scale, shape); }
+export function gev_cdf_scalar(x, location, scale, shape) { return wasmExports().gev_cdf_scalar(x, location, scale, shape); }
+export function gev_inv_scalar(p, location, scale, shape) { return wasmExports().gev_inv_scalar(p, location, scale, shape); }
+export function gev_pdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_pdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function gev_cdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_cdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function gev_logpdf_scalar(x, location, scale, shape) { return wasmExports().gev_logpdf_scalar(x, location, scale, shape); }
+export function gev_logcdf_scalar(x, location, scale, shape) { return wasmExports().gev_logcdf_scalar(x, location, scale, shape); }
+export function gev_sf_scalar(x, location, scale, shape) { return wasmExports().gev_sf_scalar(x, location, scale, shape); }
+export function gev_logsf_scalar(x, location, scale, shape) { return wasmExports().gev_logsf_scalar(x, location, scale, shape); }
+export function gev_isf_scalar(p, location, scale, shape) { return wasmExports().gev_isf_scalar(p, location, scale, shape); }
+export function gev_logpdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_logpdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function gev_logcdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_logcdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function gev_sf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_sf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function gev_logsf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().gev_logsf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+
+export function logistic_pdf_scalar(x, location, scale) { return wasmExports().logistic_pdf_scalar(x, location, scale); }
+export function logistic_cdf_scalar(x, location, scale) { return wasmExports().logistic_cdf_scalar(x, location, scale); }
+export function logistic_inv_scalar(p, location, scale) { return wasmExports().logistic_inv_scalar(p, location, scale); }
+export function logistic_pdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_pdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function logistic_cdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_cdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function logistic_logpdf_scalar(x, location, scale) { return wasmExports().logistic_logpdf_scalar(x, location, scale); }
+export function logistic_logcdf_scalar(x, location, scale) { return wasmExports().logistic_logcdf_scalar(x, location, scale); }
+export function logistic_sf_scalar(x, location, scale) { return wasmExports().logistic_sf_scalar(x, location, scale); }
+export function logistic_logsf_scalar(x, location, scale) { return wasmExports().logistic_logsf_scalar(x, location, scale); }
+export function logistic_isf_scalar(p, location, scale) { return wasmExports().logistic_isf_scalar(p, location, scale); }
+export function logistic_logpdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_logpdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function logistic_logcdf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_logcdf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function logistic_sf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_sf_inplace(inputPtr, len, location, scale, outputPtr); }
+export function logistic_logsf_inplace(inputPtr, len, location, scale, outputPtr) { wasmExports().logistic_logsf_inplace(inputPtr, len, location, scale, outputPtr); }
+
+export function loglogistic_pdf_scalar(x, scale, shape) { return wasmExports().loglogistic_pdf_scalar(x, scale, shape); }
+export function loglogistic_cdf_scalar(x, scale, shape) { return wasmExports().loglogistic_cdf_scalar(x, scale, shape); }
+export function loglogistic_inv_scalar(p, scale, shape) { return wasmExports().loglogistic_inv_scalar(p, scale, shape); }
+export function loglogistic_pdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_pdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function loglogistic_cdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_cdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function loglogistic_logpdf_scalar(x, scale, shape) { return wasmExports().loglogistic_logpdf_scalar(x, scale, shape); }
+export function loglogistic_logcdf_scalar(x, scale, shape) { return wasmExports().loglogistic_logcdf_scalar(x, scale, shape); }
+export function loglogistic_sf_scalar(x, scale, shape) { return wasmExports().loglogistic_sf_scalar(x, scale, shape); }
+export function loglogistic_logsf_scalar(x, scale, shape) { return wasmExports().loglogistic_logsf_scalar(x, scale, shape); }
+export function loglogistic_isf_scalar(p, scale, shape) { return wasmExports().loglogistic_isf_scalar(p, scale, shape); }
+export function loglogistic_logpdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_logpdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function loglogistic_logcdf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_logcdf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function loglogistic_sf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_sf_inplace(inputPtr, len, scale, shape, outputPtr); }
+export function loglogistic_logsf_inplace(inputPtr, len, scale, shape, outputPtr) { wasmExports().loglogistic_logsf_inplace(inputPtr, len, scale, shape, outputPtr); }
+
+export function rayleigh_pdf_scalar(x, scale) { return wasmExports().rayleigh_pdf_scalar(x, scale); }
+export function rayleigh_cdf_scalar(x, scale) { return wasmExports().rayleigh_cdf_scalar(x, scale); }
+export function rayleigh_inv_scalar(p, scale) { return wasmExports().rayleigh_inv_scalar(p, scale); }
+export function rayleigh_pdf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_pdf_inplace(inputPtr, len, scale, outputPtr); }
+export function rayleigh_cdf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_cdf_inplace(inputPtr, len, scale, outputPtr); }
+export function rayleigh_logpdf_scalar(x, scale) { return wasmExports().rayleigh_logpdf_scalar(x, scale); }
+export function rayleigh_logcdf_scalar(x, scale) { return wasmExports().rayleigh_logcdf_scalar(x, scale); }
+export function rayleigh_sf_scalar(x, scale) { return wasmExports().rayleigh_sf_scalar(x, scale); }
+export function rayleigh_logsf_scalar(x, scale) { return wasmExports().rayleigh_logsf_scalar(x, scale); }
+export function rayleigh_isf_scalar(p, scale) { return wasmExports().rayleigh_isf_scalar(p, scale); }
+export function rayleigh_logpdf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_logpdf_inplace(inputPtr, len, scale, outputPtr); }
+export function rayleigh_logcdf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_logcdf_inplace(inputPtr, len, scale, outputPtr); }
+export function rayleigh_sf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_sf_inplace(inputPtr, len, scale, outputPtr); }
+export function rayleigh_logsf_inplace(inputPtr, len, scale, outputPtr) { wasmExports().rayleigh_logsf_inplace(inputPtr, len, scale, outputPtr); }
+
+export function erlang_pdf_scalar(x, shape, rate) { return wasmExports().erlang_pdf_scalar(x, shape, rate); }
+export function erlang_cdf_scalar(x, shape, rate) { return wasmExports().erlang_cdf_scalar(x, shape, rate); }
+export function erlang_inv_scalar(p, shape, rate) { return wasmExports().erlang_inv_scalar(p, shape, rate); }
+export function erlang_pdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_pdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function erlang_cdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_cdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function erlang_logpdf_scalar(x, shape, rate) { return wasmExports().erlang_logpdf_scalar(x, shape, rate); }
+export function erlang_logcdf_scalar(x, shape, rate) { return wasmExports().erlang_logcdf_scalar(x, shape, rate); }
+export function erlang_sf_scalar(x, shape, rate) { return wasmExports().erlang_sf_scalar(x, shape, rate); }
+export function erlang_logsf_scalar(x, shape, rate) { return wasmExports().erlang_logsf_scalar(x, shape, rate); }
+export function erlang_isf_scalar(p, shape, rate) { return wasmExports().erlang_isf_scalar(p, shape, rate); }
+export function erlang_logpdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_logpdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function erlang_logcdf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_logcdf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function erlang_sf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_sf_inplace(inputPtr, len, shape, rate, outputPtr); }
+export function erlang_logsf_inplace(inputPtr, len, shape, rate, outputPtr) { wasmExports().erlang_logsf_inplace(inputPtr, len, shape, rate, outputPtr); }
+
+export function vonmises_pdf_scalar(x, mu, kappa) { return wasmExports().vonmises_pdf_scalar(x, mu, kappa); }
+export function vonmises_cdf_scalar(x, mu, kappa) { return wasmExports().vonmises_cdf_scalar(x, mu, kappa); }
+export function vonmises_inv_scalar(p, mu, kappa) { return wasmExports().vonmises_inv_scalar(p, mu, kappa); }
+export function vonmises_pdf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_pdf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+export function vonmises_cdf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_cdf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+export function vonmises_logpdf_scalar(x, mu, kappa) { return wasmExports().vonmises_logpdf_scalar(x, mu, kappa); }
+export function vonmises_logcdf_scalar(x, mu, kappa) { return wasmExports().vonmises_logcdf_scalar(x, mu, kappa); }
+export function vonmises_sf_scalar(x, mu, kappa) { return wasmExports().vonmises_sf_scalar(x, mu, kappa); }
+export function vonmises_logsf_scalar(x, mu, kappa) { return wasmExports().vonmises_logsf_scalar(x, mu, kappa); }
+export function vonmises_isf_scalar(p, mu, kappa) { return wasmExports().vonmises_isf_scalar(p, mu, kappa); }
+export function vonmises_logpdf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_logpdf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+export function vonmises_logcdf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_logcdf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+export function vonmises_sf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_sf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+export function vonmises_logsf_inplace(inputPtr, len, mu, kappa, outputPtr) { wasmExports().vonmises_logsf_inplace(inputPtr, len, mu, kappa, outputPtr); }
+
+export function skewnormal_pdf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_pdf_scalar(x, location, scale, shape); }
+export function skewnormal_cdf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_cdf_scalar(x, location, scale, shape); }
+export function skewnormal_inv_scalar(p, location, scale, shape) { return wasmExports().skewnormal_inv_scalar(p, location, scale, shape); }
+export function skewnormal_pdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_pdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function skewnormal_cdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_cdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function skewnormal_logpdf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_logpdf_scalar(x, location, scale, shape); }
+export function skewnormal_logcdf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_logcdf_scalar(x, location, scale, shape); }
+export function skewnormal_sf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_sf_scalar(x, location, scale, shape); }
+export function skewnormal_logsf_scalar(x, location, scale, shape) { return wasmExports().skewnormal_logsf_scalar(x, location, scale, shape); }
+export function skewnormal_isf_scalar(p, location, scale, shape) { return wasmExports().skewnormal_isf_scalar(p, location, scale, shape); }
+export function skewnormal_logpdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_logpdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function skewnormal_logcdf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_logcdf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function skewnormal_sf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_sf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+export function skewnormal_logsf_inplace(inputPtr, len, location, scale, shape, outputPtr) { wasmExports().skewnormal_logsf_inplace(inputPtr, len, location, scale, shape, outputPtr); }
+
+export function truncnormal_pdf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_pdf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_cdf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_cdf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_inv_scalar(p, location, scale, lower, upper) { return wasmExports().truncnormal_inv_scalar(p, location, scale, lower, upper); }
+export function truncnormal_pdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_pdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+export function truncnormal_cdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_cdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+export function truncnormal_logpdf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_logpdf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_logcdf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_logcdf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_sf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_sf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_logsf_scalar(x, location, scale, lower, upper) { return wasmExports().truncnormal_logsf_scalar(x, location, scale, lower, upper); }
+export function truncnormal_isf_scalar(p, location, scale, lower, upper) { return wasmExports().truncnormal_isf_scalar(p, location, scale, lower, upper); }
+export function truncnormal_logpdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_logpdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+export function truncnormal_logcdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_logcdf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+export function truncnormal_sf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_sf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+export function truncnormal_logsf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr) { wasmExports().truncnormal_logsf_inplace(inputPtr, len, location, scale, lower, upper, outputPtr); }
+
+export function hypergeom_pmf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_pmf_scalar(k, population, successes, draws); }
+export function hypergeom_cdf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_cdf_scalar(k, population, successes, draws); }
+export function hypergeom_inv_scalar(p, population, successes, draws) { return wasmExports().hypergeom_inv_scalar(p, population, successes, draws); }
+export function hypergeom_pmf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_pmf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+export function hypergeom_cdf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_cdf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+export function hypergeom_logpmf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_logpmf_scalar(k, population, successes, draws); }
+export function hypergeom_logcdf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_logcdf_scalar(k, population, successes, draws); }
+export function hypergeom_sf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_sf_scalar(k, population, successes, draws); }
+export function hypergeom_logsf_scalar(k, population, successes, draws) { return wasmExports().hypergeom_logsf_scalar(k, population, successes, draws); }
+export function hypergeom_isf_scalar(p, population, successes, draws) { return wasmExports().hypergeom_isf_scalar(p, population, successes, draws); }
+export function hypergeom_logpmf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_logpmf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+export function hypergeom_logcdf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_logcdf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+export function hypergeom_sf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_sf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+export function hypergeom_logsf_inplace(inputPtr, len, population, successes, draws, outputPtr) { wasmExports().hypergeom_logsf_inplace(inputPtr, len, population, successes, draws, outputPtr); }
+
+export function geometric_pmf_scalar(k, p) { return wasmExports().geometric_pmf_scalar(k, p); }
+export function geometric_cdf_scalar(k, p) { return wasmExports().geometric_cdf_scalar(k, p); }
+export function geometric_inv_scalar(prob, p) { return wasmExports().geometric_inv_scalar(prob, p); }
+export function geometric_pmf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_pmf_inplace(inputPtr, len, p, outputPtr); }
+export function geometric_cdf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_cdf_inplace(inputPtr, len, p, outputPtr); }
+export function geometric_logpmf_scalar(k, p) { return wasmExports().geometric_logpmf_scalar(k, p); }
+export function geometric_logcdf_scalar(k, p) { return wasmExports().geometric_logcdf_scalar(k, p); }
+export function geometric_sf_scalar(k, p) { return wasmExports().geometric_sf_scalar(k, p); }
+export function geometric_logsf_scalar(k, p) { return wasmExports().geometric_logsf_scalar(k, p); }
+export function geometric_isf_scalar(prob, p) { return wasmExports().geometric_isf_scalar(prob, p); }
+export function geometric_logpmf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_logpmf_inplace(inputPtr, len, p, outputPtr); }
+export function geometric_logcdf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_logcdf_inplace(inputPtr, len, p, outputPtr); }
+export function geometric_sf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_sf_inplace(inputPtr, len, p, outputPtr); }
+export function geometric_logsf_inplace(inputPtr, len, p, outputPtr) { wasmExports().geometric_logsf_inplace(inputPtr, len, p, outputPtr); }
+export function studentized_range_cdf_scalar(q, nmeans, df) { return wasmExports().studentized_range_cdf_scalar(q, nmeans, df); }
+export function studentized_range_inv_scalar(p, nmeans, df) { return wasmExports().studentized_range_inv_scalar(p, nmeans, df); }
+export function studentized_range_cdf_inplace(inputPtr, len, nmeans, df, outputPtr) { wasmExports().studentized_range_cdf_inplace(inputPtr, len, nmeans, df, outputPtr); }
+
+export function noncentral_chi_squared_pdf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_pdf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_cdf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_cdf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_sf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_sf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_inv_scalar(p, dof, ncp) { return wasmExports().noncentral_chi_squared_inv_scalar(p, dof, ncp); }
+export function noncentral_chi_squared_pdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_pdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_chi_squared_cdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_cdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_chi_squared_sf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_sf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_chi_squared_logpdf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_logpdf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_logcdf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_logcdf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_logsf_scalar(x, dof, ncp) { return wasmExports().noncentral_chi_squared_logsf_scalar(x, dof, ncp); }
+export function noncentral_chi_squared_isf_scalar(p, dof, ncp) { return wasmExports().noncentral_chi_squared_isf_scalar(p, dof, ncp); }
+export function noncentral_chi_squared_logpdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_logpdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_chi_squared_logcdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_logcdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_chi_squared_logsf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_chi_squared_logsf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_pdf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_pdf_scalar(x, dof, ncp); }
+export function noncentral_t_cdf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_cdf_scalar(x, dof, ncp); }
+export function noncentral_t_sf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_sf_scalar(x, dof, ncp); }
+export function noncentral_t_inv_scalar(p, dof, ncp) { return wasmExports().noncentral_t_inv_scalar(p, dof, ncp); }
+export function noncentral_t_pdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_pdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_cdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_cdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_sf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_sf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_logpdf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_logpdf_scalar(x, dof, ncp); }
+export function noncentral_t_logcdf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_logcdf_scalar(x, dof, ncp); }
+export function noncentral_t_logsf_scalar(x, dof, ncp) { return wasmExports().noncentral_t_logsf_scalar(x, dof, ncp); }
+export function noncentral_t_isf_scalar(p, dof, ncp) { return wasmExports().noncentral_t_isf_scalar(p, dof, ncp); }
+export function noncentral_t_logpdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_logpdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_logcdf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_logcdf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_t_logsf_inplace(inputPtr, len, dof, ncp, outputPtr) { wasmExports().noncentral_t_logsf_inplace(inputPtr, len, dof, ncp, outputPtr); }
+export function noncentral_f_pdf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_pdf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_cdf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_cdf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_sf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_sf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_inv_scalar(p, df1, df2, ncp) { return wasmExports().noncentral_f_inv_scalar(p, df1, df2, ncp); }
+export function noncentral_f_pdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_pdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+export function noncentral_f_cdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_cdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+export function noncentral_f_sf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_sf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+export function noncentral_f_logpdf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_logpdf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_logcdf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_logcdf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_logsf_scalar(x, df1, df2, ncp) { return wasmExports().noncentral_f_logsf_scalar(x, df1, df2, ncp); }
+export function noncentral_f_isf_scalar(p, df1, df2, ncp) { return wasmExports().noncentral_f_isf_scalar(p, df1, df2, ncp); }
+export function noncentral_f_logpdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_logpdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+export function noncentral_f_logcdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_logcdf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+export function noncentral_f_logsf_inplace(inputPtr, len, df1, df2, ncp, outputPtr) { wasmExports().noncentral_f_logsf_inplace(inputPtr, len, df1, df2, ncp, outputPtr); }
+
+// Multivariate densities (pointers to f64 buffers; matrices are row-major dim x dim)
+export function multinomial_pmf_scalar(countsPtr, probsPtr, len) { return wasmExports().multinomial_pmf_scalar(countsPtr, probsPtr, len); }
+export function multinomial_logpmf_scalar(countsPtr, probsPtr, len) { return wasmExports().multinomial_logpmf_scalar(countsPtr, probsPtr, len); }
+export function dirichlet_pdf_scalar(xPtr, alphaPtr, len) { return wasmExports().dirichlet_pdf_scalar(xPtr, alphaPtr, len); }
+export function dirichlet_logpdf_scalar(xPtr, alphaPtr, len) { return wasmExports().dirichlet_logpdf_scalar(xPtr, alphaPtr, len); }
+export function wishart_pdf_scalar(xPtr, scalePtr, dim, dof) { return wasmExports().wishart_pdf_scalar(xPtr, scalePtr, dim, dof); }
+export function wishart_logpdf_scalar(xPtr, scalePtr, dim, dof) { return wasmExports().wishart_logpdf_scalar(xPtr, scalePtr, dim, dof); }
+
+// Random sampling (seed is a non-negative integer below 2^53)
+export function normal_sample_into(len, mean, sd, seed, outputPtr) { wasmExports().normal_sample_into(len, mean, sd, seed, outputPtr); }
+export function gamma_sample_into(len, shape, rate, seed, outputPtr) { wasmExports().gamma_sample_into(len, shape, rate, seed, outputPtr); }
+export function beta_sample_into(len, alpha, beta, seed, outputPtr) { wasmExports().beta_sample_into(len, alpha, beta, seed, outputPtr); }
+export function student_t_sample_into(len, mean, scale, dof, seed, outputPtr) { wasmExports().student_t_sample_into(len, mean, scale, dof, seed, outputPtr); }
+export function chi_squared_sample_into(len, dof, seed, outputPtr) { wasmExports().chi_squared_sample_into(len, dof, seed, outputPtr); }
+export function fisher_f_sample_into(len, df1, df2, seed, outputPtr) { wasmExports().fisher_f_sample_into(len, df1, df2, seed, outputPtr); }
+export function exponential_sample_into(len, rate, seed, outputPtr) { wasmExports().exponential_sample_into(len, rate, seed, outputPtr); }
+export function poisson_sample_into(len, lambda, seed, outputPtr) { wasmExports().poisson_sample_into(len, lambda, seed, outputPtr); }
+export function binomial_sample_into(len, n, p, seed, outputPtr) { wasmExports().binomial_sample_into(len, n, p, seed, outputPtr); }
+export function uniform_sample_into(len, min, max, seed, outputPtr) { wasmExports().uniform_sample_into(len, min, max, seed, outputPtr); }
+export function cauchy_sample_into(len, location, scale, seed, outputPtr) { wasmExports().cauchy_sample_into(len, location, scale, seed, outputPtr); }
+export function laplace_sample_into(len, location, scale, seed, outputPtr) { wasmExports().laplace_sample_into(len, location, scale, seed, outputPtr); }
+export function lognormal_sample_into(len, mean, sd, seed, outputPtr) { wasmExports().lognormal_sample_into(len, mean, sd, seed, outputPtr); }
+export function weibull_sample_into(len, shape, scale, seed, outputPtr) { wasmExports().weibull_sample_into(len, shape, scale, seed, outputPtr); }
+export function pareto_sample_into(len, scale, shape, seed, outputPtr) { wasmExports().pareto_sample_into(len, scale, shape, seed, outputPtr); }
+export function triangular_sample_into(len, min, max, mode, seed, outputPtr) { wasmExports().triangular_sample_into(len, min, max, mode, seed, outputPtr); }
+export function invgamma_sample_into(len, shape, rate, seed, outputPtr) { wasmExports().invgamma_sample_into(len, shape, rate, seed, outputPtr); }
+export function negbin_sample_into(len, r, p, seed, outputPtr) { wasmExports().negbin_sample_into(len, r, p, seed, outputPtr); }
+export function gumbel_sample_into(len, location, scale, seed, outputPtr) { wasmExports().gumbel_sample_into(len, location, scale, seed, outputPtr); }
+export function gev_sample_into(len, location, scale, shape, seed, outputPtr) { wasmExports().gev_sample_into(len, location, scale, shape, seed, outputPtr); }
+export function logistic_sample_into(len, location, scale, seed, outputPtr) { wasmExports().logistic_sample_into(len, location, scale, seed, outputPtr); }
+export function loglogistic_sample_into(len, scale, shape, seed, outputPtr) { wasmExports().loglogistic_sample_into(len, scale, shape, seed, outputPtr); }
+export function rayleigh_sample_into(len, scale, seed, outputPtr) { wasmExports().rayleigh_sample_into(len, scale, seed, outputPtr); }
+export function erlang_sample_into(len, shape, rate, seed, outputPtr) { wasmExports().erlang_sample_into(len, shape, rate, seed, outputPtr); }
+export function vonmises_sample_into(len, mu, kappa, seed, outputPtr) { wasmExports().vonmises_sample_into(len, mu, kappa, seed, outputPtr); }
+export function skewnormal_sample_into(len, location, scale, shape, seed, outputPtr) { wasmExports().skewnormal_sample_into(len, location, scale, shape, seed, outputPtr); }
+export function truncnormal_sample_into(len, location, scale, lower, upper, seed, outputPtr) { wasmExports().truncnormal_sample_into(len, location, scale, lower, upper, seed, outputPtr); }
+export function hypergeom_sample_into(len, population, successes, draws, seed, outputPtr) { wasmExports().hypergeom_sample_into(len, population, successes, draws, seed, outputPtr); }
+export function geometric_sample_into(len, p, seed, outputPtr) { wasmExports().geometric_sample_into(len, p, seed, outputPtr); }
+export function noncentral_chi_squared_sample_into(len, dof, ncp, seed, outputPtr) { wasmExports().noncentral_chi_squared_sample_into(len, dof, ncp, seed, outputPtr); }
+export function noncentral_t_sample_into(len, dof, ncp, seed, outputPtr) { wasmExports().noncentral_t_sample_into(len, dof, ncp, seed, outputPtr); }
+export function noncentral_f_sample_into(len, df1, df2, ncp, seed, outputPtr) { wasmExports().noncentral_f_sample_into(len, df1, df2, ncp, seed, outputPtr); }
@@ -269,3 +269,73 @@ pub unsafe extern "C" fn histogram_custom_with_edges_f64(
     }
     res.counts.len() as isize
 }
+
+fn kde_kernel(kernel: usize) -> stat_core::Kernel {
+    match kernel {
+        0 => stat_core::Kernel::Gaussian,
+        1 => stat_core::Kernel::Epanechnikov,
+        2 => stat_core::Kernel::Rectangular,
+        3 => stat_core::Kernel::Triangular,
+        4 => stat_core::Kernel::Biweight,
+        _ => stat_core::Kernel::Gaussian,
+    }
+}
+
+fn kde_bandwidth_rule(rule: usize) -> stat_core::BandwidthRule {
+    match rule {
+        0 => stat_core::BandwidthRule::Silverman,
+        1 => stat_core::BandwidthRule::Scott,
+        _ => stat_core::BandwidthRule::Silverman,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn kde_bandwidth_f64(ptr: *const f64, len: usize, rule: usize) -> f64 {
+    stat_core::kde_bandwidth(slice_from(ptr, len), kde_bandwidth_rule(rule))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn kde_evaluate_f64(
+    dp: *const f64,
+    dl: usize,
+    pp: *const f64,
+    pl: usize,
+    kernel: usize,
+    bw: f64,
+    out_ptr: *mut f64,
+) -> isize {
+    let res = stat_core::kde_evaluate(
+        slice_from(dp, dl),
+        slice_from(pp, pl),
+        kde_kernel(kernel),
+        bw,
+    );
+    slice_from_mut(out_ptr, pl).copy_from_slice(&res);
+    pl as isize
+}
+
+// `bw <= 0` (or NaN) selects the bandwidth with `rule`; the bandwidth used is written to `b_out`.
+#[no_mangle]
+pub unsafe extern "C" fn kde_grid_f64(
+    ptr: *const f64,
+    len: usize,
+    kernel: usize,
+    rule: usize,
+    bw: f64,
+    gs: usize,
+    x_out: *mut f64,
+    d_out: *mut f64,
+    b_out: *mut f64,
+) -> isize {
+    let res = stat_core::kde_grid(
+        slice_from(ptr, len),
+        kde_kernel(kernel),
+        kde_bandwidth_rule(rule),
+        if bw > 0.0 { Some(bw) } else { None },
+        gs,
+    );
+    slice_from_mut(x_out, res.x.len()).copy_from_slice(&res.x);
+    slice_from_mut(d_out, res.density.len()).copy_from_slice(&res.density);
+    *b_out = res.bandwidth;
+    res.density.len() as isize
+}
@@ -92,3 +92,27 @@ export function histogram_custom_with_edges_f64(dp, dl, ep, el, co) {
     counts: { ptr: countsOutPtr, len: Number(written), cap: el - 1 }
   };
 }
+
+// Kernel density estimation
+// kernel: 0=Gaussian, 1=Epanechnikov, 2=Rectangular, 3=Triangular, 4=Biweight
+// rule: 0=Silverman, 1=Scott
+export function kde_bandwidth_f64(ptr, len, rule) { return wasmExports().kde_bandwidth_f64(ptr, len, rule); }
+export function kde_evaluate_f64(dp, dl, pp, pl, kernel, bandwidth) {
+  const outPtr = alloc(pl * 8);
+  const written = wasmExports().kde_evaluate_f64(dp, dl, pp, pl, kernel, bandwidth, outPtr);
+  return { ptr: outPtr, len: Number(written) };
+}
+export function kde_grid_f64(ptr, len, kernel, rule, bandwidth, gridSize) {
+  const cap = gridSize > 0 ? Math.max(gridSize, 2) : 512;
+  const xOutPtr = alloc(cap * 8);
+  const densityOutPtr = alloc(cap * 8);
+  const bwOutPtr = alloc(8);
+  const written = wasmExports().kde_grid_f64(ptr, len, kernel, rule, bandwidth, gridSize, xOutPtr, densityOutPtr, bwOutPtr);
+  const bw = new Float64Array(wasmExports().memory.buffer, bwOutPtr, 1)[0];
+  free(bwOutPtr, 8);
+  return {
+    x: { ptr: xOutPtr, len: Number(written), cap },
+    density: { ptr: densityOutPtr, len: Number(written), cap },
+    bandwidth: bw
+  };
+}
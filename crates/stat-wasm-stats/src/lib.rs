@@ -25,6 +25,15 @@ pub unsafe extern "C" fn free_f64(ptr: *mut f64, len: usize) {
     free_bytes(ptr as *mut u8, len * 8);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn alloc_f32(len: usize) -> *mut f32 {
+    alloc_bytes(len * 4) as *mut f32
+}
+#[no_mangle]
+pub unsafe extern "C" fn free_f32(ptr: *mut f32, len: usize) {
+    free_bytes(ptr as *mut u8, len * 4);
+}
+
 fn slice_from<'a>(ptr: *const f64, len: usize) -> &'a [f64] {
     unsafe { std::slice::from_raw_parts(ptr, len) }
 }
@@ -33,6 +42,10 @@ fn slice_from_mut<'a>(ptr: *mut f64, len: usize) -> &'a mut [f64] {
     unsafe { std::slice::from_raw_parts_mut(ptr, len) }
 }
 
+fn slice_from_f32<'a>(ptr: *const f32, len: usize) -> &'a [f32] {
+    unsafe { std::slice::from_raw_parts(ptr, len) }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn sum_f64(ptr: *const f64, len: usize) -> f64 {
     stat_core::sum(slice_from(ptr, len))
@@ -177,3 +190,242 @@ pub unsafe extern "C" fn histogram_f64(
     }
     bc as isize
 }
+
+// f32 input (e.g. Float32Array views); results are f64
+#[no_mangle]
+pub unsafe extern "C" fn sum_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::sum_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn mean_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::mean_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn variance_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::variance_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn sample_variance_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::sample_variance_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn stdev_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::stdev_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn sample_stdev_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::sample_stdev_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn min_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::min_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn max_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::max_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn range_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::range_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn skewness_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::skewness_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn kurtosis_f32(ptr: *const f32, len: usize) -> f64 {
+    stat_core::kurtosis_f32(slice_from_f32(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn histogram_f32(
+    ptr: *const f32,
+    len: usize,
+    bc: usize,
+    out_ptr: *mut f64,
+) -> isize {
+    let bins = stat_core::histogram_f32(slice_from_f32(ptr, len), bc);
+    let out = slice_from_mut(out_ptr, bc);
+    for i in 0..bc {
+        out[i] = bins[i] as f64;
+    }
+    bc as isize
+}
+
+// Streaming moments (handle-based)
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_new() -> *mut stat_core::RunningMoments {
+    Box::into_raw(Box::new(stat_core::RunningMoments::new()))
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_free(h: *mut stat_core::RunningMoments) {
+    if !h.is_null() {
+        drop(Box::from_raw(h));
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_reset(h: *mut stat_core::RunningMoments) {
+    (*h).reset();
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_push(h: *mut stat_core::RunningMoments, x: f64) {
+    (*h).push(x);
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_push_slice(
+    h: *mut stat_core::RunningMoments,
+    ptr: *const f64,
+    len: usize,
+) {
+    (*h).push_slice(slice_from(ptr, len));
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_merge(
+    h: *mut stat_core::RunningMoments,
+    other: *const stat_core::RunningMoments,
+) {
+    (*h).merge(&*other);
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_count(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).count() as f64
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_mean(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).mean()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_variance(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).variance()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_sample_variance(
+    h: *const stat_core::RunningMoments,
+) -> f64 {
+    (*h).sample_variance()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_stdev(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).stdev()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_sample_stdev(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).sample_stdev()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_skewness(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).skewness()
+}
+#[no_mangle]
+pub unsafe extern "C" fn running_moments_kurtosis(h: *const stat_core::RunningMoments) -> f64 {
+    (*h).kurtosis()
+}
+
+// Weighted statistics
+fn weight_kind(reliability: bool) -> stat_core::WeightKind {
+    if reliability {
+        stat_core::WeightKind::Reliability
+    } else {
+        stat_core::WeightKind::Frequency
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_mean_f64(dp: *const f64, wp: *const f64, len: usize) -> f64 {
+    stat_core::weighted_mean(slice_from(dp, len), slice_from(wp, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_variance_f64(dp: *const f64, wp: *const f64, len: usize) -> f64 {
+    stat_core::weighted_variance(slice_from(dp, len), slice_from(wp, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_sample_variance_f64(
+    dp: *const f64,
+    wp: *const f64,
+    len: usize,
+    reliability: bool,
+) -> f64 {
+    stat_core::weighted_sample_variance(
+        slice_from(dp, len),
+        slice_from(wp, len),
+        weight_kind(reliability),
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_stdev_f64(dp: *const f64, wp: *const f64, len: usize) -> f64 {
+    stat_core::weighted_stdev(slice_from(dp, len), slice_from(wp, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_sample_stdev_f64(
+    dp: *const f64,
+    wp: *const f64,
+    len: usize,
+    reliability: bool,
+) -> f64 {
+    stat_core::weighted_sample_stdev(
+        slice_from(dp, len),
+        slice_from(wp, len),
+        weight_kind(reliability),
+    )
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_skewness_f64(dp: *const f64, wp: *const f64, len: usize) -> f64 {
+    stat_core::weighted_skewness(slice_from(dp, len), slice_from(wp, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn weighted_kurtosis_f64(dp: *const f64, wp: *const f64, len: usize) -> f64 {
+    stat_core::weighted_kurtosis(slice_from(dp, len), slice_from(wp, len))
+}
+
+// Robust location and scale
+#[no_mangle]
+pub unsafe extern "C" fn trimmed_mean_f64(ptr: *const f64, len: usize, proportion: f64) -> f64 {
+    stat_core::trimmed_mean(slice_from(ptr, len), proportion)
+}
+#[no_mangle]
+pub unsafe extern "C" fn trimmed_variance_f64(ptr: *const f64, len: usize, proportion: f64) -> f64 {
+    stat_core::trimmed_variance(slice_from(ptr, len), proportion)
+}
+#[no_mangle]
+pub unsafe extern "C" fn winsorized_mean_f64(ptr: *const f64, len: usize, proportion: f64) -> f64 {
+    stat_core::winsorized_mean(slice_from(ptr, len), proportion)
+}
+#[no_mangle]
+pub unsafe extern "C" fn winsorized_variance_f64(
+    ptr: *const f64,
+    len: usize,
+    proportion: f64,
+) -> f64 {
+    stat_core::winsorized_variance(slice_from(ptr, len), proportion)
+}
+#[no_mangle]
+pub unsafe extern "C" fn mad_f64(ptr: *const f64, len: usize, constant: f64) -> f64 {
+    stat_core::mad_with_constant(slice_from(ptr, len), constant)
+}
+#[no_mangle]
+pub unsafe extern "C" fn qn_scale_f64(ptr: *const f64, len: usize) -> f64 {
+    stat_core::qn_scale(slice_from(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn sn_scale_f64(ptr: *const f64, len: usize) -> f64 {
+    stat_core::sn_scale(slice_from(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn huber_location_f64(ptr: *const f64, len: usize, k: f64) -> f64 {
+    stat_core::huber_location(slice_from(ptr, len), k)
+}
+#[no_mangle]
+pub unsafe extern "C" fn biweight_location_f64(ptr: *const f64, len: usize, c: f64) -> f64 {
+    stat_core::biweight_location(slice_from(ptr, len), c)
+}
+#[no_mangle]
+pub unsafe extern "C" fn hodges_lehmann_f64(ptr: *const f64, len: usize) -> f64 {
+    stat_core::hodges_lehmann(slice_from(ptr, len))
+}
+#[no_mangle]
+pub unsafe extern "C" fn hodges_lehmann_shift_f64(
+    xp: *const f64,
+    xl: usize,
+    yp: *const f64,
+    yl: usize,
+) -> f64 {
+    stat_core::hodges_lehmann_shift(slice_from(xp, xl), slice_from(yp, yl))
+}
@@ -5,6 +5,8 @@ export * from './core.js';
 export function get_memory() { return wasmExports().memory; }
 export function alloc_f64(len) { return alloc(len * 8); }
 export function free_f64(ptr, len) { free(ptr, len * 8); }
+export function alloc_f32(len) { return alloc(len * 4); }
+export function free_f32(ptr, len) { free(ptr, len * 4); }
 
 // Scalar functions
 export function sum_f64(ptr, len) { return wasmExports().sum_f64(ptr, len); }
@@ -62,3 +64,59 @@ export function histogram_f64(ptr, len, binCount) {
   return { ptr: outPtr, len: Number(written) };
 }
 
+// f32 input (Float32Array views); results are f64
+export function sum_f32(ptr, len) { return wasmExports().sum_f32(ptr, len); }
+export function mean_f32(ptr, len) { return wasmExports().mean_f32(ptr, len); }
+export function variance_f32(ptr, len) { return wasmExports().variance_f32(ptr, len); }
+export function sample_variance_f32(ptr, len) { return wasmExports().sample_variance_f32(ptr, len); }
+export function stdev_f32(ptr, len) { return wasmExports().stdev_f32(ptr, len); }
+export function sample_stdev_f32(ptr, len) { return wasmExports().sample_stdev_f32(ptr, len); }
+export function min_f32(ptr, len) { return wasmExports().min_f32(ptr, len); }
+export function max_f32(ptr, len) { return wasmExports().max_f32(ptr, len); }
+export function range_f32(ptr, len) { return wasmExports().range_f32(ptr, len); }
+export function skewness_f32(ptr, len) { return wasmExports().skewness_f32(ptr, len); }
+export function kurtosis_f32(ptr, len) { return wasmExports().kurtosis_f32(ptr, len); }
+export function histogram_f32(ptr, len, binCount) {
+  const outPtr = alloc(binCount * 8);
+  const written = wasmExports().histogram_f32(ptr, len, binCount, outPtr);
+  return { ptr: outPtr, len: Number(written) };
+}
+
+
+// Streaming moments (handle-based)
+export function running_moments_new() { return wasmExports().running_moments_new(); }
+export function running_moments_free(h) { wasmExports().running_moments_free(h); }
+export function running_moments_reset(h) { wasmExports().running_moments_reset(h); }
+export function running_moments_push(h, x) { wasmExports().running_moments_push(h, x); }
+export function running_moments_push_slice(h, ptr, len) { wasmExports().running_moments_push_slice(h, ptr, len); }
+export function running_moments_merge(h, other) { wasmExports().running_moments_merge(h, other); }
+export function running_moments_count(h) { return wasmExports().running_moments_count(h); }
+export function running_moments_mean(h) { return wasmExports().running_moments_mean(h); }
+export function running_moments_variance(h) { return wasmExports().running_moments_variance(h); }
+export function running_moments_sample_variance(h) { return wasmExports().running_moments_sample_variance(h); }
+export function running_moments_stdev(h) { return wasmExports().running_moments_stdev(h); }
+export function running_moments_sample_stdev(h) { return wasmExports().running_moments_sample_stdev(h); }
+export function running_moments_skewness(h) { return wasmExports().running_moments_skewness(h); }
+export function running_moments_kurtosis(h) { return wasmExports().running_moments_kurtosis(h); }
+
+// Weighted statistics (reliability: false = frequency weights, true = reliability weights)
+export function weighted_mean_f64(dp, wp, len) { return wasmExports().weighted_mean_f64(dp, wp, len); }
+export function weighted_variance_f64(dp, wp, len) { return wasmExports().weighted_variance_f64(dp, wp, len); }
+export function weighted_sample_variance_f64(dp, wp, len, reliability) { return wasmExports().weighted_sample_variance_f64(dp, wp, len, reliability); }
+export function weighted_stdev_f64(dp, wp, len) { return wasmExports().weighted_stdev_f64(dp, wp, len); }
+export function weighted_sample_stdev_f64(dp, wp, len, reliability) { return wasmExports().weighted_sample_stdev_f64(dp, wp, len, reliability); }
+export function weighted_skewness_f64(dp, wp, len) { return wasmExports().weighted_skewness_f64(dp, wp, len); }
+export function weighted_kurtosis_f64(dp, wp, len) { return wasmExports().weighted_kurtosis_f64(dp, wp, len); }
+
+// Robust location and scale (proportion: fraction cut from each tail)
+export function trimmed_mean_f64(ptr, len, proportion = 0.1) { return wasmExports().trimmed_mean_f64(ptr, len, proportion); }
+export function trimmed_variance_f64(ptr, len, proportion = 0.1) { return wasmExports().trimmed_variance_f64(ptr, len, proportion); }
+export function winsorized_mean_f64(ptr, len, proportion = 0.1) { return wasmExports().winsorized_mean_f64(ptr, len, proportion); }
+export function winsorized_variance_f64(ptr, len, proportion = 0.1) { return wasmExports().winsorized_variance_f64(ptr, len, proportion); }
+export function mad_f64(ptr, len, constant = 1.482602218505602) { return wasmExports().mad_f64(ptr, len, constant); }
+export function qn_scale_f64(ptr, len) { return wasmExports().qn_scale_f64(ptr, len); }
+export function sn_scale_f64(ptr, len) { return wasmExports().sn_scale_f64(ptr, len); }
+export function huber_location_f64(ptr, len, k = 1.345) { return wasmExports().huber_location_f64(ptr, len, k); }
+export function biweight_location_f64(ptr, len, c = 4.685) { return wasmExports().biweight_location_f64(ptr, len, c); }
+export function hodges_lehmann_f64(ptr, len) { return wasmExports().hodges_lehmann_f64(ptr, len); }
+export function hodges_lehmann_shift_f64(xPtr, xLen, yPtr, yLen) { return wasmExports().hodges_lehmann_shift_f64(xPtr, xLen, yPtr, yLen); }
@@ -99,6 +99,333 @@ pub unsafe extern "C" fn ztest_f64(
     3
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn ttest_alt_f64(
+    dp: *const f64,
+    l: usize,
+    mu0: f64,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ttest_with_alternative(slice_from(dp, l), mu0, alternative_from(alt));
+    let out = slice_from_mut(o, 3);
+    out[0] = res.statistic;
+    out[1] = res.p_value;
+    out[2] = res.df.unwrap_or(f64::NAN);
+    3
+}
+#[no_mangle]
+pub unsafe extern "C" fn ztest_alt_f64(
+    dp: *const f64,
+    l: usize,
+    mu0: f64,
+    s: f64,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ztest_with_alternative(slice_from(dp, l), mu0, s, alternative_from(alt));
+    let out = slice_from_mut(o, 3);
+    out[0] = res.statistic;
+    out[1] = res.p_value;
+    out[2] = res.df.unwrap_or(f64::NAN);
+    3
+}
+#[no_mangle]
+pub unsafe extern "C" fn normalci_alt_f64(a: f64, m: f64, se: f64, alt: i32, o: *mut f64) -> isize {
+    let ci = stat_core::normalci_with_alternative(a, m, se, alternative_from(alt));
+    let out = slice_from_mut(o, 2);
+    out[0] = ci[0];
+    out[1] = ci[1];
+    2
+}
+#[no_mangle]
+pub unsafe extern "C" fn tci_alt_f64(
+    a: f64,
+    m: f64,
+    s: f64,
+    n: f64,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let ci = stat_core::tci_with_alternative(a, m, s, n, alternative_from(alt));
+    let out = slice_from_mut(o, 2);
+    out[0] = ci[0];
+    out[1] = ci[1];
+    2
+}
+
+/// Map an alternative-hypothesis code from JS: 0 = two-sided, 1 = less, 2 = greater.
+fn alternative_from(code: i32) -> stat_core::Alternative {
+    match code {
+        1 => stat_core::Alternative::Less,
+        2 => stat_core::Alternative::Greater,
+        _ => stat_core::Alternative::TwoSided,
+    }
+}
+
+/// Write `[statistic, p_value, df, ci_lower, ci_upper]` for a test result.
+fn write_test_result(res: &stat_core::TestResult, o: *mut f64) -> isize {
+    let out = slice_from_mut(o, 5);
+    let ci = res.ci.unwrap_or([f64::NAN, f64::NAN]);
+    out[0] = res.statistic;
+    out[1] = res.p_value;
+    out[2] = res.df.unwrap_or(f64::NAN);
+    out[3] = ci[0];
+    out[4] = ci[1];
+    5
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ttest_ind_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alt: i32,
+    alpha: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ttest_ind(
+        slice_from(ap, al),
+        slice_from(bp, bl),
+        alternative_from(alt),
+        alpha,
+    );
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn ttest_welch_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alt: i32,
+    alpha: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ttest_welch(
+        slice_from(ap, al),
+        slice_from(bp, bl),
+        alternative_from(alt),
+        alpha,
+    );
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn ttest_paired_f64(
+    ap: *const f64,
+    bp: *const f64,
+    l: usize,
+    alt: i32,
+    alpha: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ttest_paired(
+        slice_from(ap, l),
+        slice_from(bp, l),
+        alternative_from(alt),
+        alpha,
+    );
+    write_test_result(&res, o)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mann_whitney_u_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::mann_whitney_u(
+        slice_from(ap, al),
+        slice_from(bp, bl),
+        alternative_from(alt),
+    );
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn wilcoxon_signed_rank_f64(
+    dp: *const f64,
+    l: usize,
+    mu0: f64,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::wilcoxon_signed_rank(slice_from(dp, l), mu0, alternative_from(alt));
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn wilcoxon_signed_rank_paired_f64(
+    ap: *const f64,
+    bp: *const f64,
+    l: usize,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::wilcoxon_signed_rank_paired(
+        slice_from(ap, l),
+        slice_from(bp, l),
+        alternative_from(alt),
+    );
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn kruskal_wallis_flat(
+    dp: *const f64,
+    lp: *const f64,
+    ng: usize,
+    o: *mut f64,
+) -> isize {
+    let lens = slice_from(lp, ng);
+    let total: usize = lens.iter().map(|&l| l as usize).sum();
+    let data = slice_from(dp, total);
+    let mut groups = Vec::with_capacity(ng);
+    let mut off = 0;
+    for &l in lens {
+        let l = l as usize;
+        groups.push(&data[off..off + l]);
+        off += l;
+    }
+    let res = stat_core::kruskal_wallis(&groups);
+    write_test_result(&res, o)
+}
+
+/// Build a continuous distribution from a JS family code and up to three parameters.
+///
+/// Codes follow the constructor argument order of each family: 0 normal(mean, sd),
+/// 1 gamma(shape, rate), 2 beta(alpha, beta), 3 student t(location, scale, dof),
+/// 4 chi-squared(dof), 5 F(df1, df2), 6 exponential(rate), 7 uniform(min, max),
+/// 8 cauchy(location, scale), 9 laplace(location, scale), 10 lognormal(location, scale),
+/// 11 weibull(shape, scale), 12 pareto(scale, shape), 13 triangular(min, max, mode),
+/// 14 inverse gamma(shape, rate), 15 gumbel(location, scale), 16 GEV(location, scale, shape),
+/// 17 logistic(location, scale), 18 log-logistic(scale, shape), 19 rayleigh(scale),
+/// 20 erlang(shape, rate), 21 von Mises(mu, kappa), 22 skew-normal(location, scale, shape).
+/// Unknown codes and invalid parameters give `None`.
+fn continuous_distribution_from(
+    family: i32,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+) -> Option<Box<dyn stat_core::ContinuousDistribution>> {
+    use stat_core::*;
+    fn boxed<D: ContinuousDistribution + 'static>(
+        d: Result<D, DistributionError>,
+    ) -> Option<Box<dyn ContinuousDistribution>> {
+        d.ok()
+            .map(|d| Box::new(d) as Box<dyn ContinuousDistribution>)
+    }
+    match family {
+        0 => boxed(Normal::new(p1, p2)),
+        1 => boxed(Gamma::new(p1, p2)),
+        2 => boxed(Beta::new(p1, p2)),
+        3 => boxed(StudentsT::new(p1, p2, p3)),
+        4 => boxed(ChiSquared::new(p1)),
+        5 => boxed(FisherSnedecor::new(p1, p2)),
+        6 => boxed(Exp::new(p1)),
+        7 => boxed(Uniform::new(p1, p2)),
+        8 => boxed(Cauchy::new(p1, p2)),
+        9 => boxed(Laplace::new(p1, p2)),
+        10 => boxed(LogNormal::new(p1, p2)),
+        11 => boxed(Weibull::new(p1, p2)),
+        12 => boxed(Pareto::new(p1, p2)),
+        13 => boxed(Triangular::new(p1, p2, p3)),
+        14 => boxed(InverseGamma::new(p1, p2)),
+        15 => boxed(Gumbel::new(p1, p2)),
+        16 => boxed(Gev::new(p1, p2, p3)),
+        17 => boxed(Logistic::new(p1, p2)),
+        18 => boxed(LogLogistic::new(p1, p2)),
+        19 => boxed(Rayleigh::new(p1)),
+        20 => boxed(Erlang::new(p1, p2)),
+        21 => boxed(VonMises::new(p1, p2)),
+        22 => boxed(SkewNormal::new(p1, p2, p3)),
+        _ => None,
+    }
+}
+
+/// Write an all-NaN test result (used when the distribution parameters are invalid).
+fn write_nan_result(o: *mut f64) -> isize {
+    slice_from_mut(o, 5).fill(f64::NAN);
+    5
+}
+
+/// One-sample Kolmogorov-Smirnov test against the family selected by
+/// [`continuous_distribution_from`].
+#[no_mangle]
+pub unsafe extern "C" fn ks_test_f64(
+    dp: *const f64,
+    l: usize,
+    family: i32,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    match continuous_distribution_from(family, p1, p2, p3) {
+        Some(dist) => {
+            let res = stat_core::ks_test(slice_from(dp, l), dist.as_ref(), alternative_from(alt));
+            write_test_result(&res, o)
+        }
+        None => write_nan_result(o),
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn ks_test_2samp_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alt: i32,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::ks_test_2samp(
+        slice_from(ap, al),
+        slice_from(bp, bl),
+        alternative_from(alt),
+    );
+    write_test_result(&res, o)
+}
+/// Anderson-Darling test against the family selected by [`continuous_distribution_from`].
+#[no_mangle]
+pub unsafe extern "C" fn anderson_darling_f64(
+    dp: *const f64,
+    l: usize,
+    family: i32,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+    o: *mut f64,
+) -> isize {
+    match continuous_distribution_from(family, p1, p2, p3) {
+        Some(dist) => {
+            let res = stat_core::anderson_darling(slice_from(dp, l), dist.as_ref());
+            write_test_result(&res, o)
+        }
+        None => write_nan_result(o),
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn anderson_darling_normal_f64(
+    dp: *const f64,
+    l: usize,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::anderson_darling_normal(slice_from(dp, l));
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn shapiro_wilk_f64(dp: *const f64, l: usize, o: *mut f64) -> isize {
+    let res = stat_core::shapiro_wilk(slice_from(dp, l));
+    write_test_result(&res, o)
+}
+#[no_mangle]
+pub unsafe extern "C" fn jarque_bera_f64(dp: *const f64, l: usize, o: *mut f64) -> isize {
+    let res = stat_core::jarque_bera(slice_from(dp, l));
+    write_test_result(&res, o)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn regress_f64(
     xp: *const f64,
@@ -338,6 +665,113 @@ pub unsafe extern "C" fn regress_simd_residuals_inplace_f32(
     3
 }
 
+/// Simple regression with inference statistics. Writes
+/// `[slope, intercept, r2, slope_se, intercept_se, slope_t, intercept_t, slope_p, intercept_p,
+/// residual_se, df, f, f_p]` to `o`.
+#[no_mangle]
+pub unsafe extern "C" fn regress_stats_f64(
+    xp: *const f64,
+    yp: *const f64,
+    len: usize,
+    o: *mut f64,
+) -> isize {
+    let r = stat_core::regress_stats(slice_from(xp, len), slice_from(yp, len));
+    let out = slice_from_mut(o, 13);
+    out[0] = r.slope;
+    out[1] = r.intercept;
+    out[2] = r.r_squared;
+    out[3] = r.slope_se;
+    out[4] = r.intercept_se;
+    out[5] = r.slope_t;
+    out[6] = r.intercept_t;
+    out[7] = r.slope_p;
+    out[8] = r.intercept_p;
+    out[9] = r.residual_se;
+    out[10] = r.df;
+    out[11] = r.f_statistic;
+    out[12] = r.f_p_value;
+    13
+}
+
+/// Fit a simple regression and write `[fit, lower, upper]` for each of the `nl` new x values.
+/// `kind` 0 gives prediction intervals, 1 gives confidence intervals for the mean response.
+#[no_mangle]
+pub unsafe extern "C" fn regress_predict_interval_f64(
+    xp: *const f64,
+    yp: *const f64,
+    len: usize,
+    np: *const f64,
+    nl: usize,
+    level: f64,
+    kind: i32,
+    o: *mut f64,
+) -> isize {
+    let r = stat_core::regress_stats(slice_from(xp, len), slice_from(yp, len));
+    let out = slice_from_mut(o, nl * 3);
+    for (i, &x_new) in slice_from(np, nl).iter().enumerate() {
+        let iv = if kind == 1 {
+            r.confidence_interval(x_new, level)
+        } else {
+            r.predict_interval(x_new, level)
+        };
+        out[i * 3] = r.predict(x_new);
+        out[i * 3 + 1] = iv[0];
+        out[i * 3 + 2] = iv[1];
+    }
+    (nl * 3) as isize
+}
+
+/// Multiple linear regression on a row-major `rows x cols` design matrix.
+///
+/// Writes six values per coefficient to `co` (`[coef, se, t, p, ci_lo, ci_hi]`), the model
+/// summary `[r2, adj_r2, f, f_p, df_model, df_resid, sigma, loglik, aic, bic]` to `so` and
+/// `rows` residuals to `ro`. Returns the number of coefficients.
+#[no_mangle]
+pub unsafe extern "C" fn ols_f64(
+    xp: *const f64,
+    rows: usize,
+    cols: usize,
+    yp: *const f64,
+    intercept: bool,
+    conf: f64,
+    co: *mut f64,
+    so: *mut f64,
+    ro: *mut f64,
+) -> isize {
+    let res = stat_core::ols_with_confidence(
+        slice_from(xp, rows * cols),
+        cols,
+        slice_from(yp, rows),
+        intercept,
+        conf,
+    );
+    let p = res.coefficients.len();
+    let c = slice_from_mut(co, p * 6);
+    for i in 0..p {
+        let b = i * 6;
+        c[b] = res.coefficients[i];
+        c[b + 1] = res.std_errors[i];
+        c[b + 2] = res.t_values[i];
+        c[b + 3] = res.p_values[i];
+        c[b + 4] = res.ci_lower[i];
+        c[b + 5] = res.ci_upper[i];
+    }
+    let s = slice_from_mut(so, 10);
+    s[0] = res.r_squared;
+    s[1] = res.adj_r_squared;
+    s[2] = res.f_statistic;
+    s[3] = res.f_p_value;
+    s[4] = res.df_model;
+    s[5] = res.df_residual;
+    s[6] = res.sigma;
+    s[7] = res.log_likelihood;
+    s[8] = res.aic;
+    s[9] = res.bic;
+    let r = slice_from_mut(ro, res.residuals.len());
+    r.copy_from_slice(&res.residuals);
+    p as isize
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn anova_f_score_flat(dp: *const f64, lp: *const f64, ng: usize) -> f64 {
     let lens = slice_from(lp, ng);
@@ -383,19 +817,122 @@ pub unsafe extern "C" fn chi_square_test(
     cp1: *const i32,
     cp2: *const i32,
     l: usize,
-    c1: usize,
-    c2: usize,
+    _c1: usize,
+    _c2: usize,
     out: *mut f64,
 ) -> isize {
-    let s1: Vec<String> = unsafe { std::slice::from_raw_parts(cp1, l) }
-        .iter()
-        .map(|x| x.to_string())
-        .collect();
-    let s2: Vec<String> = unsafe { std::slice::from_raw_parts(cp2, l) }
+    // The cardinality hints are unused now that codes feed the contingency table directly.
+    let codes = |p: *const i32| -> Vec<u32> {
+        unsafe { std::slice::from_raw_parts(p, l) }
+            .iter()
+            .map(|&x| x as u32)
+            .collect()
+    };
+    let res = stat_core::chi_square_test_codes(
+        &codes(cp1),
+        &codes(cp2),
+        stat_core::ChiSquareMethod::Pearson,
+    );
+    let o = slice_from_mut(out, 3);
+    o[0] = res.statistic;
+    o[1] = res.p_value;
+    o[2] = res.df as f64;
+    3
+}
+
+/// Fisher's exact test on the 2x2 table `[a, b; c, d]`. Writes
+/// `[odds_ratio, p_value, ci_lower, ci_upper]`.
+#[no_mangle]
+pub unsafe extern "C" fn fisher_exact_f64(
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+    alt: i32,
+    alpha: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::fisher_exact(
+        [a as u64, b as u64, c as u64, d as u64],
+        alternative_from(alt),
+        alpha,
+    );
+    let out = slice_from_mut(o, 4);
+    out[0] = res.odds_ratio;
+    out[1] = res.p_value;
+    out[2] = res.ci[0];
+    out[3] = res.ci[1];
+    4
+}
+
+/// Read a row-major `rows x cols` table of `u32` counts.
+fn count_table(tp: *const u32, rows: usize, cols: usize) -> Vec<u64> {
+    unsafe { std::slice::from_raw_parts(tp, rows * cols) }
         .iter()
-        .map(|x| x.to_string())
-        .collect();
-    let res = stat_core::chi_square_test_with_cardinality(&s1, &s2, Some(c1), Some(c2));
+        .map(|&x| x as u64)
+        .collect()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fisher_exact_table_u32(
+    tp: *const u32,
+    rows: usize,
+    cols: usize,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::fisher_exact_table(&count_table(tp, rows, cols), cols);
+    write_test_result(&res, o)
+}
+
+/// Monte Carlo Fisher test; `seed` is a non-negative integer below 2^53.
+#[no_mangle]
+pub unsafe extern "C" fn fisher_exact_table_simulated_u32(
+    tp: *const u32,
+    rows: usize,
+    cols: usize,
+    replicates: usize,
+    seed: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::fisher_exact_table_simulated(
+        &count_table(tp, rows, cols),
+        cols,
+        replicates,
+        seed as u64,
+    );
+    write_test_result(&res, o)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn binomial_test_f64(
+    successes: f64,
+    trials: f64,
+    p: f64,
+    alt: i32,
+    alpha: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::binomial_test(
+        successes as u64,
+        trials as u64,
+        p,
+        alternative_from(alt),
+        alpha,
+    );
+    write_test_result(&res, o)
+}
+
+/// Map a chi-square statistic code from JS: 0 = Pearson, 1 = Yates, 2 = G-test.
+fn chi_square_method_from(code: i32) -> stat_core::ChiSquareMethod {
+    match code {
+        1 => stat_core::ChiSquareMethod::Yates,
+        2 => stat_core::ChiSquareMethod::GTest,
+        _ => stat_core::ChiSquareMethod::Pearson,
+    }
+}
+
+/// Write `[statistic, p_value, df]` for a chi-square result.
+fn write_chi_square_result(res: &stat_core::ChiSquareResult, out: *mut f64) -> isize {
     let o = slice_from_mut(out, 3);
     o[0] = res.statistic;
     o[1] = res.p_value;
@@ -403,6 +940,464 @@ pub unsafe extern "C" fn chi_square_test(
     3
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn chi_square_test_codes_u32(
+    cp1: *const u32,
+    cp2: *const u32,
+    l: usize,
+    method: i32,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::chi_square_test_codes(
+        unsafe { std::slice::from_raw_parts(cp1, l) },
+        unsafe { std::slice::from_raw_parts(cp2, l) },
+        chi_square_method_from(method),
+    );
+    write_chi_square_result(&res, out)
+}
+
+/// Chi-square test on a row-major `rows x cols` contingency table.
+#[no_mangle]
+pub unsafe extern "C" fn chi_square_test_table_f64(
+    tp: *const f64,
+    rows: usize,
+    cols: usize,
+    method: i32,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::chi_square_test_table(
+        slice_from(tp, rows * cols),
+        cols,
+        chi_square_method_from(method),
+    );
+    write_chi_square_result(&res, out)
+}
+
+/// Chi-square goodness of fit; `el = 0` tests equal frequencies.
+#[no_mangle]
+pub unsafe extern "C" fn chi_square_goodness_of_fit_f64(
+    op: *const f64,
+    ol: usize,
+    ep: *const f64,
+    el: usize,
+    method: i32,
+    out: *mut f64,
+) -> isize {
+    let expected = if el == 0 { &[][..] } else { slice_from(ep, el) };
+    let res = stat_core::chi_square_goodness_of_fit(
+        slice_from(op, ol),
+        expected,
+        chi_square_method_from(method),
+    );
+    write_chi_square_result(&res, out)
+}
+
+/// Write `[estimate, ci_lower, ci_upper]` for an effect size.
+fn write_effect_size(res: &stat_core::EffectSize, out: *mut f64) -> isize {
+    let o = slice_from_mut(out, 3);
+    o[0] = res.estimate;
+    o[1] = res.ci[0];
+    o[2] = res.ci[1];
+    3
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cohens_d_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alpha: f64,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::cohens_d(slice_from(ap, al), slice_from(bp, bl), alpha);
+    write_effect_size(&res, out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn hedges_g_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    alpha: f64,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::hedges_g(slice_from(ap, al), slice_from(bp, bl), alpha);
+    write_effect_size(&res, out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cohens_d_one_sample_f64(
+    dp: *const f64,
+    l: usize,
+    mu0: f64,
+    alpha: f64,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::cohens_d_one_sample(slice_from(dp, l), mu0, alpha);
+    write_effect_size(&res, out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cohens_d_paired_f64(
+    ap: *const f64,
+    bp: *const f64,
+    l: usize,
+    alpha: f64,
+    out: *mut f64,
+) -> isize {
+    let res = stat_core::cohens_d_paired(slice_from(ap, l), slice_from(bp, l), alpha);
+    write_effect_size(&res, out)
+}
+
+/// Writes `[eta_squared, partial_eta_squared, omega_squared]` for groups laid out as in
+/// [`anova_flat`].
+#[no_mangle]
+pub unsafe extern "C" fn anova_effect_size_flat(
+    dp: *const f64,
+    lp: *const f64,
+    ng: usize,
+    out: *mut f64,
+) -> isize {
+    let lens = slice_from(lp, ng);
+    let total: usize = lens.iter().map(|&l| l as usize).sum();
+    let data = slice_from(dp, total);
+    let mut groups = Vec::with_capacity(ng);
+    let mut off = 0;
+    for &l in lens {
+        let l = l as usize;
+        groups.push(&data[off..off + l]);
+        off += l;
+    }
+    let res = stat_core::anova_effect_size(&stat_core::anova(&groups));
+    let o = slice_from_mut(out, 3);
+    o[0] = res.eta_squared;
+    o[1] = res.partial_eta_squared;
+    o[2] = res.omega_squared;
+    3
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn partial_eta_squared_f64(f: f64, df_effect: f64, df_error: f64) -> f64 {
+    stat_core::partial_eta_squared(f, df_effect, df_error)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cramers_v_table_f64(tp: *const f64, rows: usize, cols: usize) -> f64 {
+    stat_core::cramers_v_table(slice_from(tp, rows * cols), cols)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn phi_coefficient_f64(a: f64, b: f64, c: f64, d: f64) -> f64 {
+    stat_core::phi_coefficient([a, b, c, d])
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rank_biserial_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+) -> f64 {
+    stat_core::rank_biserial(slice_from(ap, al), slice_from(bp, bl))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rank_biserial_one_sample_f64(dp: *const f64, l: usize, mu0: f64) -> f64 {
+    stat_core::rank_biserial_one_sample(slice_from(dp, l), mu0)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rank_biserial_paired_f64(ap: *const f64, bp: *const f64, l: usize) -> f64 {
+    stat_core::rank_biserial_paired(slice_from(ap, l), slice_from(bp, l))
+}
+
+/// Map a t-test design code from JS: 0 = one-sample, 1 = two-sample, 2 = paired.
+fn t_test_design_from(code: i32) -> stat_core::TTestDesign {
+    match code {
+        1 => stat_core::TTestDesign::TwoSample,
+        2 => stat_core::TTestDesign::Paired,
+        _ => stat_core::TTestDesign::OneSample,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn power_z_test_f64(effect: f64, n: f64, alpha: f64, alt: i32) -> f64 {
+    stat_core::power_z_test(effect, n, alpha, alternative_from(alt))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sample_size_z_test_f64(
+    effect: f64,
+    power: f64,
+    alpha: f64,
+    alt: i32,
+) -> f64 {
+    stat_core::sample_size_z_test(effect, power, alpha, alternative_from(alt))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn power_t_test_f64(
+    effect: f64,
+    n: f64,
+    alpha: f64,
+    design: i32,
+    alt: i32,
+) -> f64 {
+    stat_core::power_t_test(
+        effect,
+        n,
+        alpha,
+        t_test_design_from(design),
+        alternative_from(alt),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sample_size_t_test_f64(
+    effect: f64,
+    power: f64,
+    alpha: f64,
+    design: i32,
+    alt: i32,
+) -> f64 {
+    stat_core::sample_size_t_test(
+        effect,
+        power,
+        alpha,
+        t_test_design_from(design),
+        alternative_from(alt),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn power_anova_f64(effect: f64, groups: usize, n: f64, alpha: f64) -> f64 {
+    stat_core::power_anova(effect, groups, n, alpha)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sample_size_anova_f64(
+    effect: f64,
+    groups: usize,
+    power: f64,
+    alpha: f64,
+) -> f64 {
+    stat_core::sample_size_anova(effect, groups, power, alpha)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn power_chi_square_f64(effect: f64, n: f64, df: f64, alpha: f64) -> f64 {
+    stat_core::power_chi_square(effect, n, df, alpha)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sample_size_chi_square_f64(
+    effect: f64,
+    df: f64,
+    power: f64,
+    alpha: f64,
+) -> f64 {
+    stat_core::sample_size_chi_square(effect, df, power, alpha)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn power_two_proportions_f64(
+    p1: f64,
+    p2: f64,
+    n: f64,
+    alpha: f64,
+    alt: i32,
+) -> f64 {
+    stat_core::power_two_proportions(p1, p2, n, alpha, alternative_from(alt))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sample_size_two_proportions_f64(
+    p1: f64,
+    p2: f64,
+    power: f64,
+    alpha: f64,
+    alt: i32,
+) -> f64 {
+    stat_core::sample_size_two_proportions(p1, p2, power, alpha, alternative_from(alt))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cohens_h_f64(p1: f64, p2: f64) -> f64 {
+    stat_core::cohens_h(p1, p2)
+}
+
+/// Map a p-value adjustment code from JS: 0 = Bonferroni, 1 = Holm, 2 = Hochberg,
+/// 3 = Hommel, 4 = Benjamini-Hochberg, 5 = Benjamini-Yekutieli.
+fn p_adjust_method_from(code: i32) -> stat_core::PAdjustMethod {
+    match code {
+        0 => stat_core::PAdjustMethod::Bonferroni,
+        2 => stat_core::PAdjustMethod::Hochberg,
+        3 => stat_core::PAdjustMethod::Hommel,
+        4 => stat_core::PAdjustMethod::BenjaminiHochberg,
+        5 => stat_core::PAdjustMethod::BenjaminiYekutieli,
+        _ => stat_core::PAdjustMethod::Holm,
+    }
+}
+
+/// Write the `l` adjusted p-values to `o`; returns the count written.
+#[no_mangle]
+pub unsafe extern "C" fn p_adjust_f64(pp: *const f64, l: usize, method: i32, o: *mut f64) -> isize {
+    let adjusted = stat_core::p_adjust(slice_from(pp, l), p_adjust_method_from(method));
+    slice_from_mut(o, l).copy_from_slice(&adjusted);
+    l as isize
+}
+
+/// Write the `l` Storey q-values to `o`; returns the estimated `pi0`.
+#[no_mangle]
+pub unsafe extern "C" fn q_values_f64(pp: *const f64, l: usize, lambda: f64, o: *mut f64) -> f64 {
+    let res = stat_core::q_values(slice_from(pp, l), lambda);
+    slice_from_mut(o, l).copy_from_slice(&res.q_values);
+    res.pi0
+}
+
+/// Map a bootstrap interval code from JS: 0 = percentile, 1 = basic, 2 = BCa.
+fn bootstrap_method_from(code: i32) -> stat_core::BootstrapMethod {
+    match code {
+        1 => stat_core::BootstrapMethod::Basic,
+        2 => stat_core::BootstrapMethod::Bca,
+        _ => stat_core::BootstrapMethod::Percentile,
+    }
+}
+
+/// Map a one-sample statistic code from JS: 0 = mean, 1 = median, 2 = sample standard
+/// deviation, 3 = IQR, 4 = coefficient of variation.
+fn sample_statistic_from(code: i32) -> fn(&[f64]) -> f64 {
+    match code {
+        1 => stat_core::median,
+        2 => stat_core::sample_stdev,
+        3 => stat_core::iqr,
+        4 => stat_core::coeffvar,
+        _ => stat_core::mean,
+    }
+}
+
+/// Map a paired statistic code from JS: 0 = Pearson, 1 = Spearman correlation.
+fn paired_statistic_from(code: i32) -> fn(&[f64], &[f64]) -> f64 {
+    match code {
+        1 => stat_core::spearmancoeff,
+        _ => stat_core::corrcoeff,
+    }
+}
+
+/// Map a two-sample statistic code from JS: 0 = difference in means, 1 = difference in
+/// medians.
+fn two_sample_statistic_from(code: i32) -> fn(&[f64], &[f64]) -> f64 {
+    match code {
+        1 => |a, b| stat_core::median(a) - stat_core::median(b),
+        _ => |a, b| stat_core::mean(a) - stat_core::mean(b),
+    }
+}
+
+/// Write `[estimate, std_error, bias, ci_lower, ci_upper]` for a bootstrap result.
+fn write_bootstrap_result(res: &stat_core::BootstrapResult, o: *mut f64) -> isize {
+    let out = slice_from_mut(o, 5);
+    out[0] = res.estimate;
+    out[1] = res.std_error;
+    out[2] = res.bias;
+    out[3] = res.ci[0];
+    out[4] = res.ci[1];
+    5
+}
+
+/// Bootstrap interval for a one-sample statistic; `seed` is a non-negative integer below 2^53.
+#[no_mangle]
+pub unsafe extern "C" fn bootstrap_ci_f64(
+    dp: *const f64,
+    l: usize,
+    statistic: i32,
+    method: i32,
+    replicates: usize,
+    alpha: f64,
+    seed: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::bootstrap_ci(
+        slice_from(dp, l),
+        sample_statistic_from(statistic),
+        bootstrap_method_from(method),
+        replicates,
+        alpha,
+        seed as u64,
+    );
+    write_bootstrap_result(&res, o)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bootstrap_ci_paired_f64(
+    xp: *const f64,
+    yp: *const f64,
+    l: usize,
+    statistic: i32,
+    method: i32,
+    replicates: usize,
+    alpha: f64,
+    seed: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::bootstrap_ci_paired(
+        slice_from(xp, l),
+        slice_from(yp, l),
+        paired_statistic_from(statistic),
+        bootstrap_method_from(method),
+        replicates,
+        alpha,
+        seed as u64,
+    );
+    write_bootstrap_result(&res, o)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn permutation_test_f64(
+    ap: *const f64,
+    al: usize,
+    bp: *const f64,
+    bl: usize,
+    statistic: i32,
+    alt: i32,
+    permutations: usize,
+    seed: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::permutation_test(
+        slice_from(ap, al),
+        slice_from(bp, bl),
+        two_sample_statistic_from(statistic),
+        alternative_from(alt),
+        permutations,
+        seed as u64,
+    );
+    write_test_result(&res, o)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn permutation_test_paired_f64(
+    xp: *const f64,
+    yp: *const f64,
+    l: usize,
+    statistic: i32,
+    alt: i32,
+    permutations: usize,
+    seed: f64,
+    o: *mut f64,
+) -> isize {
+    let res = stat_core::permutation_test_paired(
+        slice_from(xp, l),
+        slice_from(yp, l),
+        paired_statistic_from(statistic),
+        alternative_from(alt),
+        permutations,
+        seed as u64,
+    );
+    write_test_result(&res, o)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn anova_f_score_categorical(
     gp: *const i32,
@@ -441,12 +1436,23 @@ pub unsafe extern "C" fn tukey_hsd_categorical(
     vp: *const f64,
     l: usize,
     out: *mut f64,
+) -> isize {
+    tukey_hsd_categorical_conf(gp, vp, l, 0.95, out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tukey_hsd_categorical_conf(
+    gp: *const i32,
+    vp: *const f64,
+    l: usize,
+    conf: f64,
+    out: *mut f64,
 ) -> isize {
     let s: Vec<String> = unsafe { std::slice::from_raw_parts(gp, l) }
         .iter()
         .map(|x| x.to_string())
         .collect();
-    let res = stat_core::tukey_hsd_categorical(&s, slice_from(vp, l));
+    let res = stat_core::tukey_hsd_categorical_with_confidence(&s, slice_from(vp, l), conf);
     let nc = res.comparisons.len();
     let o = slice_from_mut(out, 3 + nc * 7);
     o[0] = res.num_groups as f64;
@@ -28,6 +28,109 @@ export function ztest_f64(dp, l, mu0, s) {
   return res;
 }
 
+export function ttest_alt_f64(dp, l, mu0, alt = 0) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().ttest_alt_f64(dp, l, mu0, alt, outPtr);
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
+  const res = { statistic: view[0], p_value: view[1], df: isNaN(view[2]) ? undefined : view[2] };
+  free(outPtr, 3 * 8);
+  return res;
+}
+export function ztest_alt_f64(dp, l, mu0, s, alt = 0) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().ztest_alt_f64(dp, l, mu0, s, alt, outPtr);
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
+  const res = { statistic: view[0], p_value: view[1], df: isNaN(view[2]) ? undefined : view[2] };
+  free(outPtr, 3 * 8);
+  return res;
+}
+
+// Alternative hypothesis codes: 0 = two-sided, 1 = less, 2 = greater
+function readTestResult(outPtr) {
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 5);
+  const res = {
+    statistic: view[0],
+    p_value: view[1],
+    df: isNaN(view[2]) ? undefined : view[2],
+    ci: isNaN(view[3]) && isNaN(view[4]) ? undefined : [view[3], view[4]],
+  };
+  free(outPtr, 5 * 8);
+  return res;
+}
+export function ttest_ind_f64(ap, al, bp, bl, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().ttest_ind_f64(ap, al, bp, bl, alt, alpha, outPtr);
+  return readTestResult(outPtr);
+}
+export function ttest_welch_f64(ap, al, bp, bl, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().ttest_welch_f64(ap, al, bp, bl, alt, alpha, outPtr);
+  return readTestResult(outPtr);
+}
+export function ttest_paired_f64(ap, bp, l, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().ttest_paired_f64(ap, bp, l, alt, alpha, outPtr);
+  return readTestResult(outPtr);
+}
+export function mann_whitney_u_f64(ap, al, bp, bl, alt = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().mann_whitney_u_f64(ap, al, bp, bl, alt, outPtr);
+  return readTestResult(outPtr);
+}
+export function wilcoxon_signed_rank_f64(dp, l, mu0 = 0, alt = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().wilcoxon_signed_rank_f64(dp, l, mu0, alt, outPtr);
+  return readTestResult(outPtr);
+}
+export function wilcoxon_signed_rank_paired_f64(ap, bp, l, alt = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().wilcoxon_signed_rank_paired_f64(ap, bp, l, alt, outPtr);
+  return readTestResult(outPtr);
+}
+export function kruskal_wallis_flat(dp, lp, ng) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().kruskal_wallis_flat(dp, lp, ng, outPtr);
+  return readTestResult(outPtr);
+}
+
+// Goodness of fit. `family` codes: 0 normal, 1 gamma, 2 beta, 3 student t, 4 chi-squared,
+// 5 F, 6 exponential, 7 uniform, 8 cauchy, 9 laplace, 10 lognormal, 11 weibull, 12 pareto,
+// 13 triangular, 14 inverse gamma, 15 gumbel, 16 GEV, 17 logistic, 18 log-logistic,
+// 19 rayleigh, 20 erlang, 21 von Mises, 22 skew-normal; `params` follow the Rust constructor
+// order.
+export function ks_test_f64(dp, l, family, params, alt = 0) {
+  const [p1 = NaN, p2 = NaN, p3 = NaN] = params;
+  const outPtr = alloc(5 * 8);
+  wasmExports().ks_test_f64(dp, l, family, p1, p2, p3, alt, outPtr);
+  return readTestResult(outPtr);
+}
+export function ks_test_2samp_f64(ap, al, bp, bl, alt = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().ks_test_2samp_f64(ap, al, bp, bl, alt, outPtr);
+  return readTestResult(outPtr);
+}
+export function anderson_darling_f64(dp, l, family, params) {
+  const [p1 = NaN, p2 = NaN, p3 = NaN] = params;
+  const outPtr = alloc(5 * 8);
+  wasmExports().anderson_darling_f64(dp, l, family, p1, p2, p3, outPtr);
+  return readTestResult(outPtr);
+}
+export function anderson_darling_normal_f64(dp, l) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().anderson_darling_normal_f64(dp, l, outPtr);
+  return readTestResult(outPtr);
+}
+export function shapiro_wilk_f64(dp, l) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().shapiro_wilk_f64(dp, l, outPtr);
+  return readTestResult(outPtr);
+}
+export function jarque_bera_f64(dp, l) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().jarque_bera_f64(dp, l, outPtr);
+  return readTestResult(outPtr);
+}
+
 // CI
 export function normalci_f64(a, m, s) {
   const out = alloc(2 * 8);
@@ -45,6 +148,22 @@ export function tci_f64(a, m, s, n) {
   free(out, 2 * 8);
   return copy;
 }
+export function normalci_alt_f64(a, m, s, alt = 0) {
+  const out = alloc(2 * 8);
+  wasmExports().normalci_alt_f64(a, m, s, alt, out);
+  const v = new Float64Array(wasmExports().memory.buffer, out, 2);
+  const copy = new Float64Array(v);
+  free(out, 2 * 8);
+  return copy;
+}
+export function tci_alt_f64(a, m, s, n, alt = 0) {
+  const out = alloc(2 * 8);
+  wasmExports().tci_alt_f64(a, m, s, n, alt, out);
+  const v = new Float64Array(wasmExports().memory.buffer, out, 2);
+  const copy = new Float64Array(v);
+  free(out, 2 * 8);
+  return copy;
+}
 
 // Regression
 export function regress_f64(xp, xl, yp, yl) {
@@ -160,6 +279,72 @@ export function regress_simd_residuals_inplace_f32(xp, xl, yp, yl, rop) {
   return res;
 }
 
+export function regress_stats_f64(xp, yp, len) {
+  const out = alloc(13 * 8);
+  wasmExports().regress_stats_f64(xp, yp, len, out);
+  const v = new Float64Array(wasmExports().memory.buffer, out, 13);
+  const res = {
+    slope: v[0],
+    intercept: v[1],
+    r_squared: v[2],
+    slope_se: v[3],
+    intercept_se: v[4],
+    slope_t: v[5],
+    intercept_t: v[6],
+    slope_p: v[7],
+    intercept_p: v[8],
+    residual_se: v[9],
+    df: v[10],
+    f_statistic: v[11],
+    f_p_value: v[12],
+  };
+  free(out, 13 * 8);
+  return res;
+}
+// kind: 0 = prediction interval, 1 = confidence interval for the mean response
+export function regress_predict_interval_f64(xp, yp, len, np, nl, level = 0.95, kind = 0) {
+  const out = alloc(nl * 3 * 8);
+  wasmExports().regress_predict_interval_f64(xp, yp, len, np, nl, level, kind, out);
+  const v = new Float64Array(wasmExports().memory.buffer, out, nl * 3);
+  const res = Array.from({ length: nl }, (_, i) => ({ fit: v[i * 3], lower: v[i * 3 + 1], upper: v[i * 3 + 2] }));
+  free(out, nl * 3 * 8);
+  return res;
+}
+
+// Multiple regression: xp is a row-major rows x cols design matrix
+export function ols_f64(xp, rows, cols, yp, intercept = true, conf = 0.95) {
+  const p = cols + (intercept ? 1 : 0);
+  const cOut = alloc(p * 6 * 8);
+  const sOut = alloc(10 * 8);
+  const rOut = alloc(rows * 8);
+  wasmExports().ols_f64(xp, rows, cols, yp, intercept, conf, cOut, sOut, rOut);
+  const cv = new Float64Array(wasmExports().memory.buffer, cOut, p * 6);
+  const pick = (k) => Array.from({ length: p }, (_, i) => cv[i * 6 + k]);
+  const sv = new Float64Array(wasmExports().memory.buffer, sOut, 10);
+  const res = {
+    coefficients: pick(0),
+    std_errors: pick(1),
+    t_values: pick(2),
+    p_values: pick(3),
+    ci_lower: pick(4),
+    ci_upper: pick(5),
+    r_squared: sv[0],
+    adj_r_squared: sv[1],
+    f_statistic: sv[2],
+    f_p_value: sv[3],
+    df_model: sv[4],
+    df_residual: sv[5],
+    sigma: sv[6],
+    log_likelihood: sv[7],
+    aic: sv[8],
+    bic: sv[9],
+    residuals: { ptr: rOut, len: rows },
+  };
+  free(cOut, p * 6 * 8);
+  free(sOut, 10 * 8);
+  return res;
+}
+
 // ANOVA & Chi-Square
 export function anova_f_score_flat(dp, lp, ng) { return wasmExports().anova_f_score_flat(dp, lp, ng); }
 export function anova_flat(dp, lp, ng, outPtr) {
@@ -172,6 +357,137 @@ export function chi_square_test(p1, p2, len, card1, card2, outPtr) {
   const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
   return { statistic: view[0], p_value: view[1], df: view[2] };
 }
+// Exact tests
+export function fisher_exact_f64(a, b, c, d, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(4 * 8);
+  wasmExports().fisher_exact_f64(a, b, c, d, alt, alpha, outPtr);
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 4);
+  const res = { odds_ratio: view[0], p_value: view[1], ci: [view[2], view[3]] };
+  free(outPtr, 4 * 8);
+  return res;
+}
+export function fisher_exact_table_u32(tp, rows, cols) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().fisher_exact_table_u32(tp, rows, cols, outPtr);
+  return readTestResult(outPtr);
+}
+export function fisher_exact_table_simulated_u32(tp, rows, cols, replicates = 2000, seed = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().fisher_exact_table_simulated_u32(tp, rows, cols, replicates, seed, outPtr);
+  return readTestResult(outPtr);
+}
+export function binomial_test_f64(successes, trials, p = 0.5, alt = 0, alpha = 0.05) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().binomial_test_f64(successes, trials, p, alt, alpha, outPtr);
+  return readTestResult(outPtr);
+}
+// Chi-square `method`: 0 = Pearson, 1 = Yates, 2 = G-test.
+function readChiSquare(outPtr) {
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
+  const res = { statistic: view[0], p_value: view[1], df: view[2] };
+  free(outPtr, 3 * 8);
+  return res;
+}
+export function chi_square_test_codes_u32(p1, p2, len, method = 0) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().chi_square_test_codes_u32(p1, p2, len, method, outPtr);
+  return readChiSquare(outPtr);
+}
+export function chi_square_test_table_f64(tp, rows, cols, method = 0) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().chi_square_test_table_f64(tp, rows, cols, method, outPtr);
+  return readChiSquare(outPtr);
+}
+export function chi_square_goodness_of_fit_f64(op, ol, ep = 0, el = 0, method = 0) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().chi_square_goodness_of_fit_f64(op, ol, ep, el, method, outPtr);
+  return readChiSquare(outPtr);
+}
+function readEffectSize(outPtr) {
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
+  const res = { estimate: view[0], ci: [view[1], view[2]] };
+  free(outPtr, 3 * 8);
+  return res;
+}
+export function cohens_d_f64(ap, al, bp, bl, alpha = 0.05) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().cohens_d_f64(ap, al, bp, bl, alpha, outPtr);
+  return readEffectSize(outPtr);
+}
+export function hedges_g_f64(ap, al, bp, bl, alpha = 0.05) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().hedges_g_f64(ap, al, bp, bl, alpha, outPtr);
+  return readEffectSize(outPtr);
+}
+export function cohens_d_one_sample_f64(dp, len, mu0 = 0, alpha = 0.05) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().cohens_d_one_sample_f64(dp, len, mu0, alpha, outPtr);
+  return readEffectSize(outPtr);
+}
+export function cohens_d_paired_f64(ap, bp, len, alpha = 0.05) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().cohens_d_paired_f64(ap, bp, len, alpha, outPtr);
+  return readEffectSize(outPtr);
+}
+export function anova_effect_size_flat(dp, lp, ng) {
+  const outPtr = alloc(3 * 8);
+  wasmExports().anova_effect_size_flat(dp, lp, ng, outPtr);
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 3);
+  const res = { eta_squared: view[0], partial_eta_squared: view[1], omega_squared: view[2] };
+  free(outPtr, 3 * 8);
+  return res;
+}
+export function partial_eta_squared_f64(f, dfEffect, dfError) { return wasmExports().partial_eta_squared_f64(f, dfEffect, dfError); }
+export function cramers_v_table_f64(tp, rows, cols) { return wasmExports().cramers_v_table_f64(tp, rows, cols); }
+export function phi_coefficient_f64(a, b, c, d) { return wasmExports().phi_coefficient_f64(a, b, c, d); }
+export function rank_biserial_f64(ap, al, bp, bl) { return wasmExports().rank_biserial_f64(ap, al, bp, bl); }
+export function rank_biserial_one_sample_f64(dp, len, mu0 = 0) { return wasmExports().rank_biserial_one_sample_f64(dp, len, mu0); }
+export function rank_biserial_paired_f64(ap, bp, len) { return wasmExports().rank_biserial_paired_f64(ap, bp, len); }
+// Power analysis. t-test `design`: 0 = one-sample, 1 = two-sample (n per group), 2 = paired.
+export function power_z_test_f64(effect, n, alpha = 0.05, alt = 0) { return wasmExports().power_z_test_f64(effect, n, alpha, alt); }
+export function sample_size_z_test_f64(effect, power = 0.8, alpha = 0.05, alt = 0) { return wasmExports().sample_size_z_test_f64(effect, power, alpha, alt); }
+export function power_t_test_f64(effect, n, alpha = 0.05, design = 0, alt = 0) { return wasmExports().power_t_test_f64(effect, n, alpha, design, alt); }
+export function sample_size_t_test_f64(effect, power = 0.8, alpha = 0.05, design = 0, alt = 0) { return wasmExports().sample_size_t_test_f64(effect, power, alpha, design, alt); }
+export function power_anova_f64(effect, groups, n, alpha = 0.05) { return wasmExports().power_anova_f64(effect, groups, n, alpha); }
+export function sample_size_anova_f64(effect, groups, power = 0.8, alpha = 0.05) { return wasmExports().sample_size_anova_f64(effect, groups, power, alpha); }
+export function power_chi_square_f64(effect, n, df, alpha = 0.05) { return wasmExports().power_chi_square_f64(effect, n, df, alpha); }
+export function sample_size_chi_square_f64(effect, df, power = 0.8, alpha = 0.05) { return wasmExports().sample_size_chi_square_f64(effect, df, power, alpha); }
+export function power_two_proportions_f64(p1, p2, n, alpha = 0.05, alt = 0) { return wasmExports().power_two_proportions_f64(p1, p2, n, alpha, alt); }
+export function sample_size_two_proportions_f64(p1, p2, power = 0.8, alpha = 0.05, alt = 0) { return wasmExports().sample_size_two_proportions_f64(p1, p2, power, alpha, alt); }
+export function cohens_h_f64(p1, p2) { return wasmExports().cohens_h_f64(p1, p2); }
+// Multiple testing. `method`: 0 = Bonferroni, 1 = Holm, 2 = Hochberg, 3 = Hommel,
+// 4 = Benjamini-Hochberg, 5 = Benjamini-Yekutieli. Results are written to `outPtr` (len f64s).
+export function p_adjust_f64(pp, len, method, outPtr) { return wasmExports().p_adjust_f64(pp, len, method, outPtr); }
+export function q_values_f64(pp, len, lambda, outPtr) { return wasmExports().q_values_f64(pp, len, lambda, outPtr); }
+// Resampling. Bootstrap `method`: 0 = percentile, 1 = basic, 2 = BCa. One-sample `statistic`:
+// 0 = mean, 1 = median, 2 = sample sd, 3 = IQR, 4 = coefficient of variation. Paired
+// `statistic`: 0 = Pearson, 1 = Spearman. Two-sample `statistic`: 0 = mean diff, 1 = median diff.
+function readBootstrap(outPtr) {
+  const view = new Float64Array(wasmExports().memory.buffer, outPtr, 5);
+  const res = { estimate: view[0], std_error: view[1], bias: view[2], ci: [view[3], view[4]] };
+  free(outPtr, 5 * 8);
+  return res;
+}
+export function bootstrap_ci_f64(dp, l, statistic = 0, method = 0, replicates = 2000, alpha = 0.05, seed = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().bootstrap_ci_f64(dp, l, statistic, method, replicates, alpha, seed, outPtr);
+  return readBootstrap(outPtr);
+}
+export function bootstrap_ci_paired_f64(xp, yp, l, statistic = 0, method = 0, replicates = 2000, alpha = 0.05, seed = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().bootstrap_ci_paired_f64(xp, yp, l, statistic, method, replicates, alpha, seed, outPtr);
+  return readBootstrap(outPtr);
+}
+export function permutation_test_f64(ap, al, bp, bl, statistic = 0, alt = 0, permutations = 9999, seed = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().permutation_test_f64(ap, al, bp, bl, statistic, alt, permutations, seed, outPtr);
+  return readTestResult(outPtr);
+}
+export function permutation_test_paired_f64(xp, yp, l, statistic = 0, alt = 0, permutations = 9999, seed = 0) {
+  const outPtr = alloc(5 * 8);
+  wasmExports().permutation_test_paired_f64(xp, yp, l, statistic, alt, permutations, seed, outPtr);
+  return readTestResult(outPtr);
+}
 export function anova_f_score_categorical(gp, pv, len) { return wasmExports().anova_f_score_categorical(gp, pv, len); }
 export function anova_categorical(gp, pv, len, outPtr) {
   wasmExports().anova_categorical(gp, pv, len, outPtr);
@@ -181,4 +497,7 @@ export function anova_categorical(gp, pv, len, outPtr) {
 export function tukey_hsd_categorical(gp, pv, len, outPtr) {
   return wasmExports().tukey_hsd_categorical(gp, pv, len, outPtr);
 }
+export function tukey_hsd_categorical_conf(gp, pv, len, conf, outPtr) {
+  return wasmExports().tukey_hsd_categorical_conf(gp, pv, len, conf, outPtr);
+}
 
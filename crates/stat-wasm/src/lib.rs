@@ -914,19 +914,22 @@ pub unsafe extern "C" fn chi_square_test(
     cp1: *const i32,
     cp2: *const i32,
     l: usize,
-    c1: usize,
-    c2: usize,
+    _c1: usize,
+    _c2: usize,
     out: *mut f64,
 ) -> isize {
-    let s1: Vec<String> = unsafe { std::slice::from_raw_parts(cp1, l) }
-        .iter()
-        .map(|x| x.to_string())
-        .collect();
-    let s2: Vec<String> = unsafe { std::slice::from_raw_parts(cp2, l) }
-        .iter()
-        .map(|x| x.to_string())
-        .collect();
-    let res = stat_core::chi_square_test_with_cardinality(&s1, &s2, Some(c1), Some(c2));
+    // The cardinality hints are unused now that codes feed the contingency table directly.
+    let codes = |p: *const i32| -> Vec<u32> {
+        unsafe { std::slice::from_raw_parts(p, l) }
+            .iter()
+            .map(|&x| x as u32)
+            .collect()
+    };
+    let res = stat_core::chi_square_test_codes(
+        &codes(cp1),
+        &codes(cp2),
+        stat_core::ChiSquareMethod::Pearson,
+    );
     let o = slice_from_mut(out, 3);
     o[0] = res.statistic;
     o[1] = res.p_value;